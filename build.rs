@@ -59,6 +59,8 @@ fn main() {
 const RSRC_GLOBS: &[&str] = &[
     "data/backgrounds/*.bg",
     "data/fonts/*.ahf",
+    "data/locale/*.toml",
+    "data/scenes/**/*.scene",
     "data/sprites/**/*.ahi",
 ];
 
@@ -104,8 +104,8 @@ impl ClueDisplay {
     pub fn set_visible(&mut self, visible: bool) { self.visible = visible; }
 }
 
-impl Element<&'static str, bool> for ClueDisplay {
-    fn draw(&self, clue: &&'static str, canvas: &mut Canvas) {
+impl<'a> Element<&'a str, bool> for ClueDisplay {
+    fn draw(&self, clue: &&'a str, canvas: &mut Canvas) {
         if self.visible {
             canvas.draw_text(&self.font,
                              Align::Center,
@@ -115,7 +115,7 @@ impl Element<&'static str, bool> for ClueDisplay {
         }
     }
 
-    fn handle_event(&mut self, event: &Event, _: &mut &'static str)
+    fn handle_event(&mut self, event: &Event, _: &mut &'a str)
                     -> Action<bool> {
         if self.visible {
             self.arrows.handle_event(event, &mut ())
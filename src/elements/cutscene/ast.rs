@@ -18,28 +18,18 @@
 // +--------------------------------------------------------------------------+
 
 use elements::Paragraph;
-use gui::{Align, Point, Resources, Sound};
+use gui::{Align, Message, Point, Resources, Sound};
+use save::TalkStyle;
 use super::scene::{AnimNode, DarkNode, JumpNode, LightNode, LoopNode,
                    ParallelNode, PlaceNode, QueueNode, RemoveNode, Scene,
                    SceneNode, SequenceNode, SetBgNode, SetPosNode,
                    SetSpriteNode, ShakeNode, SlideNode, SoundNode, SwapNode,
                    TalkNode, WaitNode};
+use super::script::intern_str;
 use super::theater::TalkPos;
 
 // ========================================================================= //
 
-#[derive(Clone, Copy)]
-pub enum TalkStyle {
-    Comm,
-    Evil,
-    Good,
-    Normal,
-    System,
-    Thought,
-}
-
-// ========================================================================= //
-
 pub enum Ast {
     Seq(Vec<Ast>),
     Par(Vec<Ast>),
@@ -64,29 +54,43 @@ pub enum Ast {
 
 impl Ast {
     pub fn compile_scene(resources: &mut Resources, nodes: Vec<Ast>) -> Scene {
+        let mut lines = 0;
         Scene::new(nodes
                        .into_iter()
-                       .map(|ast| ast.to_scene_node(resources))
+                       .map(|ast| ast.to_scene_node(resources, &mut lines))
                        .collect())
     }
 
-    fn to_scene_node(self, resources: &mut Resources) -> Box<SceneNode> {
+    /// Returns a `Talk` node for a line of dialogue built in Rust rather than
+    /// a script, with the message translated for the current locale.
+    pub fn talk_message(resources: &Resources, slot: i32, style: TalkStyle,
+                        pos: TalkPos, message: Message)
+                        -> Ast {
+        let text = resources.catalog().message(message).to_string();
+        Ast::Talk(slot, style, pos, intern_str(text))
+    }
+
+    /// Compiles this node, numbering its `Talk` nodes in order starting from
+    /// `*lines`.
+    fn to_scene_node(self, resources: &mut Resources, lines: &mut usize)
+                     -> Box<SceneNode> {
         match self {
             Ast::Seq(asts) => {
                 let nodes = asts.into_iter()
-                    .map(|ast| ast.to_scene_node(resources))
+                    .map(|ast| ast.to_scene_node(resources, lines))
                     .collect();
                 Box::new(SequenceNode::new(nodes))
             }
             Ast::Par(asts) => {
                 let nodes = asts.into_iter()
-                    .map(|ast| ast.to_scene_node(resources))
+                    .map(|ast| ast.to_scene_node(resources, lines))
                     .collect();
                 Box::new(ParallelNode::new(nodes))
             }
             Ast::Loop(min, max, ast) => {
                 let max = if max <= 0 { None } else { Some(max) };
-                Box::new(LoopNode::new(ast.to_scene_node(resources), min, max))
+                let node = ast.to_scene_node(resources, lines);
+                Box::new(LoopNode::new(node, min, max))
             }
             Ast::Anim(slot, name, indices, slowdown) => {
                 let all_sprites = resources.get_sprites(name);
@@ -110,7 +114,7 @@ impl Ast {
             }
             Ast::Place(slot, name, index, (x, y)) => {
                 let sprite = resources.get_sprites(name)[index].clone();
-                Box::new(PlaceNode::new(slot, sprite, Point::new(x, y)))
+                Box::new(PlaceNode::new(slot, name, sprite, Point::new(x, y)))
             }
             Ast::Queue(v1, v2) => Box::new(QueueNode::new((v1, v2))),
             Ast::Remove(slot) => Box::new(RemoveNode::new(slot)),
@@ -159,13 +163,82 @@ impl Ast {
                 let sprites = resources.get_sprites(bubble_name);
                 let paragraph =
                     Paragraph::new(resources, init_font, init_align, text);
-                Box::new(TalkNode::new(slot, sprites, color, pos, paragraph))
+                let line = *lines;
+                *lines += 1;
+                Box::new(TalkNode::new(slot,
+                                       style,
+                                       text,
+                                       line,
+                                       sprites,
+                                       color,
+                                       pos,
+                                       paragraph))
             }
             Ast::Wait(duration) => Box::new(WaitNode::new(duration)),
         }
     }
 }
 
+#[cfg(test)]
+impl Ast {
+    /// Renders the AST as a string, so that trees can be compared in tests.
+    /// Sounds can't be compared, so they all render the same.
+    pub fn describe(&self) -> String {
+        let list = |asts: &Vec<Ast>| {
+            asts.iter().map(Ast::describe).collect::<Vec<String>>().join(" ")
+        };
+        match *self {
+            Ast::Seq(ref asts) => format!("Seq[{}]", list(asts)),
+            Ast::Par(ref asts) => format!("Par[{}]", list(asts)),
+            Ast::Loop(min, max, ref ast) => {
+                format!("Loop({}, {}, {})", min, max, ast.describe())
+            }
+            Ast::Anim(slot, name, indices, slowdown) => {
+                format!("Anim({}, {:?}, {:?}, {})",
+                        slot,
+                        name,
+                        indices,
+                        slowdown)
+            }
+            Ast::Dark(dark) => format!("Dark({})", dark),
+            Ast::Jump(slot, pos, duration) => {
+                format!("Jump({}, {:?}, {})", slot, pos, duration)
+            }
+            Ast::Light(slot, light) => format!("Light({}, {})", slot, light),
+            Ast::Place(slot, name, index, pos) => {
+                format!("Place({}, {:?}, {}, {:?})", slot, name, index, pos)
+            }
+            Ast::Queue(v1, v2) => format!("Queue({}, {})", v1, v2),
+            Ast::Remove(slot) => format!("Remove({})", slot),
+            Ast::SetBg(name) => format!("SetBg({:?})", name),
+            Ast::SetPos(slot, pos) => format!("SetPos({}, {:?})", slot, pos),
+            Ast::SetSprite(slot, name, index) => {
+                format!("SetSprite({}, {:?}, {})", slot, name, index)
+            }
+            Ast::Shake(amount) => format!("Shake({})", amount),
+            Ast::Slide(slot, pos, accel, decel, duration) => {
+                format!("Slide({}, {:?}, {}, {}, {})",
+                        slot,
+                        pos,
+                        accel,
+                        decel,
+                        duration)
+            }
+            Ast::Sound(_) => "Sound".to_string(),
+            Ast::Swap(slot1, slot2) => format!("Swap({}, {})", slot1, slot2),
+            Ast::Talk(slot, style, pos, text) => {
+                format!("Talk({}, {:?}, {:?}, {:?})", slot, style, pos, text)
+            }
+            Ast::Wait(duration) => format!("Wait({})", duration),
+        }
+    }
+
+    /// Renders each AST in the list as a string, for comparing in tests.
+    pub fn describe_all(asts: &[Ast]) -> Vec<String> {
+        asts.iter().map(Ast::describe).collect()
+    }
+}
+
 // ========================================================================= //
 
 const BLACK: (u8, u8, u8) = (0, 0, 0);
@@ -19,14 +19,16 @@
 
 mod ast;
 mod scene;
+mod script;
 mod theater;
 
-pub use self::ast::{Ast, TalkStyle};
+pub use self::ast::Ast;
 pub use self::scene::{AnimNode, DarkNode, JumpNode, LightNode, LoopNode,
                       ParallelNode, PlaceNode, QueueNode, RemoveNode, Scene,
                       SceneNode, SequenceNode, SetBgNode, SetPosNode,
                       SetSpriteNode, ShakeNode, SlideNode, SoundNode,
                       TalkNode, WaitNode};
 pub use self::theater::{TalkPos, Theater};
+pub use save::TalkStyle;
 
 // ========================================================================= //
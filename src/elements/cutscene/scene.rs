@@ -23,6 +23,7 @@ use std::rc::Rc;
 use elements::Paragraph;
 use gui::{Action, Background, Canvas, Element, Event, FRAME_DELAY_MILLIS,
           Keycode, Point, Sound, Sprite};
+use save::TalkStyle;
 use super::theater::{TalkPos, Theater};
 
 // ========================================================================= //
@@ -35,6 +36,7 @@ const FRAMES_TO_HIDE_SKIP: i32 = 50;
 
 #[derive(Clone)]
 pub struct Scene {
+    name: String,
     nodes: Vec<Box<SceneNode>>,
     index: usize,
     began: bool,
@@ -45,6 +47,7 @@ pub struct Scene {
 impl Scene {
     pub fn new(nodes: Vec<Box<SceneNode>>) -> Scene {
         Scene {
+            name: String::new(),
             nodes: nodes,
             index: 0,
             began: false,
@@ -55,7 +58,12 @@ impl Scene {
 
     pub fn empty() -> Scene { Scene::new(Vec::new()) }
 
+    /// Sets the scene's ID (e.g. `cube_tangle/intro`), which identifies its
+    /// lines of dialogue in the player's transcript.
+    pub fn set_name(&mut self, name: String) { self.name = name; }
+
     pub fn begin(&mut self, theater: &mut Theater) {
+        theater.set_scene_name(&self.name);
         if !self.began {
             if !self.nodes.is_empty() {
                 self.nodes[0].begin(theater, true);
@@ -71,6 +79,7 @@ impl Scene {
         if !theater.drain_queue().is_empty() {
             debug_assert!(false, "Theater queue was not drained.");
         }
+        theater.set_scene_name(&self.name);
         let mut changed = false;
         if self.index < self.nodes.len() {
             changed |= self.nodes[self.index].tick(theater, false);
@@ -92,6 +101,7 @@ impl Scene {
     pub fn show_skip(&self) -> bool { self.skip_clicks >= CLICKS_TO_SHOW_SKIP }
 
     pub fn skip(&mut self, theater: &mut Theater) {
+        theater.set_scene_name(&self.name);
         while self.index < self.nodes.len() {
             self.nodes[self.index].skip(theater);
             self.index += 1;
@@ -157,7 +167,9 @@ impl Element<Theater, ()> for Scene {
                         }
                     }
                     if self.is_paused() {
-                        self.unpause();
+                        if !theater.reveal_all_speech() {
+                            self.unpause();
+                        }
                         redraw = true;
                     }
                     Action::redraw_if(redraw).and_stop()
@@ -615,14 +627,18 @@ impl SceneNode for LightNode {
 #[derive(Clone)]
 pub struct PlaceNode {
     slot: i32,
+    name: &'static str,
     sprite: Sprite,
     position: Point,
 }
 
 impl PlaceNode {
-    pub fn new(slot: i32, sprite: Sprite, position: Point) -> PlaceNode {
+    pub fn new(slot: i32, name: &'static str, sprite: Sprite,
+               position: Point)
+               -> PlaceNode {
         PlaceNode {
             slot: slot,
+            name: name,
             sprite: sprite,
             position: position,
         }
@@ -635,7 +651,10 @@ impl SceneNode for PlaceNode {
     fn begin(&mut self, theater: &mut Theater, _: bool) { self.skip(theater); }
 
     fn skip(&mut self, theater: &mut Theater) {
-        theater.place_actor(self.slot, self.sprite.clone(), self.position);
+        theater.place_actor(self.slot,
+                            self.name,
+                            self.sprite.clone(),
+                            self.position);
     }
 }
 
@@ -910,6 +929,9 @@ impl SceneNode for SwapNode {
 #[derive(Clone)]
 pub struct TalkNode {
     slot: i32,
+    style: TalkStyle,
+    text: &'static str,
+    line: usize,
     bubble_sprites: Vec<Sprite>,
     bg_color: (u8, u8, u8),
     talk_pos: TalkPos,
@@ -919,12 +941,15 @@ pub struct TalkNode {
 }
 
 impl TalkNode {
-    pub fn new(slot: i32, bubble_sprites: Vec<Sprite>,
-               bg_color: (u8, u8, u8), talk_pos: TalkPos,
-               paragraph: Paragraph)
+    pub fn new(slot: i32, style: TalkStyle, text: &'static str, line: usize,
+               bubble_sprites: Vec<Sprite>, bg_color: (u8, u8, u8),
+               talk_pos: TalkPos, paragraph: Paragraph)
                -> TalkNode {
         TalkNode {
             slot: slot,
+            style: style,
+            text: text,
+            line: line,
             bubble_sprites: bubble_sprites,
             bg_color: bg_color,
             talk_pos: talk_pos,
@@ -947,6 +972,7 @@ impl SceneNode for TalkNode {
         } else {
             self.status = Status::Twiddling;
         }
+        theater.record_speech(self.slot, self.style, self.text, self.line);
         theater.set_actor_speech(self.slot,
                                  self.bubble_sprites.clone(),
                                  self.bg_color,
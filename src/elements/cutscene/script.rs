@@ -0,0 +1,952 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+//! Parser for cutscene script files (`data/scenes/**/*.scene`).
+//!
+//! Every location's scenes are written as scripts, except for System
+//! Failure's password hints, which are built in Rust from a table of
+//! messages (see `data/locale/en.toml`).  Each location's `scenes.rs` tests
+//! check that its scripts parse to the same ASTs as the Rust code they
+//! replaced.
+//!
+//! A script is a sequence of statements, each of which becomes one `Ast`
+//! node; the top-level statements form the list passed to
+//! `Ast::compile_scene`.  Whitespace between tokens is insignificant, and `#`
+//! starts a comment that runs to the end of the line.  Integer arguments may
+//! name a constant declared earlier with `const NAME = 5`, or with
+//! `param NAME = 5` for a value (such as a position just off the edge of the
+//! screen) that the code loading the scene may replace.  The statements are:
+//!
+//! ```text
+//! seq { ... }                      par { ... }
+//! loop MIN MAX STATEMENT           anim SLOT "sprites" [0, 1, 2] SLOWDOWN
+//! dark on|off                      jump SLOT (X, Y) DURATION
+//! light SLOT on|off                place SLOT "sprites" INDEX (X, Y)
+//! queue V1 V2                      remove SLOT
+//! set-bg "background"              set-pos SLOT (X, Y)
+//! set-sprite SLOT "sprites" INDEX  shake AMOUNT
+//! slide SLOT (X, Y) [accel] [decel] DURATION
+//! sound NAME [ARG]                 swap SLOT1 SLOT2
+//! talk ID SLOT STYLE POS "text"    wait DURATION
+//! ```
+//!
+//! `ID` is a name for the line of dialogue (such as `oof`) that is unique
+//! within the script; `STYLE` is one of `comm`, `evil`, `good`, `normal`,
+//! `system`, or `thought`; `POS` is one of `auto`, `NE`, `NW`, `E`, `W`,
+//! `SE`, or `SW`; and `NAME` is the name of a `Sound` constructor, such as
+//! `talk_hi` or `platform_shift 2`.  Strings may contain `\"`, `\\`, and `\n`
+//! escapes, and may span several lines; a line break within a string becomes
+//! a newline, and leading whitespace on the following line is ignored.
+//!
+//! When a scene is loaded, the text of each `talk` statement is replaced with
+//! the message `scene.<name>.<ID>` from the current locale's catalog, if it
+//! has one, so translations stay attached to their lines when statements are
+//! added, removed, or reordered.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+#[cfg(test)]
+use std::fs;
+#[cfg(test)]
+use std::path::PathBuf;
+
+use gui::{Catalog, Resources, Sound};
+use save::TalkStyle;
+use super::ast::Ast;
+use super::scene::Scene;
+use super::theater::TalkPos;
+
+// ========================================================================= //
+
+/// An error encountered while parsing a cutscene script, with the 1-based
+/// line and column where it occurred.
+#[derive(Debug)]
+pub struct ScriptError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter,
+               "line {}, column {}: {}",
+               self.line,
+               self.column,
+               self.message)
+    }
+}
+
+// ========================================================================= //
+
+impl Ast {
+    /// Parses the text of a cutscene script into the list of nodes that
+    /// `Ast::compile_scene` expects.  If a catalog and scene name are given,
+    /// the text of each `talk` statement is replaced with its translation
+    /// from the catalog, if there is one.  The value of each `param` named in
+    /// `params` is replaced with the given value; it is an error to give a
+    /// value for a `param` that the script doesn't declare.
+    pub fn parse_script(source: &str, catalog: Option<(&Catalog, &str)>,
+                        params: &[(&str, i32)])
+                        -> Result<Vec<Ast>, ScriptError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens: tokens,
+            index: 0,
+            last: 0,
+            consts: HashMap::new(),
+            params: params,
+            param_names: HashSet::new(),
+            talk_ids: HashSet::new(),
+            catalog: catalog,
+        };
+        parser.parse_script()
+    }
+
+    /// Loads the script `data/scenes/<name>.scene` and compiles it into a
+    /// scene.  Panics if the script is missing or malformed.
+    pub fn load_scene(resources: &mut Resources, name: &str) -> Scene {
+        Ast::load_scene_with_params(resources, name, &[])
+    }
+
+    /// Like `load_scene`, but replaces the value of each `param` named in
+    /// `params` with the given value.
+    pub fn load_scene_with_params(resources: &mut Resources, name: &str,
+                                  params: &[(&str, i32)])
+                                  -> Scene {
+        let source = resources.get_scene_script(name);
+        let catalog = resources.catalog();
+        match Ast::parse_script(&source, Some((&catalog, name)), params) {
+            Ok(ast) => Ast::compile_scene(resources, ast),
+            Err(error) => panic!("scenes/{}.scene: {}", name, error),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Ast {
+    /// Asserts that the script `data/scenes/<name>.scene` parses to the same
+    /// nodes as `expected`, the Rust code that the script replaced.
+    pub fn assert_script_matches(name: &str, expected: Vec<Ast>) {
+        Ast::assert_script_with_params_matches(name, &[], expected);
+    }
+
+    /// Like `assert_script_matches`, but parses the script with the given
+    /// `param` values.
+    pub fn assert_script_with_params_matches(name: &str,
+                                             params: &[(&str, i32)],
+                                             expected: Vec<Ast>) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("data/scenes")
+            .join(format!("{}.scene", name));
+        let source = fs::read_to_string(&path).unwrap();
+        let actual = match Ast::parse_script(&source, None, params) {
+            Ok(actual) => actual,
+            Err(error) => panic!("scenes/{}.scene: {}", name, error),
+        };
+        assert_eq!(Ast::describe_all(&actual), Ast::describe_all(&expected));
+    }
+}
+
+// ========================================================================= //
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Int(i32),
+    Float(f64),
+    Str(String),
+    Punct(char),
+    End,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Token::Word(ref word) => write!(formatter, "`{}`", word),
+            Token::Int(value) => write!(formatter, "`{}`", value),
+            Token::Float(value) => write!(formatter, "`{}`", value),
+            Token::Str(_) => formatter.write_str("string"),
+            Token::Punct(chr) => write!(formatter, "`{}`", chr),
+            Token::End => formatter.write_str("end of file"),
+        }
+    }
+}
+
+struct Lexer {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Lexer {
+    fn peek(&self) -> Option<char> { self.chars.get(self.index).cloned() }
+
+    fn advance(&mut self) -> Option<char> {
+        let chr = self.peek();
+        if let Some(chr) = chr {
+            self.index += 1;
+            if chr == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        chr
+    }
+
+    fn error(&self, message: String) -> ScriptError {
+        ScriptError {
+            line: self.line,
+            column: self.column,
+            message: message,
+        }
+    }
+}
+
+fn tokenize(source: &str)
+            -> Result<Vec<(Token, usize, usize)>, ScriptError> {
+    let mut lexer = Lexer {
+        chars: source.chars().collect(),
+        index: 0,
+        line: 1,
+        column: 1,
+    };
+    let mut tokens = Vec::new();
+    loop {
+        while let Some(chr) = lexer.peek() {
+            if chr == '#' {
+                while lexer.peek().map_or(false, |chr| chr != '\n') {
+                    lexer.advance();
+                }
+            } else if chr.is_whitespace() {
+                lexer.advance();
+            } else {
+                break;
+            }
+        }
+        let (line, column) = (lexer.line, lexer.column);
+        let token = match lexer.peek() {
+            None => {
+                tokens.push((Token::End, line, column));
+                return Ok(tokens);
+            }
+            Some('"') => lex_string(&mut lexer)?,
+            Some(chr) if chr == '-' || chr.is_digit(10) => {
+                lex_number(&mut lexer)?
+            }
+            Some(chr) if chr.is_alphabetic() || chr == '_' => {
+                let mut word = String::new();
+                while let Some(chr) = lexer.peek() {
+                    if chr.is_alphanumeric() || chr == '_' || chr == '-' {
+                        word.push(chr);
+                        lexer.advance();
+                    } else {
+                        break;
+                    }
+                }
+                Token::Word(word)
+            }
+            Some(chr) if "{}()[],=".contains(chr) => {
+                lexer.advance();
+                Token::Punct(chr)
+            }
+            Some(chr) => {
+                return Err(lexer.error(format!("unexpected character {:?}",
+                                               chr)));
+            }
+        };
+        tokens.push((token, line, column));
+    }
+}
+
+fn lex_string(lexer: &mut Lexer) -> Result<Token, ScriptError> {
+    let start = lexer.error("unterminated string".to_string());
+    lexer.advance();
+    let mut string = String::new();
+    loop {
+        match lexer.advance() {
+            None => return Err(start),
+            Some('"') => return Ok(Token::Str(string)),
+            Some('\\') => {
+                match lexer.peek() {
+                    Some('"') => string.push('"'),
+                    Some('\\') => string.push('\\'),
+                    Some('n') => string.push('\n'),
+                    Some(chr) => {
+                        let msg = format!("invalid escape \"\\{}\"", chr);
+                        return Err(lexer.error(msg));
+                    }
+                    None => return Err(start),
+                }
+                lexer.advance();
+            }
+            Some('\n') => {
+                string.push('\n');
+                while lexer.peek().map_or(false, |chr| chr == ' ' ||
+                                                       chr == '\t') {
+                    lexer.advance();
+                }
+            }
+            Some(chr) => string.push(chr),
+        }
+    }
+}
+
+fn lex_number(lexer: &mut Lexer) -> Result<Token, ScriptError> {
+    let start = lexer.error(String::new());
+    let mut text = String::new();
+    if lexer.peek() == Some('-') {
+        text.push('-');
+        lexer.advance();
+    }
+    while let Some(chr) = lexer.peek() {
+        if chr.is_digit(10) || chr == '.' {
+            text.push(chr);
+            lexer.advance();
+        } else {
+            break;
+        }
+    }
+    let token = if text.contains('.') {
+        text.parse().ok().map(Token::Float)
+    } else {
+        text.parse().ok().map(Token::Int)
+    };
+    token.ok_or_else(|| {
+        ScriptError {
+            message: format!("invalid number `{}`", text),
+            ..start
+        }
+    })
+}
+
+// ========================================================================= //
+
+struct Parser<'a> {
+    tokens: Vec<(Token, usize, usize)>,
+    index: usize,
+    last: usize,
+    consts: HashMap<String, i32>,
+    params: &'a [(&'a str, i32)],
+    param_names: HashSet<String>,
+    talk_ids: HashSet<String>,
+    catalog: Option<(&'a Catalog, &'a str)>,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_script(&mut self) -> Result<Vec<Ast>, ScriptError> {
+        let mut asts = Vec::new();
+        while *self.peek() != Token::End {
+            if *self.peek() == Token::Word("const".to_string()) {
+                self.next();
+                self.parse_const(false)?;
+            } else if *self.peek() == Token::Word("param".to_string()) {
+                self.next();
+                self.parse_const(true)?;
+            } else {
+                asts.push(self.parse_statement()?);
+            }
+        }
+        for &(name, _) in self.params {
+            if !self.param_names.contains(name) {
+                self.next();
+                let msg = format!("no `param` named `{}`", name);
+                return Err(self.error(msg));
+            }
+        }
+        Ok(asts)
+    }
+
+    /// Parses the rest of a `const` or `param` declaration.
+    fn parse_const(&mut self, is_param: bool) -> Result<(), ScriptError> {
+        let name = match self.next() {
+            Token::Word(name) => name,
+            token => return Err(self.unexpected(token, "a constant name")),
+        };
+        if self.consts.contains_key(&name) {
+            let msg = format!("constant `{}` is already defined", name);
+            return Err(self.error(msg));
+        }
+        self.expect_punct('=')?;
+        let mut value = match self.next() {
+            Token::Int(value) => value,
+            token => return Err(self.unexpected(token, "an integer")),
+        };
+        if is_param {
+            for &(param, param_value) in self.params {
+                if param == name {
+                    value = param_value;
+                }
+            }
+            self.param_names.insert(name.clone());
+        }
+        self.consts.insert(name, value);
+        Ok(())
+    }
+
+    fn parse_block(&mut self) -> Result<Vec<Ast>, ScriptError> {
+        self.expect_punct('{')?;
+        let mut asts = Vec::new();
+        while *self.peek() != Token::Punct('}') {
+            asts.push(self.parse_statement()?);
+        }
+        self.next();
+        Ok(asts)
+    }
+
+    fn parse_statement(&mut self) -> Result<Ast, ScriptError> {
+        let command = match self.next() {
+            Token::Word(command) => command,
+            token => return Err(self.unexpected(token, "a command")),
+        };
+        let ast = match command.as_str() {
+            "seq" => Ast::Seq(self.parse_block()?),
+            "par" => Ast::Par(self.parse_block()?),
+            "loop" => {
+                let min = self.parse_int()?;
+                let max = self.parse_int()?;
+                Ast::Loop(min, max, Box::new(self.parse_statement()?))
+            }
+            "anim" => {
+                let slot = self.parse_int()?;
+                let name = self.parse_string()?;
+                let indices = self.parse_indices()?;
+                let slowdown = self.parse_int()?;
+                Ast::Anim(slot, name, indices, slowdown)
+            }
+            "dark" => Ast::Dark(self.parse_on_off()?),
+            "jump" => {
+                let slot = self.parse_int()?;
+                let position = self.parse_point()?;
+                Ast::Jump(slot, position, self.parse_float()?)
+            }
+            "light" => {
+                let slot = self.parse_int()?;
+                Ast::Light(slot, self.parse_on_off()?)
+            }
+            "place" => {
+                let slot = self.parse_int()?;
+                let name = self.parse_string()?;
+                let index = self.parse_index()?;
+                Ast::Place(slot, name, index, self.parse_point()?)
+            }
+            "queue" => {
+                let value1 = self.parse_int()?;
+                Ast::Queue(value1, self.parse_int()?)
+            }
+            "remove" => Ast::Remove(self.parse_int()?),
+            "set-bg" => Ast::SetBg(self.parse_string()?),
+            "set-pos" => {
+                let slot = self.parse_int()?;
+                Ast::SetPos(slot, self.parse_point()?)
+            }
+            "set-sprite" => {
+                let slot = self.parse_int()?;
+                let name = self.parse_string()?;
+                Ast::SetSprite(slot, name, self.parse_index()?)
+            }
+            "shake" => Ast::Shake(self.parse_int()?),
+            "slide" => {
+                let slot = self.parse_int()?;
+                let position = self.parse_point()?;
+                let accel = self.parse_flag("accel");
+                let decel = self.parse_flag("decel");
+                let duration = self.parse_float()?;
+                Ast::Slide(slot, position, accel, decel, duration)
+            }
+            "sound" => Ast::Sound(self.parse_sound()?),
+            "swap" => {
+                let slot1 = self.parse_int()?;
+                Ast::Swap(slot1, self.parse_int()?)
+            }
+            "talk" => {
+                let id = self.parse_talk_id()?;
+                let slot = self.parse_int()?;
+                let style = self.parse_talk_style()?;
+                let pos = self.parse_talk_pos()?;
+                let text = self.parse_string()?;
+                Ast::Talk(slot, style, pos, self.localize_talk(&id, text))
+            }
+            "wait" => Ast::Wait(self.parse_float()?),
+            _ => {
+                let msg = format!("unknown command `{}`", command);
+                return Err(self.error(msg));
+            }
+        };
+        Ok(ast)
+    }
+
+    fn parse_int(&mut self) -> Result<i32, ScriptError> {
+        match self.next() {
+            Token::Int(value) => Ok(value),
+            Token::Word(ref name) if is_const_name(name) => {
+                match self.consts.get(name) {
+                    Some(&value) => Ok(value),
+                    None => {
+                        let msg = format!("undefined constant `{}`", name);
+                        Err(self.error(msg))
+                    }
+                }
+            }
+            token => Err(self.unexpected(token, "an integer")),
+        }
+    }
+
+    fn parse_index(&mut self) -> Result<usize, ScriptError> {
+        let value = self.parse_int()?;
+        if value < 0 {
+            let msg = format!("index must not be negative: {}", value);
+            return Err(self.error(msg));
+        }
+        Ok(value as usize)
+    }
+
+    fn parse_float(&mut self) -> Result<f64, ScriptError> {
+        match self.next() {
+            Token::Float(value) => Ok(value),
+            Token::Int(value) => Ok(value as f64),
+            token => Err(self.unexpected(token, "a number")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<&'static str, ScriptError> {
+        match self.next() {
+            Token::Str(string) => Ok(intern_str(string)),
+            token => Err(self.unexpected(token, "a string")),
+        }
+    }
+
+    fn parse_point(&mut self) -> Result<(i32, i32), ScriptError> {
+        self.expect_punct('(')?;
+        let x = self.parse_int()?;
+        self.expect_punct(',')?;
+        let y = self.parse_int()?;
+        self.expect_punct(')')?;
+        Ok((x, y))
+    }
+
+    fn parse_indices(&mut self) -> Result<&'static [usize], ScriptError> {
+        self.expect_punct('[')?;
+        let mut indices = Vec::new();
+        if *self.peek() != Token::Punct(']') {
+            indices.push(self.parse_index()?);
+            while *self.peek() == Token::Punct(',') {
+                self.next();
+                indices.push(self.parse_index()?);
+            }
+        }
+        self.expect_punct(']')?;
+        Ok(intern_indices(indices))
+    }
+
+    fn parse_on_off(&mut self) -> Result<bool, ScriptError> {
+        match self.next() {
+            Token::Word(ref word) if word == "on" => Ok(true),
+            Token::Word(ref word) if word == "off" => Ok(false),
+            token => Err(self.unexpected(token, "`on` or `off`")),
+        }
+    }
+
+    fn parse_flag(&mut self, flag: &str) -> bool {
+        if *self.peek() == Token::Word(flag.to_string()) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_talk_id(&mut self) -> Result<String, ScriptError> {
+        let id = match self.next() {
+            Token::Word(id) => id,
+            token => return Err(self.unexpected(token, "a talk ID")),
+        };
+        if is_const_name(&id) {
+            let msg = format!("expected a talk ID, found constant `{}`", id);
+            return Err(self.error(msg));
+        }
+        if !self.talk_ids.insert(id.clone()) {
+            let msg = format!("talk ID `{}` is already used", id);
+            return Err(self.error(msg));
+        }
+        Ok(id)
+    }
+
+    /// Returns the translation of a `talk` statement's text from the catalog,
+    /// if there is one, or else the English text.
+    fn localize_talk(&self, id: &str, english: &'static str) -> &'static str {
+        if let Some((catalog, scene)) = self.catalog {
+            let id = format!("scene.{}.{}", scene, id);
+            if let Some(translation) = catalog.get(&id) {
+                return intern_str(translation.to_string());
+            }
+        }
+        english
+    }
+
+    fn parse_talk_style(&mut self) -> Result<TalkStyle, ScriptError> {
+        let style = match self.next() {
+            Token::Word(ref word) => {
+                match word.as_str() {
+                    "comm" => Some(TalkStyle::Comm),
+                    "evil" => Some(TalkStyle::Evil),
+                    "good" => Some(TalkStyle::Good),
+                    "normal" => Some(TalkStyle::Normal),
+                    "system" => Some(TalkStyle::System),
+                    "thought" => Some(TalkStyle::Thought),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        style.ok_or_else(|| self.current_error("expected a talk style"))
+    }
+
+    fn parse_talk_pos(&mut self) -> Result<TalkPos, ScriptError> {
+        let pos = match self.next() {
+            Token::Word(ref word) => {
+                match word.as_str() {
+                    "auto" => Some(TalkPos::Auto),
+                    "NE" => Some(TalkPos::NE),
+                    "NW" => Some(TalkPos::NW),
+                    "E" => Some(TalkPos::E),
+                    "W" => Some(TalkPos::W),
+                    "SE" => Some(TalkPos::SE),
+                    "SW" => Some(TalkPos::SW),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        pos.ok_or_else(|| self.current_error("expected a talk position"))
+    }
+
+    fn parse_sound(&mut self) -> Result<Sound, ScriptError> {
+        let name = match self.next() {
+            Token::Word(name) => name,
+            token => return Err(self.unexpected(token, "a sound name")),
+        };
+        let sound = match name.as_str() {
+            "atlatl_beam" => Sound::atlatl_beam(self.parse_int()?),
+            "atlatl_charge" => {
+                Sound::atlatl_charge(self.parse_float()? as f32)
+            }
+            "beep" => Sound::beep(),
+            "bridge_break" => Sound::bridge_break(),
+            "bridge_crack" => Sound::bridge_crack(),
+            "character_collision" => Sound::character_collision(),
+            "device_drop" => Sound::device_drop(),
+            "device_pickup" => Sound::device_pickup(),
+            "device_rotate" => Sound::device_rotate(),
+            "device_slide" => Sound::device_slide(),
+            "explosion_small" => Sound::explosion_small(),
+            "mid_puzzle_chime" => Sound::mid_puzzle_chime(),
+            "platform_shift" => Sound::platform_shift(self.parse_int()?),
+            "platform_shift_double" => {
+                Sound::platform_shift_double(self.parse_int()?)
+            }
+            "small_jump" => Sound::small_jump(),
+            "solve_puzzle_chime" => Sound::solve_puzzle_chime(),
+            "spawn_zap" => Sound::spawn_zap(),
+            "talk_annoyed_hi" => Sound::talk_annoyed_hi(),
+            "talk_annoyed_lo" => Sound::talk_annoyed_lo(),
+            "talk_hi" => Sound::talk_hi(),
+            "talk_lo" => Sound::talk_lo(),
+            "talk_thought" => Sound::talk_thought(),
+            "transform_final" => Sound::transform_final(),
+            "transform_step" => Sound::transform_step(self.parse_index()?),
+            _ => {
+                let msg = format!("unknown sound `{}`", name);
+                return Err(self.error(msg));
+            }
+        };
+        Ok(sound)
+    }
+
+    fn expect_punct(&mut self, punct: char) -> Result<(), ScriptError> {
+        match self.next() {
+            Token::Punct(chr) if chr == punct => Ok(()),
+            token => {
+                let expected = format!("`{}`", punct);
+                Err(self.unexpected(token, &expected))
+            }
+        }
+    }
+
+    fn peek(&self) -> &Token { &self.tokens[self.index].0 }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.index].0.clone();
+        self.last = self.index;
+        if token != Token::End {
+            self.index += 1;
+        }
+        token
+    }
+
+    /// Returns an error located at the most recently consumed token.
+    fn error(&self, message: String) -> ScriptError {
+        let (_, line, column) = self.tokens[self.last];
+        ScriptError {
+            line: line,
+            column: column,
+            message: message,
+        }
+    }
+
+    fn current_error(&self, message: &str) -> ScriptError {
+        let token = &self.tokens[self.last].0;
+        self.error(format!("{}, found {}", message, token))
+    }
+
+    fn unexpected(&self, token: Token, expected: &str) -> ScriptError {
+        self.error(format!("expected {}, found {}", expected, token))
+    }
+}
+
+fn is_const_name(name: &str) -> bool {
+    name.chars().all(|chr| chr.is_uppercase() || chr.is_digit(10) ||
+                         chr == '_')
+}
+
+// ========================================================================= //
+
+// Scene nodes borrow their strings and index lists for the life of the
+// program (just like the ones written in Rust), so parsed and translated
+// values are leaked once and then shared by every later scene that uses the
+// same value.
+thread_local! {
+    static INTERNED_STRS: RefCell<HashSet<&'static str>> =
+        RefCell::new(HashSet::new());
+    static INTERNED_INDICES: RefCell<HashSet<&'static [usize]>> =
+        RefCell::new(HashSet::new());
+}
+
+pub fn intern_str(string: String) -> &'static str {
+    INTERNED_STRS.with(|set| {
+        let mut set = set.borrow_mut();
+        if let Some(&interned) = set.get(string.as_str()) {
+            return interned;
+        }
+        let interned: &'static str = Box::leak(string.into_boxed_str());
+        set.insert(interned);
+        interned
+    })
+}
+
+fn intern_indices(indices: Vec<usize>) -> &'static [usize] {
+    INTERNED_INDICES.with(|set| {
+        let mut set = set.borrow_mut();
+        if let Some(&interned) = set.get(indices.as_slice()) {
+            return interned;
+        }
+        let interned: &'static [usize] =
+            Box::leak(indices.into_boxed_slice());
+        set.insert(interned);
+        interned
+    })
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use gui::{Catalog, Sound};
+    use save::TalkStyle;
+    use super::super::ast::Ast;
+    use super::super::theater::TalkPos;
+
+    fn parse_error(source: &str) -> (usize, usize, String) {
+        match Ast::parse_script(source, None, &[]) {
+            Ok(_) => panic!("parse_script unexpectedly succeeded"),
+            Err(error) => (error.line, error.column, error.message),
+        }
+    }
+
+    #[test]
+    fn parse_every_statement() {
+        let source = "# A scene that uses every kind of statement.
+                      const HERO = 3
+                      const PROP = -2
+                      set-bg \"plane_and_simple\"
+                      seq {
+                          place HERO \"chars/tezure\" 0 (-16, 112)
+                          set-pos PROP (1, -2)
+                          set-sprite HERO \"chars/tezure\" 4
+                          anim PROP \"chars/invis\" [0, 1, 2] 3
+                          anim PROP \"chars/invis\" [] 1
+                          par {
+                              slide HERO (96, 112) 1
+                              slide HERO (96, 112) accel 0.5
+                              slide HERO (96, 112) decel 0.5
+                              slide HERO (96, 112) accel decel 0.25
+                          }
+                      }
+                      loop 2 0 seq { sound platform_shift 2 shake 4 }
+                      dark on light HERO off jump HERO (5, 6) 0.75
+                      queue 1 -1 swap HERO 7 remove PROP
+                      sound talk_hi
+                      talk hmm HERO thought SW \"Hmm, \\\"quoted\\\" text
+                                across two lines.\\nThree, really.\"
+                      wait 0.5";
+        let expected = vec![
+            Ast::SetBg("plane_and_simple"),
+            Ast::Seq(vec![
+                Ast::Place(3, "chars/tezure", 0, (-16, 112)),
+                Ast::SetPos(-2, (1, -2)),
+                Ast::SetSprite(3, "chars/tezure", 4),
+                Ast::Anim(-2, "chars/invis", &[0, 1, 2], 3),
+                Ast::Anim(-2, "chars/invis", &[], 1),
+                Ast::Par(vec![
+                    Ast::Slide(3, (96, 112), false, false, 1.0),
+                    Ast::Slide(3, (96, 112), true, false, 0.5),
+                    Ast::Slide(3, (96, 112), false, true, 0.5),
+                    Ast::Slide(3, (96, 112), true, true, 0.25),
+                ]),
+            ]),
+            Ast::Loop(2, 0, Box::new(Ast::Seq(vec![
+                Ast::Sound(Sound::platform_shift(2)),
+                Ast::Shake(4),
+            ]))),
+            Ast::Dark(true),
+            Ast::Light(3, false),
+            Ast::Jump(3, (5, 6), 0.75),
+            Ast::Queue(1, -1),
+            Ast::Swap(3, 7),
+            Ast::Remove(-2),
+            Ast::Sound(Sound::talk_hi()),
+            Ast::Talk(3, TalkStyle::Thought, TalkPos::SW,
+                      "Hmm, \"quoted\" text\n\
+                       across two lines.\nThree, really."),
+            Ast::Wait(0.5),
+        ];
+        let actual = Ast::parse_script(source, None, &[]).unwrap();
+        assert_eq!(Ast::describe_all(&actual), Ast::describe_all(&expected));
+    }
+
+    #[test]
+    fn talk_text_is_localized() {
+        let source = "talk one 1 normal NE \"One\"
+                      par { wait 1  talk two 2 evil SW \"Two\" }
+                      loop 0 1 talk three 1 normal NE \"Three\"";
+        let catalog = Catalog::parse("[scene.\"test/scene\"]\n\
+                                      one = \"Un\"\n\
+                                      three = \"Trois\"\n\
+                                      [scene.\"test/other\"]\n\
+                                      two = \"Deux\"\n")
+            .unwrap();
+        let ast = Ast::parse_script(source,
+                                    Some((&catalog, "test/scene")),
+                                    &[])
+            .unwrap();
+        assert_eq!(Ast::describe_all(&ast),
+                   vec!["Talk(1, Normal, NE, \"Un\")",
+                        "Par[Wait(1) Talk(2, Evil, SW, \"Two\")]",
+                        "Loop(0, 1, Talk(1, Normal, NE, \"Trois\"))"]);
+    }
+
+    #[test]
+    fn params_can_be_replaced() {
+        let source = "const HERO = 1
+                      param EDGE = 592
+                      param FLOOR = 320
+                      place HERO \"chars/tezure\" 0 (EDGE, FLOOR)";
+        let ast = Ast::parse_script(source, None, &[]).unwrap();
+        assert_eq!(Ast::describe_all(&ast),
+                   vec!["Place(1, \"chars/tezure\", 0, (592, 320))"]);
+        let ast = Ast::parse_script(source, None, &[("EDGE", 656)])
+            .unwrap();
+        assert_eq!(Ast::describe_all(&ast),
+                   vec!["Place(1, \"chars/tezure\", 0, (656, 320))"]);
+        match Ast::parse_script(source, None, &[("HERO", 2)]) {
+            Ok(_) => panic!("parse_script unexpectedly succeeded"),
+            Err(error) => {
+                assert_eq!(error.message, "no `param` named `HERO`")
+            }
+        }
+    }
+
+    #[test]
+    fn parse_errors_report_position() {
+        assert_eq!(parse_error("wait 1\n  bogus 2"),
+                   (2, 3, "unknown command `bogus`".to_string()));
+        assert_eq!(parse_error("seq {\n    wait (1, 2)\n}"),
+                   (2, 10, "expected a number, found `(`".to_string()));
+        assert_eq!(parse_error("remove HERO"),
+                   (1, 8, "undefined constant `HERO`".to_string()));
+        assert_eq!(parse_error("seq {\n    wait 1\n"),
+                   (3, 1, "expected a command, found end of file"
+                       .to_string()));
+        assert_eq!(parse_error("talk hi 1 normal NE\n  \"unfinished"),
+                   (2, 3, "unterminated string".to_string()));
+        assert_eq!(parse_error("talk hi 1 loud NE \"Hi!\""),
+                   (1, 11, "expected a talk style, found `loud`"
+                       .to_string()));
+        assert_eq!(parse_error("talk 1 normal NE \"Hi!\""),
+                   (1, 6, "expected a talk ID, found `1`".to_string()));
+        assert_eq!(parse_error("talk HI 1 normal NE \"Hi!\""),
+                   (1, 6, "expected a talk ID, found constant `HI`"
+                       .to_string()));
+        assert_eq!(parse_error("talk hi 1 normal NE \"Hi!\"\n\
+                                talk hi 1 normal NE \"Bye!\""),
+                   (2, 6, "talk ID `hi` is already used".to_string()));
+        assert_eq!(parse_error("sound talk_medium"),
+                   (1, 7, "unknown sound `talk_medium`".to_string()));
+        assert_eq!(parse_error("wait 1 ; wait 2"),
+                   (1, 8, "unexpected character ';'".to_string()));
+        assert_eq!(parse_error("const X = 1 const X = 2"),
+                   (1, 19, "constant `X` is already defined".to_string()));
+    }
+
+    #[test]
+    fn all_scene_scripts_parse() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("data/scenes");
+        let mut num_scripts = 0;
+        let mut dirs = vec![root];
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(&dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path.extension() == Some("scene".as_ref()) {
+                    check_script(&path);
+                    num_scripts += 1;
+                }
+            }
+        }
+        assert!(num_scripts > 0);
+    }
+
+    fn check_script(path: &Path) {
+        let source = fs::read_to_string(path).unwrap();
+        if let Err(error) = Ast::parse_script(&source, None, &[]) {
+            panic!("{:?}: {}", path, error);
+        }
+    }
+}
+
+// ========================================================================= //
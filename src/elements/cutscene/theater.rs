@@ -17,14 +17,15 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use std::cmp;
+use std::{cmp, f64};
+use std::cell::Cell;
 use std::collections::BTreeMap;
 use std::mem;
 use std::rc::Rc;
 
 use elements::Paragraph;
 use gui::{Background, Canvas, Point, Rect, Sound, Sprite};
-use save::Direction;
+use save::{Direction, TalkStyle, TranscriptLine};
 
 // ========================================================================= //
 
@@ -33,8 +34,12 @@ pub struct Theater {
     actors: BTreeMap<i32, Actor>,
     queue: Vec<(i32, i32)>,
     sounds: Vec<Sound>,
+    new_speaker: Option<i32>,
+    scene_name: String,
+    transcript: Vec<TranscriptLine>,
     shake: i32,
     dark: bool,
+    text_speed: Rc<Cell<f64>>,
 }
 
 impl Theater {
@@ -44,17 +49,29 @@ impl Theater {
             actors: BTreeMap::new(),
             queue: Vec::new(),
             sounds: Vec::new(),
+            new_speaker: None,
+            scene_name: String::new(),
+            transcript: Vec::new(),
             shake: 0,
             dark: false,
+            text_speed: Rc::new(Cell::new(f64::INFINITY)),
         }
     }
 
+    /// Makes speech bubbles reveal their text at the speed held in the given
+    /// cell (infinite for all at once), so that changing the setting also
+    /// affects speech that is already on stage.
+    pub fn set_text_speed(&mut self, speed: Rc<Cell<f64>>) {
+        self.text_speed = speed;
+    }
+
     pub fn set_background(&mut self, background: Rc<Background>) {
         self.background = Some(background);
     }
 
-    pub fn place_actor(&mut self, slot: i32, sprite: Sprite, position: Point) {
-        self.actors.insert(slot, Actor::new(sprite, position));
+    pub fn place_actor(&mut self, slot: i32, name: &'static str,
+                       sprite: Sprite, position: Point) {
+        self.actors.insert(slot, Actor::new(name, sprite, position));
     }
 
     pub fn remove_actor(&mut self, slot: i32) { self.actors.remove(&slot); }
@@ -99,9 +116,26 @@ impl Theater {
                             paragraph: Rc<Paragraph>) {
         if let Some(actor) = self.actors.get_mut(&slot) {
             actor.set_speech(bubble_sprites, bg_color, talk_pos, paragraph);
+            if self.text_speed.get().is_infinite() {
+                if let Some(ref mut speech) = actor.speech {
+                    speech.reveal_all();
+                }
+            }
         }
     }
 
+    /// Immediately reveals the full text of any speech bubbles that are still
+    /// being written out.  Returns true if any text was revealed.
+    pub fn reveal_all_speech(&mut self) -> bool {
+        let mut revealed = false;
+        for actor in self.actors.values_mut() {
+            if let Some(ref mut speech) = actor.speech {
+                revealed |= speech.reveal_all();
+            }
+        }
+        revealed
+    }
+
     pub fn clear_actor_speech(&mut self, slot: i32) {
         if let Some(actor) = self.actors.get_mut(&slot) {
             actor.clear_speech();
@@ -131,8 +165,53 @@ impl Theater {
 
     pub fn add_sound(&mut self, sound: Sound) { self.sounds.push(sound); }
 
+    /// Sets the ID of the scene that is currently playing, for tagging the
+    /// lines of dialogue that it records.
+    pub fn set_scene_name(&mut self, name: &str) {
+        if self.scene_name != name {
+            self.scene_name = name.to_string();
+        }
+    }
+
+    /// Notes the given line (counting from zero) of the current scene,
+    /// spoken by the actor in the given slot, so that it can be added to the
+    /// player's transcript.
+    pub fn record_speech(&mut self, slot: i32, style: TalkStyle, text: &str,
+                         line: usize) {
+        self.new_speaker = Some(slot);
+        let speaker = match self.actors.get(&slot) {
+            Some(actor) => speaker_name(actor.name),
+            None => UNKNOWN_SPEAKER,
+        };
+        self.transcript.push(TranscriptLine {
+                                 speaker: speaker.to_string(),
+                                 style: style,
+                                 text: text.to_string(),
+                                 scene: self.scene_name.clone(),
+                                 line: line,
+                             });
+    }
+
+    pub fn drain_transcript(&mut self) -> Vec<TranscriptLine> {
+        mem::replace(&mut self.transcript, Vec::new())
+    }
+
+    /// Returns the sounds added since the last call.  If an actor started
+    /// speaking in the meantime, the sounds (which are usually that actor's
+    /// talking noises) are panned to match the actor's position; otherwise
+    /// they are centered.
     pub fn drain_sounds(&mut self) -> Vec<Sound> {
+        let speaker_x = self.new_speaker
+            .take()
+            .and_then(|slot| self.actors.get(&slot))
+            .map(|actor| actor.position.x());
         mem::replace(&mut self.sounds, Vec::new())
+            .into_iter()
+            .map(|sound| match speaker_x {
+                     Some(x) => sound.at_x(x),
+                     None => sound.panned(0.0),
+                 })
+            .collect()
     }
 
     pub fn shake_offset(&self) -> Point {
@@ -212,17 +291,39 @@ impl Theater {
 
     pub fn tick_animations(&mut self) -> bool {
         let mut redraw = false;
+        let text_speed = self.text_speed.get();
         if self.shake > 0 {
             self.shake -= 1;
             redraw = true;
         }
         for (_, actor) in self.actors.iter_mut() {
             redraw |= actor.tick_animation();
+            if let Some(ref mut speech) = actor.speech {
+                redraw |= speech.tick_reveal(text_speed);
+            }
         }
         redraw
     }
 }
 
+const UNKNOWN_SPEAKER: &str = "???";
+
+/// Returns the name to show in the transcript for an actor that was placed
+/// with the given sprite sheet.
+fn speaker_name(sprite_name: &str) -> &'static str {
+    match sprite_name {
+        "chars/argony" => "Argony",
+        "chars/elinsa" => "Elinsa",
+        "chars/mezure" => "Mezure",
+        "chars/relyng" => "Relyng",
+        "chars/srb" | "chars/srbdmg" => "SRB",
+        "chars/system" => "System",
+        "chars/ugrent" => "Ugrent",
+        "chars/yttris" => "Yttris",
+        _ => UNKNOWN_SPEAKER,
+    }
+}
+
 fn remove_rect(rects: &mut Vec<Rect>, remove: Rect) {
     let mut new_rects = Vec::new();
     for &rect in rects.iter() {
@@ -263,6 +364,7 @@ fn remove_rect(rects: &mut Vec<Rect>, remove: Rect) {
 // ========================================================================= //
 
 struct Actor {
+    name: &'static str,
     sprites: Vec<Sprite>,
     anim_slowdown: i32,
     anim_step: i32,
@@ -272,8 +374,9 @@ struct Actor {
 }
 
 impl Actor {
-    fn new(sprite: Sprite, position: Point) -> Actor {
+    fn new(name: &'static str, sprite: Sprite, position: Point) -> Actor {
         Actor {
+            name: name,
             sprites: vec![sprite],
             anim_slowdown: 0,
             anim_step: 0,
@@ -345,11 +448,14 @@ impl Actor {
 // ========================================================================= //
 
 const SPEECH_MARGIN: i32 = 8;
+const SPEECH_CHARS_PER_FRAME: f64 = 1.5;
 
 struct SpeechBubble {
     sprites: Vec<Sprite>,
     bg_color: (u8, u8, u8),
     paragraph: Rc<Paragraph>,
+    num_chars: usize,
+    revealed: f64,
     rect: Rect,
     tail_pos: Point,
     tail_dir: Direction,
@@ -428,7 +534,9 @@ impl SpeechBubble {
         SpeechBubble {
             sprites: bubble_sprites,
             bg_color: bg_color,
+            num_chars: paragraph.num_chars(),
             paragraph: paragraph,
+            revealed: 0.0,
             rect: Rect::new(left, top, width as u32, height as u32),
             tail_pos: Point::new(tail_x, tail_y),
             tail_dir: tail_dir,
@@ -436,6 +544,25 @@ impl SpeechBubble {
         }
     }
 
+    fn num_revealed(&self) -> usize {
+        cmp::min(self.num_chars, self.revealed as usize)
+    }
+
+    fn tick_reveal(&mut self, speed: f64) -> bool {
+        let old_revealed = self.num_revealed();
+        if old_revealed >= self.num_chars {
+            return false;
+        }
+        self.revealed += SPEECH_CHARS_PER_FRAME * speed;
+        self.num_revealed() != old_revealed
+    }
+
+    fn reveal_all(&mut self) -> bool {
+        let was_hidden = self.num_revealed() < self.num_chars;
+        self.revealed = self.num_chars as f64;
+        was_hidden
+    }
+
     fn draw(&self, canvas: &mut Canvas) {
         // Draw bubble:
         {
@@ -502,7 +629,9 @@ impl SpeechBubble {
                               (self.rect.height() - height) as i32 / 2,
                           width as u32,
                           height);
-            self.paragraph.draw(&mut canvas.subcanvas(subrect));
+            self.paragraph
+                .draw_partial(&mut canvas.subcanvas(subrect),
+                              self.num_revealed());
         }
     }
 }
@@ -517,7 +646,7 @@ fn round_up_to_16(mut size: i32) -> i32 {
 
 // ========================================================================= //
 
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TalkPos {
     Auto,
     NE,
@@ -529,3 +658,80 @@ pub enum TalkPos {
 }
 
 // ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::f64;
+    use std::rc::Rc;
+
+    use elements::Paragraph;
+    use gui::{Align, Offscreen, Point, Sound, Sprite};
+    use save::TalkStyle;
+    use super::{TalkPos, Theater};
+
+    #[test]
+    fn talk_sounds_are_panned_to_speaker() {
+        let mut theater = Theater::new();
+        let sprite = Sprite::from_rgba_data(1, 1, vec![0; 4]);
+        theater.place_actor(1, "Elinsa", sprite, Point::new(0, 100));
+        theater.add_sound(Sound::talk_hi());
+        theater.record_speech(1, TalkStyle::Normal, "Hi.", 0);
+        let sounds = theater.drain_sounds();
+        assert_eq!(sounds.len(), 1);
+        assert!(sounds[0].pan() < 0.0);
+        // Without a new speaker, sounds are centered, even if a puzzle would
+        // otherwise pan them to the mouse position:
+        theater.add_sound(Sound::beep());
+        let sounds = theater.drain_sounds();
+        assert_eq!(sounds[0].clone().at_x(0).pan(), 0.0);
+    }
+
+    #[test]
+    fn text_speed_applies_to_speech_on_stage() {
+        let mut offscreen = Offscreen::new(480, 320);
+        let paragraph = Rc::new(Paragraph::new(&mut offscreen.resources(),
+                                               "roman",
+                                               Align::Left,
+                                               "Hello there."));
+        let speed = Rc::new(Cell::new(1.0));
+        let mut theater = Theater::new();
+        theater.set_text_speed(speed.clone());
+        let sprite = Sprite::from_rgba_data(1, 1, vec![0; 4]);
+        theater.place_actor(1, "Elinsa", sprite.clone(), Point::new(100, 100));
+        let speak = |theater: &mut Theater| {
+            theater.set_actor_speech(1,
+                                     vec![sprite.clone(); 5],
+                                     (255, 255, 255),
+                                     TalkPos::NE,
+                                     paragraph.clone());
+        };
+        // At normal speed, one tick reveals only part of the text:
+        speak(&mut theater);
+        theater.tick_animations();
+        assert!(theater.reveal_all_speech());
+        // Switching to instant text finishes the current speech:
+        speak(&mut theater);
+        theater.tick_animations();
+        speed.set(f64::INFINITY);
+        theater.tick_animations();
+        assert!(!theater.reveal_all_speech());
+        // And new speech appears all at once:
+        speak(&mut theater);
+        assert!(!theater.reveal_all_speech());
+    }
+
+    #[test]
+    fn speech_is_tagged_with_scene_and_line() {
+        let mut theater = Theater::new();
+        theater.set_scene_name("cube_tangle/intro");
+        theater.record_speech(2, TalkStyle::Thought, "Um.", 14);
+        let transcript = theater.drain_transcript();
+        assert_eq!(transcript.len(), 1);
+        assert_eq!(transcript[0].scene, "cube_tangle/intro");
+        assert_eq!(transcript[0].line, 14);
+        assert!(theater.drain_transcript().is_empty());
+    }
+}
+
+// ========================================================================= //
@@ -20,8 +20,8 @@
 use std::cmp;
 use std::rc::Rc;
 
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
-          Resources, Sprite};
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Point,
+          Rect, Resources, Sprite};
 use super::paragraph::Paragraph;
 
 // ========================================================================= //
@@ -124,11 +124,15 @@ impl<A: 'static + Clone> Element<(), A> for DialogBox<A> {
     fn handle_event(&mut self, event: &Event, state: &mut ()) -> Action<A> {
         self.buttons.handle_event(event, state)
     }
+
+    fn focus_targets(&self, state: &()) -> Vec<FocusTarget> {
+        self.buttons.focus_targets(state)
+    }
 }
 
 // ========================================================================= //
 
-struct DialogButton<A> {
+pub struct DialogButton<A> {
     sprite: Sprite,
     font: Rc<Font>,
     rect: Rect,
@@ -137,8 +141,9 @@ struct DialogButton<A> {
 }
 
 impl<A> DialogButton<A> {
-    fn new(resources: &mut Resources, rect: Rect, label: String, value: A)
-           -> DialogButton<A> {
+    pub fn new(resources: &mut Resources, rect: Rect, label: String,
+               value: A)
+               -> DialogButton<A> {
         DialogButton {
             sprite: resources.get_sprites("dialog/button")[0].clone(),
             font: resources.get_font("roman"),
@@ -147,6 +152,12 @@ impl<A> DialogButton<A> {
             value: value,
         }
     }
+
+    pub fn rect(&self) -> Rect { self.rect }
+
+    pub fn value(&self) -> &A { &self.value }
+
+    pub fn set_label(&mut self, label: String) { self.label = label; }
 }
 
 impl<A: Clone> Element<(), A> for DialogButton<A> {
@@ -165,6 +176,10 @@ impl<A: Clone> Element<(), A> for DialogButton<A> {
             _ => Action::ignore(),
         }
     }
+
+    fn focus_targets(&self, _: &()) -> Vec<FocusTarget> {
+        vec![FocusTarget::new(self.rect)]
+    }
 }
 
 // ========================================================================= //
@@ -18,11 +18,11 @@
 // +--------------------------------------------------------------------------+
 
 use std::cmp;
-use std::rc::Rc;
+use std::collections::HashMap;
 
 use elements::Paragraph;
-use gui::{Action, Align, Canvas, Element, Event, Font, KeyMod, Keycode,
-          Point, Rect, Resources, Sound, Sprite};
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, KeyMod,
+          Keycode, Message, Point, Rect, Resources, Sound, Sprite};
 use save::{Access, Location};
 
 // ========================================================================= //
@@ -31,35 +31,46 @@ const BUTTON_HEIGHT: u32 = 16;
 const NAMEBOX_WIDTH: u32 = 114;
 const NAMEBOX_HEIGHT: u32 = 16;
 const SCROLL_SPEED: i32 = 2;
-const PAUSE_TEXT: &str = "$M{Tap}{Click} anywhere to continue";
+const PAUSE_TEXT: Message = Message {
+    id: "hud.pause",
+    english: "$M{Tap}{Click} anywhere to continue",
+};
 const PAUSE_TEXT_MARGIN_HORZ: i32 = 5;
 const PAUSE_TEXT_MARGIN_VERT: i32 = 3;
-const SKIP_TEXT: &str = "$M{Tap here}{Click here or press ESC} to skip scene";
+const SKIP_TEXT: Message = Message {
+    id: "hud.skip",
+    english: "$M{Tap here}{Click here or press ESC} to skip scene",
+};
 const SKIP_TEXT_MARGIN_HORZ: i32 = 5;
 const SKIP_TEXT_MARGIN_VERT: i32 = 3;
 
 // ========================================================================= //
 
 pub struct HudInput {
-    pub name: &'static str,
+    pub location: Location,
     pub access: Access,
     pub is_paused: bool,
     pub show_skip: bool,
     pub active: bool,
     pub can_undo: bool,
     pub can_redo: bool,
+    pub has_timeline: bool,
     pub can_reset: bool,
+    pub can_hint: bool,
 }
 
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum HudCmd {
     Back,
     Info,
+    Log,
+    Hint,
     Undo,
     Redo,
     Reset,
     Replay,
     Solve,
+    Timeline,
     Skip,
 }
 
@@ -86,6 +97,9 @@ impl Hud {
             HudButton::new(resources, location, HudCmd::Redo, cx + 150, bot),
             HudButton::new(resources, location, HudCmd::Reset, cx + 210, bot),
             HudButton::new(resources, location, HudCmd::Replay, cx + 160, bot),
+            HudButton::new(resources, location, HudCmd::Hint, cx - 204, bot),
+            HudButton::new(resources, location, HudCmd::Timeline, cx + 266,
+                           bot),
         ];
         Hud {
             namebox: Hud::namebox(resources, cx, bot),
@@ -120,8 +134,18 @@ impl Element<HudInput, HudCmd> for Hud {
         if !action.should_stop() || event == &Event::ClockTick {
             action.merge(self.buttons.handle_event(event, input));
         }
+        if !action.should_stop() {
+            action.merge(self.namebox.handle_event(event, input));
+        }
         action
     }
+
+    fn focus_targets(&self, input: &HudInput) -> Vec<FocusTarget> {
+        let mut targets = self.skip.focus_targets(input);
+        targets.extend(self.buttons.focus_targets(input));
+        targets.extend(self.namebox.focus_targets(input));
+        targets
+    }
 }
 
 // ========================================================================= //
@@ -152,6 +176,9 @@ impl HudButton {
             HudCmd::Reset => (10, 54),
             HudCmd::Replay => (12, 60),
             HudCmd::Solve => (14, 54),
+            HudCmd::Hint => (16, 46),
+            HudCmd::Timeline => (18, 50),
+            HudCmd::Log => panic!("HudButton HudCmd::Log"),
             HudCmd::Skip => panic!("HudButton HudCmd::Skip"),
         };
         let sprite = sprites[index].clone();
@@ -185,6 +212,11 @@ impl HudButton {
             HudCmd::Reset => active && input.can_reset && !solved,
             HudCmd::Replay => active && solved,
             HudCmd::Solve => active && input.access == Access::Replaying,
+            HudCmd::Hint => {
+                active && input.can_hint && input.access == Access::Unsolved
+            }
+            HudCmd::Timeline => active && input.has_timeline && !solved,
+            HudCmd::Log => panic!("HudButton HudCmd::Log"),
             HudCmd::Skip => panic!("HudButton HudCmd::Skip"),
         }
     }
@@ -261,31 +293,78 @@ impl Element<HudInput, HudCmd> for HudButton {
             _ => Action::ignore(),
         }
     }
+
+    fn focus_targets(&self, input: &HudInput) -> Vec<FocusTarget> {
+        if self.scroll == 0 && self.is_enabled(input) {
+            vec![FocusTarget::new(self.rect)]
+        } else {
+            Vec::new()
+        }
+    }
 }
 
 // ========================================================================= //
 
+/// Returns the name of the location, translated for the current locale.
+pub fn location_name(resources: &Resources, location: Location) -> String {
+    let id = format!("location.{}", location.key());
+    resources.catalog().localize(&id, location.name()).to_string()
+}
+
+// ========================================================================= //
+
+/// Shows the name of the current location.  Clicking on it opens the
+/// dialogue log.
 struct HudNamebox {
     sprites: Vec<Sprite>,
-    font: Rc<Font>,
+    names: HashMap<Location, Paragraph>,
     rect: Rect,
 }
 
 impl HudNamebox {
     fn new(resources: &mut Resources, rect: Rect) -> HudNamebox {
+        let mut names = HashMap::new();
+        for &location in Location::all() {
+            let name = location_name(resources, location);
+            let paragraph =
+                Paragraph::new(resources, "roman", Align::Center, &name);
+            names.insert(location, paragraph);
+        }
         HudNamebox {
             sprites: resources.get_sprites("hud/namebox"),
-            font: resources.get_font("roman"),
+            names: names,
             rect: rect,
         }
     }
+}
 
+impl Element<HudInput, HudCmd> for HudNamebox {
     fn draw(&self, input: &HudInput, canvas: &mut Canvas) {
         let mut canvas = canvas.subcanvas(self.rect);
         canvas.draw_sprite(&self.sprites[0], Point::new(0, 0));
         canvas.fill_rect((200, 200, 200), Rect::new(2, 2, 110, 14));
-        let start = Point::new(canvas.width() as i32 / 2, 12);
-        canvas.draw_text(&self.font, Align::Center, start, input.name);
+        let width = canvas.width();
+        let mut canvas = canvas.subcanvas(Rect::new(0, 3, width, 13));
+        self.names[&input.location].draw(&mut canvas);
+    }
+
+    fn handle_event(&mut self, event: &Event, input: &mut HudInput)
+                    -> Action<HudCmd> {
+        match event {
+            &Event::MouseDown(pt)
+                if input.active && self.rect.contains_point(pt) => {
+                Action::redraw().and_return(HudCmd::Log)
+            }
+            _ => Action::ignore(),
+        }
+    }
+
+    fn focus_targets(&self, input: &HudInput) -> Vec<FocusTarget> {
+        if input.active {
+            vec![FocusTarget::new(self.rect)]
+        } else {
+            Vec::new()
+        }
     }
 }
 
@@ -300,8 +379,11 @@ struct PauseIndicator {
 
 impl PauseIndicator {
     fn new(resources: &mut Resources, visible: Rect) -> PauseIndicator {
-        let paragraph =
-            Paragraph::new(resources, "roman", Align::Center, PAUSE_TEXT);
+        let catalog = resources.catalog();
+        let paragraph = Paragraph::new(resources,
+                                       "roman",
+                                       Align::Center,
+                                       catalog.message(PAUSE_TEXT));
         let inner_width = paragraph.min_width();
         let outer_width = inner_width + 2 * PAUSE_TEXT_MARGIN_HORZ;
         let inner_height = paragraph.height();
@@ -350,8 +432,11 @@ struct SkipIndicator {
 
 impl SkipIndicator {
     fn new(resources: &mut Resources, visible: Rect) -> SkipIndicator {
-        let paragraph =
-            Paragraph::new(resources, "roman", Align::Center, SKIP_TEXT);
+        let catalog = resources.catalog();
+        let paragraph = Paragraph::new(resources,
+                                       "roman",
+                                       Align::Center,
+                                       catalog.message(SKIP_TEXT));
         let inner_width = paragraph.min_width();
         let outer_width = inner_width + 2 * SKIP_TEXT_MARGIN_HORZ;
         let inner_height = paragraph.height();
@@ -403,6 +488,14 @@ impl Element<HudInput, HudCmd> for SkipIndicator {
             _ => Action::ignore(),
         }
     }
+
+    fn focus_targets(&self, input: &HudInput) -> Vec<FocusTarget> {
+        if input.show_skip {
+            vec![FocusTarget::new(self.outer_rect)]
+        } else {
+            Vec::new()
+        }
+    }
 }
 
 // ========================================================================= //
@@ -22,8 +22,8 @@ use std::cmp;
 use std::collections::HashMap;
 use std::rc::Rc;
 
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
-          Resources, Sound, Sprite};
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Point,
+          Rect, Resources, Sound, Sprite};
 use save::Direction;
 use save::ice::{BlockSlide, Object, ObjectGrid, Transform};
 
@@ -331,7 +331,10 @@ impl Element<ObjectGrid, (Point, Direction)> for GridView {
                         }
                         self.animation = Some(anim);
                     } else {
-                        action.also_play_sound(Sound::device_rotate());
+                        let x = self.rect.left() +
+                            anim.to_coords.x() * GRID_CELL_SIZE +
+                            GRID_CELL_SIZE / 2;
+                        action.also_play_sound(Sound::device_rotate().at_x(x));
                     }
                     return action;
                 }
@@ -367,6 +370,19 @@ impl Element<ObjectGrid, (Point, Direction)> for GridView {
         }
         Action::ignore()
     }
+
+    fn focus_targets(&self, grid: &ObjectGrid) -> Vec<FocusTarget> {
+        grid.ice_blocks()
+            .keys()
+            .map(|&coords| {
+                let top_left = self.rect.top_left() + coords * GRID_CELL_SIZE;
+                FocusTarget::new(Rect::new(top_left.x(),
+                                           top_left.y(),
+                                           GRID_CELL_SIZE as u32,
+                                           GRID_CELL_SIZE as u32))
+            })
+            .collect()
+    }
 }
 
 // ========================================================================= //
@@ -18,13 +18,15 @@
 // +--------------------------------------------------------------------------+
 
 use std::cmp;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashMap;
 use std::rc::Rc;
+use toml;
 
-use gui::{Action, Align, Canvas, Element, Event, FRAME_DELAY_MILLIS, Font,
-          Point, Rect, Resources, Sound, Sprite};
+use gui::{Action, Align, Canvas, Element, Event, FRAME_DELAY_MILLIS,
+          FocusTarget, Font, Point, Rect, Resources, Sound, Sprite};
 use save::{Direction, MixedColor};
-use save::device::{Device, DeviceGrid};
+use save::device::{Device, DeviceGrid, LaserBeams, Spark};
+use save::util::{Tomlable, to_table};
 
 // ========================================================================= //
 
@@ -41,6 +43,42 @@ pub enum LaserCmd {
     Rotated(i32, i32),
 }
 
+impl Tomlable for LaserCmd {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        let kind = match *self {
+            LaserCmd::Moved(from_col, from_row, to_col, to_row) => {
+                table.insert("col".to_string(), from_col.to_toml());
+                table.insert("row".to_string(), from_row.to_toml());
+                table.insert("to_col".to_string(), to_col.to_toml());
+                table.insert("to_row".to_string(), to_row.to_toml());
+                "moved"
+            }
+            LaserCmd::Rotated(col, row) => {
+                table.insert("col".to_string(), col.to_toml());
+                table.insert("row".to_string(), row.to_toml());
+                "rotated"
+            }
+        };
+        table.insert("kind".to_string(),
+                     toml::Value::String(kind.to_string()));
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> LaserCmd {
+        let mut table = to_table(value);
+        let col = i32::pop_from_table(&mut table, "col");
+        let row = i32::pop_from_table(&mut table, "row");
+        if String::pop_from_table(&mut table, "kind") == "moved" {
+            let to_col = i32::pop_from_table(&mut table, "to_col");
+            let to_row = i32::pop_from_table(&mut table, "to_row");
+            LaserCmd::Moved(col, row, to_col, to_row)
+        } else {
+            LaserCmd::Rotated(col, row)
+        }
+    }
+}
+
 // ========================================================================= //
 
 struct GridDrag {
@@ -63,8 +101,7 @@ pub struct LaserField {
     sparks_sprites: Vec<Sprite>,
     wall_sprites: Vec<Sprite>,
     drag: Option<GridDrag>,
-    lasers: HashMap<(Point, Direction), (MixedColor, i32)>,
-    sparks: HashMap<(Point, Direction), i32>,
+    beams: LaserBeams,
     anim_counter: i32,
     font: Rc<Font>,
     letters: HashMap<(i32, i32), char>,
@@ -85,8 +122,7 @@ impl LaserField {
             sparks_sprites: resources.get_sprites("devices/sparks"),
             wall_sprites: resources.get_sprites("devices/walls"),
             drag: None,
-            lasers: HashMap::new(),
-            sparks: HashMap::new(),
+            beams: LaserBeams::default(),
             anim_counter: 0,
             font: resources.get_font("block"),
             letters: HashMap::new(),
@@ -99,46 +135,13 @@ impl LaserField {
         self.letters.insert(coords, letter);
     }
 
-    pub fn satisfied_detector_positions(&self, grid: &DeviceGrid)
-                                        -> HashSet<(i32, i32)> {
-        let mut positions = HashSet::new();
-        let (num_cols, num_rows) = grid.size();
-        for row in 0..num_rows {
-            for col in 0..num_cols {
-                match grid.get(col, row) {
-                    Some((Device::Detector(color), dir)) => {
-                        let coords = Point::new(col, row);
-                        match self.lasers.get(&(coords, dir)) {
-                            Some(&(laser, _)) if laser == color => {
-                                positions.insert((col, row));
-                            }
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        }
-        positions
-    }
+    /// Returns the laser beams as of the last call to `recalculate_lasers`.
+    pub fn beams(&self) -> &LaserBeams { &self.beams }
 
-    pub fn all_detectors_satisfied(&self, grid: &DeviceGrid) -> bool {
-        let (num_cols, num_rows) = grid.size();
-        for row in 0..num_rows {
-            for col in 0..num_cols {
-                match grid.get(col, row) {
-                    Some((Device::Detector(color), dir)) => {
-                        let coords = Point::new(col, row);
-                        match self.lasers.get(&(coords, dir)) {
-                            Some(&(laser, _)) if laser == color => {}
-                            _ => return false,
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        }
-        true
+    /// Returns the screen x-coordinate of the center of the given column,
+    /// for panning sounds.
+    fn cell_center_x(&self, col: i32) -> i32 {
+        self.rect.left() + col * GRID_CELL_SIZE + GRID_CELL_SIZE / 2
     }
 
     fn draw_device_bg(&self, canvas: &mut Canvas, center: Point,
@@ -208,124 +211,13 @@ impl LaserField {
     }
 
     pub fn clear_lasers(&mut self) {
-        self.lasers.clear();
-        self.sparks.clear();
+        self.beams = LaserBeams::default();
         self.drag = None;
     }
 
     pub fn recalculate_lasers(&mut self, grid: &DeviceGrid) {
         self.clear_lasers();
-        let (num_cols, num_rows) = grid.size();
-        let mut queue: VecDeque<(Point, Direction, MixedColor)> =
-            VecDeque::new();
-        for row in 0..num_rows {
-            for col in 0..num_cols {
-                match grid.get(col, row) {
-                    Some((Device::Emitter(color), dir)) => {
-                        if color != MixedColor::Black {
-                            let coords = Point::new(col, row);
-                            self.lasers.insert((coords, dir), (color, 10));
-                            queue.push_back((coords, dir, color));
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        }
-        while let Some((coords, laser_dir, color)) = queue.pop_front() {
-            let next = coords + laser_dir.delta();
-            let anti_dir = laser_dir.opposite();
-            if self.lasers.contains_key(&(next, anti_dir)) {
-                if !self.sparks.contains_key(&(next, anti_dir)) {
-                    self.sparks.insert((coords, laser_dir), 0);
-                }
-                continue;
-            }
-            match grid.get(next.x(), next.y()) {
-                Some((Device::Wall, _)) |
-                Some((Device::Emitter(_), _)) => {
-                    self.sparks.insert((coords, laser_dir), 0);
-                }
-                Some((Device::Channel, ch_dir))
-                    if !ch_dir.is_parallel_to(laser_dir) => {
-                    self.sparks.insert((coords, laser_dir), 0);
-                }
-                Some((Device::Channel, _)) |
-                Some((Device::CrossChannel, _)) |
-                None => {
-                    let perp_dir = laser_dir.rotated_cw();
-                    let mut dist = GRID_CELL_SIZE / 2;
-                    if self.lasers.contains_key(&(next, perp_dir)) {
-                        dist -= LASER_THICKNESS / 2;
-                    }
-                    self.lasers.insert((next, anti_dir), (color, dist));
-                    self.lasers.insert((next, laser_dir), (color, dist));
-                    queue.push_back((next, laser_dir, color));
-                }
-                Some((Device::Detector(det_color), det_dir)) => {
-                    if det_dir == anti_dir {
-                        self.lasers.insert((next, anti_dir), (color, 10));
-                        if det_color != color {
-                            self.sparks.insert((next, anti_dir), 10);
-                        }
-                    } else {
-                        self.sparks.insert((coords, laser_dir), 0);
-                    }
-                }
-                Some((Device::Mirror, mir_dir)) => {
-                    let mut reflect_dir = match anti_dir {
-                        Direction::East => Direction::South,
-                        Direction::South => Direction::East,
-                        Direction::West => Direction::North,
-                        Direction::North => Direction::West,
-                    };
-                    if mir_dir.is_vertical() {
-                        reflect_dir = reflect_dir.opposite();
-                    }
-                    self.lasers.insert((next, anti_dir), (color, 15));
-                    self.lasers.insert((next, reflect_dir), (color, 15));
-                    queue.push_back((next, reflect_dir, color));
-                }
-                Some((Device::Splitter, split_dir)) => {
-                    if split_dir == laser_dir {
-                        self.lasers.insert((next, anti_dir), (color, 6));
-                        let left_dir = laser_dir.rotated_ccw();
-                        let right_dir = laser_dir.rotated_cw();
-                        self.lasers.insert((next, left_dir), (color, 6));
-                        self.lasers.insert((next, right_dir), (color, 6));
-                        self.sparks.remove(&(next, left_dir));
-                        self.sparks.remove(&(next, right_dir));
-                        queue.push_back((next, left_dir, color));
-                        queue.push_back((next, right_dir, color));
-                    } else if split_dir == anti_dir {
-                        self.lasers.insert((next, anti_dir), (color, 3));
-                        self.sparks.insert((next, anti_dir), 3);
-                    } else {
-                        self.lasers.insert((next, anti_dir), (color, 6));
-                        self.sparks.insert((next, anti_dir), 6);
-                    }
-                }
-                Some((Device::Mixer, mixer_dir)) => {
-                    if mixer_dir == laser_dir {
-                        self.lasers.insert((next, anti_dir), (color, 1));
-                        self.sparks.insert((next, anti_dir), 1);
-                    } else if mixer_dir == anti_dir {
-                        self.lasers.insert((next, anti_dir), (color, 3));
-                        self.sparks.insert((next, anti_dir), 3);
-                    } else {
-                        self.lasers.insert((next, anti_dir), (color, 3));
-                        if let Some(&(other, _)) =
-                            self.lasers.get(&(next, laser_dir))
-                        {
-                            let output = mixer_output(color, other);
-                            self.lasers.insert((next, mixer_dir), (output, 3));
-                            self.sparks.remove(&(next, mixer_dir));
-                            queue.push_back((next, mixer_dir, output));
-                        }
-                    }
-                }
-            }
-        }
+        self.beams = grid.trace_lasers();
     }
 
     pub fn draw_immovables(&self, grid: &DeviceGrid, canvas: &mut Canvas) {
@@ -397,9 +289,40 @@ impl LaserField {
         }
     }
 
-    pub fn draw_lasers(&self, canvas: &mut Canvas) {
+    /// Returns how far, in pixels, the beam segment running from the middle
+    /// of the given cell to its edge in direction `dir` should be drawn,
+    /// measured inwards from the edge.
+    fn segment_length(&self, grid: &DeviceGrid, col: i32, row: i32,
+                      dir: Direction)
+                      -> i32 {
+        match grid.get(col, row) {
+            Some((Device::Emitter(_), _)) |
+            Some((Device::Detector(_), _)) => 10,
+            Some((Device::Mirror, _)) => 15,
+            Some((Device::Splitter, split_dir)) => {
+                if dir == split_dir { 3 } else { 6 }
+            }
+            Some((Device::Mixer, mixer_dir)) => {
+                if dir == mixer_dir.opposite() { 1 } else { 3 }
+            }
+            _ => {
+                // Where two beams cross, stop the vertical beam short of the
+                // horizontal one, so that the two don't overlap.
+                let horizontal =
+                    self.beams.color_at(col, row, Direction::East).is_some();
+                if dir.is_vertical() && horizontal {
+                    GRID_CELL_SIZE / 2 - LASER_THICKNESS / 2
+                } else {
+                    GRID_CELL_SIZE / 2
+                }
+            }
+        }
+    }
+
+    pub fn draw_lasers(&self, grid: &DeviceGrid, canvas: &mut Canvas) {
         let mut canvas = canvas.subcanvas(self.rect);
-        for (&(coords, dir), &(laser_color, dist)) in self.lasers.iter() {
+        for (&((col, row), dir), &laser_color) in self.beams.segments() {
+            let dist = self.segment_length(grid, col, row, dir);
             let fill_color = match laser_color {
                 MixedColor::Black => unreachable!(),
                 MixedColor::Red => (255, 64, 64),
@@ -436,18 +359,21 @@ impl LaserField {
                               dist as u32)
                 }
             };
-            fill_rect.offset(coords.x() * GRID_CELL_SIZE,
-                             coords.y() * GRID_CELL_SIZE);
+            fill_rect.offset(col * GRID_CELL_SIZE, row * GRID_CELL_SIZE);
             canvas.fill_rect(fill_color, fill_rect);
         }
     }
 
-    pub fn draw_sparks(&self, canvas: &mut Canvas) {
-        for (&(coords, dir), &dist) in self.sparks.iter() {
+    pub fn draw_sparks(&self, grid: &DeviceGrid, canvas: &mut Canvas) {
+        for (&((col, row), dir), &spark) in self.beams.sparks() {
+            let dist = match spark {
+                Spark::Edge => 0,
+                Spark::Device => self.segment_length(grid, col, row, dir),
+            };
             let center = self.rect.top_left() +
                 dir.delta() * (GRID_CELL_SIZE / 2 - dist) +
-                Point::new(coords.x() * GRID_CELL_SIZE + GRID_CELL_SIZE / 2,
-                           coords.y() * GRID_CELL_SIZE + GRID_CELL_SIZE / 2);
+                Point::new(col * GRID_CELL_SIZE + GRID_CELL_SIZE / 2,
+                           row * GRID_CELL_SIZE + GRID_CELL_SIZE / 2);
             canvas.draw_sprite_transformed(&self.sparks_sprites[0],
                                            center,
                                            dir.degrees(),
@@ -469,9 +395,9 @@ impl Element<DeviceGrid, LaserCmd> for LaserField {
             canvas.draw_char(&self.font, Align::Center, pt, letter);
         }
         self.draw_movables_bg(grid, canvas);
-        self.draw_lasers(canvas);
+        self.draw_lasers(grid, canvas);
         self.draw_movables_fg(grid, canvas);
-        self.draw_sparks(canvas);
+        self.draw_sparks(grid, canvas);
         if let Some(ref drag) = self.drag {
             if drag.from_pt != drag.to_pt {
                 let center = self.rect.top_left() + drag.to_pt;
@@ -492,7 +418,7 @@ impl Element<DeviceGrid, LaserCmd> for LaserField {
                 self.anim_counter += 1;
                 self.anim_counter %= 2 * ANIM_SLOWDOWN;
                 if self.anim_counter % ANIM_SLOWDOWN == 0 &&
-                    !self.sparks.is_empty()
+                    !self.beams.sparks().is_empty()
                 {
                     return Action::redraw();
                 }
@@ -521,12 +447,12 @@ impl Element<DeviceGrid, LaserCmd> for LaserField {
             &Event::MouseDrag(pt) => {
                 if let Some(ref mut drag) = self.drag {
                     drag.to_pt = pt - self.rect.top_left();
-                    self.lasers.clear();
-                    self.sparks.clear();
+                    self.beams = LaserBeams::default();
                     let mut action = Action::redraw();
                     if !drag.moved {
                         drag.moved = true;
-                        action.also_play_sound(Sound::device_pickup());
+                        let sound = Sound::device_pickup().at_x(pt.x());
+                        action.also_play_sound(sound);
                     }
                     return action;
                 }
@@ -541,8 +467,9 @@ impl Element<DeviceGrid, LaserCmd> for LaserField {
                         if drag.millis <= ROTATE_MAX_MILLIS {
                             grid.rotate(drag.from_col, drag.from_row);
                             self.recalculate_lasers(grid);
+                            let x = self.cell_center_x(drag.from_col);
                             Action::redraw()
-                                .and_play_sound(Sound::device_rotate())
+                                .and_play_sound(Sound::device_rotate().at_x(x))
                                 .and_return(LaserCmd::Rotated(drag.from_col,
                                                               drag.from_row))
                         } else {
@@ -556,8 +483,9 @@ impl Element<DeviceGrid, LaserCmd> for LaserField {
                                                    to_row);
                         self.recalculate_lasers(grid);
                         if success {
+                            let x = self.cell_center_x(to_col);
                             Action::redraw()
-                                .and_play_sound(Sound::device_drop())
+                                .and_play_sound(Sound::device_drop().at_x(x))
                                 .and_return(LaserCmd::Moved(drag.from_col,
                                                             drag.from_row,
                                                             to_col,
@@ -572,6 +500,21 @@ impl Element<DeviceGrid, LaserCmd> for LaserField {
         }
         Action::ignore()
     }
+
+    fn focus_targets(&self, grid: &DeviceGrid) -> Vec<FocusTarget> {
+        let (num_cols, num_rows) = grid.size();
+        let mut targets = Vec::new();
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                let rect = Rect::new(self.rect.left() + col * GRID_CELL_SIZE,
+                                     self.rect.top() + row * GRID_CELL_SIZE,
+                                     GRID_CELL_SIZE as u32,
+                                     GRID_CELL_SIZE as u32);
+                targets.push(FocusTarget::new(rect));
+            }
+        }
+        targets
+    }
 }
 
 // ========================================================================= //
@@ -625,17 +568,4 @@ fn color_index(color: MixedColor) -> usize {
     }
 }
 
-fn mixer_output(color1: MixedColor, color2: MixedColor) -> MixedColor {
-    let red = (color1.has_red() && color2.has_red()) ||
-        (color1.has_green() && color2.has_blue()) ||
-        (color1.has_blue() && color2.has_green());
-    let green = (color1.has_green() && color2.has_green()) ||
-        (color1.has_red() && color2.has_blue()) ||
-        (color1.has_blue() && color2.has_red());
-    let blue = (color1.has_blue() && color2.has_blue()) ||
-        (color1.has_red() && color2.has_green()) ||
-        (color1.has_green() && color2.has_red());
-    MixedColor::from_rgb(red, green, blue)
-}
-
 // ========================================================================= //
@@ -269,7 +269,8 @@ impl Element<Option<Shape>, Point> for NextShapeView {
                         for (coords, _) in shape.tiles() {
                             if self.cell_rect(coords).contains_point(rel_pt) {
                                 self.drag = Some(ShapeDrag::new(pt));
-                                let sound = Sound::device_pickup();
+                                let sound =
+                                    Sound::device_pickup().at_x(pt.x());
                                 return Action::ignore().and_play_sound(sound);
                             }
                         }
@@ -34,15 +34,17 @@ mod progress;
 mod puzzle;
 pub mod shift;
 mod stars;
+mod undo;
 
 pub use self::crossword::CrosswordView;
 pub use self::cutscene::{Ast, Scene, TalkPos, TalkStyle, Theater};
-pub use self::dialog::DialogBox;
+pub use self::dialog::{DialogBox, DialogButton};
 pub use self::fade::{FadeStyle, ScreenFade};
-pub use self::hud::{Hud, HudCmd, HudInput};
+pub use self::hud::{Hud, HudCmd, HudInput, location_name};
 pub use self::paragraph::Paragraph;
 pub use self::progress::ProgressBar;
 pub use self::puzzle::{PuzzleCmd, PuzzleCore, PuzzleView};
 pub use self::stars::MovingStars;
+pub use self::undo::{Timeline, UndoTree};
 
 // ========================================================================= //
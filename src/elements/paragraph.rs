@@ -38,7 +38,11 @@ use gui::{Align, Canvas, Font, Point, Resources};
 //
 // Example:
 // "$M{Tap}{Click} the button to be $ireally$r awesome."
+//
+// Characters that the current font lacks are drawn in the fallback font if it
+// has them, or else with any accents removed (e.g. "é" is drawn as "e").
 
+const FALLBACK_FONT: &str = "roman";
 const LINE_SPACING: i32 = 4;
 const MIN_LINE_HEIGHT: u32 = 10;
 
@@ -105,10 +109,24 @@ impl Paragraph {
         height
     }
 
+    /// Returns the total number of visible characters in the paragraph.
+    pub fn num_chars(&self) -> usize {
+        self.lines.iter().map(Line::num_chars).sum()
+    }
+
     pub fn draw(&self, canvas: &mut Canvas) {
+        self.draw_partial(canvas, self.num_chars());
+    }
+
+    /// Draws only the first `num_chars` characters of the paragraph, with
+    /// each character in the same place it would be in the full paragraph.
+    pub fn draw_partial(&self, canvas: &mut Canvas, mut num_chars: usize) {
         let mut top = 0;
         for line in self.lines.iter() {
-            line.draw(canvas, top);
+            if num_chars == 0 {
+                break;
+            }
+            line.draw(canvas, top, &mut num_chars);
             top += line.height() as i32 + LINE_SPACING;
         }
     }
@@ -146,6 +164,14 @@ impl Line {
         baseline
     }
 
+    fn num_chars(&self) -> usize {
+        (self.left.iter())
+            .chain(self.center.iter())
+            .chain(self.right.iter())
+            .map(Piece::num_chars)
+            .sum()
+    }
+
     fn min_width(&self) -> i32 {
         let mut width = 0;
         for piece in (self.left.iter())
@@ -171,12 +197,12 @@ impl Line {
         height
     }
 
-    fn draw(&self, canvas: &mut Canvas, top: i32) {
+    fn draw(&self, canvas: &mut Canvas, top: i32, num_chars: &mut usize) {
         let baseline = top + self.baseline();
         if !self.left.is_empty() {
             let mut left = 0;
             for piece in self.left.iter() {
-                piece.draw(canvas, left, baseline);
+                piece.draw(canvas, left, baseline, num_chars);
                 left += piece.width();
             }
         }
@@ -187,15 +213,19 @@ impl Line {
             }
             let mut left = (canvas.width() as i32 - width) / 2;
             for piece in self.center.iter() {
-                piece.draw(canvas, left, baseline);
+                piece.draw(canvas, left, baseline, num_chars);
                 left += piece.width();
             }
         }
         if !self.right.is_empty() {
-            let mut left = canvas.rect().right();
+            let mut width = 0;
             for piece in self.right.iter() {
-                left -= piece.width();
-                piece.draw(canvas, left, baseline);
+                width += piece.width();
+            }
+            let mut left = canvas.rect().right() - width;
+            for piece in self.right.iter() {
+                piece.draw(canvas, left, baseline, num_chars);
+                left += piece.width();
             }
         }
     }
@@ -215,11 +245,23 @@ impl Piece {
 
     fn height(&self) -> u32 { self.font.height() }
 
-    fn draw(&self, canvas: &mut Canvas, left: i32, baseline: i32) {
-        canvas.draw_text(&self.font,
-                         Align::Left,
-                         Point::new(left, baseline),
-                         &self.text);
+    fn num_chars(&self) -> usize { self.text.chars().count() }
+
+    fn draw(&self, canvas: &mut Canvas, left: i32, baseline: i32,
+            num_chars: &mut usize) {
+        if *num_chars == 0 {
+            return;
+        }
+        let count = self.num_chars();
+        let start = Point::new(left, baseline);
+        if *num_chars >= count {
+            canvas.draw_text(&self.font, Align::Left, start, &self.text);
+            *num_chars -= count;
+        } else {
+            let text: String = self.text.chars().take(*num_chars).collect();
+            canvas.draw_text(&self.font, Align::Left, start, &text);
+            *num_chars = 0;
+        }
     }
 }
 
@@ -278,19 +320,47 @@ impl<'a, 'b> Parser<'a, 'b> {
         if !self.current_piece.is_empty() {
             let mut text = String::new();
             mem::swap(&mut text, &mut self.current_piece);
-            let piece = Piece {
-                font: self.resources.get_font(&self.current_font),
-                text: text,
-            };
-            let pieces = match self.current_align {
-                Align::Left => &mut self.current_line.left,
-                Align::Center => &mut self.current_line.center,
-                Align::Right => &mut self.current_line.right,
-            };
-            pieces.push(piece);
+            let font = self.resources.get_font(&self.current_font);
+            let fallback = self.resources.get_font(FALLBACK_FONT);
+            let mut run_font = font.clone();
+            let mut run = String::new();
+            for chr in text.chars() {
+                let (chr_font, chr) = if font.has_glyph(chr) {
+                    (&font, chr)
+                } else if fallback.has_glyph(chr) {
+                    (&fallback, chr)
+                } else {
+                    match unaccented(chr) {
+                        Some(base) if font.has_glyph(base) => (&font, base),
+                        _ => (&font, chr),
+                    }
+                };
+                if !Rc::ptr_eq(chr_font, &run_font) {
+                    if !run.is_empty() {
+                        let run = mem::replace(&mut run, String::new());
+                        self.push_piece(run_font, run);
+                    }
+                    run_font = chr_font.clone();
+                }
+                run.push(chr);
+            }
+            self.push_piece(run_font, run);
         }
     }
 
+    fn push_piece(&mut self, font: Rc<Font>, text: String) {
+        let piece = Piece {
+            font: font,
+            text: text,
+        };
+        let pieces = match self.current_align {
+            Align::Left => &mut self.current_line.left,
+            Align::Center => &mut self.current_line.center,
+            Align::Right => &mut self.current_line.right,
+        };
+        pieces.push(piece);
+    }
+
     fn finish(mut self) -> Paragraph {
         self.shift_piece();
         if !self.current_line.is_empty() {
@@ -302,6 +372,33 @@ impl<'a, 'b> Parser<'a, 'b> {
 
 // ========================================================================= //
 
+/// Returns the character with its accent removed, or a plain ASCII
+/// equivalent for typographic punctuation.
+fn unaccented(chr: char) -> Option<char> {
+    match chr {
+        'À'...'Å' => Some('A'),
+        'Ç' => Some('C'),
+        'È'...'Ë' => Some('E'),
+        'Ì'...'Ï' => Some('I'),
+        'Ñ' => Some('N'),
+        'Ò'...'Ö' | 'Ø' => Some('O'),
+        'Ù'...'Ü' => Some('U'),
+        'Ý' => Some('Y'),
+        'à'...'å' => Some('a'),
+        'ç' => Some('c'),
+        'è'...'ë' => Some('e'),
+        'ì'...'ï' => Some('i'),
+        'ñ' => Some('n'),
+        'ò'...'ö' | 'ø' => Some('o'),
+        'ù'...'ü' => Some('u'),
+        'ý' | 'ÿ' => Some('y'),
+        '‘' | '’' => Some('\''),
+        '“' | '”' => Some('"'),
+        '–' | '—' => Some('-'),
+        _ => None,
+    }
+}
+
 fn parse_arg(chars: &mut Chars) -> String {
     let mut result = String::new();
     if chars.next() == Some('{') {
@@ -317,3 +414,35 @@ fn parse_arg(chars: &mut Chars) -> String {
 }
 
 // ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use gui::{Align, Offscreen};
+    use super::{Paragraph, unaccented};
+
+    #[test]
+    fn unaccented_chars() {
+        assert_eq!(unaccented('é'), Some('e'));
+        assert_eq!(unaccented('Ö'), Some('O'));
+        assert_eq!(unaccented('’'), Some('\''));
+        assert_eq!(unaccented('e'), None);
+        assert_eq!(unaccented('ß'), None);
+    }
+
+    #[test]
+    fn missing_glyphs_fall_back() {
+        let mut offscreen = Offscreen::new(32, 32);
+        let mut resources = offscreen.resources();
+        let width = |resources: &mut _, font: &str, text: &str| {
+            Paragraph::new(resources, font, Align::Left, text).min_width()
+        };
+        assert_eq!(width(&mut resources, "roman", "Caf\u{e9}"),
+                   width(&mut resources, "roman", "Cafe"));
+        assert_eq!(width(&mut resources, "block", "AbB"),
+                   width(&mut resources, "block", "A") +
+                       width(&mut resources, "roman", "b") +
+                       width(&mut resources, "block", "B"));
+    }
+}
+
+// ========================================================================= //
@@ -22,8 +22,8 @@ use std::collections::HashMap;
 use std::mem;
 use std::rc::Rc;
 
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
-          Resources, Sprite};
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Point,
+          Rect, Resources, Sprite};
 use save::Direction;
 use save::plane::{PlaneGrid, PlaneObj};
 
@@ -222,6 +222,20 @@ impl Element<PlaneGrid, PlaneCmd> for PlaneGridView {
             _ => Action::ignore(),
         }
     }
+
+    fn focus_targets(&self, grid: &PlaneGrid) -> Vec<FocusTarget> {
+        let mut targets = Vec::new();
+        for row in 0..(grid.num_rows() as i32) {
+            for col in 0..(grid.num_cols() as i32) {
+                let rect = Rect::new(self.left + col * TILE_ISIZE,
+                                     self.top + row * TILE_ISIZE,
+                                     TILE_USIZE,
+                                     TILE_USIZE);
+                targets.push(FocusTarget::new(rect));
+            }
+        }
+        targets
+    }
 }
 
 // ========================================================================= //
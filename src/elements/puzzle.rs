@@ -19,20 +19,26 @@
 
 use std::collections::HashMap;
 
-use elements::{FadeStyle, Hud, HudCmd, HudInput, Scene, ScreenFade, Theater};
-use gui::{Action, Canvas, Element, Event, Rect, Resources};
-use save::{Access, Game, Location, PuzzleState};
+use elements::{FadeStyle, Hud, HudCmd, HudInput, Scene, ScreenFade, Theater,
+               Timeline, UndoTree};
+use gui::{Action, Canvas, Element, Event, FocusTarget, Message, Rect,
+          Resources};
+use save::{Access, Game, Location, PuzzleState, TranscriptLine, UndoHistory};
+use save::util::Tomlable;
 
 // ========================================================================= //
 
 pub enum PuzzleCmd {
     Back,
     Info,
+    Log,
+    Hint,
     Undo,
     Redo,
     Reset,
     Replay,
     Solve,
+    Timeline,
     Next,
     Save,
 }
@@ -40,22 +46,59 @@ pub enum PuzzleCmd {
 // ========================================================================= //
 
 pub trait PuzzleView: Element<Game, PuzzleCmd> {
-    fn info_text(&self, game: &Game) -> &'static str;
+    /// The type of the steps in the view's undo tree.
+    type UndoRedo: Clone + Tomlable;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo>;
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo>;
+
+    fn info_text(&self, game: &Game) -> Message;
+
+    /// Returns the number of moves the player has made in this view.
+    fn moves_made(&self) -> i32 { self.timeline().moves_made() }
+
+    /// Returns the lines of dialogue spoken in this view since the last call.
+    fn drain_transcript(&mut self) -> Vec<TranscriptLine> {
+        self.core_mut().drain_transcript()
+    }
+
+    /// Returns the view's undo tree, so that it can be saved.
+    fn undo_history(&self) -> UndoHistory { self.core().undo_history() }
+
+    /// Replaces the view's undo tree with a previously saved one.
+    fn restore_undo_history(&mut self, history: &UndoHistory) {
+        self.core_mut().restore_undo_history(history);
+    }
 
     fn undo(&mut self, game: &mut Game);
 
     fn redo(&mut self, game: &mut Game);
 
+    /// Returns the shape of the view's undo tree, for the timeline overlay.
+    fn timeline(&self) -> Timeline { self.core().timeline() }
+
+    /// Aims the redo line at the given node of the undo tree, and returns
+    /// how many undos and then redos will reach it.
+    fn prepare_jump(&mut self, node: usize) -> (usize, usize) {
+        self.core_mut().prepare_jump(node)
+    }
+
     fn reset(&mut self, game: &mut Game);
 
     fn solve(&mut self, game: &mut Game);
 
+    /// Reveals one step towards the solution, if the puzzle supports hints.
+    /// Returns false (without charging a hint) if no step could be found.
+    fn hint(&mut self, _game: &mut Game) -> bool { false }
+
     fn drain_queue(&mut self);
 }
 
 // ========================================================================= //
 
 pub struct PuzzleCore<U> {
+    location: Location,
     theater: Theater,
     intro_scene: Scene,
     middle_scene: Option<Scene>,
@@ -63,8 +106,7 @@ pub struct PuzzleCore<U> {
     extra_scenes: HashMap<i32, Scene>,
     hud: Hud,
     screen_fade: ScreenFade<PuzzleCmd>,
-    undo_stack: Vec<U>,
-    redo_stack: Vec<U>,
+    undo_tree: UndoTree<U>,
     previously_solved: bool,
 }
 
@@ -75,6 +117,9 @@ impl<U: Clone> PuzzleCore<U> {
                                mut outro_scene: Scene)
                                -> PuzzleCore<U> {
         let mut theater = Theater::new();
+        theater.set_text_speed(resources.text_speed());
+        intro_scene.set_name(scene_name(S::location(), "intro"));
+        outro_scene.set_name(scene_name(S::location(), "outro"));
         if state.is_visited() {
             intro_scene.skip(&mut theater);
             if state.is_solved() {
@@ -84,6 +129,7 @@ impl<U: Clone> PuzzleCore<U> {
             intro_scene.begin(&mut theater);
         }
         PuzzleCore {
+            location: S::location(),
             theater: theater,
             intro_scene: intro_scene,
             middle_scene: None,
@@ -91,8 +137,7 @@ impl<U: Clone> PuzzleCore<U> {
             extra_scenes: HashMap::new(),
             hud: Hud::new(resources, visible, S::location()),
             screen_fade: ScreenFade::new(resources, fade.0, fade.1),
-            undo_stack: Vec::new(),
-            redo_stack: Vec::new(),
+            undo_tree: UndoTree::new(),
             previously_solved: state.access() >= Access::Solved,
         }
     }
@@ -107,7 +152,12 @@ impl<U: Clone> PuzzleCore<U> {
         self.theater.drain_queue()
     }
 
-    pub fn add_extra_scene(&mut self, (key, scene): (i32, Scene)) {
+    pub fn drain_transcript(&mut self) -> Vec<TranscriptLine> {
+        self.theater.drain_transcript()
+    }
+
+    pub fn add_extra_scene(&mut self, (key, mut scene): (i32, Scene)) {
+        scene.set_name(scene_name(self.location, &format!("extra{}", key)));
         self.extra_scenes.insert(key, scene);
     }
 
@@ -138,32 +188,18 @@ impl<U: Clone> PuzzleCore<U> {
         self.outro_scene.begin(&mut self.theater);
     }
 
-    pub fn push_undo(&mut self, change: U) {
-        self.undo_stack.push(change);
-        self.redo_stack.clear();
-    }
+    pub fn push_undo(&mut self, change: U) { self.undo_tree.push(change); }
 
-    pub fn pop_undo(&mut self) -> Option<U> {
-        if let Some(change) = self.undo_stack.pop() {
-            self.redo_stack.push(change.clone());
-            Some(change)
-        } else {
-            None
-        }
-    }
+    pub fn pop_undo(&mut self) -> Option<U> { self.undo_tree.undo() }
 
-    pub fn pop_redo(&mut self) -> Option<U> {
-        if let Some(change) = self.redo_stack.pop() {
-            self.undo_stack.push(change.clone());
-            Some(change)
-        } else {
-            None
-        }
-    }
+    pub fn pop_redo(&mut self) -> Option<U> { self.undo_tree.redo() }
+
+    pub fn clear_undo_redo(&mut self) { self.undo_tree.clear(); }
 
-    pub fn clear_undo_redo(&mut self) {
-        self.undo_stack.clear();
-        self.redo_stack.clear();
+    pub fn timeline(&self) -> Timeline { self.undo_tree.timeline() }
+
+    pub fn prepare_jump(&mut self, node: usize) -> (usize, usize) {
+        self.undo_tree.prepare_jump(node)
     }
 
     fn hud_input<S: PuzzleState>(&self, state: &S) -> HudInput {
@@ -178,18 +214,19 @@ impl<U: Clone> PuzzleCore<U> {
         };
         let mut can_reset = state.can_reset();
         if !can_reset && state.allow_reset_for_undo_redo() {
-            can_reset = !self.undo_stack.is_empty() ||
-                !self.redo_stack.is_empty();
+            can_reset = !self.undo_tree.is_empty();
         }
         HudInput {
-            name: S::location().name(),
+            location: S::location(),
             access: state.access(),
             is_paused: scene.is_paused(),
             show_skip: scene.show_skip(),
             active: self.screen_fade.is_transparent() && scene.is_finished(),
-            can_undo: !self.undo_stack.is_empty(),
-            can_redo: !self.redo_stack.is_empty(),
+            can_undo: self.undo_tree.can_undo(),
+            can_redo: self.undo_tree.can_redo(),
+            has_timeline: !self.undo_tree.is_empty(),
             can_reset: can_reset,
+            can_hint: state.can_hint(),
         }
     }
 
@@ -217,6 +254,13 @@ impl<U: Clone> PuzzleCore<U> {
         self.screen_fade.draw(&(), canvas);
     }
 
+    /// Returns the HUD buttons that the keyboard cursor can select.  Puzzle
+    /// views add their own interactive cells to these.
+    pub fn focus_targets<S: PuzzleState>(&self, state: &S)
+                                         -> Vec<FocusTarget> {
+        self.hud.focus_targets(&self.hud_input(state))
+    }
+
     pub fn handle_event<S: PuzzleState>(&mut self, event: &Event,
                                         state: &mut S)
                                         -> Action<PuzzleCmd> {
@@ -235,6 +279,8 @@ impl<U: Clone> PuzzleCore<U> {
                     subaction.but_no_value()
                 }
                 Some(&HudCmd::Info) => subaction.but_return(PuzzleCmd::Info),
+                Some(&HudCmd::Log) => subaction.but_return(PuzzleCmd::Log),
+                Some(&HudCmd::Hint) => subaction.but_return(PuzzleCmd::Hint),
                 Some(&HudCmd::Undo) => subaction.but_return(PuzzleCmd::Undo),
                 Some(&HudCmd::Redo) => subaction.but_return(PuzzleCmd::Redo),
                 Some(&HudCmd::Reset) => subaction.but_return(PuzzleCmd::Reset),
@@ -243,6 +289,9 @@ impl<U: Clone> PuzzleCore<U> {
                     subaction.but_no_value()
                 }
                 Some(&HudCmd::Solve) => subaction.but_return(PuzzleCmd::Solve),
+                Some(&HudCmd::Timeline) => {
+                    subaction.but_return(PuzzleCmd::Timeline)
+                }
                 Some(&HudCmd::Skip) => {
                     if !self.intro_scene.is_finished() {
                         self.intro_scene.skip(&mut self.theater);
@@ -288,4 +337,20 @@ impl<U: Clone> PuzzleCore<U> {
     }
 }
 
+impl<U: Clone + Tomlable> PuzzleCore<U> {
+    /// Returns the whole undo tree, including its other branches, for
+    /// saving.
+    pub fn undo_history(&self) -> UndoHistory { self.undo_tree.to_history() }
+
+    pub fn restore_undo_history(&mut self, history: &UndoHistory) {
+        self.undo_tree = UndoTree::from_history(history);
+    }
+}
+
+/// Returns the ID for one of a location's scenes, such as
+/// `cube_tangle/intro`.
+fn scene_name(location: Location, part: &str) -> String {
+    format!("{}/{}", location.key(), part)
+}
+
 // ========================================================================= //
@@ -0,0 +1,487 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use save::{UndoHistory, UndoHistoryNode};
+use save::util::Tomlable;
+
+// ========================================================================= //
+
+const ROOT: usize = 0;
+
+// ========================================================================= //
+
+struct UndoNode<U> {
+    parent: usize,
+    depth: usize,
+    change: Option<U>,
+    children: Vec<usize>,
+    redo_child: Option<usize>,
+}
+
+/// A puzzle's undo history.  Unlike a pair of undo/redo stacks, making a new
+/// move after undoing doesn't throw away the moves that were undone; they
+/// remain in the tree as a separate branch that can be jumped back to.
+pub struct UndoTree<U> {
+    nodes: Vec<UndoNode<U>>,
+    current: usize,
+    moves_made: i32,
+}
+
+impl<U: Clone> UndoTree<U> {
+    pub fn new() -> UndoTree<U> {
+        UndoTree {
+            nodes: vec![
+                UndoNode {
+                    parent: ROOT,
+                    depth: 0,
+                    change: None,
+                    children: Vec::new(),
+                    redo_child: None,
+                },
+            ],
+            current: ROOT,
+            moves_made: 0,
+        }
+    }
+
+    /// Returns true if no changes have been recorded at all.
+    pub fn is_empty(&self) -> bool { self.nodes.len() <= 1 }
+
+    pub fn can_undo(&self) -> bool { self.current != ROOT }
+
+    pub fn can_redo(&self) -> bool {
+        self.nodes[self.current].redo_child.is_some()
+    }
+
+    /// Records a new change as a child of the current node, and makes it the
+    /// current node.  Any existing children are kept as other branches.
+    pub fn push(&mut self, change: U) {
+        let index = self.nodes.len();
+        let depth = self.nodes[self.current].depth + 1;
+        self.nodes.push(UndoNode {
+                            parent: self.current,
+                            depth: depth,
+                            change: Some(change),
+                            children: Vec::new(),
+                            redo_child: None,
+                        });
+        let parent = &mut self.nodes[self.current];
+        parent.children.push(index);
+        parent.redo_child = Some(index);
+        self.current = index;
+        self.moves_made += 1;
+    }
+
+    /// Steps back to the parent of the current node, returning the change
+    /// that should be undone.  The parent remembers which branch we came
+    /// from, so that a redo will return to it.
+    pub fn undo(&mut self) -> Option<U> {
+        if self.current == ROOT {
+            return None;
+        }
+        let node = &self.nodes[self.current];
+        self.current = node.parent;
+        node.change.clone()
+    }
+
+    /// Steps forward along the most recently visited branch, returning the
+    /// change that should be redone.
+    pub fn redo(&mut self) -> Option<U> {
+        if let Some(child) = self.nodes[self.current].redo_child {
+            self.current = child;
+            self.nodes[child].change.clone()
+        } else {
+            None
+        }
+    }
+
+    /// Discards all recorded changes.  The count of moves made is kept.
+    pub fn clear(&mut self) {
+        let moves_made = self.moves_made;
+        *self = UndoTree::new();
+        self.moves_made = moves_made;
+    }
+
+    /// Returns the changes from the root to the current node, with the most
+    /// recent change last.
+    #[cfg(test)]
+    pub fn undo_stack(&self) -> Vec<U> {
+        let mut stack = Vec::new();
+        let mut index = self.current;
+        while index != ROOT {
+            let node = &self.nodes[index];
+            stack.extend(node.change.clone());
+            index = node.parent;
+        }
+        stack.reverse();
+        stack
+    }
+
+    /// Returns the changes that repeated redos would apply, with the next
+    /// one last.  Branches off of this line are not included.
+    #[cfg(test)]
+    pub fn redo_stack(&self) -> Vec<U> {
+        let mut stack = Vec::new();
+        let mut index = self.current;
+        while let Some(child) = self.nodes[index].redo_child {
+            stack.extend(self.nodes[child].change.clone());
+            index = child;
+        }
+        stack.reverse();
+        stack
+    }
+
+    /// Aims the redo line at the given node, and returns how many undos and
+    /// then redos will get from the current node to it.
+    pub fn prepare_jump(&mut self, target: usize) -> (usize, usize) {
+        if target >= self.nodes.len() {
+            return (0, 0);
+        }
+        let mut from = self.current;
+        let mut to = target;
+        while self.nodes[from].depth > self.nodes[to].depth {
+            from = self.nodes[from].parent;
+        }
+        while self.nodes[to].depth > self.nodes[from].depth {
+            to = self.nodes[to].parent;
+        }
+        while from != to {
+            from = self.nodes[from].parent;
+            to = self.nodes[to].parent;
+        }
+        let common = from;
+        let mut index = target;
+        while index != common {
+            let parent = self.nodes[index].parent;
+            self.nodes[parent].redo_child = Some(index);
+            index = parent;
+        }
+        let common_depth = self.nodes[common].depth;
+        (self.nodes[self.current].depth - common_depth,
+         self.nodes[target].depth - common_depth)
+    }
+
+    /// Lays out the tree for display, one lane per branch.  The current redo
+    /// line is kept in lane zero.
+    pub fn timeline(&self) -> Timeline {
+        let mut nodes: Vec<TimelineNode> = self.nodes
+            .iter()
+            .map(|node| {
+                     TimelineNode {
+                         parent: node.parent,
+                         depth: node.depth,
+                         lane: 0,
+                     }
+                 })
+            .collect();
+        let mut num_lanes = 1;
+        let mut stack = vec![(ROOT, Some(0))];
+        while let Some((index, lane)) = stack.pop() {
+            // A branch only gets a fresh lane once it is reached, after all
+            // of the earlier branches' subtrees have been laid out, so that
+            // each lane holds a single unbroken line of moves.
+            let lane = lane.unwrap_or_else(|| {
+                                               num_lanes += 1;
+                                               num_lanes - 1
+                                           });
+            nodes[index].lane = lane;
+            let node = &self.nodes[index];
+            let mut children = node.children.clone();
+            if let Some(child) = node.redo_child {
+                children.retain(|&other| other != child);
+                children.insert(0, child);
+            }
+            for (position, &child) in children.iter().enumerate().rev() {
+                let child_lane = if position == 0 { Some(lane) } else { None };
+                stack.push((child, child_lane));
+            }
+        }
+        Timeline {
+            nodes: nodes,
+            current: self.current,
+            num_lanes: num_lanes,
+            moves_made: self.moves_made,
+        }
+    }
+}
+
+impl<U: Clone + Tomlable> UndoTree<U> {
+    /// Returns the whole tree, including its other branches, for saving.
+    pub fn to_history(&self) -> UndoHistory {
+        let nodes: Vec<UndoHistoryNode<U>> = self.nodes
+            .iter()
+            .map(|node| {
+                     UndoHistoryNode {
+                         parent: node.parent,
+                         change: node.change.clone(),
+                         redo_child: node.redo_child,
+                     }
+                 })
+            .collect();
+        UndoHistory::new(&nodes, self.current)
+    }
+
+    /// Rebuilds a tree from a saved history.  The count of moves made starts
+    /// over from zero.
+    pub fn from_history(history: &UndoHistory) -> UndoTree<U> {
+        let mut tree = UndoTree::new();
+        for (index, saved) in history.nodes::<U>().into_iter().enumerate() {
+            if index == ROOT {
+                tree.nodes[ROOT].redo_child = saved.redo_child;
+                continue;
+            }
+            let depth = tree.nodes[saved.parent].depth + 1;
+            tree.nodes[saved.parent].children.push(index);
+            tree.nodes.push(UndoNode {
+                                parent: saved.parent,
+                                depth: depth,
+                                change: saved.change,
+                                children: Vec::new(),
+                                redo_child: saved.redo_child,
+                            });
+        }
+        tree.current = history.current();
+        tree
+    }
+}
+
+// ========================================================================= //
+
+/// Where one node of an `UndoTree` appears in its `Timeline`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimelineNode {
+    pub parent: usize,
+    pub depth: usize,
+    pub lane: usize,
+}
+
+/// A snapshot of an `UndoTree`'s shape, without the changes themselves, for
+/// drawing the tree and choosing a node to jump to.  Node 0 is the starting
+/// position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Timeline {
+    nodes: Vec<TimelineNode>,
+    current: usize,
+    num_lanes: usize,
+    moves_made: i32,
+}
+
+impl Timeline {
+    pub fn nodes(&self) -> &[TimelineNode] { &self.nodes }
+
+    pub fn current(&self) -> usize { self.current }
+
+    pub fn num_lanes(&self) -> usize { self.num_lanes }
+
+    /// Returns the number of changes pushed onto the tree since it was
+    /// created, including ones since cleared or restored from a save.
+    pub fn moves_made(&self) -> i32 { self.moves_made }
+
+    pub fn max_depth(&self) -> usize {
+        self.nodes.iter().map(|node| node.depth).max().unwrap_or(0)
+    }
+
+    /// Returns the node one move earlier than the given node, if any.
+    pub fn earlier(&self, index: usize) -> Option<usize> {
+        if index == ROOT { None } else { Some(self.nodes[index].parent) }
+    }
+
+    /// Returns the node one move later than the given node, preferring the
+    /// one in the same lane.
+    pub fn later(&self, index: usize) -> Option<usize> {
+        let lane = self.nodes[index].lane;
+        let mut result = None;
+        for (child, node) in self.nodes.iter().enumerate() {
+            if child != ROOT && node.parent == index {
+                if node.lane == lane {
+                    return Some(child);
+                } else if result.is_none() {
+                    result = Some(child);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the node at the same depth in the nearest lane above (if
+    /// `upward` is true) or below the given node's lane, if any.
+    pub fn across(&self, index: usize, upward: bool) -> Option<usize> {
+        let depth = self.nodes[index].depth;
+        let lane = self.nodes[index].lane;
+        let mut result: Option<usize> = None;
+        for (other, node) in self.nodes.iter().enumerate() {
+            if node.depth != depth ||
+                (upward && node.lane >= lane) ||
+                (!upward && node.lane <= lane)
+            {
+                continue;
+            }
+            let closer = match result {
+                None => true,
+                Some(best) => {
+                    let best_lane = self.nodes[best].lane;
+                    if upward {
+                        node.lane > best_lane
+                    } else {
+                        node.lane < best_lane
+                    }
+                }
+            };
+            if closer {
+                result = Some(other);
+            }
+        }
+        result
+    }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use super::UndoTree;
+
+    #[test]
+    fn linear_undo_and_redo() {
+        let mut tree = UndoTree::new();
+        assert!(tree.is_empty());
+        assert!(!tree.can_undo());
+        tree.push(1);
+        tree.push(2);
+        assert_eq!(tree.undo(), Some(2));
+        assert_eq!(tree.undo(), Some(1));
+        assert_eq!(tree.undo(), None);
+        assert_eq!(tree.redo(), Some(1));
+        assert_eq!(tree.undo_stack(), vec![1]);
+        assert_eq!(tree.redo_stack(), vec![2]);
+        assert_eq!(tree.redo(), Some(2));
+        assert_eq!(tree.redo(), None);
+        tree.clear();
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn moves_made_survives_clear() {
+        let mut tree = UndoTree::new();
+        tree.push(1);
+        tree.push(2);
+        let mut tree = UndoTree::<i32>::from_history(&tree.to_history());
+        assert_eq!(tree.timeline().moves_made(), 0);
+        tree.push(4);
+        tree.undo();
+        tree.push(5);
+        tree.clear();
+        tree.push(6);
+        assert_eq!(tree.timeline().moves_made(), 3);
+    }
+
+    #[test]
+    fn new_move_keeps_old_branch() {
+        let mut tree = UndoTree::new();
+        tree.push(1);
+        tree.push(2);
+        tree.undo();
+        tree.push(3);
+        assert!(!tree.can_redo());
+        assert_eq!(tree.undo_stack(), vec![1, 3]);
+        let timeline = tree.timeline();
+        assert_eq!(timeline.nodes().len(), 4);
+        assert_eq!(timeline.num_lanes(), 2);
+        assert_eq!(timeline.current(), 3);
+        // The abandoned move (node 2) is still in the tree.
+        assert_eq!(tree.prepare_jump(2), (1, 1));
+        assert_eq!(tree.undo(), Some(3));
+        assert_eq!(tree.redo(), Some(2));
+        assert_eq!(tree.undo_stack(), vec![1, 2]);
+    }
+
+    #[test]
+    fn jump_across_branches() {
+        let mut tree = UndoTree::new();
+        tree.push('a');
+        tree.push('b');
+        tree.push('c');
+        tree.undo();
+        tree.undo();
+        tree.push('d');
+        tree.push('e');
+        // Nodes: 1=a, 2=b, 3=c, 4=d, 5=e; current is e.
+        assert_eq!(tree.prepare_jump(3), (2, 2));
+        assert_eq!(tree.undo(), Some('e'));
+        assert_eq!(tree.undo(), Some('d'));
+        assert_eq!(tree.redo(), Some('b'));
+        assert_eq!(tree.redo(), Some('c'));
+        assert_eq!(tree.prepare_jump(0), (3, 0));
+        assert_eq!(tree.prepare_jump(3), (0, 0));
+        assert_eq!(tree.prepare_jump(99), (0, 0));
+    }
+
+    #[test]
+    fn timeline_lanes_and_navigation() {
+        let mut tree = UndoTree::new();
+        tree.push(1);
+        tree.push(2);
+        tree.undo();
+        tree.push(3);
+        tree.push(4);
+        tree.undo();
+        tree.undo();
+        tree.undo();
+        tree.push(5);
+        // Nodes: 1 -> 2 and 1 -> 3 -> 4; 5 branches off of the root.
+        let timeline = tree.timeline();
+        let lanes: Vec<usize> =
+            timeline.nodes().iter().map(|node| node.lane).collect();
+        assert_eq!(lanes, vec![0, 1, 2, 1, 1, 0]);
+        assert_eq!(timeline.num_lanes(), 3);
+        assert_eq!(timeline.max_depth(), 3);
+        assert_eq!(timeline.earlier(3), Some(1));
+        assert_eq!(timeline.earlier(0), None);
+        assert_eq!(timeline.later(1), Some(3));
+        assert_eq!(timeline.later(0), Some(5));
+        assert_eq!(timeline.later(4), None);
+        assert_eq!(timeline.across(5, false), Some(1));
+        assert_eq!(timeline.across(3, false), Some(2));
+        assert_eq!(timeline.across(2, true), Some(3));
+        assert_eq!(timeline.across(3, true), None);
+    }
+
+    #[test]
+    fn history_round_trip_keeps_branches() {
+        let mut tree = UndoTree::new();
+        tree.push(1);
+        tree.push(2);
+        tree.undo();
+        tree.push(3);
+        tree.push(4);
+        tree.undo();
+        let copy = UndoTree::<i32>::from_history(&tree.to_history());
+        assert_eq!(copy.timeline().nodes(), tree.timeline().nodes());
+        assert_eq!(copy.timeline().current(), tree.timeline().current());
+        assert_eq!(copy.undo_stack(), vec![1, 3]);
+        assert_eq!(copy.redo_stack(), vec![4]);
+        // The abandoned move is still there to jump back to.
+        let mut copy = copy;
+        assert_eq!(copy.prepare_jump(2), (1, 1));
+        assert_eq!(copy.undo(), Some(3));
+        assert_eq!(copy.redo(), Some(2));
+    }
+}
+
+// ========================================================================= //
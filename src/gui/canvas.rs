@@ -17,26 +17,23 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use sdl2::pixels::Color;
 use sdl2::rect::{Point, Rect};
-use sdl2::render::Canvas as SdlCanvas;
-use sdl2::video::Window as SdlWindow;
 use super::background::Background;
 use super::font::Font;
+use super::renderer::Renderer;
 use super::sprite::Sprite;
 
 // ========================================================================= //
 
 pub struct Canvas<'a> {
-    renderer: &'a mut SdlCanvas<SdlWindow>,
+    renderer: &'a mut Renderer,
     offset_rect: Rect,
     clip_rect: Option<Rect>,
     prev_clip_rect: Option<Rect>,
 }
 
 impl<'a> Canvas<'a> {
-    pub fn new(renderer: &'a mut SdlCanvas<SdlWindow>, rect: Rect)
-               -> Canvas<'a> {
+    pub fn new(renderer: &'a mut Renderer, rect: Rect) -> Canvas<'a> {
         Canvas {
             renderer: renderer,
             offset_rect: rect,
@@ -92,18 +89,14 @@ impl<'a> Canvas<'a> {
     }
 
     pub fn clear(&mut self, color: (u8, u8, u8)) {
-        let (r, g, b) = color;
-        self.renderer.set_draw_color(Color::RGB(r, g, b));
         if let Some(clip) = self.clip_rect {
-            self.renderer.fill_rect(clip).unwrap();
+            self.renderer.fill_rect(color, clip);
         } else {
-            self.renderer.clear();
+            self.renderer.clear(color);
         }
     }
 
     pub fn fill_rect(&mut self, color: (u8, u8, u8), mut rect: Rect) {
-        let (r, g, b) = color;
-        self.renderer.set_draw_color(Color::RGB(r, g, b));
         rect.offset(self.offset_rect.x(), self.offset_rect.y());
         if let Some(clip) = self.clip_rect {
             if let Some(intersection) = clip.intersection(rect) {
@@ -112,14 +105,12 @@ impl<'a> Canvas<'a> {
                 rect.resize(0, 0);
             }
         }
-        self.renderer.fill_rect(rect).unwrap();
+        self.renderer.fill_rect(color, rect);
     }
 
     pub fn draw_rect(&mut self, color: (u8, u8, u8), mut rect: Rect) {
-        let (r, g, b) = color;
-        self.renderer.set_draw_color(Color::RGB(r, g, b));
         rect.offset(self.offset_rect.x(), self.offset_rect.y());
-        self.renderer.draw_rect(rect).unwrap();
+        self.renderer.draw_rect(color, rect);
     }
 
     pub fn draw_background(&mut self, background: &Background) {
@@ -130,49 +121,32 @@ impl<'a> Canvas<'a> {
 
     pub fn draw_sprite(&mut self, sprite: &Sprite, mut top_left: Point) {
         top_left = top_left.offset(self.offset_rect.x(), self.offset_rect.y());
-        self.renderer
-            .copy(&sprite.sdl2_texture(),
-                  None,
-                  Some(Rect::new(top_left.x(),
-                                 top_left.y(),
-                                 sprite.width(),
-                                 sprite.height())))
-            .unwrap();
+        let dest = Rect::new(top_left.x(),
+                             top_left.y(),
+                             sprite.width(),
+                             sprite.height());
+        self.renderer.draw_sprite(sprite, dest, 0.0, false, false);
     }
 
     pub fn draw_sprite_transposed(&mut self, sprite: &Sprite,
                                   mut top_left: Point) {
         top_left = top_left.offset(self.offset_rect.x(), self.offset_rect.y());
-        self.renderer
-            .copy_ex(&sprite.sdl2_texture(),
-                     None,
-                     Some(Rect::new(top_left.x(),
-                                    top_left.y(),
-                                    sprite.width(),
-                                    sprite.height())),
-                     90.0,
-                     None,
-                     false,
-                     true)
-            .unwrap();
+        let dest = Rect::new(top_left.x(),
+                             top_left.y(),
+                             sprite.width(),
+                             sprite.height());
+        self.renderer.draw_sprite(sprite, dest, 90.0, false, true);
     }
 
     pub fn draw_sprite_flipped(&mut self, sprite: &Sprite,
                                mut top_left: Point, flip_horz: bool,
                                flip_vert: bool) {
         top_left = top_left.offset(self.offset_rect.x(), self.offset_rect.y());
-        self.renderer
-            .copy_ex(&sprite.sdl2_texture(),
-                     None,
-                     Some(Rect::new(top_left.x(),
-                                    top_left.y(),
-                                    sprite.width(),
-                                    sprite.height())),
-                     0.0,
-                     None,
-                     flip_horz,
-                     flip_vert)
-            .unwrap();
+        let dest = Rect::new(top_left.x(),
+                             top_left.y(),
+                             sprite.width(),
+                             sprite.height());
+        self.renderer.draw_sprite(sprite, dest, 0.0, flip_horz, flip_vert);
     }
 
     pub fn draw_sprite_centered(&mut self, sprite: &Sprite, center: Point) {
@@ -195,14 +169,7 @@ impl<'a> Canvas<'a> {
                              sprite.width(),
                              sprite.height());
         self.renderer
-            .copy_ex(&sprite.sdl2_texture(),
-                     None,
-                     Some(dest),
-                     degrees as f64,
-                     None,
-                     flip_horz,
-                     flip_vert)
-            .unwrap();
+            .draw_sprite(sprite, dest, degrees as f64, flip_horz, flip_vert);
     }
 
     pub fn draw_char(&mut self, font: &Font, alignment: Align, start: Point,
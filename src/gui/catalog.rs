@@ -0,0 +1,197 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::collections::HashMap;
+use toml;
+
+// ========================================================================= //
+
+/// A piece of user-facing text, identified by a stable ID that message
+/// catalogs can use to provide a translation.  The English text is used when
+/// the current catalog has no entry for the ID.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Message {
+    pub id: &'static str,
+    pub english: &'static str,
+}
+
+// ========================================================================= //
+
+/// A set of translated messages for one locale, loaded from a TOML file.
+/// Nested tables are flattened into dotted IDs, so that the `pause` key in
+/// the `[hud]` table has the ID `hud.pause`.  Each value is either a string
+/// or a list of `[string, string]` pairs (used for word puzzle answers and
+/// clues).
+#[derive(Debug, Default)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+    pairs: HashMap<String, Vec<(String, String)>>,
+}
+
+impl Catalog {
+    /// Returns an empty catalog, in which every message is in English.
+    pub fn new() -> Catalog { Catalog::default() }
+
+    pub fn parse(text: &str) -> Result<Catalog, String> {
+        let value =
+            text.parse::<toml::Value>().map_err(|err| err.to_string())?;
+        let mut catalog = Catalog::new();
+        catalog.add_value(String::new(), value)?;
+        Ok(catalog)
+    }
+
+    fn add_value(&mut self, id: String, value: toml::Value)
+                 -> Result<(), String> {
+        match value {
+            toml::Value::Table(table) => {
+                for (key, value) in table.into_iter() {
+                    let id = if id.is_empty() {
+                        key
+                    } else {
+                        format!("{}.{}", id, key)
+                    };
+                    self.add_value(id, value)?;
+                }
+            }
+            toml::Value::String(string) => {
+                self.messages.insert(id, string);
+            }
+            toml::Value::Array(array) => {
+                let mut pairs = Vec::with_capacity(array.len());
+                for item in array.into_iter() {
+                    match to_pair(item) {
+                        Some(pair) => pairs.push(pair),
+                        None => {
+                            return Err(format!("{}: expected a list of \
+                                                [string, string] pairs",
+                                               id))
+                        }
+                    }
+                }
+                self.pairs.insert(id, pairs);
+            }
+            _ => return Err(format!("{}: unsupported value", id)),
+        }
+        Ok(())
+    }
+
+    /// Returns the translation for the given ID, if the catalog has one.
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.messages.get(id).map(String::as_str)
+    }
+
+    /// Returns the list of pairs for the given ID, if the catalog has one.
+    pub fn get_pairs(&self, id: &str) -> Option<&[(String, String)]> {
+        self.pairs.get(id).map(Vec::as_slice)
+    }
+
+    /// Returns the translation of the message, or its English text if the
+    /// catalog has no translation for it.
+    pub fn message(&self, message: Message) -> &str {
+        self.localize(message.id, message.english)
+    }
+
+    /// Returns the translation for the given ID, or `english` if the catalog
+    /// has no translation for it.
+    pub fn localize<'a>(&'a self, id: &str, english: &'a str) -> &'a str {
+        self.get(id).unwrap_or(english)
+    }
+}
+
+fn to_pair(value: toml::Value) -> Option<(String, String)> {
+    if let toml::Value::Array(array) = value {
+        if array.len() == 2 {
+            let mut strings = array.into_iter().filter_map(|value| {
+                match value {
+                    toml::Value::String(string) => Some(string),
+                    _ => None,
+                }
+            });
+            if let (Some(first), Some(second)) =
+                (strings.next(), strings.next())
+            {
+                return Some((first, second));
+            }
+        }
+    }
+    None
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::{Catalog, Message};
+
+    const GREETING: Message = Message {
+        id: "title.greeting",
+        english: "Hello",
+    };
+
+    #[test]
+    fn empty_catalog_uses_english() {
+        let catalog = Catalog::new();
+        assert_eq!(catalog.message(GREETING), "Hello");
+        assert_eq!(catalog.get_pairs("sauce.clues"), None);
+    }
+
+    #[test]
+    fn parse_nested_tables_and_pairs() {
+        let catalog = Catalog::parse("[title]\n\
+                                      greeting = \"Bonjour\"\n\
+                                      [sauce]\n\
+                                      clues = [[\"UN\", \"one\"], \
+                                               [\"DEUX\", \"two\"]]\n")
+            .unwrap();
+        assert_eq!(catalog.message(GREETING), "Bonjour");
+        assert_eq!(catalog.localize("title.farewell", "Bye"), "Bye");
+        assert_eq!(catalog.get_pairs("sauce.clues"),
+                   Some(&[("UN".to_string(), "one".to_string()),
+                          ("DEUX".to_string(), "two".to_string())]
+                            as &[(String, String)]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Catalog::parse("greeting = ").is_err());
+        assert!(Catalog::parse("count = 5").is_err());
+        assert!(Catalog::parse("clues = [[\"A\", \"B\", \"C\"]]").is_err());
+        assert!(Catalog::parse("clues = [\"A\"]").is_err());
+    }
+
+    #[test]
+    fn all_catalogs_parse() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("data/locale");
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension() == Some("toml".as_ref()) {
+                let text = fs::read_to_string(&path).unwrap();
+                if let Err(error) = Catalog::parse(&text) {
+                    panic!("{:?}: {}", path, error);
+                }
+            }
+        }
+    }
+}
+
+// ========================================================================= //
@@ -0,0 +1,508 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use sdl2;
+use sdl2::GameControllerSubsystem;
+use sdl2::controller::{Axis, Button, GameController};
+use std::collections::{BTreeMap, HashMap};
+
+use super::event::{Event, KeyMod, Keycode};
+
+// ========================================================================= //
+
+const STICK_DEADZONE: i16 = 16000;
+const STICK_REPEAT_DELAY: i32 = 8;
+const STICK_REPEAT_INTERVAL: i32 = 3;
+
+// ========================================================================= //
+
+/// A button on a game controller, using SDL's standard controller layout.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ControllerButton {
+    A,
+    B,
+    X,
+    Y,
+    Back,
+    Start,
+    LeftShoulder,
+    RightShoulder,
+    LeftStick,
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl ControllerButton {
+    pub fn all() -> &'static [ControllerButton] {
+        &[
+            ControllerButton::A,
+            ControllerButton::B,
+            ControllerButton::X,
+            ControllerButton::Y,
+            ControllerButton::Back,
+            ControllerButton::Start,
+            ControllerButton::LeftShoulder,
+            ControllerButton::RightShoulder,
+            ControllerButton::LeftStick,
+            ControllerButton::RightStick,
+            ControllerButton::DPadUp,
+            ControllerButton::DPadDown,
+            ControllerButton::DPadLeft,
+            ControllerButton::DPadRight,
+        ]
+    }
+
+    /// Returns the name of the button, as used in SDL's controller mappings.
+    pub fn name(self) -> &'static str {
+        match self {
+            ControllerButton::A => "a",
+            ControllerButton::B => "b",
+            ControllerButton::X => "x",
+            ControllerButton::Y => "y",
+            ControllerButton::Back => "back",
+            ControllerButton::Start => "start",
+            ControllerButton::LeftShoulder => "leftshoulder",
+            ControllerButton::RightShoulder => "rightshoulder",
+            ControllerButton::LeftStick => "leftstick",
+            ControllerButton::RightStick => "rightstick",
+            ControllerButton::DPadUp => "dpup",
+            ControllerButton::DPadDown => "dpdown",
+            ControllerButton::DPadLeft => "dpleft",
+            ControllerButton::DPadRight => "dpright",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ControllerButton> {
+        ControllerButton::all()
+            .iter()
+            .cloned()
+            .find(|button| button.name() == name)
+    }
+
+    fn from_sdl2(button: Button) -> Option<ControllerButton> {
+        match button {
+            Button::A => Some(ControllerButton::A),
+            Button::B => Some(ControllerButton::B),
+            Button::X => Some(ControllerButton::X),
+            Button::Y => Some(ControllerButton::Y),
+            Button::Back => Some(ControllerButton::Back),
+            Button::Guide => None,
+            Button::Start => Some(ControllerButton::Start),
+            Button::LeftStick => Some(ControllerButton::LeftStick),
+            Button::RightStick => Some(ControllerButton::RightStick),
+            Button::LeftShoulder => Some(ControllerButton::LeftShoulder),
+            Button::RightShoulder => Some(ControllerButton::RightShoulder),
+            Button::DPadUp => Some(ControllerButton::DPadUp),
+            Button::DPadDown => Some(ControllerButton::DPadDown),
+            Button::DPadLeft => Some(ControllerButton::DPadLeft),
+            Button::DPadRight => Some(ControllerButton::DPadRight),
+        }
+    }
+}
+
+// ========================================================================= //
+
+/// What pressing a controller button does.  Most actions stand in for a key
+/// press, so that controllers drive the same keyboard cursor as the arrow
+/// keys do.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ControllerAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Clicks on the focused item (like Enter).
+    Select,
+    /// Skips a scene or closes a dialog (like Escape).
+    Cancel,
+    /// Moves focus to the next item (like Tab).
+    Next,
+    /// Moves focus to the previous item (like Shift+Tab).
+    Previous,
+    /// While held, directions drag the focused item (like Shift).
+    Drag,
+    Undo,
+    Redo,
+}
+
+impl ControllerAction {
+    pub fn all() -> &'static [ControllerAction] {
+        &[
+            ControllerAction::Up,
+            ControllerAction::Down,
+            ControllerAction::Left,
+            ControllerAction::Right,
+            ControllerAction::Select,
+            ControllerAction::Cancel,
+            ControllerAction::Next,
+            ControllerAction::Previous,
+            ControllerAction::Drag,
+            ControllerAction::Undo,
+            ControllerAction::Redo,
+        ]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ControllerAction::Up => "up",
+            ControllerAction::Down => "down",
+            ControllerAction::Left => "left",
+            ControllerAction::Right => "right",
+            ControllerAction::Select => "select",
+            ControllerAction::Cancel => "cancel",
+            ControllerAction::Next => "next",
+            ControllerAction::Previous => "previous",
+            ControllerAction::Drag => "drag",
+            ControllerAction::Undo => "undo",
+            ControllerAction::Redo => "redo",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ControllerAction> {
+        ControllerAction::all()
+            .iter()
+            .cloned()
+            .find(|action| action.name() == name)
+    }
+
+    /// Returns the key press that this action stands for, if any.
+    fn key_event(self, drag_held: bool) -> Option<Event> {
+        let arrow_mod = if drag_held {
+            KeyMod::shift()
+        } else {
+            KeyMod::none()
+        };
+        let (keycode, keymod) = match self {
+            ControllerAction::Up => (Keycode::Up, arrow_mod),
+            ControllerAction::Down => (Keycode::Down, arrow_mod),
+            ControllerAction::Left => (Keycode::Left, arrow_mod),
+            ControllerAction::Right => (Keycode::Right, arrow_mod),
+            ControllerAction::Select => (Keycode::Return, KeyMod::none()),
+            ControllerAction::Cancel => (Keycode::Escape, KeyMod::none()),
+            ControllerAction::Next => (Keycode::Tab, KeyMod::none()),
+            ControllerAction::Previous => (Keycode::Tab, KeyMod::shift()),
+            ControllerAction::Drag => return None,
+            ControllerAction::Undo => (Keycode::Z, KeyMod::command()),
+            ControllerAction::Redo => {
+                (Keycode::Z, KeyMod::command() | KeyMod::shift())
+            }
+        };
+        Some(Event::KeyDown(keycode, keymod))
+    }
+}
+
+// ========================================================================= //
+
+/// Maps controller buttons to actions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ControllerMap {
+    bindings: BTreeMap<ControllerButton, ControllerAction>,
+}
+
+impl ControllerMap {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub fn with_defaults() -> ControllerMap {
+        let bindings = [
+            (ControllerButton::A, ControllerAction::Select),
+            (ControllerButton::B, ControllerAction::Cancel),
+            (ControllerButton::X, ControllerAction::Drag),
+            (ControllerButton::Y, ControllerAction::Undo),
+            (ControllerButton::Back, ControllerAction::Redo),
+            (ControllerButton::Start, ControllerAction::Select),
+            (ControllerButton::LeftShoulder, ControllerAction::Previous),
+            (ControllerButton::RightShoulder, ControllerAction::Next),
+            (ControllerButton::DPadUp, ControllerAction::Up),
+            (ControllerButton::DPadDown, ControllerAction::Down),
+            (ControllerButton::DPadLeft, ControllerAction::Left),
+            (ControllerButton::DPadRight, ControllerAction::Right),
+        ];
+        ControllerMap { bindings: bindings.iter().cloned().collect() }
+    }
+
+    pub fn action(&self, button: ControllerButton)
+                  -> Option<ControllerAction> {
+        self.bindings.get(&button).cloned()
+    }
+
+    /// Rebinds a button; binding it to `None` makes it do nothing.
+    pub fn bind(&mut self, button: ControllerButton,
+                action: Option<ControllerAction>) {
+        match action {
+            Some(action) => self.bindings.insert(button, action),
+            None => self.bindings.remove(&button),
+        };
+    }
+}
+
+// ========================================================================= //
+
+/// Turns controller input into key events, independent of SDL's controller
+/// objects.  The left stick acts like the D-pad, repeating while held.
+struct PadState {
+    map: ControllerMap,
+    drag_held: bool,
+    stick_x: i16,
+    stick_y: i16,
+    stick_action: Option<ControllerAction>,
+    repeat_frames: i32,
+}
+
+impl PadState {
+    fn new() -> PadState {
+        PadState {
+            map: ControllerMap::with_defaults(),
+            drag_held: false,
+            stick_x: 0,
+            stick_y: 0,
+            stick_action: None,
+            repeat_frames: 0,
+        }
+    }
+
+    fn button_down(&mut self, button: ControllerButton) -> Option<Event> {
+        let action = self.map.action(button);
+        if action == Some(ControllerAction::Drag) {
+            self.drag_held = true;
+        }
+        action.and_then(|action| action.key_event(self.drag_held))
+    }
+
+    fn button_up(&mut self, button: ControllerButton) {
+        if self.map.action(button) == Some(ControllerAction::Drag) {
+            self.drag_held = false;
+        }
+    }
+
+    fn stick_motion(&mut self, horizontal: bool, value: i16)
+                    -> Option<Event> {
+        if horizontal {
+            self.stick_x = value;
+        } else {
+            self.stick_y = value;
+        }
+        let (x, y) = (self.stick_x as i32, self.stick_y as i32);
+        let deadzone = STICK_DEADZONE as i32;
+        let action = if x.abs() < deadzone && y.abs() < deadzone {
+            None
+        } else if x.abs() >= y.abs() {
+            Some(if x < 0 {
+                     ControllerAction::Left
+                 } else {
+                     ControllerAction::Right
+                 })
+        } else {
+            Some(if y < 0 {
+                     ControllerAction::Up
+                 } else {
+                     ControllerAction::Down
+                 })
+        };
+        if action == self.stick_action {
+            return None;
+        }
+        self.stick_action = action;
+        self.repeat_frames = STICK_REPEAT_DELAY;
+        action.and_then(|action| action.key_event(self.drag_held))
+    }
+
+    fn tick(&mut self) -> Option<Event> {
+        if let Some(action) = self.stick_action {
+            self.repeat_frames -= 1;
+            if self.repeat_frames <= 0 {
+                self.repeat_frames = STICK_REPEAT_INTERVAL;
+                return action.key_event(self.drag_held);
+            }
+        }
+        None
+    }
+
+    fn release_all(&mut self) {
+        self.drag_held = false;
+        self.stick_x = 0;
+        self.stick_y = 0;
+        self.stick_action = None;
+    }
+}
+
+// ========================================================================= //
+
+/// Keeps track of connected game controllers (opening and closing them as
+/// they are plugged in and unplugged) and translates their input.
+pub struct Controllers {
+    subsystem: Option<GameControllerSubsystem>,
+    open: HashMap<u32, GameController>,
+    state: PadState,
+}
+
+impl Controllers {
+    pub fn new(subsystem: Option<GameControllerSubsystem>) -> Controllers {
+        Controllers {
+            subsystem: subsystem,
+            open: HashMap::new(),
+            state: PadState::new(),
+        }
+    }
+
+    pub fn set_map(&mut self, map: ControllerMap) { self.state.map = map; }
+
+    /// Handles an SDL controller event, returning the key event that it
+    /// stands for (if any).
+    pub fn handle_sdl2(&mut self, event: &sdl2::event::Event)
+                       -> Option<Event> {
+        match event {
+            &sdl2::event::Event::ControllerDeviceAdded { which, .. } => {
+                self.open_controller(which as u32);
+            }
+            &sdl2::event::Event::ControllerDeviceRemoved { which, .. } => {
+                if self.open.remove(&(which as u32)).is_some() &&
+                    self.open.is_empty()
+                {
+                    self.state.release_all();
+                }
+            }
+            &sdl2::event::Event::ControllerButtonDown { button, .. } => {
+                return ControllerButton::from_sdl2(button)
+                    .and_then(|button| self.state.button_down(button));
+            }
+            &sdl2::event::Event::ControllerButtonUp { button, .. } => {
+                if let Some(button) = ControllerButton::from_sdl2(button) {
+                    self.state.button_up(button);
+                }
+            }
+            &sdl2::event::Event::ControllerAxisMotion {
+                axis: Axis::LeftX,
+                value,
+                ..
+            } => return self.state.stick_motion(true, value),
+            &sdl2::event::Event::ControllerAxisMotion {
+                axis: Axis::LeftY,
+                value,
+                ..
+            } => return self.state.stick_motion(false, value),
+            _ => {}
+        }
+        None
+    }
+
+    /// Called once per clock tick; returns a repeated direction if the stick
+    /// is being held.
+    pub fn tick(&mut self) -> Option<Event> { self.state.tick() }
+
+    fn open_controller(&mut self, index: u32) {
+        if let Some(ref subsystem) = self.subsystem {
+            if !subsystem.is_game_controller(index) {
+                return;
+            }
+            match subsystem.open(index) {
+                Ok(controller) => {
+                    self.open.insert(controller.instance_id() as u32,
+                                     controller);
+                }
+                Err(error) => {
+                    println!("Failed to open game controller: {}", error);
+                }
+            }
+        }
+    }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use super::{ControllerAction, ControllerButton, ControllerMap,
+                PadState, STICK_REPEAT_DELAY, STICK_REPEAT_INTERVAL};
+    use super::super::event::{Event, KeyMod, Keycode};
+
+    #[test]
+    fn name_round_trip() {
+        for &button in ControllerButton::all() {
+            assert_eq!(ControllerButton::from_name(button.name()),
+                       Some(button));
+        }
+        for &action in ControllerAction::all() {
+            assert_eq!(ControllerAction::from_name(action.name()),
+                       Some(action));
+        }
+        assert_eq!(ControllerButton::from_name("guide"), None);
+        assert_eq!(ControllerAction::from_name("jump"), None);
+    }
+
+    #[test]
+    fn buttons_map_to_key_presses() {
+        let mut state = PadState::new();
+        assert_eq!(state.button_down(ControllerButton::A),
+                   Some(Event::KeyDown(Keycode::Return, KeyMod::none())));
+        assert_eq!(state.button_down(ControllerButton::DPadLeft),
+                   Some(Event::KeyDown(Keycode::Left, KeyMod::none())));
+        assert_eq!(state.button_down(ControllerButton::LeftShoulder),
+                   Some(Event::KeyDown(Keycode::Tab, KeyMod::shift())));
+        assert_eq!(state.button_down(ControllerButton::LeftStick), None);
+    }
+
+    #[test]
+    fn drag_button_acts_as_shift() {
+        let mut state = PadState::new();
+        assert_eq!(state.button_down(ControllerButton::X), None);
+        assert_eq!(state.button_down(ControllerButton::DPadUp),
+                   Some(Event::KeyDown(Keycode::Up, KeyMod::shift())));
+        state.button_up(ControllerButton::X);
+        assert_eq!(state.button_down(ControllerButton::DPadUp),
+                   Some(Event::KeyDown(Keycode::Up, KeyMod::none())));
+    }
+
+    #[test]
+    fn rebound_buttons() {
+        let mut map = ControllerMap::with_defaults();
+        map.bind(ControllerButton::A, Some(ControllerAction::Cancel));
+        map.bind(ControllerButton::B, None);
+        let mut state = PadState::new();
+        state.map = map;
+        assert_eq!(state.button_down(ControllerButton::A),
+                   Some(Event::KeyDown(Keycode::Escape, KeyMod::none())));
+        assert_eq!(state.button_down(ControllerButton::B), None);
+    }
+
+    #[test]
+    fn held_stick_repeats() {
+        let mut state = PadState::new();
+        let right = Some(Event::KeyDown(Keycode::Right, KeyMod::none()));
+        assert_eq!(state.stick_motion(true, 2000), None);
+        assert_eq!(state.stick_motion(true, 30000), right);
+        assert_eq!(state.stick_motion(false, 1000), None);
+        for _ in 1..STICK_REPEAT_DELAY {
+            assert_eq!(state.tick(), None);
+        }
+        assert_eq!(state.tick(), right);
+        for _ in 1..STICK_REPEAT_INTERVAL {
+            assert_eq!(state.tick(), None);
+        }
+        assert_eq!(state.tick(), right);
+        assert_eq!(state.stick_motion(false, -32000),
+                   Some(Event::KeyDown(Keycode::Up, KeyMod::none())));
+        assert_eq!(state.stick_motion(true, 0), None);
+        assert_eq!(state.stick_motion(false, 0), None);
+        assert_eq!(state.tick(), None);
+    }
+}
+
+// ========================================================================= //
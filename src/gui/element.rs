@@ -20,12 +20,17 @@
 use super::action::Action;
 use super::canvas::Canvas;
 use super::event::Event;
+use super::focus::FocusTarget;
 
 // ========================================================================= //
 
 pub trait Element<S, A> {
     fn draw(&self, state: &S, canvas: &mut Canvas);
     fn handle_event(&mut self, event: &Event, state: &mut S) -> Action<A>;
+
+    /// Returns the parts of this element that the keyboard cursor can
+    /// select.  Elements with no targets are driven by a free pointer.
+    fn focus_targets(&self, _state: &S) -> Vec<FocusTarget> { Vec::new() }
 }
 
 impl<S, A, E: Element<S, A>> Element<S, A> for Vec<E> {
@@ -45,6 +50,12 @@ impl<S, A, E: Element<S, A>> Element<S, A> for Vec<E> {
         }
         action
     }
+
+    fn focus_targets(&self, state: &S) -> Vec<FocusTarget> {
+        self.iter()
+            .flat_map(|element| element.focus_targets(state))
+            .collect()
+    }
 }
 
 // ========================================================================= //
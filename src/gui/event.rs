@@ -108,6 +108,10 @@ impl KeyMod {
 
     pub fn command() -> KeyMod { KeyMod { bits: 0x4 } }
 
+    pub fn bits(&self) -> u8 { self.bits }
+
+    pub fn from_bits(bits: u8) -> KeyMod { KeyMod { bits: bits & 0x7 } }
+
     fn from_sdl2(kmod: sdl2::keyboard::Mod) -> KeyMod {
         let mut result = KeyMod::none();
 
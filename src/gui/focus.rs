@@ -0,0 +1,540 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use sdl2::rect::{Point, Rect};
+use std::f64::consts::FRAC_PI_3;
+
+use super::action::Action;
+use super::canvas::Canvas;
+use super::element::Element;
+use super::event::{Event, KeyMod, Keycode};
+
+// ========================================================================= //
+
+const FREE_STEP: i32 = 16;
+const FREE_DRAG_DIST: i32 = 32;
+const CROSSHAIR_SIZE: i32 = 5;
+const HIGHLIGHT_COLOR: (u8, u8, u8) = (255, 255, 0);
+
+// ========================================================================= //
+
+/// How a focused target responds to Shift+arrow keys.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FocusGesture {
+    /// Drag in a straight line by one target width or height.
+    Drag,
+    /// Drag around the target's center by a sixth of a turn.
+    Turn,
+}
+
+/// A region of an element that can be selected with the keyboard.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FocusTarget {
+    pub rect: Rect,
+    pub gesture: FocusGesture,
+}
+
+impl FocusTarget {
+    pub fn new(rect: Rect) -> FocusTarget {
+        FocusTarget {
+            rect: rect,
+            gesture: FocusGesture::Drag,
+        }
+    }
+
+    pub fn turn(rect: Rect) -> FocusTarget {
+        FocusTarget {
+            rect: rect,
+            gesture: FocusGesture::Turn,
+        }
+    }
+}
+
+// ========================================================================= //
+
+/// Wraps an element so that it can be used without a mouse.  Arrow keys move
+/// a highlighted cursor between the element's focus targets (or move a free
+/// pointer, if it has none), Tab and Shift+Tab cycle through the targets,
+/// Enter and Space click on the focused target, and Shift+arrow keys perform
+/// the focused target's drag gesture.  Key presses go to the wrapped element
+/// first, so that its own keyboard shortcuts keep working.
+pub struct KeyboardFocus<E> {
+    element: E,
+    visible: Rect,
+    cursor: Option<Point>,
+}
+
+impl<E> KeyboardFocus<E> {
+    pub fn new(element: E, visible: Rect) -> KeyboardFocus<E> {
+        KeyboardFocus {
+            element: element,
+            visible: visible,
+            cursor: None,
+        }
+    }
+
+    pub fn element(&self) -> &E { &self.element }
+
+    pub fn element_mut(&mut self) -> &mut E { &mut self.element }
+
+    fn handle_key<S, A>(&mut self, keycode: Keycode, keymod: KeyMod,
+                        state: &mut S)
+                        -> Action<A>
+        where E: Element<S, A>
+    {
+        let targets = self.element.focus_targets(state);
+        let cursor = match self.cursor {
+            Some(cursor) => cursor,
+            None => {
+                if is_activation_key(keycode) ||
+                    arrow_delta(keycode).is_some()
+                {
+                    self.cursor = Some(tab_order(&targets)
+                                           .first()
+                                           .map(|target| target.rect.center())
+                                           .unwrap_or(self.visible.center()));
+                    return Action::redraw();
+                }
+                return Action::ignore();
+            }
+        };
+        let focused = focused_target(&targets, cursor);
+        match keycode {
+            Keycode::Tab => {
+                let backward = keymod == KeyMod::shift();
+                if let Some(point) = cycle(&targets, cursor, backward) {
+                    self.cursor = Some(point);
+                    return Action::redraw();
+                }
+            }
+            Keycode::Return | Keycode::KpEnter | Keycode::Space => {
+                let point = focused.map(|target| target.rect.center())
+                    .unwrap_or(cursor);
+                let events = vec![Event::MouseDown(point), Event::MouseUp];
+                return self.dispatch(events, state);
+            }
+            _ => {
+                if let Some(delta) = arrow_delta(keycode) {
+                    if keymod == KeyMod::shift() {
+                        let (events, end) = gesture(focused, cursor, delta);
+                        self.cursor = Some(match focused_target(&targets,
+                                                                end) {
+                            Some(target) => target.rect.center(),
+                            None if targets.is_empty() => self.clamp(end),
+                            None => cursor,
+                        });
+                        return self.dispatch(events, state);
+                    } else if keymod == KeyMod::none() {
+                        let point = if targets.is_empty() {
+                            let (dx, dy) = delta;
+                            self.clamp(cursor +
+                                       Point::new(dx, dy) * FREE_STEP)
+                        } else {
+                            let from = focused
+                                .map(|target| target.rect.center())
+                                .unwrap_or(cursor);
+                            match nearest_in_direction(&targets, from, delta) {
+                                Some(point) => point,
+                                None => return Action::ignore(),
+                            }
+                        };
+                        self.cursor = Some(point);
+                        return Action::redraw();
+                    }
+                }
+            }
+        }
+        Action::ignore()
+    }
+
+    fn dispatch<S, A>(&mut self, events: Vec<Event>, state: &mut S)
+                      -> Action<A>
+        where E: Element<S, A>
+    {
+        let mut action = Action::redraw();
+        for event in events {
+            action.merge(self.element.handle_event(&event, state));
+        }
+        action
+    }
+
+    fn clamp(&self, point: Point) -> Point {
+        let x = point.x().max(self.visible.left())
+            .min(self.visible.right() - 1);
+        let y = point.y().max(self.visible.top())
+            .min(self.visible.bottom() - 1);
+        Point::new(x, y)
+    }
+}
+
+impl<S, A, E: Element<S, A>> Element<S, A> for KeyboardFocus<E> {
+    fn draw(&self, state: &S, canvas: &mut Canvas) {
+        self.element.draw(state, canvas);
+        if let Some(cursor) = self.cursor {
+            let targets = self.element.focus_targets(state);
+            if let Some(target) = focused_target(&targets, cursor) {
+                let rect = target.rect;
+                canvas.draw_rect(HIGHLIGHT_COLOR, rect);
+                canvas.draw_rect(HIGHLIGHT_COLOR,
+                                 Rect::new(rect.x() - 1,
+                                           rect.y() - 1,
+                                           rect.width() + 2,
+                                           rect.height() + 2));
+            } else {
+                let size = 2 * CROSSHAIR_SIZE as u32 + 1;
+                canvas.fill_rect(HIGHLIGHT_COLOR,
+                                 Rect::new(cursor.x() - CROSSHAIR_SIZE,
+                                           cursor.y(),
+                                           size,
+                                           1));
+                canvas.fill_rect(HIGHLIGHT_COLOR,
+                                 Rect::new(cursor.x(),
+                                           cursor.y() - CROSSHAIR_SIZE,
+                                           1,
+                                           size));
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, state: &mut S) -> Action<A> {
+        match event {
+            &Event::KeyDown(keycode, keymod) => {
+                let mut action = self.element.handle_event(event, state);
+                if !action.should_redraw() && !action.should_stop() {
+                    action.merge(self.handle_key(keycode, keymod, state));
+                }
+                action
+            }
+            &Event::MouseDown(_) |
+            &Event::MouseDrag(_) if self.cursor.is_some() => {
+                self.cursor = None;
+                let mut action = Action::redraw();
+                action.merge(self.element.handle_event(event, state));
+                action
+            }
+            _ => self.element.handle_event(event, state),
+        }
+    }
+
+    fn focus_targets(&self, state: &S) -> Vec<FocusTarget> {
+        self.element.focus_targets(state)
+    }
+}
+
+// ========================================================================= //
+
+fn is_activation_key(keycode: Keycode) -> bool {
+    match keycode {
+        Keycode::Return | Keycode::KpEnter | Keycode::Space |
+        Keycode::Tab => true,
+        _ => false,
+    }
+}
+
+fn arrow_delta(keycode: Keycode) -> Option<(i32, i32)> {
+    match keycode {
+        Keycode::Left => Some((-1, 0)),
+        Keycode::Right => Some((1, 0)),
+        Keycode::Up => Some((0, -1)),
+        Keycode::Down => Some((0, 1)),
+        _ => None,
+    }
+}
+
+fn focused_target(targets: &[FocusTarget], point: Point)
+                  -> Option<&FocusTarget> {
+    targets.iter().find(|target| target.rect.contains_point(point))
+}
+
+/// Returns the targets sorted in reading order (top to bottom, then left to
+/// right), which is the order that Tab moves through them.
+fn tab_order(targets: &[FocusTarget]) -> Vec<&FocusTarget> {
+    let mut sorted: Vec<&FocusTarget> = targets.iter().collect();
+    sorted.sort_by_key(|target| {
+                           let center = target.rect.center();
+                           (center.y(), center.x())
+                       });
+    sorted
+}
+
+fn cycle(targets: &[FocusTarget], cursor: Point, backward: bool)
+         -> Option<Point> {
+    let sorted = tab_order(targets);
+    if sorted.is_empty() {
+        return None;
+    }
+    let num = sorted.len();
+    let index = match sorted
+        .iter()
+        .position(|target| target.rect.contains_point(cursor)) {
+        Some(index) if backward => (index + num - 1) % num,
+        Some(index) => (index + 1) % num,
+        None if backward => num - 1,
+        None => 0,
+    };
+    Some(sorted[index].rect.center())
+}
+
+/// Returns the center of the target closest to `from` in the given direction,
+/// preferring targets that are roughly in line with `from`.
+fn nearest_in_direction(targets: &[FocusTarget], from: Point,
+                        (dx, dy): (i32, i32))
+                        -> Option<Point> {
+    targets
+        .iter()
+        .map(|target| target.rect.center())
+        .filter_map(|center| {
+            let delta = center - from;
+            let along = delta.x() * dx + delta.y() * dy;
+            let across = (delta.x() * dy).abs() + (delta.y() * dx).abs();
+            if along > 0 {
+                Some((along + 2 * across, center))
+            } else {
+                None
+            }
+        })
+        .min_by_key(|&(score, center)| (score, center.y(), center.x()))
+        .map(|(_, center)| center)
+}
+
+/// Returns the pointer events for a Shift+arrow gesture on the focused
+/// target (or at the free pointer), along with the point where the drag ends.
+fn gesture(focused: Option<&FocusTarget>, cursor: Point,
+           (dx, dy): (i32, i32))
+           -> (Vec<Event>, Point) {
+    let (start, end) = match focused {
+        None => (cursor, cursor + Point::new(dx, dy) * FREE_DRAG_DIST),
+        Some(&FocusTarget {
+                 rect,
+                 gesture: FocusGesture::Drag,
+             }) => {
+            let center = rect.center();
+            let dist = if dx != 0 { rect.width() } else { rect.height() };
+            (center, center + Point::new(dx, dy) * dist as i32)
+        }
+        Some(&FocusTarget {
+                 rect,
+                 gesture: FocusGesture::Turn,
+             }) => {
+            let center = rect.center();
+            let radius = rect.width().min(rect.height()) as f64 / 4.0;
+            let theta = if dx + dy > 0 { FRAC_PI_3 } else { -FRAC_PI_3 };
+            let start = center + Point::new(radius.round() as i32, 0);
+            let end = center +
+                Point::new((radius * theta.cos()).round() as i32,
+                           (radius * theta.sin()).round() as i32);
+            return (vec![Event::MouseDown(start),
+                         Event::MouseDrag(end),
+                         Event::MouseUp],
+                    center);
+        }
+    };
+    (vec![Event::MouseDown(start), Event::MouseDrag(end), Event::MouseUp],
+     end)
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use sdl2::rect::{Point, Rect};
+
+    use super::{FocusTarget, KeyboardFocus};
+    use super::super::action::Action;
+    use super::super::canvas::Canvas;
+    use super::super::element::Element;
+    use super::super::event::{Event, KeyMod, Keycode};
+
+    /// A 3x3 grid of 10x10 cells that records the pointer events it gets,
+    /// along with presses of its own shortcut key (if any).
+    struct Grid {
+        has_targets: bool,
+        shortcut: Option<Keycode>,
+    }
+
+    impl Element<Vec<Event>, ()> for Grid {
+        fn draw(&self, _: &Vec<Event>, _: &mut Canvas) {}
+
+        fn handle_event(&mut self, event: &Event, events: &mut Vec<Event>)
+                        -> Action<()> {
+            match event {
+                &Event::KeyDown(keycode, _) => {
+                    if self.shortcut == Some(keycode) {
+                        events.push(event.clone());
+                        return Action::redraw();
+                    }
+                }
+                _ => events.push(event.clone()),
+            }
+            Action::ignore()
+        }
+
+        fn focus_targets(&self, _: &Vec<Event>) -> Vec<FocusTarget> {
+            if !self.has_targets {
+                return Vec::new();
+            }
+            let mut targets = Vec::new();
+            for row in 0..3 {
+                for col in 0..3 {
+                    let rect = Rect::new(col * 10, row * 10, 10, 10);
+                    targets.push(FocusTarget::new(rect));
+                }
+            }
+            targets
+        }
+    }
+
+    fn focus(has_targets: bool) -> KeyboardFocus<Grid> {
+        let grid = Grid {
+            has_targets: has_targets,
+            shortcut: None,
+        };
+        KeyboardFocus::new(grid, Rect::new(0, 0, 100, 100))
+    }
+
+    fn press(focus: &mut KeyboardFocus<Grid>, events: &mut Vec<Event>,
+             keycode: Keycode, keymod: KeyMod) {
+        let action = focus.handle_event(&Event::KeyDown(keycode, keymod),
+                                        events);
+        assert!(action.should_redraw());
+    }
+
+    #[test]
+    fn first_key_press_shows_cursor() {
+        let mut focus = focus(true);
+        let mut events = Vec::new();
+        assert_eq!(focus.cursor, None);
+        press(&mut focus, &mut events, Keycode::Return, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(5, 5)));
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn arrow_keys_move_between_targets() {
+        let mut focus = focus(true);
+        let mut events = Vec::new();
+        press(&mut focus, &mut events, Keycode::Down, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Right, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(15, 5)));
+        press(&mut focus, &mut events, Keycode::Down, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Down, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(15, 25)));
+        let action = focus.handle_event(&Event::KeyDown(Keycode::Down,
+                                                        KeyMod::none()),
+                                        &mut events);
+        assert!(!action.should_redraw());
+        assert_eq!(focus.cursor, Some(Point::new(15, 25)));
+        press(&mut focus, &mut events, Keycode::Left, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(5, 25)));
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn tab_cycles_through_targets() {
+        let mut focus = focus(true);
+        let mut events = Vec::new();
+        press(&mut focus, &mut events, Keycode::Tab, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Tab, KeyMod::shift());
+        assert_eq!(focus.cursor, Some(Point::new(25, 25)));
+        press(&mut focus, &mut events, Keycode::Tab, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(5, 5)));
+        press(&mut focus, &mut events, Keycode::Tab, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Tab, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Tab, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(5, 15)));
+    }
+
+    #[test]
+    fn enter_clicks_focused_target() {
+        let mut focus = focus(true);
+        let mut events = Vec::new();
+        press(&mut focus, &mut events, Keycode::Right, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Right, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Space, KeyMod::none());
+        assert_eq!(events,
+                   vec![Event::MouseDown(Point::new(15, 5)), Event::MouseUp]);
+    }
+
+    #[test]
+    fn element_shortcuts_take_priority_over_clicks() {
+        let mut focus = focus(true);
+        focus.element_mut().shortcut = Some(Keycode::Return);
+        let mut events = Vec::new();
+        press(&mut focus, &mut events, Keycode::Down, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(5, 5)));
+        press(&mut focus, &mut events, Keycode::Return, KeyMod::none());
+        assert_eq!(events,
+                   vec![Event::KeyDown(Keycode::Return, KeyMod::none())]);
+        events.clear();
+        press(&mut focus, &mut events, Keycode::Space, KeyMod::none());
+        assert_eq!(events,
+                   vec![Event::MouseDown(Point::new(5, 5)), Event::MouseUp]);
+    }
+
+    #[test]
+    fn shift_arrow_drags_focused_target() {
+        let mut focus = focus(true);
+        let mut events = Vec::new();
+        press(&mut focus, &mut events, Keycode::Down, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Down, KeyMod::shift());
+        assert_eq!(events,
+                   vec![Event::MouseDown(Point::new(5, 5)),
+                        Event::MouseDrag(Point::new(5, 15)),
+                        Event::MouseUp]);
+        assert_eq!(focus.cursor, Some(Point::new(5, 15)));
+        events.clear();
+        press(&mut focus, &mut events, Keycode::Left, KeyMod::shift());
+        assert_eq!(events,
+                   vec![Event::MouseDown(Point::new(5, 15)),
+                        Event::MouseDrag(Point::new(-5, 15)),
+                        Event::MouseUp]);
+        assert_eq!(focus.cursor, Some(Point::new(5, 15)));
+    }
+
+    #[test]
+    fn free_pointer_without_targets() {
+        let mut focus = focus(false);
+        let mut events = Vec::new();
+        press(&mut focus, &mut events, Keycode::Up, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(50, 50)));
+        press(&mut focus, &mut events, Keycode::Up, KeyMod::none());
+        press(&mut focus, &mut events, Keycode::Left, KeyMod::none());
+        assert_eq!(focus.cursor, Some(Point::new(34, 34)));
+        press(&mut focus, &mut events, Keycode::Return, KeyMod::none());
+        assert_eq!(events,
+                   vec![Event::MouseDown(Point::new(34, 34)), Event::MouseUp]);
+        for _ in 0..5 {
+            press(&mut focus, &mut events, Keycode::Up, KeyMod::none());
+        }
+        assert_eq!(focus.cursor, Some(Point::new(34, 0)));
+    }
+
+    #[test]
+    fn mouse_hides_cursor() {
+        let mut focus = focus(true);
+        let mut events = Vec::new();
+        press(&mut focus, &mut events, Keycode::Down, KeyMod::none());
+        let event = Event::MouseDown(Point::new(25, 25));
+        assert!(focus.handle_event(&event, &mut events).should_redraw());
+        assert_eq!(focus.cursor, None);
+        assert_eq!(events, vec![event]);
+    }
+}
+
+// ========================================================================= //
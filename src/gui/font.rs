@@ -18,9 +18,8 @@
 // +--------------------------------------------------------------------------+
 
 use ahi;
-use sdl2::render::Canvas as SdlCanvas;
-use sdl2::video::Window as SdlWindow;
 use std::collections::HashMap;
+use super::renderer::Renderer;
 use super::sprite::Sprite;
 
 // ========================================================================= //
@@ -32,7 +31,7 @@ pub struct Font {
 }
 
 impl Font {
-    pub fn new(renderer: &SdlCanvas<SdlWindow>, font: &ahi::Font) -> Font {
+    pub fn new(renderer: &Renderer, font: &ahi::Font) -> Font {
         let mut glyphs = HashMap::new();
         for chr in font.chars() {
             glyphs.insert(chr, Glyph::new(renderer, &font[chr]));
@@ -57,6 +56,12 @@ impl Font {
         width
     }
 
+    /// Returns true if the font has its own glyph for the character (rather
+    /// than drawing it with the default glyph).
+    pub fn has_glyph(&self, chr: char) -> bool {
+        self.glyphs.contains_key(&chr)
+    }
+
     pub fn glyph(&self, chr: char) -> &Glyph {
         self.glyphs.get(&chr).unwrap_or(&self.default_glyph)
     }
@@ -71,9 +76,9 @@ pub struct Glyph {
 }
 
 impl Glyph {
-    fn new(renderer: &SdlCanvas<SdlWindow>, glyph: &ahi::Glyph) -> Glyph {
+    fn new(renderer: &Renderer, glyph: &ahi::Glyph) -> Glyph {
         Glyph {
-            sprite: Sprite::new(renderer, glyph.image()),
+            sprite: renderer.new_sprite(glyph.image()),
             left_edge: glyph.left_edge(),
             right_edge: glyph.right_edge(),
         }
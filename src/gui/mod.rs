@@ -20,10 +20,19 @@
 mod action;
 mod background;
 mod canvas;
+mod catalog;
+mod controller;
 mod element;
 mod event;
+mod focus;
 mod font;
 mod loader;
+mod music;
+mod offline;
+#[cfg(test)]
+mod offscreen;
+mod record;
+mod renderer;
 mod resources;
 mod sound;
 mod sprite;
@@ -33,9 +42,17 @@ pub use sdl2::rect::{Point, Rect};
 pub use self::action::Action;
 pub use self::background::Background;
 pub use self::canvas::{Align, Canvas};
+pub use self::catalog::{Catalog, Message};
+pub use self::controller::{ControllerAction, ControllerButton, ControllerMap};
 pub use self::element::Element;
 pub use self::event::{Event, KeyMod, Keycode};
+pub use self::focus::{FocusTarget, KeyboardFocus};
 pub use self::font::Font;
+pub use self::music::Music;
+pub use self::offline::{render_sound, save_wav};
+#[cfg(test)]
+pub use self::offscreen::Offscreen;
+pub use self::record::{EventRecorder, EventReplay};
 pub use self::resources::Resources;
 pub use self::sound::Sound;
 pub use self::sprite::Sprite;
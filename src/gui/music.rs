@@ -0,0 +1,540 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use itersynth::{Wave, WaveGen};
+
+// ========================================================================= //
+
+/// How long it takes one piece of music to fade into another.
+const CROSSFADE_SECONDS: f32 = 2.0;
+
+/// The release time of every instrument; notes are shortened by this much so
+/// that they have died away by the time the next one starts.
+const RELEASE_SECONDS: f32 = 0.08;
+
+// ========================================================================= //
+
+/// A synthesized voice that a music track can be played on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instrument {
+    /// A bright pulse wave, for melodies.
+    Lead,
+    /// A sine tone that decays quickly, like a struck bell.
+    Bell,
+    /// A soft triangle wave with a slow attack, for chords.
+    Pad,
+    /// A low pulse wave.
+    Bass,
+    /// A short burst of noise; the note's pitch sets the noise frequency.
+    Drum,
+}
+
+impl Instrument {
+    fn note_wave(self, freq: f32, seconds: f32) -> Wave {
+        let hold = (seconds - RELEASE_SECONDS).max(0.0);
+        match self {
+            Instrument::Lead => {
+                Wave::pulse(freq, 0.25)
+                    .adshr(0.01, 0.05, 0.5, hold, RELEASE_SECONDS)
+            }
+            Instrument::Bell => {
+                Wave::sine(freq).adshr(0.0, 0.25, 0.3, hold, RELEASE_SECONDS)
+            }
+            Instrument::Pad => {
+                let duty = Wave::sine(2.0) * 0.1 + 0.5;
+                Wave::triangle(freq, duty)
+                    .adshr(0.15, 0.0, 0.6, hold, RELEASE_SECONDS)
+            }
+            Instrument::Bass => {
+                Wave::pulse(freq, 0.5)
+                    .adshr(0.0, 0.1, 0.5, hold, RELEASE_SECONDS)
+            }
+            Instrument::Drum => {
+                Wave::noise(freq).adshr(0.0, 0.0, 0.5, 0.0, 0.08)
+            }
+        }
+    }
+}
+
+// ========================================================================= //
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Note {
+    /// The pitch in Hz, or `None` for a rest.
+    freq: Option<f32>,
+    beats: f32,
+}
+
+impl Note {
+    /// Parses a note such as `C4`, `F#3`, or `Bb2`, or a rest (`-`),
+    /// optionally followed by a length in beats (e.g. `E5:2` or `-:0.5`).
+    fn parse(token: &str) -> Result<Note, String> {
+        let (pitch, beats) = match token.find(':') {
+            Some(index) => {
+                let beats = token[(index + 1)..]
+                    .parse::<f32>()
+                    .map_err(|_| format!("bad note length in {:?}", token))?;
+                (&token[..index], beats)
+            }
+            None => (token, 1.0),
+        };
+        if !(beats > 0.0 && beats.is_finite()) {
+            return Err(format!("note length must be positive in {:?}",
+                               token));
+        }
+        let freq = if pitch == "-" {
+            None
+        } else {
+            Some(parse_pitch(pitch)
+                     .ok_or_else(|| format!("bad pitch in {:?}", token))?)
+        };
+        Ok(Note {
+               freq: freq,
+               beats: beats,
+           })
+    }
+}
+
+/// Converts a note name and octave (e.g. `A4` or `C#5`) into a frequency in
+/// Hz, using equal temperament with A4 at 440 Hz.
+fn parse_pitch(pitch: &str) -> Option<f32> {
+    let mut chars = pitch.chars();
+    let mut semitone: i32 = match chars.next() {
+        Some('C') => 0,
+        Some('D') => 2,
+        Some('E') => 4,
+        Some('F') => 5,
+        Some('G') => 7,
+        Some('A') => 9,
+        Some('B') => 11,
+        _ => return None,
+    };
+    let mut rest = chars.as_str();
+    if rest.starts_with('#') {
+        semitone += 1;
+        rest = &rest[1..];
+    } else if rest.starts_with('b') {
+        semitone -= 1;
+        rest = &rest[1..];
+    }
+    let octave = rest.parse::<i32>().ok()?;
+    if octave < 0 || octave > 8 {
+        return None;
+    }
+    let midi = (octave + 1) * 12 + semitone;
+    Some(440.0 * 2f32.powf((midi - 69) as f32 / 12.0))
+}
+
+// ========================================================================= //
+
+/// One looping line of music, played on a single instrument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track {
+    instrument: Instrument,
+    volume: f32,
+    notes: Vec<Note>,
+}
+
+impl Track {
+    /// Parses a whitespace-separated list of notes (see `Note::parse`).
+    pub fn parse(instrument: Instrument, volume: f32, pattern: &str)
+                 -> Result<Track, String> {
+        let notes = pattern
+            .split_whitespace()
+            .map(Note::parse)
+            .collect::<Result<Vec<Note>, String>>()?;
+        if notes.is_empty() {
+            return Err("track has no notes".to_string());
+        }
+        Ok(Track {
+               instrument: instrument,
+               volume: volume,
+               notes: notes,
+           })
+    }
+}
+
+// ========================================================================= //
+
+/// A piece of background music: several tracks that loop together at a
+/// given tempo.
+#[derive(Clone, Debug)]
+pub struct Music {
+    name: &'static str,
+    beats_per_minute: f32,
+    tracks: Vec<Track>,
+}
+
+impl Music {
+    fn new(name: &'static str, beats_per_minute: f32,
+           tracks: &[(Instrument, f32, &str)])
+           -> Music {
+        Music {
+            name: name,
+            beats_per_minute: beats_per_minute,
+            tracks: tracks
+                .iter()
+                .map(|&(instrument, volume, pattern)| {
+                         Track::parse(instrument, volume, pattern).unwrap()
+                     })
+                .collect(),
+        }
+    }
+
+    /// Returns a name that identifies this piece, so that asking for the
+    /// piece that is already playing doesn't restart it.
+    pub fn name(&self) -> &'static str { self.name }
+
+    pub fn map() -> Music {
+        Music::new("map",
+                   88.0,
+                   &[(Instrument::Bell,
+                      0.15,
+                      "E5 - G5 - A5:2 G5 E5 D5 - E5 - C5:2 - -"),
+                     (Instrument::Pad, 0.1, "A3:4 F3:4 C4:4 G3:4"),
+                     (Instrument::Bass, 0.08, "A2:4 F2:4 C3:4 G2:4")])
+    }
+
+    pub fn prolog() -> Music {
+        Music::new("prolog",
+                   72.0,
+                   &[(Instrument::Pad, 0.12, "D3:4 Bb2:4 F3:4 C3:4"),
+                     (Instrument::Bell,
+                      0.1,
+                      "- - A4 - - - F4 - - - C5 - - - G4 -")])
+    }
+
+    pub fn ice() -> Music {
+        Music::new("ice",
+                   100.0,
+                   &[(Instrument::Bell,
+                      0.12,
+                      "B5 F#5 D5 F#5 B5 F#5 D5 F#5 \
+                       A5 E5 C#5 E5 A5 E5 C#5 E5"),
+                     (Instrument::Pad, 0.1, "B3:8 A3:8"),
+                     (Instrument::Bass,
+                      0.07,
+                      "B2:2 - - B2:2 - - A2:2 - - A2:2 - -")])
+    }
+
+    pub fn circuits() -> Music {
+        Music::new("circuits",
+                   120.0,
+                   &[(Instrument::Lead,
+                      0.08,
+                      "C5 - Eb5 - G5 F5 Eb5 - C5 - G4 - Bb4 C5 - -"),
+                     (Instrument::Bass,
+                      0.1,
+                      "C3 C3 - C3 Ab2 Ab2 - Ab2 Bb2 Bb2 - Bb2 G2 G2 - G2"),
+                     (Instrument::Drum, 0.05, "C7 - F7 - C7 C7 F7 -")])
+    }
+
+    pub fn memory() -> Music {
+        Music::new("memory",
+                   92.0,
+                   &[(Instrument::Bell,
+                      0.12,
+                      "G4 B4 D5 B4 G4 B4 D5 G5 \
+                       F#5 D5 A4 D5 F#5 D5 A4 -"),
+                     (Instrument::Pad, 0.1, "G3:8 D3:8"),
+                     (Instrument::Bass, 0.07, "G2:4 E2:4 D2:4 D2:4")])
+    }
+
+    pub fn machinery() -> Music {
+        Music::new("machinery",
+                   108.0,
+                   &[(Instrument::Lead,
+                      0.07,
+                      "D5 - D5 F5 - A4 - - D5 - D5 G5 - F5 E5 -"),
+                     (Instrument::Bass,
+                      0.1,
+                      "D2 - D3 - D2 - D3 - Bb1 - Bb2 - C2 - C3 -"),
+                     (Instrument::Drum, 0.04, "A6 - - A6 - - A6 -")])
+    }
+
+    pub fn failure() -> Music {
+        Music::new("failure",
+                   132.0,
+                   &[(Instrument::Bass,
+                      0.1,
+                      "E2 E2 E3 E2 E2 E3 F2 F3 E2 E2 E3 E2 E2 E3 G2 F#2"),
+                     (Instrument::Lead,
+                      0.06,
+                      "B4:2 C5:2 B4:2 - - E5:2 D#5:2 B4:4"),
+                     (Instrument::Drum, 0.05, "C7 - C7 - F7 - C7 C7")])
+    }
+
+    pub fn finale() -> Music {
+        Music::new("finale",
+                   84.0,
+                   &[(Instrument::Pad, 0.12, "C4:4 A3:4 F3:4 G3:4"),
+                     (Instrument::Bell,
+                      0.12,
+                      "E5 G5 C6 G5 E5 A5 C6 A5 F5 A5 C6 A5 G5 B5 D6 B5"),
+                     (Instrument::Bass, 0.08, "C2:4 A1:4 F1:4 G1:4")])
+    }
+}
+
+// ========================================================================= //
+
+struct TrackCursor {
+    track: Track,
+    index: usize,
+    /// Seconds until the next note starts.
+    until_next: f32,
+}
+
+/// Plays one piece of music, looping forever, with a gain that can be faded
+/// in or out.
+struct Sequencer {
+    name: &'static str,
+    seconds_per_beat: f32,
+    cursors: Vec<TrackCursor>,
+    voices: Vec<Wave>,
+    gain: f32,
+    target_gain: f32,
+}
+
+impl Sequencer {
+    fn new(music: Music, gain: f32) -> Sequencer {
+        Sequencer {
+            name: music.name,
+            seconds_per_beat: 60.0 / music.beats_per_minute,
+            cursors: music
+                .tracks
+                .into_iter()
+                .map(|track| {
+                         TrackCursor {
+                             track: track,
+                             index: 0,
+                             until_next: 0.0,
+                         }
+                     })
+                .collect(),
+            voices: Vec::new(),
+            gain: gain,
+            target_gain: 1.0,
+        }
+    }
+
+    fn is_silent(&self) -> bool {
+        self.gain <= 0.0 && self.target_gain <= 0.0
+    }
+
+    /// Starts any notes that are due, and then returns the next sample.
+    fn next_sample(&mut self, time_step: f32) -> f32 {
+        for cursor in self.cursors.iter_mut() {
+            while cursor.until_next <= 0.0 {
+                let note = cursor.track.notes[cursor.index];
+                let seconds = note.beats * self.seconds_per_beat;
+                if let Some(freq) = note.freq {
+                    let instrument = cursor.track.instrument;
+                    self.voices.push(instrument.note_wave(freq, seconds) *
+                                         cursor.track.volume);
+                }
+                cursor.until_next += seconds;
+                cursor.index = (cursor.index + 1) % cursor.track.notes.len();
+            }
+            cursor.until_next -= time_step;
+        }
+        let mut sample = 0.0;
+        let mut index = 0;
+        while index < self.voices.len() {
+            if let Some(value) = self.voices[index].next(time_step) {
+                sample += value;
+                index += 1;
+            } else {
+                self.voices.swap_remove(index);
+            }
+        }
+        let fade_step = time_step / CROSSFADE_SECONDS;
+        if self.gain < self.target_gain {
+            self.gain = (self.gain + fade_step).min(self.target_gain);
+        } else if self.gain > self.target_gain {
+            self.gain = (self.gain - fade_step).max(self.target_gain);
+        }
+        sample * self.gain
+    }
+}
+
+// ========================================================================= //
+
+/// Plays background music, crossfading whenever the music changes.
+pub struct MusicPlayer {
+    current: Option<Sequencer>,
+    fading: Vec<Sequencer>,
+}
+
+impl MusicPlayer {
+    pub fn new() -> MusicPlayer {
+        MusicPlayer {
+            current: None,
+            fading: Vec::new(),
+        }
+    }
+
+    /// Fades out the current music (if any) while fading in the new music
+    /// (if any).  Does nothing if the new music is already playing.
+    pub fn switch_to(&mut self, music: Option<Music>) {
+        let same = match (&self.current, &music) {
+            (&Some(ref current), &Some(ref music)) => {
+                current.name == music.name
+            }
+            (&None, &None) => true,
+            _ => false,
+        };
+        if same {
+            return;
+        }
+        if let Some(mut old) = self.current.take() {
+            old.target_gain = 0.0;
+            self.fading.push(old);
+        }
+        let start_gain = if self.fading.is_empty() { 1.0 } else { 0.0 };
+        self.current = music.map(|music| Sequencer::new(music, start_gain));
+    }
+
+    /// Adds the music, scaled by `gain`, into the output buffer.
+    pub fn mix_into(&mut self, out: &mut [f32], time_step: f32, gain: f32) {
+        let sequencers = self.current.iter_mut().chain(self.fading.iter_mut());
+        for sequencer in sequencers {
+            for sample in out.iter_mut() {
+                *sample += sequencer.next_sample(time_step) * gain;
+            }
+        }
+        self.fading.retain(|sequencer| !sequencer.is_silent());
+    }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use super::{CROSSFADE_SECONDS, Instrument, Music, MusicPlayer, Note,
+                Sequencer, Track, parse_pitch};
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 0.01,
+                "{} is not close to {}",
+                actual,
+                expected);
+    }
+
+    #[test]
+    fn pitches() {
+        assert_close(parse_pitch("A4").unwrap(), 440.0);
+        assert_close(parse_pitch("A5").unwrap(), 880.0);
+        assert_close(parse_pitch("C4").unwrap(), 261.63);
+        assert_close(parse_pitch("F#3").unwrap(), 185.0);
+        assert_close(parse_pitch("Gb3").unwrap(), 185.0);
+        assert_close(parse_pitch("Bb2").unwrap(), 116.54);
+        assert_eq!(parse_pitch("H4"), None);
+        assert_eq!(parse_pitch("C"), None);
+        assert_eq!(parse_pitch("C9"), None);
+        assert_eq!(parse_pitch("c4"), None);
+    }
+
+    #[test]
+    fn parse_notes() {
+        let note = Note::parse("E5:2").unwrap();
+        assert_close(note.freq.unwrap(), 659.26);
+        assert_eq!(note.beats, 2.0);
+        assert_eq!(Note::parse("-").unwrap(),
+                   Note {
+                       freq: None,
+                       beats: 1.0,
+                   });
+        assert_eq!(Note::parse("-:0.5").unwrap().beats, 0.5);
+        assert!(Note::parse("E5:").is_err());
+        assert!(Note::parse("E5:0").is_err());
+        assert!(Note::parse("E5:-1").is_err());
+        assert!(Note::parse("X5").is_err());
+    }
+
+    #[test]
+    fn parse_tracks() {
+        let track = Track::parse(Instrument::Bass, 0.5, "C3:2 - G2").unwrap();
+        assert_eq!(track.notes.len(), 3);
+        assert!(Track::parse(Instrument::Bass, 0.5, "").is_err());
+        assert!(Track::parse(Instrument::Bass, 0.5, "C3 Q3").is_err());
+    }
+
+    #[test]
+    fn all_themes_parse() {
+        let themes = vec![Music::map(),
+                          Music::prolog(),
+                          Music::ice(),
+                          Music::circuits(),
+                          Music::memory(),
+                          Music::machinery(),
+                          Music::failure(),
+                          Music::finale()];
+        let mut names: Vec<&str> =
+            themes.iter().map(Music::name).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), themes.len());
+    }
+
+    #[test]
+    fn sequencer_loops_track() {
+        // At 60 BPM, a beat is one second.  The first sample starts the first
+        // note, and each later step of a quarter second advances the clock.
+        let music = Music::new("test",
+                               60.0,
+                               &[(Instrument::Lead, 1.0, "C4 D4:2 -")]);
+        let mut sequencer = Sequencer::new(music, 1.0);
+        let mut indices = Vec::new();
+        for _ in 0..17 {
+            sequencer.next_sample(0.25);
+            indices.push(sequencer.cursors[0].index);
+        }
+        // C4 from 0s to 1s, D4 from 1s to 3s, a rest from 3s to 4s, and then
+        // C4 again from 4s.
+        assert_eq!(indices,
+                   vec![1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn crossfade() {
+        let step = CROSSFADE_SECONDS / 4.0;
+        let mut out = [0.0; 1];
+        let mut player = MusicPlayer::new();
+        player.switch_to(Some(Music::map()));
+        assert_eq!(player.current.as_ref().unwrap().gain, 1.0);
+        player.switch_to(Some(Music::map()));
+        assert!(player.fading.is_empty());
+        player.switch_to(Some(Music::ice()));
+        assert_eq!(player.fading.len(), 1);
+        assert_eq!(player.current.as_ref().unwrap().gain, 0.0);
+        player.mix_into(&mut out, step, 1.0);
+        assert_close(player.fading[0].gain, 0.75);
+        assert_close(player.current.as_ref().unwrap().gain, 0.25);
+        for _ in 0..3 {
+            player.mix_into(&mut out, step, 1.0);
+        }
+        assert!(player.fading.is_empty());
+        assert_eq!(player.current.as_ref().unwrap().gain, 1.0);
+        player.switch_to(None);
+        assert!(player.current.is_none());
+        assert_eq!(player.fading.len(), 1);
+    }
+}
+
+// ========================================================================= //
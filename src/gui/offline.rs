@@ -0,0 +1,357 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+#[cfg(test)]
+use std::env;
+use std::fs::File;
+#[cfg(test)]
+use std::fs;
+use std::io::{self, Write};
+#[cfg(test)]
+use std::io::Read;
+use std::path::Path;
+#[cfg(test)]
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[cfg(test)]
+use super::music::Music;
+#[cfg(test)]
+use super::offscreen::{BLESS_ENV_VAR, GOLDEN_DIR};
+use super::sound::{Sound, SoundMixer, SoundQueue};
+
+// ========================================================================= //
+
+/// How many frames the mixer is asked for at a time, matching the buffer
+/// size that the game requests from the audio device.
+const CHUNK_FRAMES: usize = 2048;
+
+/// Offline output is always stereo, like the game's audio device.
+const NUM_CHANNELS: u16 = 2;
+
+/// How far (as a fraction of full scale) a rendered sample may stray from
+/// the golden recording before the two are considered different.  This is
+/// comfortably larger than the 16-bit quantization error of the WAV file.
+#[cfg(test)]
+const GOLDEN_TOLERANCE: f32 = 1.0 / 1024.0;
+
+// ========================================================================= //
+
+/// Runs a `SoundMixer` without an audio device, collecting its (interleaved
+/// stereo) output samples so that they can be inspected or saved to a file.
+pub struct OfflineMixer {
+    sample_rate: i32,
+    sound_queue: Arc<SoundQueue>,
+    mixer: SoundMixer,
+}
+
+impl OfflineMixer {
+    pub fn new(sample_rate: i32) -> OfflineMixer {
+        let sound_queue = Arc::new(SoundQueue::new());
+        OfflineMixer {
+            sample_rate: sample_rate,
+            sound_queue: sound_queue.clone(),
+            mixer: SoundMixer::new(sample_rate,
+                                   NUM_CHANNELS as u8,
+                                   sound_queue),
+        }
+    }
+
+    /// Sets the overall gain, as `Window::set_sound_gain` does.
+    #[cfg(test)]
+    pub fn set_gain(&mut self, gain: f32) { self.sound_queue.set_gain(gain); }
+
+    pub fn play_sounds(&mut self, sounds: Vec<Sound>) {
+        self.sound_queue.enqueue(sounds);
+    }
+
+    #[cfg(test)]
+    pub fn play_music(&mut self, music: Option<Music>) {
+        self.sound_queue.change_music(music);
+    }
+
+    /// Renders the next `num_frames` frames of output, returning
+    /// `NUM_CHANNELS` samples per frame.
+    pub fn render(&mut self, num_frames: usize) -> Vec<f32> {
+        let mut out = vec![0.0; num_frames * NUM_CHANNELS as usize];
+        for chunk in out.chunks_mut(CHUNK_FRAMES * NUM_CHANNELS as usize) {
+            self.mixer.mix(chunk);
+        }
+        out
+    }
+
+    /// Renders output until no sound effects are playing any more, or until
+    /// `max_seconds` have been rendered, whichever comes first.  The result
+    /// is always a whole number of mixer buffers long.
+    pub fn render_until_idle(&mut self, max_seconds: f32) -> Vec<f32> {
+        let max_frames = (max_seconds * self.sample_rate as f32) as usize;
+        let mut out = Vec::new();
+        while out.len() < max_frames * NUM_CHANNELS as usize {
+            out.extend(self.render(CHUNK_FRAMES));
+            if self.mixer.is_idle() {
+                break;
+            }
+        }
+        out
+    }
+}
+
+/// Renders a single sound effect from start to finish (or for at most
+/// `max_seconds`) at the given sample rate.
+pub fn render_sound(sound: Sound, sample_rate: i32, max_seconds: f32)
+                    -> Vec<f32> {
+    let mut mixer = OfflineMixer::new(sample_rate);
+    mixer.play_sounds(vec![sound]);
+    mixer.render_until_idle(max_seconds)
+}
+
+// ========================================================================= //
+
+/// Writes interleaved stereo samples (as returned by `OfflineMixer`) to a
+/// 16-bit PCM WAV file.
+pub fn save_wav(path: &Path, sample_rate: i32, samples: &[f32])
+                -> io::Result<()> {
+    let data = encode_wav(sample_rate, NUM_CHANNELS, samples);
+    File::create(path)?.write_all(&data)
+}
+
+/// Panics unless `samples` (interleaved stereo, as returned by
+/// `OfflineMixer`) match the golden WAV recording with the given
+/// name to within `GOLDEN_TOLERANCE`.  As with golden images, the recording
+/// is (re)written instead if the `SYZYGY_BLESS_GOLDENS` environment variable
+/// is set, a missing recording is an error, and on a mismatch the actual
+/// recording is written next to the golden one.
+#[cfg(test)]
+pub fn assert_matches_golden_wav(name: &str, sample_rate: i32,
+                                 samples: &[f32]) {
+    let dir = PathBuf::from(GOLDEN_DIR);
+    let golden_path = dir.join(name).with_extension("wav");
+    let actual_path = dir.join(format!("{}.actual.wav", name));
+    let golden = match read_file(&golden_path) {
+        Ok(golden) => Some(golden),
+        Err(ref error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => panic!("{}: {}", golden_path.display(), error),
+    };
+    if env::var(BLESS_ENV_VAR).is_ok() {
+        fs::create_dir_all(&dir).unwrap();
+        save_wav(&golden_path, sample_rate, samples).unwrap();
+        println!("Wrote golden recording {}", golden_path.display());
+        return;
+    }
+    let mismatch = match golden.as_ref().map(|data| decode_wav(data)) {
+        None => Some("the golden recording is missing".to_string()),
+        Some(Err(error)) => Some(error),
+        Some(Ok((_, channels, _))) if channels != NUM_CHANNELS => {
+            Some(format!("golden recording has {} channels, expected {}",
+                         channels,
+                         NUM_CHANNELS))
+        }
+        Some(Ok((golden_rate, _, golden_samples))) => {
+            compare_samples(sample_rate, samples, golden_rate, &golden_samples)
+        }
+    };
+    if let Some(mismatch) = mismatch {
+        save_wav(&actual_path, sample_rate, samples).unwrap();
+        panic!("Rendered audio does not match {} ({}; see {}); set {}=1 to \
+                update the golden recording",
+               golden_path.display(),
+               mismatch,
+               actual_path.display(),
+               BLESS_ENV_VAR);
+    } else {
+        let _ = fs::remove_file(&actual_path);
+    }
+}
+
+/// Returns a description of the first difference between the actual and
+/// golden samples, or `None` if they match to within `GOLDEN_TOLERANCE`.
+#[cfg(test)]
+fn compare_samples(actual_rate: i32, actual: &[f32], golden_rate: i32,
+                   golden: &[f32])
+                   -> Option<String> {
+    if actual_rate != golden_rate {
+        return Some(format!("sample rate is {} Hz, expected {} Hz",
+                            actual_rate,
+                            golden_rate));
+    }
+    if actual.len() != golden.len() {
+        return Some(format!("{} samples, expected {}",
+                            actual.len(),
+                            golden.len()));
+    }
+    for (index, (&actual, &golden)) in
+        actual.iter().zip(golden.iter()).enumerate()
+    {
+        if (actual - golden).abs() > GOLDEN_TOLERANCE {
+            return Some(format!("sample {} is {}, expected {}",
+                                index,
+                                actual,
+                                golden));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+fn read_file(path: &PathBuf) -> io::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    File::open(path)?.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+// ========================================================================= //
+
+/// Encodes interleaved samples as a 16-bit PCM WAV file with the given
+/// number of channels.  Samples outside the range [-1, 1] are clipped.
+pub fn encode_wav(sample_rate: i32, num_channels: u16, samples: &[f32])
+                  -> Vec<u8> {
+    let frame_size = 2 * num_channels as u32;
+    let data_size = 2 * samples.len() as u32;
+    let mut out = Vec::with_capacity(44 + data_size as usize);
+    out.extend_from_slice(b"RIFF");
+    push_u32(&mut out, 36 + data_size);
+    out.extend_from_slice(b"WAVEfmt ");
+    push_u32(&mut out, 16); // fmt chunk size
+    push_u16(&mut out, 1); // PCM format
+    push_u16(&mut out, num_channels);
+    push_u32(&mut out, sample_rate as u32);
+    push_u32(&mut out, frame_size * sample_rate as u32); // bytes/second
+    push_u16(&mut out, frame_size as u16); // bytes/frame
+    push_u16(&mut out, 16); // bits/sample
+    out.extend_from_slice(b"data");
+    push_u32(&mut out, data_size);
+    for &sample in samples {
+        let value = (sample.max(-1.0).min(1.0) * 32767.0).round() as i16;
+        push_u16(&mut out, value as u16);
+    }
+    out
+}
+
+/// Decodes a WAV file written by `encode_wav`, returning its sample rate,
+/// number of channels, and interleaved samples.
+#[cfg(test)]
+pub fn decode_wav(data: &[u8]) -> Result<(i32, u16, Vec<f32>), String> {
+    if data.len() < 44 || &data[0..4] != b"RIFF" ||
+        &data[8..16] != b"WAVEfmt "
+    {
+        return Err("not a WAV file".to_string());
+    }
+    let num_channels = read_u16(data, 22);
+    if read_u16(data, 20) != 1 || num_channels == 0 ||
+        read_u16(data, 34) != 16
+    {
+        return Err("not a 16-bit PCM WAV file".to_string());
+    }
+    let sample_rate = read_u32(data, 24) as i32;
+    if &data[36..40] != b"data" {
+        return Err("missing data chunk".to_string());
+    }
+    let data_size = read_u32(data, 40) as usize;
+    if data.len() < 44 + data_size {
+        return Err("truncated data chunk".to_string());
+    }
+    let samples = data[44..(44 + data_size)]
+        .chunks(2)
+        .map(|bytes| {
+                 let value = (bytes[0] as u16 | (bytes[1] as u16) << 8) as i16;
+                 value as f32 / 32767.0
+             })
+        .collect();
+    Ok((sample_rate, num_channels, samples))
+}
+
+fn push_u16(out: &mut Vec<u8>, value: u16) {
+    out.push(value as u8);
+    out.push((value >> 8) as u8);
+}
+
+fn push_u32(out: &mut Vec<u8>, value: u32) {
+    push_u16(out, value as u16);
+    push_u16(out, (value >> 16) as u16);
+}
+
+#[cfg(test)]
+fn read_u16(data: &[u8], index: usize) -> u16 {
+    data[index] as u16 | (data[index + 1] as u16) << 8
+}
+
+#[cfg(test)]
+fn read_u32(data: &[u8], index: usize) -> u32 {
+    read_u16(data, index) as u32 | (read_u16(data, index + 2) as u32) << 16
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use super::{GOLDEN_TOLERANCE, OfflineMixer, compare_samples, decode_wav,
+                encode_wav};
+
+    #[test]
+    fn wav_structure() {
+        let wav = encode_wav(8000, 1, &[0.0, 1.0, -1.0]);
+        assert_eq!(wav.len(), 44 + 6);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[4..8], &[42, 0, 0, 0]);
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        assert_eq!(&wav[22..24], &[1, 0]);
+        assert_eq!(&wav[24..28], &[0x40, 0x1f, 0, 0]);
+        assert_eq!(&wav[36..44], b"data\x06\0\0\0");
+        assert_eq!(&wav[44..], &[0, 0, 0xff, 0x7f, 0x01, 0x80]);
+    }
+
+    #[test]
+    fn wav_round_trip() {
+        let samples = vec![0.0, 0.25, -0.5, 0.999, -0.001, 2.0];
+        let (rate, channels, decoded) =
+            decode_wav(&encode_wav(22050, 2, &samples)).unwrap();
+        assert_eq!(rate, 22050);
+        assert_eq!(channels, 2);
+        assert_eq!(decoded.len(), samples.len());
+        for (&original, &decoded) in samples.iter().zip(decoded.iter()) {
+            let expected = original.min(1.0);
+            assert!((expected - decoded).abs() < 1.0 / 32767.0,
+                    "{} decoded as {}",
+                    expected,
+                    decoded);
+        }
+        assert!(decode_wav(b"RIFF").is_err());
+    }
+
+    #[test]
+    fn sample_comparison() {
+        let golden = [0.0, 0.5, -0.5];
+        let close = [GOLDEN_TOLERANCE / 2.0, 0.5, -0.5];
+        assert_eq!(compare_samples(100, &close, 100, &golden), None);
+        assert!(compare_samples(200, &close, 100, &golden).is_some());
+        assert!(compare_samples(100, &close[..2], 100, &golden).is_some());
+        let far = [0.0, 0.5 + 2.0 * GOLDEN_TOLERANCE, -0.5];
+        assert_eq!(compare_samples(100, &far, 100, &golden),
+                   Some(format!("sample 1 is {}, expected 0.5", far[1])));
+    }
+
+    #[test]
+    fn silence_renders_whole_buffers() {
+        let mut mixer = OfflineMixer::new(1000);
+        assert_eq!(mixer.render(5000), vec![0.0; 10000]);
+        assert_eq!(mixer.render_until_idle(10.0).len(), 4096);
+    }
+}
+
+// ========================================================================= //
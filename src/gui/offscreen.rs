@@ -0,0 +1,747 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use ahi;
+use sdl2::rect::{Point, Rect};
+use std::cmp;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+use super::canvas::Canvas;
+use super::element::Element;
+use super::renderer::Renderer;
+use super::resources::{ResourceCache, Resources};
+use super::sprite::Sprite;
+
+// ========================================================================= //
+
+pub const GOLDEN_DIR: &str = "tests/golden";
+pub const BLESS_ENV_VAR: &str = "SYZYGY_BLESS_GOLDENS";
+
+// ========================================================================= //
+
+/// A software render target, for drawing elements in tests without needing
+/// a display or GPU.
+pub struct Offscreen {
+    renderer: OffscreenRenderer,
+    resource_cache: ResourceCache,
+}
+
+impl Offscreen {
+    pub fn new(width: u32, height: u32) -> Offscreen {
+        Offscreen {
+            renderer: OffscreenRenderer::new(width, height),
+            resource_cache: ResourceCache::new(),
+        }
+    }
+
+    pub fn visible_rect(&self) -> Rect {
+        Rect::new(0, 0, self.renderer.width, self.renderer.height)
+    }
+
+    pub fn resources(&mut self) -> Resources {
+        Resources::new(&self.renderer, &mut self.resource_cache)
+    }
+
+    pub fn canvas(&mut self) -> Canvas {
+        let rect = self.visible_rect();
+        Canvas::new(&mut self.renderer, rect)
+    }
+
+    pub fn render<S, A, E: Element<S, A>>(&mut self, state: &S, view: &E) {
+        view.draw(state, &mut self.canvas());
+    }
+
+    pub fn pixel(&self, point: Point) -> (u8, u8, u8) {
+        self.renderer.pixel(point.x(), point.y())
+    }
+
+    /// Encodes the current contents of the render target as a PNG file.
+    pub fn to_png(&self) -> Vec<u8> {
+        encode_png(self.renderer.width,
+                   self.renderer.height,
+                   &self.renderer.pixels)
+    }
+
+    /// Panics unless the current contents of the render target match the
+    /// golden image with the given name.  If the `SYZYGY_BLESS_GOLDENS`
+    /// environment variable is set, the golden image is (re)written instead;
+    /// otherwise a missing golden image is an error.  On a mismatch, the
+    /// actual image is written next to the golden one so the two can be
+    /// compared.
+    pub fn assert_matches_golden(&self, name: &str) {
+        let dir = PathBuf::from(GOLDEN_DIR);
+        let golden_path = dir.join(name).with_extension("png");
+        let actual_path = dir.join(format!("{}.actual.png", name));
+        let actual = self.to_png();
+        let golden = match read_file(&golden_path) {
+            Ok(golden) => Some(golden),
+            Err(ref error) if error.kind() == io::ErrorKind::NotFound => None,
+            Err(error) => panic!("{}: {}", golden_path.display(), error),
+        };
+        if env::var(BLESS_ENV_VAR).is_ok() {
+            fs::create_dir_all(&dir).unwrap();
+            File::create(&golden_path).unwrap().write_all(&actual).unwrap();
+            println!("Wrote golden image {}", golden_path.display());
+        } else if golden.is_none() {
+            File::create(&actual_path).unwrap().write_all(&actual).unwrap();
+            panic!("Missing golden image {} (see {}); set {}=1 to create it",
+                   golden_path.display(),
+                   actual_path.display(),
+                   BLESS_ENV_VAR);
+        } else if golden.as_ref() != Some(&actual) {
+            File::create(&actual_path).unwrap().write_all(&actual).unwrap();
+            panic!("Rendered image does not match {} (see {}); set {}=1 to \
+                    update the golden image",
+                   golden_path.display(),
+                   actual_path.display(),
+                   BLESS_ENV_VAR);
+        } else {
+            let _ = fs::remove_file(&actual_path);
+        }
+    }
+}
+
+fn read_file(path: &PathBuf) -> io::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    File::open(path)?.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+// ========================================================================= //
+
+pub struct OffscreenRenderer {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    clip_rect: Option<Rect>,
+}
+
+impl OffscreenRenderer {
+    pub fn new(width: u32, height: u32) -> OffscreenRenderer {
+        OffscreenRenderer {
+            width: width,
+            height: height,
+            pixels: vec![0; (width * height * 3) as usize],
+            clip_rect: None,
+        }
+    }
+
+    pub fn size(&self) -> (u32, u32) { (self.width, self.height) }
+
+    fn pixel(&self, x: i32, y: i32) -> (u8, u8, u8) {
+        assert!(x >= 0 && x < self.width as i32);
+        assert!(y >= 0 && y < self.height as i32);
+        let index = 3 * (y as usize * self.width as usize + x as usize);
+        (self.pixels[index], self.pixels[index + 1], self.pixels[index + 2])
+    }
+
+    /// Returns the rect within which drawing is currently allowed.
+    fn drawable_rect(&self) -> Rect {
+        let full = Rect::new(0, 0, self.width, self.height);
+        match self.clip_rect {
+            Some(clip) => {
+                clip.intersection(full).unwrap_or(Rect::new(0, 0, 0, 0))
+            }
+            None => full,
+        }
+    }
+
+    fn blend_pixel(&mut self, x: i32, y: i32, rgba: &[u8]) {
+        let alpha = rgba[3] as u32;
+        if alpha == 0 {
+            return;
+        }
+        let index = 3 * (y as usize * self.width as usize + x as usize);
+        for channel in 0..3 {
+            let old = self.pixels[index + channel] as u32;
+            let new = rgba[channel] as u32;
+            self.pixels[index + channel] =
+                ((new * alpha + old * (255 - alpha) + 127) / 255) as u8;
+        }
+    }
+
+    fn fill_clipped(&mut self, color: (u8, u8, u8), rect: Rect) {
+        if let Some(rect) = rect.intersection(self.drawable_rect()) {
+            let (r, g, b) = color;
+            for y in rect.top()..rect.bottom() {
+                for x in rect.left()..rect.right() {
+                    self.blend_pixel(x, y, &[r, g, b, 255]);
+                }
+            }
+        }
+    }
+}
+
+impl Renderer for OffscreenRenderer {
+    fn new_sprite(&self, image: &ahi::Image) -> Sprite {
+        Sprite::from_rgba_data(image.width(),
+                               image.height(),
+                               image.rgba_data())
+    }
+
+    fn set_clip_rect(&mut self, rect: Option<Rect>) { self.clip_rect = rect; }
+
+    fn clear(&mut self, color: (u8, u8, u8)) {
+        let (r, g, b) = color;
+        for pixel in self.pixels.chunks_mut(3) {
+            pixel.copy_from_slice(&[r, g, b]);
+        }
+    }
+
+    fn fill_rect(&mut self, color: (u8, u8, u8), rect: Rect) {
+        self.fill_clipped(color, rect);
+    }
+
+    fn draw_rect(&mut self, color: (u8, u8, u8), rect: Rect) {
+        let (left, top) = (rect.left(), rect.top());
+        let (width, height) = (rect.width(), rect.height());
+        self.fill_clipped(color, Rect::new(left, top, width, 1));
+        self.fill_clipped(color, Rect::new(left, rect.bottom() - 1, width, 1));
+        self.fill_clipped(color, Rect::new(left, top, 1, height));
+        self.fill_clipped(color, Rect::new(rect.right() - 1, top, 1, height));
+    }
+
+    fn draw_sprite(&mut self, sprite: &Sprite, dest: Rect, degrees: f64,
+                   flip_horz: bool, flip_vert: bool) {
+        let (src_width, src_height) = (sprite.width(), sprite.height());
+        if src_width == 0 || src_height == 0 || dest.width() == 0 ||
+            dest.height() == 0
+        {
+            return;
+        }
+        let data = sprite.rgba_data();
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let half_width = dest.width() as f64 / 2.0;
+        let half_height = dest.height() as f64 / 2.0;
+        let center_x = dest.x() as f64 + half_width;
+        let center_y = dest.y() as f64 + half_height;
+        // Find the bounding box of the rotated destination rect:
+        let extent_x = half_width * cos.abs() + half_height * sin.abs();
+        let extent_y = half_width * sin.abs() + half_height * cos.abs();
+        let bounds = Rect::new((center_x - extent_x).floor() as i32,
+                               (center_y - extent_y).floor() as i32,
+                               (2.0 * extent_x).ceil() as u32 + 1,
+                               (2.0 * extent_y).ceil() as u32 + 1);
+        let bounds = match bounds.intersection(self.drawable_rect()) {
+            Some(rect) => rect,
+            None => return,
+        };
+        for y in bounds.top()..bounds.bottom() {
+            for x in bounds.left()..bounds.right() {
+                // Undo the rotation to find where this pixel falls within
+                // the (unrotated) destination rect.
+                let dx = x as f64 + 0.5 - center_x;
+                let dy = y as f64 + 0.5 - center_y;
+                let u = dx * cos + dy * sin + half_width;
+                let v = dy * cos - dx * sin + half_height;
+                if u < 0.0 || v < 0.0 || u >= dest.width() as f64 ||
+                    v >= dest.height() as f64
+                {
+                    continue;
+                }
+                let mut col = (u * src_width as f64 / dest.width() as f64)
+                    .floor() as u32;
+                let mut row = (v * src_height as f64 / dest.height() as f64)
+                    .floor() as u32;
+                col = col.min(src_width - 1);
+                row = row.min(src_height - 1);
+                if flip_horz {
+                    col = src_width - 1 - col;
+                }
+                if flip_vert {
+                    row = src_height - 1 - row;
+                }
+                let index = 4 * (row * src_width + col) as usize;
+                self.blend_pixel(x, y, &data[index..(index + 4)]);
+            }
+        }
+    }
+}
+
+// ========================================================================= //
+
+/// Encodes 8-bit RGB pixel data as a PNG file.  The encoder is simple
+/// enough that its output never changes for the same pixels, so that golden
+/// images can be compared byte for byte.
+fn encode_png(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    let row_len = 3 * width as usize;
+    let mut raw = Vec::with_capacity((row_len + 1) * height as usize);
+    for row in rgb.chunks(row_len) {
+        raw.push(0); // filter type: none
+        raw.extend_from_slice(row);
+    }
+    let mut header = Vec::with_capacity(13);
+    push_u32(&mut header, width);
+    push_u32(&mut header, height);
+    // Bit depth 8, color type 2 (RGB), default compression/filter, no
+    // interlacing:
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    push_png_chunk(&mut png, b"IHDR", &header);
+    push_png_chunk(&mut png, b"IDAT", &zlib_compress(&raw));
+    push_png_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn push_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&[(value >> 24) as u8,
+                            (value >> 16) as u8,
+                            (value >> 8) as u8,
+                            value as u8]);
+}
+
+fn push_png_chunk(out: &mut Vec<u8>, kind: &[u8], data: &[u8]) {
+    push_u32(out, data.len() as u32);
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    push_u32(out, crc);
+}
+
+// ========================================================================= //
+
+const MIN_MATCH_LEN: usize = 3;
+const MAX_MATCH_LEN: usize = 258;
+const WINDOW_SIZE: usize = 32768;
+/// How many earlier positions with the same hash to try for each match.
+const MAX_CHAIN_LEN: usize = 64;
+const HASH_BITS: usize = 15;
+const NO_POS: usize = !0;
+
+const LENGTH_BASES: [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19,
+                                 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115,
+                                 131, 163, 195, 227, 258];
+const LENGTH_EXTRA_BITS: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2,
+                                     2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5,
+                                     5, 0];
+const DIST_BASES: [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65,
+                               97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+                               2049, 3073, 4097, 6145, 8193, 12289, 16385,
+                               24577];
+const DIST_EXTRA_BITS: [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5,
+                                   6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12,
+                                   12, 13, 13];
+
+/// Compresses the data into a zlib stream holding a single deflate block
+/// that uses the fixed Huffman codes from RFC 1951, with greedy LZ77
+/// matching.  This doesn't compress as well as zlib proper, but rendered
+/// screens are mostly flat color and tiles, which it handles well.
+fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    let mut bits = BitWriter::new();
+    bits.write(1, 1); // final block
+    bits.write(1, 2); // fixed Huffman codes
+    let mut head = vec![NO_POS; 1 << HASH_BITS];
+    let mut prev = vec![NO_POS; data.len()];
+    let mut pos = 0;
+    while pos < data.len() {
+        let (length, dist) = longest_match(data, pos, &head, &prev);
+        if length >= MIN_MATCH_LEN {
+            write_length(&mut bits, length);
+            write_dist(&mut bits, dist);
+        } else {
+            write_symbol(&mut bits, data[pos] as u16);
+        }
+        let step = cmp::max(1, length);
+        for index in pos..cmp::min(pos + step, data.len()) {
+            if index + MIN_MATCH_LEN <= data.len() {
+                let hash = hash_at(data, index);
+                prev[index] = head[hash];
+                head[hash] = index;
+            }
+        }
+        pos += step;
+    }
+    write_symbol(&mut bits, 256); // end of block
+    let mut out = vec![0x78, 0x01];
+    out.extend_from_slice(&bits.finish());
+    push_u32(&mut out, adler32(data));
+    out
+}
+
+fn hash_at(data: &[u8], pos: usize) -> usize {
+    let value = ((data[pos] as usize) << 16) |
+        ((data[pos + 1] as usize) << 8) |
+        (data[pos + 2] as usize);
+    (value.wrapping_mul(2654435761) >> 7) & ((1 << HASH_BITS) - 1)
+}
+
+/// Returns the length and distance of the longest earlier match for the
+/// data starting at `pos`, or a length of zero if there is none.
+fn longest_match(data: &[u8], pos: usize, head: &[usize], prev: &[usize])
+                 -> (usize, usize) {
+    if pos + MIN_MATCH_LEN > data.len() {
+        return (0, 0);
+    }
+    let max_len = cmp::min(MAX_MATCH_LEN, data.len() - pos);
+    let mut best = (0, 0);
+    let mut candidate = head[hash_at(data, pos)];
+    let mut chain = 0;
+    while candidate != NO_POS && pos - candidate <= WINDOW_SIZE &&
+        chain < MAX_CHAIN_LEN
+    {
+        let mut length = 0;
+        while length < max_len &&
+            data[candidate + length] == data[pos + length]
+        {
+            length += 1;
+        }
+        if length > best.0 {
+            best = (length, pos - candidate);
+            if length == max_len {
+                break;
+            }
+        }
+        candidate = prev[candidate];
+        chain += 1;
+    }
+    best
+}
+
+/// Writes a literal/length symbol using the fixed Huffman code.
+fn write_symbol(bits: &mut BitWriter, symbol: u16) {
+    let (code, len) = match symbol {
+        0...143 => (0x30 + symbol, 8),
+        144...255 => (0x190 + symbol - 144, 9),
+        256...279 => (symbol - 256, 7),
+        _ => (0xc0 + symbol - 280, 8),
+    };
+    bits.write_huffman(code as u32, len);
+}
+
+fn write_length(bits: &mut BitWriter, length: usize) {
+    let index = LENGTH_BASES
+        .iter()
+        .rposition(|&base| base as usize <= length)
+        .unwrap();
+    write_symbol(bits, 257 + index as u16);
+    bits.write((length - LENGTH_BASES[index] as usize) as u32,
+               LENGTH_EXTRA_BITS[index]);
+}
+
+fn write_dist(bits: &mut BitWriter, dist: usize) {
+    let index = DIST_BASES
+        .iter()
+        .rposition(|&base| base as usize <= dist)
+        .unwrap();
+    bits.write_huffman(index as u32, 5);
+    bits.write((dist - DIST_BASES[index] as usize) as u32,
+               DIST_EXTRA_BITS[index]);
+}
+
+/// Packs bits into bytes, least significant bit first, as deflate requires.
+struct BitWriter {
+    out: Vec<u8>,
+    buffer: u32,
+    num_bits: u8,
+}
+
+impl BitWriter {
+    fn new() -> BitWriter {
+        BitWriter {
+            out: Vec::new(),
+            buffer: 0,
+            num_bits: 0,
+        }
+    }
+
+    fn write(&mut self, value: u32, num_bits: u8) {
+        for bit in 0..num_bits {
+            self.buffer |= ((value >> bit) & 1) << self.num_bits;
+            self.num_bits += 1;
+            if self.num_bits == 8 {
+                self.out.push(self.buffer as u8);
+                self.buffer = 0;
+                self.num_bits = 0;
+            }
+        }
+    }
+
+    /// Writes a Huffman code, which (unlike other values) is packed starting
+    /// from its most significant bit.
+    fn write_huffman(&mut self, code: u32, num_bits: u8) {
+        for bit in (0..num_bits).rev() {
+            self.write((code >> bit) & 1, 1);
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.num_bits > 0 {
+            self.out.push(self.buffer as u8);
+        }
+        self.out
+    }
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let mut a: u32 = 1;
+    let mut b: u32 = 0;
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xffffffff;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb88320 & mask);
+        }
+    }
+    !crc
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use sdl2::rect::{Point, Rect};
+
+    use super::super::canvas::Align;
+    use super::super::sprite::Sprite;
+    use super::{DIST_BASES, DIST_EXTRA_BITS, LENGTH_BASES, LENGTH_EXTRA_BITS,
+                Offscreen, adler32, crc32, encode_png, zlib_compress};
+
+    const WHITE: (u8, u8, u8) = (255, 255, 255);
+    const RED: (u8, u8, u8) = (255, 0, 0);
+    const BLUE: (u8, u8, u8) = (0, 0, 255);
+
+    /// Returns a 4x2 sprite whose pixels are all different, with a
+    /// transparent pixel in the bottom-right corner.
+    fn test_sprite() -> Sprite {
+        let data = vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 0,
+                        0, 0, 255, 255, 255, 0, 255, 0, 255, 255, 255, 255,
+                        0, 255, 255, 0, 0, 0, 0];
+        Sprite::from_rgba_data(4, 2, data)
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    }
+
+    /// Reads bits from a deflate stream, least significant bit first.
+    struct BitReader<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    impl<'a> BitReader<'a> {
+        fn read(&mut self, num_bits: u8) -> usize {
+            let mut value = 0;
+            for bit in 0..num_bits {
+                let byte = self.data[self.pos / 8];
+                value |= (((byte >> (self.pos % 8)) & 1) as usize) << bit;
+                self.pos += 1;
+            }
+            value
+        }
+
+        fn read_huffman(&mut self, num_bits: u8) -> usize {
+            (0..num_bits).fold(0, |code, _| (code << 1) | self.read(1))
+        }
+
+        fn read_symbol(&mut self) -> usize {
+            let code = self.read_huffman(7);
+            if code < 0x18 {
+                return 256 + code;
+            }
+            let code = (code << 1) | self.read(1);
+            match code {
+                0x30...0xbf => code - 0x30,
+                0xc0...0xc7 => 280 + code - 0xc0,
+                _ => 144 + ((code << 1) | self.read(1)) - 0x190,
+            }
+        }
+    }
+
+    /// Decompresses a zlib stream made by `zlib_compress`.
+    fn zlib_decompress(stream: &[u8]) -> Vec<u8> {
+        assert_eq!(&stream[0..2], &[0x78, 0x01]);
+        let mut bits = BitReader {
+            data: &stream[2..(stream.len() - 4)],
+            pos: 0,
+        };
+        assert_eq!(bits.read(3), 0b011);
+        let mut out = Vec::new();
+        loop {
+            let symbol = bits.read_symbol();
+            if symbol < 256 {
+                out.push(symbol as u8);
+            } else if symbol == 256 {
+                break;
+            } else {
+                let index = symbol - 257;
+                let length = LENGTH_BASES[index] as usize +
+                    bits.read(LENGTH_EXTRA_BITS[index]);
+                let index = bits.read_huffman(5);
+                let dist = DIST_BASES[index] as usize +
+                    bits.read(DIST_EXTRA_BITS[index]);
+                for _ in 0..length {
+                    let byte = out[out.len() - dist];
+                    out.push(byte);
+                }
+            }
+        }
+        let checksum = &stream[(stream.len() - 4)..];
+        let adler = adler32(&out);
+        assert_eq!(checksum,
+                   &[(adler >> 24) as u8,
+                     (adler >> 16) as u8,
+                     (adler >> 8) as u8,
+                     adler as u8]);
+        out
+    }
+
+    #[test]
+    fn zlib_empty_stream() {
+        assert_eq!(zlib_compress(&[]),
+                   vec![0x78, 0x01, 0x03, 0x00, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn zlib_round_trip() {
+        let mut data = b"abcabcabcabc hello hello".to_vec();
+        data.extend((0..5000).map(|index| (index % 7) as u8));
+        let mut seed: u32 = 1;
+        data.extend((0..70000).map(|_| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) as u8
+        }));
+        data.extend(vec![200; 1000]);
+        for length in vec![0, 1, 2, 3, 4, 20, data.len()] {
+            let stream = zlib_compress(&data[..length]);
+            assert_eq!(zlib_decompress(&stream), &data[..length]);
+        }
+        let flat = vec![17; 100000];
+        let stream = zlib_compress(&flat);
+        assert!(stream.len() < 1000);
+        assert_eq!(zlib_decompress(&stream), flat);
+    }
+
+    #[test]
+    fn png_structure() {
+        let png = encode_png(2, 1, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(&png[0..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[8..16], b"\0\0\0\x0dIHDR");
+        assert_eq!(&png[16..29],
+                   &[0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+        assert_eq!(&png[(png.len() - 12)..],
+                   b"\0\0\0\0IEND\xae\x42\x60\x82");
+    }
+
+    #[test]
+    fn fill_rect_respects_clipping() {
+        let mut offscreen = Offscreen::new(8, 8);
+        {
+            let mut canvas = offscreen.canvas();
+            canvas.clear(WHITE);
+            let mut canvas = canvas.subcanvas(Rect::new(2, 2, 4, 4));
+            canvas.fill_rect(RED, Rect::new(-1, -1, 3, 3));
+            canvas.clipped(Rect::new(2, 2, 1, 1)).clear(BLUE);
+        }
+        assert_eq!(offscreen.pixel(Point::new(1, 1)), WHITE);
+        assert_eq!(offscreen.pixel(Point::new(2, 2)), RED);
+        assert_eq!(offscreen.pixel(Point::new(3, 3)), RED);
+        assert_eq!(offscreen.pixel(Point::new(4, 4)), BLUE);
+        assert_eq!(offscreen.pixel(Point::new(5, 5)), WHITE);
+        // Dropping the subcanvas restores the full clip rect:
+        offscreen.canvas().fill_rect(RED, Rect::new(7, 7, 1, 1));
+        assert_eq!(offscreen.pixel(Point::new(7, 7)), RED);
+    }
+
+    #[test]
+    fn sprite_flips_and_rotations() {
+        let sprite = test_sprite();
+        let mut offscreen = Offscreen::new(4, 4);
+        let corners = |offscreen: &Offscreen| {
+            (offscreen.pixel(Point::new(0, 1)),
+             offscreen.pixel(Point::new(3, 1)),
+             offscreen.pixel(Point::new(0, 2)),
+             offscreen.pixel(Point::new(3, 2)))
+        };
+        let center = Point::new(2, 2);
+        offscreen.canvas().clear(WHITE);
+        offscreen.canvas().draw_sprite(&sprite, Point::new(0, 1));
+        assert_eq!(corners(&offscreen), (RED, (0, 0, 0), (255, 255, 0),
+                                         WHITE));
+        offscreen.canvas().clear(WHITE);
+        offscreen.canvas()
+            .draw_sprite_transformed(&sprite, center, 0, true, false);
+        assert_eq!(corners(&offscreen), ((0, 0, 0), RED, WHITE,
+                                         (255, 255, 0)));
+        offscreen.canvas().clear(WHITE);
+        offscreen.canvas().draw_sprite_rotated(&sprite, center, 180);
+        assert_eq!(corners(&offscreen), (WHITE, (255, 255, 0), (0, 0, 0),
+                                         RED));
+        offscreen.canvas().clear(WHITE);
+        offscreen.canvas().draw_sprite_rotated(&sprite, center, 90);
+        assert_eq!(offscreen.pixel(Point::new(2, 0)), RED);
+        assert_eq!(offscreen.pixel(Point::new(1, 0)), (255, 255, 0));
+        assert_eq!(offscreen.pixel(Point::new(1, 3)), WHITE);
+        assert_eq!(offscreen.pixel(Point::new(2, 3)), (0, 0, 0));
+    }
+
+    #[test]
+    fn canvas_primitives_golden() {
+        let sprite = test_sprite();
+        let mut offscreen = Offscreen::new(48, 32);
+        {
+            let mut canvas = offscreen.canvas();
+            canvas.clear((64, 64, 64));
+            canvas.fill_rect(RED, Rect::new(2, 2, 10, 6));
+            canvas.draw_rect(WHITE, Rect::new(14, 2, 10, 6));
+            canvas.draw_sprite(&sprite, Point::new(26, 2));
+            canvas.draw_sprite_flipped(&sprite, Point::new(32, 2), true, true);
+            canvas.draw_sprite_transposed(&sprite, Point::new(40, 2));
+            canvas.draw_sprite_rotated(&sprite, Point::new(6, 20), 30);
+            let mut sub = canvas.subcanvas(Rect::new(14, 12, 20, 16));
+            sub.clear(BLUE);
+            sub.fill_rect(WHITE, Rect::new(-4, 10, 40, 2));
+            sub.draw_sprite_centered(&sprite, Point::new(0, 0));
+        }
+        offscreen.assert_matches_golden("canvas_primitives");
+    }
+
+    #[test]
+    fn canvas_text_golden() {
+        let mut offscreen = Offscreen::new(96, 40);
+        let font = offscreen.resources().get_font("roman");
+        {
+            let mut canvas = offscreen.canvas();
+            canvas.clear(WHITE);
+            canvas.draw_text(&font, Align::Left, Point::new(2, 12), "Left");
+            canvas.draw_text(&font,
+                             Align::Center,
+                             Point::new(48, 24),
+                             "Center");
+            canvas.draw_text(&font, Align::Right, Point::new(94, 36), "Right");
+        }
+        offscreen.assert_matches_golden("canvas_text");
+    }
+}
+
+// ========================================================================= //
@@ -0,0 +1,248 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, LineWriter, Write};
+use std::path::Path;
+
+use super::event::{Event, KeyMod, Keycode};
+use sdl2::rect::Point;
+
+// ========================================================================= //
+
+// An event log is a text file with one entry per line.  The first line is a
+// header giving the format version, the seed for the game logic random
+// number generator, and the day (counted from 1970-01-01) to use as today's
+// date; each line after that is one event:
+//
+//   syzygy-events 2 <seed> <day>
+//   tick
+//   down <x> <y>
+//   drag <x> <y>
+//   up
+//   key <keycode> <keymod bits>
+//   text <text>
+//   quit
+
+const HEADER_MAGIC: &str = "syzygy-events";
+const FORMAT_VERSION: u32 = 2;
+
+// ========================================================================= //
+
+/// Writes each event that the game processes to a log file.
+pub struct EventRecorder {
+    writer: LineWriter<File>,
+}
+
+impl EventRecorder {
+    pub fn create(path: &Path, seed: u32, day: i32)
+                  -> io::Result<EventRecorder> {
+        let mut writer = LineWriter::new(File::create(path)?);
+        writeln!(writer,
+                 "{} {} {} {}",
+                 HEADER_MAGIC,
+                 FORMAT_VERSION,
+                 seed,
+                 day)?;
+        Ok(EventRecorder { writer: writer })
+    }
+
+    pub fn record(&mut self, event: &Event) {
+        if let Err(error) = writeln!(self.writer, "{}", format_event(event)) {
+            println!("Failed to record event: {}", error);
+        }
+    }
+}
+
+// ========================================================================= //
+
+/// A previously-recorded stream of events, to be fed back into the game in
+/// place of live input.
+pub struct EventReplay {
+    seed: u32,
+    day: i32,
+    events: VecDeque<Event>,
+}
+
+impl EventReplay {
+    pub fn load(path: &Path) -> io::Result<EventReplay> {
+        EventReplay::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads an event log from any source, such as a string in a test.
+    pub fn read<R: BufRead>(reader: R) -> io::Result<EventReplay> {
+        let mut lines = reader.lines();
+        let header = match lines.next() {
+            Some(line) => line?,
+            None => return Err(invalid_data("event log is empty".to_string())),
+        };
+        let (seed, day) = parse_header(&header).map_err(invalid_data)?;
+        let mut events = VecDeque::new();
+        for (index, line) in lines.enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let event = parse_event(&line).map_err(|error| {
+                invalid_data(format!("line {}: {}", index + 2, error))
+            })?;
+            events.push_back(event);
+        }
+        Ok(EventReplay {
+               seed: seed,
+               day: day,
+               events: events,
+           })
+    }
+
+    pub fn seed(&self) -> u32 { self.seed }
+
+    pub fn day(&self) -> i32 { self.day }
+
+    pub fn next_event(&mut self) -> Option<Event> { self.events.pop_front() }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+// ========================================================================= //
+
+fn parse_header(line: &str) -> Result<(u32, i32), String> {
+    let words: Vec<&str> = line.split(' ').collect();
+    if words.len() < 2 || words[0] != HEADER_MAGIC {
+        return Err("not an event log".to_string());
+    }
+    if words[1] != FORMAT_VERSION.to_string() {
+        return Err(format!("unsupported event log version {}", words[1]));
+    }
+    if words.len() != 4 {
+        return Err("malformed event log header".to_string());
+    }
+    let seed = words[2]
+        .parse()
+        .map_err(|_| format!("invalid seed: {:?}", words[2]))?;
+    let day = words[3]
+        .parse()
+        .map_err(|_| format!("invalid day: {:?}", words[3]))?;
+    Ok((seed, day))
+}
+
+fn format_event(event: &Event) -> String {
+    match event {
+        &Event::Quit => "quit".to_string(),
+        &Event::ClockTick => "tick".to_string(),
+        &Event::MouseDrag(pt) => format!("drag {} {}", pt.x(), pt.y()),
+        &Event::MouseDown(pt) => format!("down {} {}", pt.x(), pt.y()),
+        &Event::MouseUp => "up".to_string(),
+        &Event::KeyDown(keycode, keymod) => {
+            format!("key {} {}", keycode as i32, keymod.bits())
+        }
+        &Event::TextInput(ref text) => {
+            format!("text {}", text.replace('\n', " "))
+        }
+    }
+}
+
+fn parse_event(line: &str) -> Result<Event, String> {
+    let (kind, rest) = match line.find(' ') {
+        Some(index) => (&line[..index], &line[(index + 1)..]),
+        None => (line, ""),
+    };
+    match kind {
+        "quit" => Ok(Event::Quit),
+        "tick" => Ok(Event::ClockTick),
+        "drag" => Ok(Event::MouseDrag(parse_point(rest)?)),
+        "down" => Ok(Event::MouseDown(parse_point(rest)?)),
+        "up" => Ok(Event::MouseUp),
+        "key" => {
+            let numbers = parse_numbers(rest, 2)?;
+            let keycode = Keycode::from_i32(numbers[0])
+                .ok_or_else(|| format!("invalid keycode: {}", numbers[0]))?;
+            let keymod = KeyMod::from_bits(numbers[1] as u8);
+            Ok(Event::KeyDown(keycode, keymod))
+        }
+        "text" => Ok(Event::TextInput(rest.to_string())),
+        _ => Err(format!("unknown event type: {:?}", kind)),
+    }
+}
+
+fn parse_point(string: &str) -> Result<Point, String> {
+    let numbers = parse_numbers(string, 2)?;
+    Ok(Point::new(numbers[0], numbers[1]))
+}
+
+fn parse_numbers(string: &str, count: usize) -> Result<Vec<i32>, String> {
+    let numbers = string
+        .split(' ')
+        .map(|word| word.parse::<i32>())
+        .collect::<Result<Vec<i32>, _>>()
+        .map_err(|_| format!("invalid numbers: {:?}", string))?;
+    if numbers.len() != count {
+        return Err(format!("expected {} numbers, found {:?}", count, string));
+    }
+    Ok(numbers)
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use gui::{Event, KeyMod, Keycode, Point};
+    use super::{format_event, parse_event, parse_header};
+
+    #[test]
+    fn event_round_trip() {
+        let events = vec![
+            Event::Quit,
+            Event::ClockTick,
+            Event::MouseDrag(Point::new(-3, 17)),
+            Event::MouseDown(Point::new(250, 100)),
+            Event::MouseUp,
+            Event::KeyDown(Keycode::Z, KeyMod::command() | KeyMod::shift()),
+            Event::KeyDown(Keycode::Escape, KeyMod::none()),
+            Event::TextInput("Hello, world!".to_string()),
+        ];
+        for event in events {
+            assert_eq!(parse_event(&format_event(&event)), Ok(event));
+        }
+    }
+
+    #[test]
+    fn reject_malformed_events() {
+        assert!(parse_event("").is_err());
+        assert!(parse_event("click 1 2").is_err());
+        assert!(parse_event("down 1").is_err());
+        assert!(parse_event("down 1 2 3").is_err());
+        assert!(parse_event("drag x y").is_err());
+    }
+
+    #[test]
+    fn parse_log_header() {
+        assert_eq!(parse_header("syzygy-events 2 12345 17226"),
+                   Ok((12345, 17226)));
+        assert!(parse_header("syzygy-events 1 12345").is_err());
+        assert!(parse_header("syzygy-events 2 12345").is_err());
+        assert!(parse_header("syzygy-events 2 12345 today").is_err());
+        assert!(parse_header("tick").is_err());
+    }
+}
+
+// ========================================================================= //
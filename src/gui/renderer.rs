@@ -0,0 +1,99 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use ahi;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas as SdlCanvas;
+use sdl2::video::Window as SdlWindow;
+use super::sprite::Sprite;
+
+// ========================================================================= //
+
+/// A render target that a `Canvas` can draw onto.  All rects given to a
+/// `Renderer` are in absolute (not canvas-relative) coordinates.
+pub trait Renderer {
+    /// Creates a sprite that this renderer is able to draw.
+    fn new_sprite(&self, image: &ahi::Image) -> Sprite;
+
+    /// Restricts all subsequent drawing to the given rect, or removes the
+    /// restriction if `None` is given.
+    fn set_clip_rect(&mut self, rect: Option<Rect>);
+
+    /// Fills the entire render target (ignoring the clip rect).
+    fn clear(&mut self, color: (u8, u8, u8));
+
+    fn fill_rect(&mut self, color: (u8, u8, u8), rect: Rect);
+
+    /// Draws a one-pixel-wide outline of the rect.
+    fn draw_rect(&mut self, color: (u8, u8, u8), rect: Rect);
+
+    /// Draws the sprite into `dest`, first flipping it as requested and then
+    /// rotating it clockwise by `degrees` around the center of `dest`.
+    fn draw_sprite(&mut self, sprite: &Sprite, dest: Rect, degrees: f64,
+                   flip_horz: bool, flip_vert: bool);
+}
+
+// ========================================================================= //
+
+impl Renderer for SdlCanvas<SdlWindow> {
+    fn new_sprite(&self, image: &ahi::Image) -> Sprite {
+        Sprite::new(self, image)
+    }
+
+    fn set_clip_rect(&mut self, rect: Option<Rect>) {
+        SdlCanvas::set_clip_rect(self, rect);
+    }
+
+    fn clear(&mut self, color: (u8, u8, u8)) {
+        let (r, g, b) = color;
+        self.set_draw_color(Color::RGB(r, g, b));
+        SdlCanvas::clear(self);
+    }
+
+    fn fill_rect(&mut self, color: (u8, u8, u8), rect: Rect) {
+        let (r, g, b) = color;
+        self.set_draw_color(Color::RGB(r, g, b));
+        SdlCanvas::fill_rect(self, rect).unwrap();
+    }
+
+    fn draw_rect(&mut self, color: (u8, u8, u8), rect: Rect) {
+        let (r, g, b) = color;
+        self.set_draw_color(Color::RGB(r, g, b));
+        SdlCanvas::draw_rect(self, rect).unwrap();
+    }
+
+    fn draw_sprite(&mut self, sprite: &Sprite, dest: Rect, degrees: f64,
+                   flip_horz: bool, flip_vert: bool) {
+        if degrees == 0.0 && !flip_horz && !flip_vert {
+            self.copy(sprite.sdl2_texture(), None, Some(dest)).unwrap();
+        } else {
+            self.copy_ex(sprite.sdl2_texture(),
+                         None,
+                         Some(dest),
+                         degrees,
+                         None,
+                         flip_horz,
+                         flip_vert)
+                .unwrap();
+        }
+    }
+}
+
+// ========================================================================= //
@@ -18,30 +18,44 @@
 // +--------------------------------------------------------------------------+
 
 use ahi;
-use sdl2::render::Canvas as SdlCanvas;
-use sdl2::video::Window as SdlWindow;
+use std::cell::Cell;
 use std::collections::HashMap;
-use std::io;
+use std::f64;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use super::background::Background;
+use super::catalog::Catalog;
 use super::font::Font;
 use super::loader::ResourceLoader;
+use super::renderer::Renderer;
 use super::sprite::Sprite;
 
 // ========================================================================= //
 
 pub struct Resources<'a> {
-    renderer: &'a SdlCanvas<SdlWindow>,
+    renderer: &'a Renderer,
     cache: &'a mut ResourceCache,
+    text_speed: Rc<Cell<f64>>,
 }
 
 impl<'a> Resources<'a> {
-    pub fn new(renderer: &'a SdlCanvas<SdlWindow>,
+    pub fn new(renderer: &'a Renderer,
                cache: &'a mut ResourceCache)
                -> Resources<'a> {
-        Resources { renderer, cache }
+        Resources {
+            renderer,
+            cache,
+            text_speed: Rc::new(Cell::new(f64::INFINITY)),
+        }
+    }
+
+    /// Returns the shared cell holding the current text speed.
+    pub fn text_speed(&self) -> Rc<Cell<f64>> { self.text_speed.clone() }
+
+    pub fn set_text_speed(&mut self, speed: Rc<Cell<f64>>) {
+        self.text_speed = speed;
     }
 
     pub fn get_background(&mut self, name: &str) -> Rc<Background> {
@@ -55,6 +69,13 @@ impl<'a> Resources<'a> {
     pub fn get_sprites(&mut self, name: &str) -> Vec<Sprite> {
         self.cache.get_sprites(self.renderer, name)
     }
+
+    pub fn get_scene_script(&mut self, name: &str) -> String {
+        self.cache.get_scene_script(name)
+    }
+
+    /// Returns the message catalog for the current locale.
+    pub fn catalog(&self) -> Rc<Catalog> { self.cache.catalog.clone() }
 }
 
 // ========================================================================= //
@@ -63,6 +84,8 @@ pub struct ResourceCache {
     backgrounds: HashMap<String, Rc<Background>>,
     fonts: HashMap<String, Rc<Font>>,
     sprites: HashMap<String, Vec<Sprite>>,
+    locale: String,
+    catalog: Rc<Catalog>,
     loader: ResourceLoader,
 }
 
@@ -72,11 +95,39 @@ impl ResourceCache {
             backgrounds: HashMap::new(),
             fonts: HashMap::new(),
             sprites: HashMap::new(),
+            locale: String::new(),
+            catalog: Rc::new(Catalog::new()),
             loader: ResourceLoader::new(),
         }
     }
 
-    fn get_background(&mut self, renderer: &SdlCanvas<SdlWindow>, name: &str)
+    /// Loads the message catalog `data/locale/<locale>.toml`.  If the
+    /// catalog is missing or malformed, all messages will be in English.
+    pub fn set_locale(&mut self, locale: &str) {
+        if locale == self.locale {
+            return;
+        }
+        if cfg!(debug_assertions) {
+            println!("Loading catalog: {}", locale);
+        }
+        let path = PathBuf::from("locale").join(locale).with_extension("toml");
+        let catalog = match load_text_from_file(&self.loader, &path) {
+            Ok(text) => {
+                Catalog::parse(&text).unwrap_or_else(|error| {
+                    println!("Failed to parse {:?}: {}", path, error);
+                    Catalog::new()
+                })
+            }
+            Err(error) => {
+                println!("Failed to load {:?}: {}", path, error);
+                Catalog::new()
+            }
+        };
+        self.locale = locale.to_string();
+        self.catalog = Rc::new(catalog);
+    }
+
+    fn get_background(&mut self, renderer: &Renderer, name: &str)
                       -> Rc<Background> {
         if let Some(background) = self.backgrounds.get(name) {
             return background.clone();
@@ -96,7 +147,7 @@ impl ResourceCache {
         background
     }
 
-    fn get_font(&mut self, renderer: &SdlCanvas<SdlWindow>, name: &str)
+    fn get_font(&mut self, renderer: &Renderer, name: &str)
                 -> Rc<Font> {
         if let Some(font) = self.fonts.get(name) {
             return font.clone();
@@ -111,7 +162,7 @@ impl ResourceCache {
         font
     }
 
-    fn get_sprites(&mut self, renderer: &SdlCanvas<SdlWindow>, name: &str)
+    fn get_sprites(&mut self, renderer: &Renderer, name: &str)
                    -> Vec<Sprite> {
         if let Some(vec) = self.sprites.get(name) {
             return vec.clone();
@@ -122,14 +173,30 @@ impl ResourceCache {
         let path = PathBuf::from("sprites").join(name).with_extension("ahi");
         let ahi = load_ahi_from_file(&self.loader, &path).expect(name);
         let vec: Vec<Sprite> =
-            ahi.iter().map(|image| Sprite::new(renderer, image)).collect();
+            ahi.iter().map(|image| renderer.new_sprite(image)).collect();
         self.sprites.insert(name.to_string(), vec.clone());
         vec
     }
+
+    fn get_scene_script(&mut self, name: &str) -> String {
+        if cfg!(debug_assertions) {
+            println!("Loading scene script: {}", name);
+        }
+        let path = PathBuf::from("scenes").join(name).with_extension("scene");
+        load_text_from_file(&self.loader, &path).expect(name)
+    }
 }
 
 // ========================================================================= //
 
+fn load_text_from_file(loader: &ResourceLoader, path: &Path)
+                       -> io::Result<String> {
+    let mut file = loader.load(path)?;
+    let mut text = String::new();
+    file.read_to_string(&mut text)?;
+    Ok(text)
+}
+
 fn load_ahf_from_file(loader: &ResourceLoader, path: &Path)
                       -> io::Result<ahi::Font> {
     let mut file = loader.load(path)?;
@@ -19,25 +19,125 @@
 
 use sdl2::AudioSubsystem;
 use sdl2::audio::{AudioCallback, AudioDevice, AudioSpecDesired};
+use std::cmp;
 use std::mem;
 use std::sync::{Arc, Mutex};
 use itersynth::{self, Wave, WaveGen};
+use super::music::{Music, MusicPlayer};
 
 // ========================================================================= //
 
 const DESIRED_AUDIO_RATE: i32 = 44100; // samples/second
 const DESIRED_BUFFER_SIZE: u16 = 2048; // num samples
-const DESIRED_NUM_CHANNELS: u8 = 1; // mono
+const DESIRED_NUM_CHANNELS: u8 = 2; // stereo
+
+/// The width of the game screen, in pixels, for converting horizontal
+/// positions into stereo pan.
+const SCREEN_WIDTH: i32 = 576;
+/// How far to the left or right a sound at the very edge of the screen is
+/// panned (1.0 would put it entirely in one speaker).
+const MAX_SCREEN_PAN: f32 = 0.75;
+
+/// The most sound effects that may play at once; when more are started, the
+/// oldest ones are cut off.
+const MAX_VOICES: usize = 16;
+/// How long a voice that has been cut off takes to fade out, so that it
+/// doesn't click.
+const STEAL_FADE_SECONDS: f32 = 0.01;
+
+/// The level above which the limiter starts to reduce the gain of the mix.
+const LIMITER_KNEE: f32 = 0.7;
+/// How long the limiter takes to recover (to within 1/e) after a peak.
+const LIMITER_RELEASE_SECONDS: f32 = 0.2;
 
 // ========================================================================= //
 
 #[derive(Clone)]
 pub struct Sound {
     wave: Wave,
+    pan: Option<f32>,
 }
 
 impl Sound {
-    fn new(wave: Wave) -> Sound { Sound { wave: wave } }
+    fn new(wave: Wave) -> Sound {
+        Sound {
+            wave: wave,
+            pan: None,
+        }
+    }
+
+    /// Returns this sound panned between the left (-1) and right (1)
+    /// speakers.
+    pub fn panned(mut self, pan: f32) -> Sound {
+        self.pan = Some(pan.max(-1.0).min(1.0));
+        self
+    }
+
+    /// Returns this sound panned to match a horizontal position on the
+    /// screen, unless it has already been panned.
+    pub fn at_x(self, x: i32) -> Sound {
+        if self.pan.is_some() {
+            self
+        } else {
+            let fraction = x as f32 / SCREEN_WIDTH as f32;
+            self.panned((2.0 * fraction - 1.0) * MAX_SCREEN_PAN)
+        }
+    }
+
+    /// Returns how far this sound is panned to the left (-1) or right (1).
+    pub fn pan(&self) -> f32 { self.pan.unwrap_or(0.0) }
+
+    /// Returns the sound effect made by the constructor with the given
+    /// name.  For constructors that take an argument, the argument is
+    /// appended to the name after an underscore (e.g. `platform_shift_3`).
+    pub fn named(name: &str) -> Option<Sound> {
+        if let Some(index) = name.rfind('_') {
+            let arg = &name[(index + 1)..];
+            let sound = match &name[..index] {
+                "atlatl_beam" => arg.parse().ok().map(Sound::atlatl_beam),
+                "atlatl_charge" => arg.parse().ok().map(Sound::atlatl_charge),
+                "platform_shift" => {
+                    arg.parse().ok().map(Sound::platform_shift)
+                }
+                "platform_shift_double" => {
+                    arg.parse().ok().map(Sound::platform_shift_double)
+                }
+                "transform_step" => {
+                    arg.parse().ok().map(Sound::transform_step)
+                }
+                _ => None,
+            };
+            if sound.is_some() {
+                return sound;
+            }
+        }
+        let sound = match name {
+            "beep" => Sound::beep(),
+            "bridge_break" => Sound::bridge_break(),
+            "bridge_crack" => Sound::bridge_crack(),
+            "character_collision" => Sound::character_collision(),
+            "device_drop" => Sound::device_drop(),
+            "device_pickup" => Sound::device_pickup(),
+            "device_rotate" => Sound::device_rotate(),
+            "device_slide" => Sound::device_slide(),
+            "explosion_small" => Sound::explosion_small(),
+            "small_jump" => Sound::small_jump(),
+            "mid_puzzle_chime" => Sound::mid_puzzle_chime(),
+            "solve_puzzle_chime" => Sound::solve_puzzle_chime(),
+            "spawn_zap" => Sound::spawn_zap(),
+            "talk_annoyed_hi" => Sound::talk_annoyed_hi(),
+            "talk_annoyed_lo" => Sound::talk_annoyed_lo(),
+            "talk_hi" => Sound::talk_hi(),
+            "talk_lo" => Sound::talk_lo(),
+            "talk_thought" => Sound::talk_thought(),
+            "transform_final" => Sound::transform_final(),
+            "undo" => Sound::undo(),
+            "redo" => Sound::redo(),
+            "reset" => Sound::reset(),
+            _ => return None,
+        };
+        Some(sound)
+    }
 
     pub fn atlatl_beam(stage: i32) -> Sound {
         Sound::new(Wave::pulse(Wave::from(420.0 - 35.0 * (stage as f32))
@@ -212,10 +312,41 @@ impl Sound {
 
 pub struct SoundQueue {
     queue: Mutex<Vec<Sound>>,
+    gain: Mutex<f32>,
+    music_gain: Mutex<f32>,
+    pending_music: Mutex<Option<Option<Music>>>,
 }
 
 impl SoundQueue {
-    pub fn new() -> SoundQueue { SoundQueue { queue: Mutex::new(Vec::new()) } }
+    pub fn new() -> SoundQueue {
+        SoundQueue {
+            queue: Mutex::new(Vec::new()),
+            gain: Mutex::new(1.0),
+            music_gain: Mutex::new(1.0),
+            pending_music: Mutex::new(None),
+        }
+    }
+
+    pub fn gain(&self) -> f32 { *self.gain.lock().unwrap() }
+
+    pub fn set_gain(&self, gain: f32) {
+        *self.gain.lock().unwrap() = gain.max(0.0).min(1.0);
+    }
+
+    pub fn music_gain(&self) -> f32 { *self.music_gain.lock().unwrap() }
+
+    pub fn set_music_gain(&self, gain: f32) {
+        *self.music_gain.lock().unwrap() = gain.max(0.0).min(1.0);
+    }
+
+    /// Asks the mixer to crossfade to the given music (or to silence).
+    pub fn change_music(&self, music: Option<Music>) {
+        *self.pending_music.lock().unwrap() = Some(music);
+    }
+
+    fn take_music_change(&self) -> Option<Option<Music>> {
+        self.pending_music.lock().unwrap().take()
+    }
 
     pub fn enqueue(&self, mut sounds: Vec<Sound>) {
         if !sounds.is_empty() {
@@ -234,16 +365,30 @@ impl SoundQueue {
 
 pub struct SoundMixer {
     time_step: f32,
+    num_channels: usize,
     sound_queue: Arc<SoundQueue>,
-    active_sounds: Vec<Sound>,
+    voices: Vec<Voice>,
+    music_player: MusicPlayer,
+    music_buffer: Vec<f32>,
+    limiter: Limiter,
 }
 
 impl SoundMixer {
-    fn new(audio_rate: i32, sound_queue: Arc<SoundQueue>) -> SoundMixer {
+    /// Creates a mixer that isn't attached to any audio device; its output
+    /// can be pulled with `mix` (see also `audio_device`).  The output is
+    /// interleaved if there is more than one channel.
+    pub fn new(audio_rate: i32, num_channels: u8,
+               sound_queue: Arc<SoundQueue>)
+               -> SoundMixer {
+        let time_step = 1.0 / audio_rate as f32;
         SoundMixer {
-            time_step: 1.0 / audio_rate as f32,
+            time_step: time_step,
+            num_channels: cmp::max(1, num_channels as usize),
             sound_queue: sound_queue,
-            active_sounds: Vec::new(),
+            voices: Vec::new(),
+            music_player: MusicPlayer::new(),
+            music_buffer: Vec::new(),
+            limiter: Limiter::new(time_step),
         }
     }
 
@@ -257,34 +402,320 @@ impl SoundMixer {
         };
         audio_subsystem
             .open_playback(None, &desired_audio_spec, |spec| {
-                SoundMixer::new(spec.freq, sound_queue)
+                SoundMixer::new(spec.freq, spec.channels, sound_queue)
             })
             .unwrap()
     }
+
+    /// Returns true if no sound effects are currently playing (background
+    /// music is not counted).
+    pub fn is_idle(&self) -> bool { self.voices.is_empty() }
+
+    /// Fills `out` with the next samples of mixed output, picking up any
+    /// newly queued sounds and music changes first.
+    pub fn mix(&mut self, out: &mut [itersynth::Sample]) {
+        for sound in self.sound_queue.drain() {
+            self.voices.push(Voice::new(sound));
+        }
+        self.steal_voices();
+        if let Some(music) = self.sound_queue.take_music_change() {
+            self.music_player.switch_to(music);
+        }
+        let num_frames = out.len() / self.num_channels;
+        self.music_buffer.clear();
+        self.music_buffer.resize(num_frames, 0.0);
+        let music_gain = self.sound_queue.music_gain();
+        self.music_player
+            .mix_into(&mut self.music_buffer, self.time_step, music_gain);
+        let time_step = self.time_step;
+        let gain = self.sound_queue.gain();
+        for (frame, &music) in
+            out.chunks_mut(self.num_channels).zip(self.music_buffer.iter())
+        {
+            let (mut left, mut right) = (music, music);
+            for voice in self.voices.iter_mut() {
+                if let Some(value) = voice.next(time_step) {
+                    left += value * voice.left_gain;
+                    right += value * voice.right_gain;
+                }
+            }
+            let limit = self.limiter.next_gain(left.abs().max(right.abs()));
+            left *= limit * gain;
+            right *= limit * gain;
+            if frame.len() == 1 {
+                frame[0] = 0.5 * (left + right);
+            } else {
+                frame[0] = left;
+                frame[1] = right;
+                for sample in frame[2..].iter_mut() {
+                    *sample = 0.0;
+                }
+            }
+        }
+        self.voices.retain(|voice| !voice.finished);
+    }
+
+    /// If there are more than `MAX_VOICES` voices playing, starts fading out
+    /// the oldest ones.
+    fn steal_voices(&mut self) {
+        let num_live = self.voices
+            .iter()
+            .filter(|voice| voice.fade.is_none())
+            .count();
+        let mut num_to_steal = num_live.saturating_sub(MAX_VOICES);
+        for voice in self.voices.iter_mut() {
+            if num_to_steal == 0 {
+                break;
+            }
+            if voice.fade.is_none() {
+                voice.fade = Some(1.0);
+                num_to_steal -= 1;
+            }
+        }
+    }
 }
 
 impl AudioCallback for SoundMixer {
     type Channel = itersynth::Sample;
 
-    fn callback(&mut self, out: &mut [itersynth::Sample]) {
-        let mut new_sounds = self.sound_queue.drain();
-        self.active_sounds.append(&mut new_sounds);
-        for sample in out.iter_mut() {
-            *sample = 0.0;
+    fn callback(&mut self, out: &mut [itersynth::Sample]) { self.mix(out); }
+}
+
+// ========================================================================= //
+
+/// One sound effect being played by the mixer.
+struct Voice {
+    wave: Wave,
+    left_gain: f32,
+    right_gain: f32,
+    /// The remaining gain of a voice that has been cut off, if any.
+    fade: Option<f32>,
+    finished: bool,
+}
+
+impl Voice {
+    fn new(sound: Sound) -> Voice {
+        // Panning keeps the near speaker at full volume and attenuates the
+        // far one, so that centered sounds are as loud as they ever were.
+        let pan = sound.pan();
+        Voice {
+            wave: sound.wave,
+            left_gain: (1.0 - pan).min(1.0),
+            right_gain: (1.0 + pan).min(1.0),
+            fade: None,
+            finished: false,
         }
-        debug_assert!(new_sounds.is_empty());
-        'sounds: for mut sound in self.active_sounds.drain(..) {
-            for sample in out.iter_mut() {
-                if let Some(value) = sound.wave.next(self.time_step) {
-                    *sample += value;
-                } else {
-                    continue 'sounds;
-                }
+    }
+
+    fn next(&mut self, time_step: f32) -> Option<f32> {
+        if self.finished {
+            return None;
+        }
+        let mut value = match self.wave.next(time_step) {
+            Some(value) => value,
+            None => {
+                self.finished = true;
+                return None;
             }
-            new_sounds.push(sound);
+        };
+        if let Some(fade) = self.fade {
+            value *= fade;
+            let fade = fade - time_step / STEAL_FADE_SECONDS;
+            if fade <= 0.0 {
+                self.finished = true;
+            }
+            self.fade = Some(fade);
+        }
+        Some(value)
+    }
+}
+
+// ========================================================================= //
+
+/// A soft-knee peak limiter for the mix bus.  Levels below `LIMITER_KNEE`
+/// pass through untouched; louder peaks are squashed smoothly so that the
+/// output never exceeds full scale, and the gain then recovers gradually.
+struct Limiter {
+    gain: f32,
+    release: f32,
+}
+
+impl Limiter {
+    fn new(time_step: f32) -> Limiter {
+        Limiter {
+            gain: 1.0,
+            release: 1.0 - (-time_step / LIMITER_RELEASE_SECONDS).exp(),
+        }
+    }
+
+    /// Returns the gain to apply to a frame whose loudest channel has the
+    /// given absolute level.
+    fn next_gain(&mut self, peak: f32) -> f32 {
+        let target = if peak > LIMITER_KNEE {
+            let range = 1.0 - LIMITER_KNEE;
+            let squashed =
+                LIMITER_KNEE + range * ((peak - LIMITER_KNEE) / range).tanh();
+            squashed / peak
+        } else {
+            1.0
+        };
+        if target < self.gain {
+            self.gain = target;
+        } else {
+            self.gain += (target - self.gain) * self.release;
+        }
+        self.gain
+    }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use super::super::music::Music;
+    use super::super::offline::{OfflineMixer, assert_matches_golden_wav,
+                                render_sound};
+    use std::sync::Arc;
+
+    use super::{Limiter, MAX_VOICES, Sound, SoundMixer, SoundQueue, Voice};
+
+    const SAMPLE_RATE: i32 = 22050;
+    const MAX_SECONDS: f32 = 10.0;
+
+    fn assert_sound_matches_golden(name: &str, sound: Sound) {
+        let samples = render_sound(sound, SAMPLE_RATE, MAX_SECONDS);
+        assert!(samples.len() < (MAX_SECONDS * SAMPLE_RATE as f32) as usize,
+                "{} never finished",
+                name);
+        assert_matches_golden_wav(name, SAMPLE_RATE, &samples);
+    }
+
+    #[test]
+    fn beep() { assert_sound_matches_golden("sound_beep", Sound::beep()); }
+
+    #[test]
+    fn platform_shift() {
+        for &num_times in &[1, 3] {
+            assert_sound_matches_golden(&format!("sound_platform_shift_{}",
+                                                 num_times),
+                                        Sound::platform_shift(num_times));
+        }
+    }
+
+    #[test]
+    fn transform_step() {
+        for step in 0..3 {
+            assert_sound_matches_golden(&format!("sound_transform_step_{}",
+                                                 step),
+                                        Sound::transform_step(step));
+        }
+    }
+
+    #[test]
+    fn named_sounds() {
+        let render = |sound| render_sound(sound, SAMPLE_RATE, MAX_SECONDS);
+        assert_eq!(render(Sound::named("beep").unwrap()),
+                   render(Sound::beep()));
+        assert_eq!(render(Sound::named("platform_shift_3").unwrap()),
+                   render(Sound::platform_shift(3)));
+        assert_eq!(render(Sound::named("platform_shift_double_2").unwrap()),
+                   render(Sound::platform_shift_double(2)));
+        assert!(Sound::named("talk_hi").is_some());
+        assert!(Sound::named("atlatl_charge_0.5").is_some());
+        assert!(Sound::named("platform_shift").is_none());
+        assert!(Sound::named("transform_step_x").is_none());
+        assert!(Sound::named("kazoo").is_none());
+    }
+
+    #[test]
+    fn panning() {
+        assert_eq!(Sound::beep().pan(), 0.0);
+        assert_eq!(Sound::beep().at_x(0).pan(), -0.75);
+        assert_eq!(Sound::beep().at_x(288).pan(), 0.0);
+        assert_eq!(Sound::beep().at_x(576).pan(), 0.75);
+        assert_eq!(Sound::beep().panned(0.5).at_x(0).pan(), 0.5);
+        assert_eq!(Sound::beep().panned(-3.0).pan(), -1.0);
+        let voice = Voice::new(Sound::beep());
+        assert_eq!((voice.left_gain, voice.right_gain), (1.0, 1.0));
+        let voice = Voice::new(Sound::beep().panned(-1.0));
+        assert_eq!((voice.left_gain, voice.right_gain), (1.0, 0.0));
+        let voice = Voice::new(Sound::beep().panned(0.5));
+        assert_eq!((voice.left_gain, voice.right_gain), (0.5, 1.0));
+    }
+
+    #[test]
+    fn oldest_voices_are_stolen() {
+        let queue = Arc::new(SoundQueue::new());
+        let mut mixer = SoundMixer::new(1000, 2, queue);
+        for _ in 0..(MAX_VOICES + 3) {
+            mixer.voices.push(Voice::new(Sound::beep()));
+        }
+        mixer.steal_voices();
+        let fading: Vec<bool> =
+            mixer.voices.iter().map(|voice| voice.fade.is_some()).collect();
+        assert_eq!(fading.iter().filter(|&&fading| fading).count(), 3);
+        assert!(fading[..3].iter().all(|&fading| fading));
+        // Stealing again doesn't cut off any more voices:
+        mixer.steal_voices();
+        assert_eq!(mixer.voices.iter().filter(|v| v.fade.is_some()).count(),
+                   3);
+    }
+
+    #[test]
+    fn limiter() {
+        let mut limiter = Limiter::new(0.01);
+        assert_eq!(limiter.next_gain(0.5), 1.0);
+        assert_eq!(limiter.next_gain(0.7), 1.0);
+        let gain = limiter.next_gain(4.0);
+        assert!(4.0 * gain <= 1.0);
+        assert!(4.0 * gain > 0.9);
+        let gain = limiter.next_gain(0.8);
+        assert!(0.8 * gain < 0.8);
+        for _ in 0..200 {
+            limiter.next_gain(0.0);
+        }
+        assert!(limiter.next_gain(0.0) > 0.99);
+        // Peaks just over the knee are only squashed a little:
+        let mut limiter = Limiter::new(0.01);
+        let gain = limiter.next_gain(0.75);
+        assert!(gain < 1.0 && 0.75 * gain > 0.74);
+    }
+
+    #[test]
+    fn overlapping_panned_sounds() {
+        let mut mixer = OfflineMixer::new(SAMPLE_RATE);
+        let mut sounds = Vec::new();
+        for index in 0..6 {
+            sounds.push(Sound::device_slide().at_x(index * 100));
+            sounds.push(Sound::explosion_small().at_x(500 - index * 100));
         }
-        debug_assert!(self.active_sounds.is_empty());
-        mem::replace(&mut self.active_sounds, new_sounds);
+        mixer.play_sounds(sounds);
+        let samples = mixer.render_until_idle(MAX_SECONDS);
+        assert!(samples.iter().all(|sample| sample.abs() <= 1.0));
+        assert_matches_golden_wav("sound_overlapping_panned",
+                                  SAMPLE_RATE,
+                                  &samples);
+    }
+
+    #[test]
+    fn mixed_queue() {
+        let mut mixer = OfflineMixer::new(SAMPLE_RATE);
+        mixer.set_gain(0.5);
+        mixer.play_sounds(vec![Sound::solve_puzzle_chime(), Sound::beep()]);
+        let mut samples = mixer.render(SAMPLE_RATE as usize / 4);
+        mixer.play_sounds(vec![Sound::talk_hi()]);
+        samples.extend(mixer.render_until_idle(MAX_SECONDS));
+        assert_matches_golden_wav("sound_mixed_queue", SAMPLE_RATE, &samples);
+    }
+
+    #[test]
+    fn music_crossfade() {
+        let mut mixer = OfflineMixer::new(SAMPLE_RATE);
+        mixer.play_music(Some(Music::map()));
+        let mut samples = mixer.render(SAMPLE_RATE as usize);
+        mixer.play_music(Some(Music::ice()));
+        samples.extend(mixer.render(3 * SAMPLE_RATE as usize));
+        assert_matches_golden_wav("music_crossfade", SAMPLE_RATE, &samples);
     }
 }
 
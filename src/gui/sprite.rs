@@ -32,7 +32,14 @@ use std::rc::Rc;
 pub struct Sprite {
     width: u32,
     height: u32,
-    texture: Rc<Texture>,
+    data: SpriteData,
+}
+
+#[derive(Clone)]
+enum SpriteData {
+    Texture(Rc<Texture>),
+    #[cfg(test)]
+    Rgba(Rc<Vec<u8>>),
 }
 
 impl Sprite {
@@ -52,12 +59,23 @@ impl Sprite {
                                          width * bytes_per_pixel,
                                          format)
             .unwrap();
+        let texture = renderer.create_texture_from_surface(&surface).unwrap();
+        Sprite {
+            width: width,
+            height: height,
+            data: SpriteData::Texture(Rc::new(texture)),
+        }
+    }
+
+    /// Creates a sprite that keeps its pixels in memory (in RGBA order), for
+    /// drawing with a software renderer.
+    #[cfg(test)]
+    pub fn from_rgba_data(width: u32, height: u32, data: Vec<u8>) -> Sprite {
+        assert_eq!(data.len(), (width * height * 4) as usize);
         Sprite {
             width: width,
             height: height,
-            texture: Rc::new(renderer
-                                 .create_texture_from_surface(&surface)
-                                 .unwrap()),
+            data: SpriteData::Rgba(Rc::new(data)),
         }
     }
 
@@ -67,7 +85,21 @@ impl Sprite {
 
     pub fn rect(&self) -> Rect { Rect::new(0, 0, self.width, self.height) }
 
-    pub fn sdl2_texture(&self) -> &Texture { &self.texture }
+    pub fn sdl2_texture(&self) -> &Texture {
+        match self.data {
+            SpriteData::Texture(ref texture) => texture,
+            #[cfg(test)]
+            SpriteData::Rgba(_) => panic!("sprite has no texture"),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn rgba_data(&self) -> &[u8] {
+        match self.data {
+            SpriteData::Texture(_) => panic!("sprite has no pixel data"),
+            SpriteData::Rgba(ref data) => data,
+        }
+    }
 }
 
 // ========================================================================= //
@@ -23,29 +23,39 @@ use sdl2::rect::{Point, Rect};
 use sdl2::render::Canvas as SdlCanvas;
 use sdl2::video::FullscreenType;
 use sdl2::video::Window as SdlWindow;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::f64;
 use std::rc::Rc;
 use std::sync::Arc;
 
 use super::canvas::{Align, Canvas};
+use super::controller::{ControllerMap, Controllers};
 use super::element::Element;
 use super::event::Event;
 use super::font::Font;
+use super::record::{EventRecorder, EventReplay};
 use super::resources::{ResourceCache, Resources};
+use super::music::Music;
+#[cfg(test)]
+use super::offscreen::OffscreenRenderer;
+use super::renderer::Renderer;
 use super::sound::{Sound, SoundMixer, SoundQueue};
 
 // ========================================================================= //
 
 pub struct Window {
-    _audio_subsystem: AudioSubsystem,
-    _audio_device: AudioDevice<SoundMixer>,
+    screen: Screen,
     sound_queue: Arc<SoundQueue>,
-    _video_subsystem: VideoSubsystem,
-    renderer: SdlCanvas<SdlWindow>,
+    music_name: Option<&'static str>,
     full_rect: Rect,
-    event_pump: EventPump,
+    pending_events: VecDeque<Event>,
     resource_cache: ResourceCache,
     debug_font: Option<Rc<Font>>,
     debug_counter: i32,
+    text_speed: Rc<Cell<f64>>,
+    recorder: Option<EventRecorder>,
+    replay: Option<EventReplay>,
 }
 
 impl Window {
@@ -106,31 +116,75 @@ impl Window {
                                                     sound_queue.clone());
         audio_device.resume();
 
-        Window {
+        let screen = SdlScreen {
             _audio_subsystem: audio_subsystem,
             _audio_device: audio_device,
-            sound_queue: sound_queue,
             _video_subsystem: video_subsystem,
             renderer: renderer,
-            full_rect: Rect::new(offset_x, offset_y, full_width, full_height),
             event_pump: sdl_context.event_pump().unwrap(),
+            controllers: Controllers::new(sdl_context.game_controller().ok()),
+        };
+        Window {
+            screen: Screen::Sdl(screen),
+            sound_queue: sound_queue,
+            music_name: None,
+            full_rect: Rect::new(offset_x, offset_y, full_width, full_height),
+            pending_events: VecDeque::new(),
             resource_cache: resource_cache,
             debug_font: debug_font,
             debug_counter: 0,
+            text_speed: Rc::new(Cell::new(f64::INFINITY)),
+            recorder: None,
+            replay: None,
+        }
+    }
+
+    /// Creates a window that draws into a software render target of the
+    /// ideal size and has no live input or audio output, so that the game
+    /// can be run from an event replay in tests.
+    #[cfg(test)]
+    pub fn offscreen(full_size: (u32, u32), ideal_size: (u32, u32))
+                     -> Window {
+        let (full_width, full_height) = full_size;
+        let (ideal_width, ideal_height) = ideal_size;
+        let offset_x = (ideal_width as i32 - full_width as i32) / 2;
+        let offset_y = (ideal_height as i32 - full_height as i32) / 2;
+        let renderer = OffscreenRenderer::new(ideal_width, ideal_height);
+        Window {
+            screen: Screen::Offscreen(renderer),
+            sound_queue: Arc::new(SoundQueue::new()),
+            music_name: None,
+            full_rect: Rect::new(offset_x, offset_y, full_width, full_height),
+            pending_events: VecDeque::new(),
+            resource_cache: ResourceCache::new(),
+            debug_font: None,
+            debug_counter: 0,
+            text_speed: Rc::new(Cell::new(f64::INFINITY)),
+            recorder: None,
+            replay: None,
         }
     }
 
     pub fn visible_rect(&self) -> Rect {
-        let (width, height) = self.renderer.logical_size();
+        let (width, height) = match self.screen {
+            Screen::Sdl(ref screen) => screen.renderer.logical_size(),
+            #[cfg(test)]
+            Screen::Offscreen(ref renderer) => renderer.size(),
+        };
         Rect::new(-self.full_rect.x(), -self.full_rect.y(), width, height)
     }
 
-    #[allow(dead_code)]
     pub fn is_fullscreen(&self) -> bool {
-        self.renderer.window().fullscreen_state() != FullscreenType::Off
+        match self.screen {
+            Screen::Sdl(ref screen) => {
+                screen.renderer.window().fullscreen_state() !=
+                    FullscreenType::Off
+            }
+            #[cfg(test)]
+            Screen::Offscreen(_) => false,
+        }
     }
 
-    #[allow(dead_code)]
     pub fn set_fullscreen(&mut self, fullscreen: bool) {
         if fullscreen != self.is_fullscreen() {
             let state = if fullscreen {
@@ -141,18 +195,27 @@ impl Window {
             if cfg!(debug_assertions) {
                 println!("Setting fullscreen to {:?}.", state);
             }
-            self.renderer.window_mut().set_fullscreen(state).unwrap();
+            match self.screen {
+                Screen::Sdl(ref mut screen) => {
+                    let window = screen.renderer.window_mut();
+                    window.set_fullscreen(state).unwrap();
+                }
+                #[cfg(test)]
+                Screen::Offscreen(_) => {}
+            }
         }
     }
 
     pub fn render<S, A, E: Element<S, A>>(&mut self, state: &S, view: &E) {
         {
-            let mut canvas = Canvas::new(&mut self.renderer, self.full_rect);
+            let mut canvas = Canvas::new(self.screen.renderer_mut(),
+                                         self.full_rect);
             view.draw(state, &mut canvas);
         }
         if cfg!(debug_assertions) {
             let visible = self.visible_rect();
-            let mut canvas = Canvas::new(&mut self.renderer, self.full_rect);
+            let mut canvas = Canvas::new(self.screen.renderer_mut(),
+                                         self.full_rect);
             if let Some(ref font) = self.debug_font {
                 canvas.fill_rect((0, 0, 0),
                                  Rect::new(visible.right() - 24,
@@ -167,13 +230,48 @@ impl Window {
                 self.debug_counter = (self.debug_counter + 1) % 1000;
             }
         }
-        self.renderer.present();
+        match self.screen {
+            Screen::Sdl(ref mut screen) => screen.renderer.present(),
+            #[cfg(test)]
+            Screen::Offscreen(_) => {}
+        }
     }
 
-    /// Blocks until the next event is available.
+    /// Blocks until the next event is available.  If an event replay is in
+    /// progress, returns the next recorded event instead.
     pub fn next_event(&mut self) -> Event {
+        let event = if self.replay.is_some() {
+            self.next_replayed_event()
+        } else {
+            self.next_live_event()
+        };
+        if let Some(ref mut recorder) = self.recorder {
+            recorder.record(&event);
+        }
+        event
+    }
+
+    fn next_live_event(&mut self) -> Event {
+        if let Some(event) = self.pending_events.pop_front() {
+            return event;
+        }
+        let screen = match self.screen {
+            Screen::Sdl(ref mut screen) => screen,
+            #[cfg(test)]
+            Screen::Offscreen(_) => return Event::Quit,
+        };
         loop {
-            match Event::from_sdl2(&self.event_pump.wait_event()) {
+            let sdl_event = screen.event_pump.wait_event();
+            if let Some(event) = screen.controllers.handle_sdl2(&sdl_event) {
+                return event;
+            }
+            match Event::from_sdl2(&sdl_event) {
+                Some(Event::ClockTick) => {
+                    if let Some(event) = screen.controllers.tick() {
+                        self.pending_events.push_back(event);
+                    }
+                    return Event::ClockTick;
+                }
                 Some(event) => {
                     return event.translate(-self.full_rect.x(),
                                            -self.full_rect.y())
@@ -183,13 +281,131 @@ impl Window {
         }
     }
 
+    fn next_replayed_event(&mut self) -> Event {
+        // Live input is ignored during a replay, except that the player can
+        // still close the window to abort it.
+        match self.screen {
+            Screen::Sdl(ref mut screen) => {
+                while let Some(event) = screen.event_pump.poll_event() {
+                    if Event::from_sdl2(&event) == Some(Event::Quit) {
+                        return Event::Quit;
+                    }
+                }
+            }
+            #[cfg(test)]
+            Screen::Offscreen(_) => {}
+        }
+        let next = self.replay.as_mut().and_then(EventReplay::next_event);
+        match next {
+            Some(event) => event,
+            None => {
+                println!("Event replay finished.");
+                self.replay = None;
+                Event::Quit
+            }
+        }
+    }
+
+    /// Starts writing every event returned by `next_event` to an event log.
+    pub fn record_events(&mut self, recorder: EventRecorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Makes `next_event` return the events from an event log (instead of
+    /// live input) until the log runs out.
+    pub fn replay_events(&mut self, replay: EventReplay) {
+        self.replay = Some(replay);
+    }
+
     pub fn resources(&mut self) -> Resources {
-        Resources::new(&self.renderer, &mut self.resource_cache)
+        let mut resources =
+            Resources::new(self.screen.renderer(), &mut self.resource_cache);
+        resources.set_text_speed(self.text_speed.clone());
+        resources
     }
 
     pub fn play_sounds(&mut self, sounds: Vec<Sound>) {
         self.sound_queue.enqueue(sounds);
     }
+
+    /// Sets the gain (from 0 to 1) applied to all sound output.  Takes effect
+    /// starting with the next audio buffer.
+    pub fn set_sound_gain(&mut self, gain: f32) {
+        self.sound_queue.set_gain(gain);
+    }
+
+    /// Crossfades the background music to the given piece, or fades it out if
+    /// `music` is `None`.  Does nothing if that piece is already playing.
+    pub fn play_music(&mut self, music: Option<Music>) {
+        let name = music.as_ref().map(Music::name);
+        if name != self.music_name {
+            self.music_name = name;
+            self.sound_queue.change_music(music);
+        }
+    }
+
+    /// Sets the gain (from 0 to 1) applied to the background music, on top of
+    /// the overall sound gain.
+    pub fn set_music_gain(&mut self, gain: f32) {
+        self.sound_queue.set_music_gain(gain);
+    }
+
+    /// Sets the multiplier for how quickly speech bubble text is revealed in
+    /// cutscenes (infinite for all at once), including ones already playing.
+    pub fn set_text_speed(&mut self, speed: f64) {
+        self.text_speed.set(speed);
+    }
+
+    /// Sets the locale whose message catalog is used for text in views that
+    /// are created from now on.
+    pub fn set_locale(&mut self, locale: &str) {
+        self.resource_cache.set_locale(locale);
+    }
+
+    /// Sets which actions the game controller buttons perform.
+    pub fn set_controller_map(&mut self, map: ControllerMap) {
+        match self.screen {
+            Screen::Sdl(ref mut screen) => screen.controllers.set_map(map),
+            #[cfg(test)]
+            Screen::Offscreen(_) => {}
+        }
+    }
+}
+
+// ========================================================================= //
+
+/// Where a `Window` draws, and where its live input comes from.
+enum Screen {
+    Sdl(SdlScreen),
+    #[cfg(test)]
+    Offscreen(OffscreenRenderer),
+}
+
+impl Screen {
+    fn renderer(&self) -> &Renderer {
+        match *self {
+            Screen::Sdl(ref screen) => &screen.renderer,
+            #[cfg(test)]
+            Screen::Offscreen(ref renderer) => renderer,
+        }
+    }
+
+    fn renderer_mut(&mut self) -> &mut Renderer {
+        match *self {
+            Screen::Sdl(ref mut screen) => &mut screen.renderer,
+            #[cfg(test)]
+            Screen::Offscreen(ref mut renderer) => renderer,
+        }
+    }
+}
+
+struct SdlScreen {
+    _audio_subsystem: AudioSubsystem,
+    _audio_device: AudioDevice<SoundMixer>,
+    _video_subsystem: VideoSubsystem,
+    renderer: SdlCanvas<SdlWindow>,
+    event_pump: EventPump,
+    controllers: Controllers,
 }
 
 // ========================================================================= //
@@ -30,17 +30,25 @@ mod elements;
 mod gui;
 mod modes;
 mod save;
+mod savecmd;
 
 use std::path::PathBuf;
 
-use self::gui::{Event, FRAME_DELAY_MILLIS, Window};
+use self::gui::{Event, EventRecorder, EventReplay, FRAME_DELAY_MILLIS,
+                Window};
 use self::modes::Mode;
 use self::save::{Location, SaveData};
 
 // ========================================================================= //
 
 struct Flags {
+    command: Vec<String>,
+    edit_ice: Option<PathBuf>,
     fullscreen: Option<bool>,
+    play_ice: Option<PathBuf>,
+    record_events: Option<PathBuf>,
+    replay_events: Option<PathBuf>,
+    replay_output: Option<PathBuf>,
     save_file: Option<PathBuf>,
     window_size: Option<(u32, u32)>,
 }
@@ -50,10 +58,30 @@ impl Flags {
         let args: Vec<String> = std::env::args().collect();
         let mut opts = getopts::Options::new();
         opts.optflag("h", "help", "print this help menu");
+        opts.optopt("",
+                    "edit_ice",
+                    "open the ice puzzle level editor on FILE",
+                    "FILE");
         opts.optflagopt("",
                         "fullscreen",
                         "override fullscreen setting",
                         "BOOL");
+        opts.optopt("",
+                    "play_ice",
+                    "play the custom ice puzzle in FILE",
+                    "FILE");
+        opts.optopt("",
+                    "record_events",
+                    "record input events to FILE",
+                    "FILE");
+        opts.optopt("",
+                    "replay_events",
+                    "replay input events from FILE",
+                    "FILE");
+        opts.optopt("",
+                    "replay_output",
+                    "save the game to FILE during a replay",
+                    "FILE");
         opts.optopt("", "save_file", "override save file path", "FILE");
         opts.optopt("", "window_size", "override window size", "WxH");
         let matches = opts.parse(&args[1..]).unwrap_or_else(|failure| {
@@ -62,13 +90,22 @@ impl Flags {
             std::process::exit(1);
         });
         if matches.opt_present("help") {
-            let brief = format!("Usage: {} [options]", &args[0]);
+            let brief = format!("Usage: {} [options] [COMMAND]", &args[0]);
             print!("{}", opts.usage(&brief));
+            println!("\n{}", savecmd::USAGE);
             std::process::exit(0);
         }
+        let edit_ice = matches.opt_str("edit_ice").map(PathBuf::from);
         let fullscreen = matches
             .opt_default("fullscreen", "true")
             .and_then(|value| value.parse().ok());
+        let play_ice = matches.opt_str("play_ice").map(PathBuf::from);
+        let record_events =
+            matches.opt_str("record_events").map(PathBuf::from);
+        let replay_events =
+            matches.opt_str("replay_events").map(PathBuf::from);
+        let replay_output =
+            matches.opt_str("replay_output").map(PathBuf::from);
         let save_file = matches.opt_str("save_file").map(PathBuf::from);
         let window_size = matches
             .opt_str("window_size")
@@ -90,7 +127,13 @@ impl Flags {
                 }
             });
         Flags {
+            command: matches.free,
+            edit_ice: edit_ice,
             fullscreen: fullscreen,
+            play_ice: play_ice,
+            record_events: record_events,
+            replay_events: replay_events,
+            replay_output: replay_output,
             save_file: save_file,
             window_size: window_size,
         }
@@ -118,7 +161,50 @@ impl Flags {
 
 fn main() {
     let flags = Flags::parse_or_exit();
-    let mut save_data = SaveData::load_or_create(flags.save_file()).unwrap();
+    if !flags.command.is_empty() {
+        let code = savecmd::run_save_command(flags.save_file(),
+                                             &flags.command);
+        std::process::exit(code);
+    }
+    // The game logic RNG must be seeded (and today's date pinned) before the
+    // save file is loaded, so that recording and replaying start from the
+    // same state.
+    let replay = flags.replay_events.as_ref().map(|path| {
+        EventReplay::load(path).unwrap_or_else(|error| {
+            println!("Failed to load event log: {}", error);
+            std::process::exit(1);
+        })
+    });
+    let (seed, day) = match replay {
+        Some(ref replay) => (replay.seed(), replay.day()),
+        None => (rand::random(), save::today()),
+    };
+    let recorder = flags.record_events.as_ref().map(|path| {
+        EventRecorder::create(path, seed, day).unwrap_or_else(|error| {
+            println!("Failed to create event log: {}", error);
+            std::process::exit(1);
+        })
+    });
+    if replay.is_some() || recorder.is_some() {
+        save::seed_game_rng(seed);
+        save::pin_today(day);
+    }
+    let mut save_data = match SaveData::load_or_create(flags.save_file()) {
+        Ok(save_data) => save_data,
+        Err(error) => {
+            println!("Failed to load save data: {}", error);
+            std::process::exit(1);
+        }
+    };
+    // A replay never writes to the save file it started from, so that it can
+    // be run again; it only saves if given a separate output file.
+    if replay.is_some() {
+        if flags.replay_output == Some(flags.save_file()) {
+            println!("The replay output must not be the save file.");
+            std::process::exit(1);
+        }
+        save_data.set_save_path(flags.replay_output.clone());
+    }
     let sdl_context = sdl2::init().unwrap();
     let event_subsystem = sdl_context.event().unwrap();
     let timer_subsystem = sdl_context.timer().unwrap();
@@ -128,6 +214,13 @@ fn main() {
                                  flags.ideal_size(),
                                  flags.force_ideal(),
                                  flags.fullscreen(save_data.prefs()));
+    modes::apply_prefs(&mut window, save_data.prefs());
+    if let Some(recorder) = recorder {
+        window.record_events(recorder);
+    }
+    if let Some(replay) = replay {
+        window.replay_events(replay);
+    }
     let _timer = {
         Event::register_clock_ticks(&event_subsystem);
         let callback = Box::new(|| {
@@ -136,156 +229,207 @@ fn main() {
                                 });
         timer_subsystem.add_timer(FRAME_DELAY_MILLIS, callback)
     };
-    let mut mode = Mode::Title;
+    let mode = if let Some(ref path) = flags.edit_ice {
+        Mode::IceEditor(path.clone())
+    } else if let Some(ref path) = flags.play_ice {
+        Mode::BonusPuzzle(path.clone())
+    } else {
+        Mode::Title
+    };
+    run_game(&mut window, &mut save_data, mode);
+    if let Err(error) = save_data.save_to_disk() {
+        println!("Failed to save game: {}", error);
+    }
+}
+
+/// Runs the game, starting in the given mode, until the player quits.
+fn run_game(window: &mut Window, save_data: &mut SaveData, mut mode: Mode) {
     loop {
         mode = match mode {
-            Mode::Title => {
-                modes::run_title_screen(&mut window, &mut save_data)
-            }
+            Mode::Title => modes::run_title_screen(window, save_data),
             Mode::Location(loc) => {
                 save_data.game_mut().location = loc;
                 match loc {
-                    Location::Map => {
-                        modes::run_map_screen(&mut window, &mut save_data)
-                    }
-                    Location::Prolog => {
-                        modes::run_prolog(&mut window, &mut save_data)
-                    }
+                    Location::Map => modes::run_map_screen(window, save_data),
+                    Location::Prolog => modes::run_prolog(window, save_data),
                     Location::ALightInTheAttic => {
-                        modes::run_a_light_in_the_attic(&mut window,
-                                                        &mut save_data)
+                        modes::run_a_light_in_the_attic(window, save_data)
                     }
                     Location::AutofacTour => {
-                        modes::run_autofac_tour(&mut window, &mut save_data)
+                        modes::run_autofac_tour(window, save_data)
                     }
                     Location::BlackAndBlue => {
-                        modes::run_black_and_blue(&mut window, &mut save_data)
+                        modes::run_black_and_blue(window, save_data)
                     }
                     Location::ColumnAsIcyEm => {
-                        modes::run_column_as_icy_em(&mut window,
-                                                    &mut save_data)
+                        modes::run_column_as_icy_em(window, save_data)
                     }
                     Location::ConnectTheDots => {
-                        modes::run_connect_the_dots(&mut window,
-                                                    &mut save_data)
+                        modes::run_connect_the_dots(window, save_data)
                     }
                     Location::CrossSauce => {
-                        modes::run_cross_sauce(&mut window, &mut save_data)
+                        modes::run_cross_sauce(window, save_data)
                     }
                     Location::CrossTheLine => {
-                        modes::run_cross_the_line(&mut window, &mut save_data)
+                        modes::run_cross_the_line(window, save_data)
                     }
                     Location::CubeTangle => {
-                        modes::run_cube_tangle(&mut window, &mut save_data)
+                        modes::run_cube_tangle(window, save_data)
                     }
                     Location::Disconnected => {
-                        modes::run_disconnected(&mut window, &mut save_data)
+                        modes::run_disconnected(window, save_data)
                     }
                     Location::DoubleCross => {
-                        modes::run_double_cross(&mut window, &mut save_data)
+                        modes::run_double_cross(window, save_data)
                     }
                     Location::FactOrFiction => {
-                        modes::run_fact_or_fiction(&mut window, &mut save_data)
+                        modes::run_fact_or_fiction(window, save_data)
                     }
                     Location::HexSpangled => {
-                        modes::run_hex_spangled(&mut window, &mut save_data)
+                        modes::run_hex_spangled(window, save_data)
                     }
                     Location::IceToMeetYou => {
-                        modes::run_ice_to_meet_you(&mut window, &mut save_data)
+                        modes::run_ice_to_meet_you(window, save_data)
                     }
                     Location::IfMemoryServes => {
-                        modes::run_if_memory_serves(&mut window,
-                                                    &mut save_data)
+                        modes::run_if_memory_serves(window, save_data)
                     }
                     Location::JogYourMemory => {
-                        modes::run_jog_your_memory(&mut window, &mut save_data)
+                        modes::run_jog_your_memory(window, save_data)
                     }
                     Location::LevelHeaded => {
-                        modes::run_level_headed(&mut window, &mut save_data)
+                        modes::run_level_headed(window, save_data)
                     }
                     Location::LevelUp => {
-                        modes::run_level_up(&mut window, &mut save_data)
+                        modes::run_level_up(window, save_data)
                     }
                     Location::LightSyrup => {
-                        modes::run_light_syrup(&mut window, &mut save_data)
+                        modes::run_light_syrup(window, save_data)
                     }
                     Location::LogLevel => {
-                        modes::run_log_level(&mut window, &mut save_data)
+                        modes::run_log_level(window, save_data)
                     }
                     Location::MemoryLane => {
-                        modes::run_memory_lane(&mut window, &mut save_data)
+                        modes::run_memory_lane(window, save_data)
                     }
                     Location::MissedConnections => {
-                        modes::run_missed_connections(&mut window,
-                                                      &mut save_data)
+                        modes::run_missed_connections(window, save_data)
                     }
                     Location::PasswordFile => {
-                        modes::run_password_file(&mut window, &mut save_data)
+                        modes::run_password_file(window, save_data)
                     }
                     Location::PlaneAndSimple => {
-                        modes::run_plane_and_simple(&mut window,
-                                                    &mut save_data)
+                        modes::run_plane_and_simple(window, save_data)
                     }
                     Location::PlaneAsDay => {
-                        modes::run_plane_as_day(&mut window, &mut save_data)
+                        modes::run_plane_as_day(window, save_data)
                     }
                     Location::PointOfNoReturn => {
-                        modes::run_point_of_no_return(&mut window,
-                                                      &mut save_data)
+                        modes::run_point_of_no_return(window, save_data)
                     }
                     Location::PointOfOrder => {
-                        modes::run_point_of_order(&mut window, &mut save_data)
+                        modes::run_point_of_order(window, save_data)
                     }
                     Location::PointOfView => {
-                        modes::run_point_of_view(&mut window, &mut save_data)
+                        modes::run_point_of_view(window, save_data)
                     }
                     Location::ShiftGears => {
-                        modes::run_shift_gears(&mut window, &mut save_data)
+                        modes::run_shift_gears(window, save_data)
                     }
                     Location::ShiftTheBlame => {
-                        modes::run_shift_the_blame(&mut window, &mut save_data)
+                        modes::run_shift_the_blame(window, save_data)
                     }
                     Location::ShiftingGround => {
-                        modes::run_shifting_ground(&mut window, &mut save_data)
+                        modes::run_shifting_ground(window, save_data)
                     }
                     Location::StarCrossed => {
-                        modes::run_star_crossed(&mut window, &mut save_data)
+                        modes::run_star_crossed(window, save_data)
                     }
                     Location::SystemFailure => {
-                        modes::run_system_failure(&mut window, &mut save_data)
+                        modes::run_system_failure(window, save_data)
                     }
                     Location::SystemSyzygy => {
-                        modes::run_system_syzygy(&mut window, &mut save_data)
+                        modes::run_system_syzygy(window, save_data)
                     }
                     Location::TheIceIsRight => {
-                        modes::run_the_ice_is_right(&mut window,
-                                                    &mut save_data)
+                        modes::run_the_ice_is_right(window, save_data)
                     }
                     Location::TheYFactor => {
-                        modes::run_the_y_factor(&mut window, &mut save_data)
+                        modes::run_the_y_factor(window, save_data)
                     }
                     Location::ThreeBlindIce => {
-                        modes::run_three_blind_ice(&mut window, &mut save_data)
+                        modes::run_three_blind_ice(window, save_data)
                     }
                     Location::TreadLightly => {
-                        modes::run_tread_lightly(&mut window, &mut save_data)
+                        modes::run_tread_lightly(window, save_data)
                     }
                     Location::WhatchaColumn => {
-                        modes::run_whatcha_column(&mut window, &mut save_data)
+                        modes::run_whatcha_column(window, save_data)
                     }
                     Location::WreckedAngle => {
-                        modes::run_wrecked_angle(&mut window, &mut save_data)
-                    }
-                    Location::Finale => {
-                        modes::run_finale(&mut window, &mut save_data)
+                        modes::run_wrecked_angle(window, save_data)
                     }
+                    Location::Finale => modes::run_finale(window, save_data),
                 }
             }
+            Mode::IceEditor(path) => modes::run_ice_editor(window, &path),
+            Mode::BonusPuzzle(path) => {
+                modes::run_bonus_ice_puzzle(window, &path)
+            }
             Mode::Quit => break,
         };
     }
-    if let Err(error) = save_data.save_to_disk() {
-        println!("Failed to save game: {}", error);
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::{Read, Write};
+    use std::process;
+    use toml;
+
+    use gui::{EventReplay, Window};
+    use modes::Mode;
+    use save::{self, SaveData};
+    use super::run_game;
+
+    const SAVE: &str = include_str!("../tests/replay/cube_tangle_save.toml");
+    const EVENTS: &str = include_str!("../tests/replay/cube_tangle.events");
+    const GAME: &str = include_str!("../tests/replay/cube_tangle_game.toml");
+
+    #[test]
+    fn replay_reaches_recorded_game_state() {
+        let dir = env::temp_dir()
+            .join(format!("syzygy-test-{}-replay", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("save_data.toml");
+        fs::File::create(&path)
+            .unwrap()
+            .write_all(SAVE.as_bytes())
+            .unwrap();
+
+        let replay = EventReplay::read(EVENTS.as_bytes()).unwrap();
+        save::seed_game_rng(replay.seed());
+        save::pin_today(replay.day());
+        let mut save_data = SaveData::load_or_create(path.clone()).unwrap();
+        save_data.set_save_path(None);
+        let mut window = Window::offscreen((576, 384), (480, 320));
+        window.replay_events(replay);
+        run_game(&mut window, &mut save_data, Mode::Title);
+
+        assert_eq!(save_data.game().unwrap().to_toml(),
+                   GAME.parse::<toml::Value>().unwrap());
+        let mut string = String::new();
+        fs::File::open(&path)
+            .unwrap()
+            .read_to_string(&mut string)
+            .unwrap();
+        assert_eq!(string, SAVE);
+        fs::remove_dir_all(&dir).unwrap();
     }
 }
 
@@ -17,240 +17,283 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use elements::{Ast, Scene, TalkPos, TalkStyle};
-use gui::{Resources, Sound};
+use elements::{Ast, Scene};
+use gui::Resources;
 
 // ========================================================================= //
 
 const ARGONY: i32 = 2;
 const MEZURE: i32 = 1;
-const SYSTEM: i32 = 0;
 
 // ========================================================================= //
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
 pub fn compile_intro_scene(resources: &mut Resources) -> Scene {
-    let ast = vec![
-        Ast::Seq(vec![
-            Ast::SetBg("a_light_in_the_attic"),
-            Ast::Place(SYSTEM, "chars/system", 0, (496, 80)),
-            Ast::Dark(true),
-            Ast::Wait(0.5),
-            Ast::Place(MEZURE, "chars/mezure", 0, (-16, 320)),
-            Ast::Light(MEZURE, true),
-            Ast::Slide(MEZURE, (88, 320), true, true, 1.0),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "Whoa.  It sure is\n\
-                       dark up here.")
-        ]),
-        Ast::Seq(vec![
-            Ast::Slide(MEZURE, (104, 320), true, true, 0.5),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE, "Hmm.")
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE, "STIK, huh?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "What is this\n\
-                       place even for?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "Maybe I can just\n\
-                       pass through...")
-        ]),
-        Ast::Seq(vec![
-            Ast::Slide(MEZURE, (176, 320), true, false, 0.5),
-            Ast::Par(vec![
-                Ast::Sound(Sound::character_collision()),
-                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE, "Ow!"),
-                Ast::Jump(MEZURE, (160, 320), 0.5),
-            ]),
-        ]),
-        Ast::Seq(vec![
-            Ast::Place(ARGONY, "chars/argony", 0, (-16, 112)),
-            Ast::Light(ARGONY, true),
-            Ast::Slide(ARGONY, (170, 112), false, true, 1.0),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
-                      "Stumbling about in\n\
-                       the dark, child?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_lo()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "In more ways than one."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "I'm supposed to be helping to\n\
-                       repair the system, but I barely\n\
-                       even know what I'm doing."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE, "I see."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
-                      "Perhaps I might offer\n\
-                       a suggestion, child?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "Oh!  Um, yes, please."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
-                      "Perhaps, instead of blundering\n\
-                       around into walls like an idiot, you\n\
-                       should turn the lights on first."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_lo()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE, "Oh."),
-        ]),
-    ];
-    Ast::compile_scene(resources, ast)
+    Ast::load_scene(resources, "attic/intro")
 }
 
 // ========================================================================= //
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
 pub fn compile_argony_midscene(resources: &mut Resources) -> (i32, Scene) {
-    let ast = vec![
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
-                      "You should probably\n\
-                       start with the top row.")
-        ]),
-    ];
-    (ARGONY, Ast::compile_scene(resources, ast))
+    (ARGONY, Ast::load_scene(resources, "attic/argony"))
 }
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
 pub fn compile_mezure_midscene(resources: &mut Resources) -> (i32, Scene) {
-    let ast = vec![
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_thought()),
-            Ast::Talk(MEZURE, TalkStyle::Thought, TalkPos::NE,
-                      "Why couldn't there just be\n\
-                       a light switch or something?")
-        ]),
-    ];
-    (MEZURE, Ast::compile_scene(resources, ast))
+    (MEZURE, Ast::load_scene(resources, "attic/mezure"))
 }
 
 // ========================================================================= //
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
 pub fn compile_outro_scene(resources: &mut Resources) -> Scene {
-    let ast = vec![
-        Ast::Seq(vec![
-            Ast::Sound(Sound::solve_puzzle_chime()),
-            Ast::Wait(0.25),
-            Ast::Queue(0, 1),
-            Ast::Wait(0.1),
-            Ast::Queue(3, 1),
-            Ast::Wait(0.1),
-            Ast::Queue(4, 1),
-            Ast::Wait(0.1),
-            Ast::Queue(9, 1),
-            Ast::Wait(0.1),
-            Ast::Queue(10, 1),
-            Ast::Wait(0.1),
-            Ast::Queue(13, 1),
-            Ast::Wait(0.1),
-            Ast::Queue(15, 1),
-            Ast::Wait(0.1),
-            Ast::Dark(false),
-            Ast::Wait(0.5),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "Hey, I can see!"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_lo()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
-                      "And, once again, age and wisdom and\n\
-                       patience triumph over...whatever it\n\
-                       is that you kids are into these days."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "Thank you, I appreciate\n\
-                       the help, Ms-"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "...er, I don't think I\n\
-                       caught your name, sorry."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
-                      "That's because you didn't\n\
-                       think to ask before."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
-                      "The name's Argony, child."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "I'm Mezure.  Pleased\n\
-                       to meet you."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
-                      "Likewise.  Now get back\n\
-                       to work, whippersnapper.\n\
-                       The system is a wreck."),
-        ]),
-        Ast::Par(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
-                      "Yes, ma'am!"),
+    Ast::load_scene(resources, "attic/outro")
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use elements::{Ast, TalkPos, TalkStyle};
+    use gui::Sound;
+    use super::{ARGONY, MEZURE};
+
+    const SYSTEM: i32 = 0;
+
+    #[test]
+    fn intro_script_matches_original() {
+        Ast::assert_script_matches("attic/intro", original_intro());
+    }
+
+    #[test]
+    fn argony_script_matches_original() {
+        Ast::assert_script_matches("attic/argony", original_argony());
+    }
+
+    #[test]
+    fn mezure_script_matches_original() {
+        Ast::assert_script_matches("attic/mezure", original_mezure());
+    }
+
+    #[test]
+    fn outro_script_matches_original() {
+        Ast::assert_script_matches("attic/outro", original_outro());
+    }
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn original_intro() -> Vec<Ast> {
+        vec![
+            Ast::Seq(vec![
+                Ast::SetBg("a_light_in_the_attic"),
+                Ast::Place(SYSTEM, "chars/system", 0, (496, 80)),
+                Ast::Dark(true),
+                Ast::Wait(0.5),
+                Ast::Place(MEZURE, "chars/mezure", 0, (-16, 320)),
+                Ast::Light(MEZURE, true),
+                Ast::Slide(MEZURE, (88, 320), true, true, 1.0),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "Whoa.  It sure is\n\
+                           dark up here.")
+            ]),
+            Ast::Seq(vec![
+                Ast::Slide(MEZURE, (104, 320), true, true, 0.5),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE, "Hmm.")
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "STIK, huh?"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "What is this\n\
+                           place even for?"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "Maybe I can just\n\
+                           pass through...")
+            ]),
+            Ast::Seq(vec![
+                Ast::Slide(MEZURE, (176, 320), true, false, 0.5),
+                Ast::Par(vec![
+                    Ast::Sound(Sound::character_collision()),
+                    Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE, "Ow!"),
+                    Ast::Jump(MEZURE, (160, 320), 0.5),
+                ]),
+            ]),
+            Ast::Seq(vec![
+                Ast::Place(ARGONY, "chars/argony", 0, (-16, 112)),
+                Ast::Light(ARGONY, true),
+                Ast::Slide(ARGONY, (170, 112), false, true, 1.0),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
+                          "Stumbling about in\n\
+                           the dark, child?"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_lo()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "In more ways than one."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "I'm supposed to be helping to\n\
+                           repair the system, but I barely\n\
+                           even know what I'm doing."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE, "I see."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
+                          "Perhaps I might offer\n\
+                           a suggestion, child?"),
+            ]),
             Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "Oh!  Um, yes, please."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
+                          "Perhaps, instead of blundering\n\
+                           around into walls like an idiot, you\n\
+                           should turn the lights on first."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_lo()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE, "Oh."),
+            ]),
+        ]
+    }
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn original_argony() -> Vec<Ast> {
+        vec![
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
+                          "You should probably\n\
+                           start with the top row.")
+            ]),
+        ]
+    }
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn original_mezure() -> Vec<Ast> {
+        vec![
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_thought()),
+                Ast::Talk(MEZURE, TalkStyle::Thought, TalkPos::NE,
+                          "Why couldn't there just be\n\
+                           a light switch or something?")
+            ]),
+        ]
+    }
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn original_outro() -> Vec<Ast> {
+        vec![
+            Ast::Seq(vec![
+                Ast::Sound(Sound::solve_puzzle_chime()),
                 Ast::Wait(0.25),
-                Ast::Sound(Sound::small_jump()),
-                Ast::Jump(MEZURE, (208, 304), 0.5),
-                Ast::Slide(MEZURE, (256, 304), false, false, 0.25),
-                Ast::Sound(Sound::small_jump()),
-                Ast::Jump(MEZURE, (304, 288), 0.4),
-                Ast::Slide(MEZURE, (592, 288), false, false, 1.0),
+                Ast::Queue(0, 1),
+                Ast::Wait(0.1),
+                Ast::Queue(3, 1),
+                Ast::Wait(0.1),
+                Ast::Queue(4, 1),
+                Ast::Wait(0.1),
+                Ast::Queue(9, 1),
+                Ast::Wait(0.1),
+                Ast::Queue(10, 1),
+                Ast::Wait(0.1),
+                Ast::Queue(13, 1),
+                Ast::Wait(0.1),
+                Ast::Queue(15, 1),
+                Ast::Wait(0.1),
+                Ast::Dark(false),
+                Ast::Wait(0.5),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "Hey, I can see!"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_lo()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
+                          "And, once again, age and wisdom and\n\
+                           patience triumph over...whatever it\n\
+                           is that you kids are into these days."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "Thank you, I appreciate\n\
+                           the help, Ms-"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "...er, I don't think I\n\
+                           caught your name, sorry."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
+                          "That's because you didn't\n\
+                           think to ask before."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
+                          "The name's Argony, child."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "I'm Mezure.  Pleased\n\
+                           to meet you."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(ARGONY, TalkStyle::Normal, TalkPos::SE,
+                          "Likewise.  Now get back\n\
+                           to work, whippersnapper.\n\
+                           The system is a wreck."),
+            ]),
+            Ast::Par(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(MEZURE, TalkStyle::Normal, TalkPos::NE,
+                          "Yes, ma'am!"),
+                Ast::Seq(vec![
+                    Ast::Wait(0.25),
+                    Ast::Sound(Sound::small_jump()),
+                    Ast::Jump(MEZURE, (208, 304), 0.5),
+                    Ast::Slide(MEZURE, (256, 304), false, false, 0.25),
+                    Ast::Sound(Sound::small_jump()),
+                    Ast::Jump(MEZURE, (304, 288), 0.4),
+                    Ast::Slide(MEZURE, (592, 288), false, false, 1.0),
+                ]),
+            ]),
+            Ast::Seq(vec![
+                Ast::Remove(MEZURE),
+                Ast::Sound(Sound::talk_thought()),
+                Ast::Talk(ARGONY, TalkStyle::Thought, TalkPos::SE,
+                          "I wonder where\n\
+                           Relyng's gone off to?"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Slide(ARGONY, (-16, 112), true, false, 1.0),
+                Ast::Remove(ARGONY),
+                Ast::Wait(0.5),
             ]),
-        ]),
-        Ast::Seq(vec![
-            Ast::Remove(MEZURE),
-            Ast::Sound(Sound::talk_thought()),
-            Ast::Talk(ARGONY, TalkStyle::Thought, TalkPos::SE,
-                      "I wonder where\n\
-                       Relyng's gone off to?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Slide(ARGONY, (-16, 112), true, false, 1.0),
-            Ast::Remove(ARGONY),
-            Ast::Wait(0.5),
-        ]),
-    ];
-    Ast::compile_scene(resources, ast)
+        ]
+    }
 }
 
 // ========================================================================= //
@@ -20,8 +20,8 @@
 use std::cmp;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Canvas, Element, Event, Point, Rect, Resources, Sound,
-          Sprite};
+use gui::{Action, Canvas, Element, Event, Message, Point, Rect, Resources,
+          Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{AtticState, Game, PuzzleState};
 use super::scenes;
@@ -86,7 +86,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (i32, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.a_light_in_the_attic.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -377,7 +385,9 @@ fn tick_radius<A>(lit: bool, radius: &mut i32, max: i32) -> Action<A> {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.a_light_in_the_attic",
+    english: "\
 Your goal is to turn all thirty-two lights ON.
 
 $M{Tapp}{Click}ing on one of the lights labelled with a
@@ -387,6 +397,7 @@ The letter labels give a hint as to which other
 lights will be toggled by $M{tapp}{click}ing on that light.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
+words of wisdom.",
+};
 
 // ========================================================================= //
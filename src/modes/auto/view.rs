@@ -19,7 +19,7 @@
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::factor::{LettersView, TransformButton};
-use gui::{Action, Canvas, Element, Event, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, Message, Rect, Resources, Sound};
 use modes::SOLVED_INFO_TEXT;
 use save::{AutoState, Game, PuzzleState};
 use super::scenes;
@@ -125,7 +125,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = Vec<i8>;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.autofac_tour.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -177,13 +185,16 @@ impl PuzzleView for View {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.autofac_tour",
+    english: "\
 Your goal is to transform the starting word into a new word.
 There is only one possible new word that can be formed.
 
 $M{Tap}{Click} on one of the five buttons at the top to transform the word.
 Each button performs a different transformation.
 
-$M{Tap}{Click} on a character in the scene to hear their words of wisdom.";
+$M{Tap}{Click} on a character in the scene to hear their words of wisdom.",
+};
 
 // ========================================================================= //
@@ -20,13 +20,15 @@
 use std::cmp::min;
 use std::collections::HashMap;
 use std::rc::Rc;
+use toml;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{BlackState, Game, PuzzleState};
 use save::tree::{BasicTree, TreeOp};
+use save::util::{Tomlable, to_table};
 use super::scenes;
 
 // ========================================================================= //
@@ -37,10 +39,50 @@ enum TreeCmd {
     Remove(i32),
 }
 
+/// An undo entry: the command that was applied, along with the tree's
+/// signature from just before it was applied.
+#[derive(Clone)]
+pub struct TreeChange {
+    cmd: TreeCmd,
+    signature: [i8; 15],
+}
+
+impl Tomlable for TreeChange {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        let (kind, key) = match self.cmd {
+            TreeCmd::Insert(key) => ("insert", key),
+            TreeCmd::Remove(key) => ("remove", key),
+        };
+        table.insert(kind.to_string(), key.to_toml());
+        table.insert("signature".to_string(),
+                     self.signature.to_vec().to_toml());
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> TreeChange {
+        let mut table = to_table(value);
+        let cmd = if let Some(key) = table.remove("remove") {
+            TreeCmd::Remove(i32::from_toml(key))
+        } else {
+            TreeCmd::Insert(i32::pop_from_table(&mut table, "insert"))
+        };
+        let mut signature = [-128; 15];
+        let values = Vec::<i8>::pop_from_table(&mut table, "signature");
+        for (index, value) in values.into_iter().take(15).enumerate() {
+            signature[index] = value;
+        }
+        TreeChange {
+            cmd: cmd,
+            signature: signature,
+        }
+    }
+}
+
 // ========================================================================= //
 
 pub struct View {
-    core: PuzzleCore<(TreeCmd, [i8; 15])>,
+    core: PuzzleCore<TreeChange>,
     tree: TreeView,
     tree_visible: bool,
 }
@@ -105,7 +147,10 @@ impl Element<Game, PuzzleCmd> for View {
                         self.core.begin_outro_scene();
                         action = action.and_return(PuzzleCmd::Save);
                     } else {
-                        self.core.push_undo((cmd, signature));
+                        self.core.push_undo(TreeChange {
+                            cmd: cmd,
+                            signature: signature,
+                        });
                     }
                 }
             }
@@ -119,7 +164,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = TreeChange;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.black_and_blue.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -128,17 +181,17 @@ impl PuzzleView for View {
     }
 
     fn undo(&mut self, game: &mut Game) {
-        if let Some((_, signature)) = self.core.pop_undo() {
+        if let Some(change) = self.core.pop_undo() {
             let state = &mut game.black_and_blue;
-            state.set_from_signature(&signature);
+            state.set_from_signature(&change.signature);
             self.tree.update_fruit_positions(state);
         }
     }
 
     fn redo(&mut self, game: &mut Game) {
-        if let Some((cmd, _)) = self.core.pop_redo() {
+        if let Some(change) = self.core.pop_redo() {
             let state = &mut game.black_and_blue;
-            match cmd {
+            match change.cmd {
                 TreeCmd::Insert(key) => {
                     state.insert(key);
                 }
@@ -461,7 +514,9 @@ const LETTERS: [(char, bool); 15] = [
     ('S', false),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.black_and_blue",
+    english: "\
 Your goal is to get fruit #10 on the tree high enough
 to reach the girder.
 
@@ -477,6 +532,7 @@ rebalance/recolor itself, following two rules:
      two red fruits in a row.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
 // ========================================================================= //
@@ -17,15 +17,14 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use std::cmp::{max, min};
-
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView, Scene};
 use elements::cutscene::{JumpNode, ParallelNode, QueueNode, SceneNode,
                          SequenceNode, SlideNode, SoundNode, WaitNode};
 use elements::shift::{ArrowPair, Platform};
-use gui::{Action, Canvas, Element, Event, Point, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, Message, Point, Rect, Resources,
+          Sound};
 use modes::SOLVED_INFO_TEXT;
-use save::{BlameState, Game, PuzzleState};
+use save::{BlameState, CharacterMove, Game, PuzzleState};
 use super::scenes;
 
 // ========================================================================= //
@@ -50,6 +49,7 @@ impl View {
         };
         core.add_extra_scene(scenes::compile_mezure_midscene(resources));
         core.add_extra_scene(scenes::compile_yttris_midscene(resources));
+        let amount = BlameState::shift_amount;
         let mut view = View {
             core: core,
             animation: Scene::empty(),
@@ -64,12 +64,12 @@ impl View {
                 Platform::new(resources, (112, 280), state.get_position(6)),
             ],
             arrows: vec![
-                ArrowPair::new(resources, (64, 112), 0, 3),
-                ArrowPair::new(resources, (64, 144), 1, 5),
-                ArrowPair::new(resources, (64, 176), 2, 3),
-                ArrowPair::new(resources, (64, 208), 3, 7),
-                ArrowPair::new(resources, (64, 240), 4, 5),
-                ArrowPair::new(resources, (64, 272), 5, 3),
+                ArrowPair::new(resources, (64, 112), 0, amount(0)),
+                ArrowPair::new(resources, (64, 144), 1, amount(1)),
+                ArrowPair::new(resources, (64, 176), 2, amount(2)),
+                ArrowPair::new(resources, (64, 208), 3, amount(3)),
+                ArrowPair::new(resources, (64, 240), 4, amount(4)),
+                ArrowPair::new(resources, (64, 272), 5, amount(5)),
             ],
         };
         view.animation.begin(view.core.theater_mut());
@@ -114,130 +114,81 @@ impl View {
         }
     }
 
+    fn landing_pt(&self, row: i32, pos: i32) -> Point {
+        if row < BlameState::num_rows() {
+            self.platform_pt_for_pos(row, pos)
+        } else {
+            self.floor_pt_for_pos(pos)
+        }
+    }
+
     fn shift_platform(&mut self, state: &mut BlameState, row_1: i32,
                       delta: i32) {
         let num_rows = BlameState::num_rows() as i32;
         let last_row = num_rows - 1;
         let max_pos_for_last_row = BlameState::max_position_for_row(last_row);
-        debug_assert!(row_1 >= 0 && row_1 < num_rows - 1);
         let row_2 = row_1 + 1;
-        let max_pos_1 = BlameState::max_position_for_row(row_1);
-        let max_pos_2 = BlameState::max_position_for_row(row_2);
         let original_pos_1 = state.get_position(row_1);
         let original_pos_2 = state.get_position(row_2);
         let original_mezure_row = state.get_mezure_row();
+        let shift = state.shift_platforms(row_1, delta);
         let mut top_seq: Vec<Box<SceneNode>> = Vec::new();
 
         // Move platforms:
-        let mut mezure_row = state.get_mezure_row();
-        let mut mezure_pos = 0;
-        if mezure_row >= 0 && mezure_row < num_rows {
-            mezure_pos = state.get_position(mezure_row);
-        }
-        let mut delta_1 = delta;
-        let mut delta_2 = delta;
-        let mut pos_1 = state.get_position(row_1);
-        let mut pos_2 = state.get_position(row_2);
-        while delta_1 != 0 || delta_2 != 0 {
+        for leg in shift.legs.iter() {
             let mut platform_1_seq: Vec<Box<SceneNode>> = Vec::new();
             let mut platform_2_seq: Vec<Box<SceneNode>> = Vec::new();
             let mut mezure_seq: Vec<Box<SceneNode>> = Vec::new();
-            if delta_1 < 0 && pos_1 == 0 || delta_1 > 0 && pos_1 == max_pos_1 {
-                delta_1 = -delta_1;
-            }
-            if delta_2 < 0 && pos_2 == 0 || delta_2 > 0 && pos_2 == max_pos_2 {
-                delta_2 = -delta_2;
-            }
-            debug_assert_eq!(delta_1.abs(), delta_2.abs());
-            let mut step = delta_1.abs();
-            if pos_1 + delta_1 < 0 {
-                step = min(step, pos_1);
-            }
-            if pos_2 + delta_2 < 0 {
-                step = min(step, pos_2);
-            }
-            if pos_1 + delta_1 > max_pos_1 {
-                step = min(step, max_pos_1 - pos_1);
-            }
-            if pos_2 + delta_2 > max_pos_2 {
-                step = min(step, max_pos_2 - pos_2);
-            }
-            debug_assert!(step > 0);
-            let old_pos_1 = pos_1;
-            let old_pos_2 = pos_2;
-            if delta_1 < 0 {
-                pos_1 -= step;
-                delta_1 += step;
-            } else {
-                pos_1 += step;
-                delta_1 -= step;
-            }
-            if delta_2 < 0 {
-                pos_2 -= step;
-                delta_2 += step;
-            } else {
-                pos_2 += step;
-                delta_2 -= step;
-            }
-            let sound = Sound::platform_shift(step);
+            let (_, old_pos_1, pos_1) = leg.platforms[0];
+            let (_, old_pos_2, pos_2) = leg.platforms[1];
+            let sound = Sound::platform_shift((pos_1 - old_pos_1).abs());
             let travel_time = Platform::travel_time(old_pos_1, pos_1);
             platform_1_seq.push(Box::new(SoundNode::new(sound)));
             platform_1_seq.push(Box::new(QueueNode::new((row_1, pos_1))));
             platform_1_seq.push(Box::new(WaitNode::new(travel_time)));
             platform_2_seq.push(Box::new(QueueNode::new((row_2, pos_2))));
             platform_2_seq.push(Box::new(WaitNode::new(travel_time)));
-            let max_pos_for_row_5 = BlameState::max_position_for_row(5);
-            state.set_position(row_1, pos_1);
-            state.set_position(row_2, pos_2);
-
-            // Move Mezure and/or knock them downwards.
-            if mezure_row == row_1 || mezure_row == row_2 {
-                let impact = if mezure_row == row_1 {
-                    if row_1 == 5 && pos_1 == max_pos_for_row_5 {
-                        Some(max_pos_for_row_5 - 1)
-                    } else if row_1 > 0 {
-                        let pos_0 = state.get_position(row_1 - 1);
-                        if pos_1 > old_pos_1 && pos_0 > old_pos_1 &&
-                            pos_0 <= pos_1
-                        {
-                            Some(pos_0 - 1)
-                        } else if pos_1 < old_pos_1 && pos_0 < old_pos_1 &&
-                                   pos_0 >= pos_1
-                        {
-                            Some(pos_0 + 1)
-                        } else {
-                            None
-                        }
-                    } else if pos_1 == 0 {
-                        Some(1)
-                    } else {
-                        None
-                    }
-                } else {
-                    debug_assert_eq!(mezure_row, row_2);
-                    if old_pos_1 < old_pos_2 && pos_1 >= pos_2 {
-                        Some(old_pos_1 + (old_pos_2 - old_pos_1) / 2 + 1)
-                    } else if old_pos_1 > old_pos_2 && pos_1 <= pos_2 {
-                        Some(old_pos_2 + (old_pos_1 - old_pos_2 + 1) / 2 - 1)
-                    } else if row_2 == 5 && pos_2 == max_pos_for_row_5 {
-                        Some(max_pos_for_row_5 - 1)
-                    } else {
-                        None
-                    }
-                };
-                let (row, pos) = if mezure_row == row_1 {
-                    (row_1, pos_1)
-                } else {
-                    (row_2, pos_2)
-                };
-                if let Some(new_mezure_pos) = impact {
-                    let mut time_to_hit =
-                        Platform::travel_time(mezure_pos, new_mezure_pos);
-                    mezure_pos = new_mezure_pos;
-                    mezure_row = state.fall_from(mezure_row, mezure_pos);
-                    let mut slide_dest =
-                        self.platform_pt_for_pos(row, mezure_pos);
-                    if row == row_2 && (old_pos_1 - old_pos_2).abs() % 2 == 0 {
+            match leg.character {
+                CharacterMove::Still => {}
+                CharacterMove::Ride { platform, pos } => {
+                    let dest = self.platform_pt_for_pos(platform, pos);
+                    mezure_seq.push(Box::new(SlideNode::new(scenes::MEZURE,
+                                                            dest,
+                                                            false,
+                                                            false,
+                                                            travel_time)));
+                }
+                CharacterMove::Knocked {
+                    platform,
+                    hit_pos,
+                    pos,
+                    row,
+                } => {
+                    let time_to_hit = Platform::travel_time(old_pos_2,
+                                                            hit_pos);
+                    let dest = self.landing_pt(row, pos);
+                    let fall_dist = dest.y() - self.platform_top(platform + 1);
+                    let time_to_fall = JumpNode::time_to_fall(fall_dist + 5) +
+                        JumpNode::time_to_fall(5);
+                    mezure_seq.push(Box::new(WaitNode::new(time_to_hit)));
+                    let sound = Sound::character_collision();
+                    mezure_seq.push(Box::new(SoundNode::new(sound)));
+                    mezure_seq.push(Box::new(JumpNode::new(scenes::MEZURE,
+                                                           dest,
+                                                           time_to_fall)));
+                }
+                CharacterMove::Pushed {
+                    platform,
+                    from_pos,
+                    pos,
+                    row,
+                } => {
+                    let mut time_to_hit = Platform::travel_time(from_pos, pos);
+                    let mut slide_dest = self.platform_pt_for_pos(platform,
+                                                                  pos);
+                    if platform == row_2 &&
+                        (old_pos_1 - old_pos_2).abs() % 2 == 0
+                    {
                         slide_dest = slide_dest +
                             if old_pos_1 < old_pos_2 {
                                 Point::new(-16, 0)
@@ -246,12 +197,9 @@ impl View {
                             };
                         time_to_hit += 0.5 * Platform::travel_time(0, 1);
                     }
-                    let jump_dest = if mezure_row < num_rows {
-                        self.platform_pt_for_pos(mezure_row, mezure_pos)
-                    } else {
-                        self.floor_pt_for_pos(mezure_pos)
-                    };
-                    let fall_dist = jump_dest.y() - self.platform_top(row);
+                    let jump_dest = self.landing_pt(row, pos);
+                    let fall_dist =
+                        jump_dest.y() - self.platform_top(platform);
                     let time_to_fall = JumpNode::time_to_fall(fall_dist + 5) +
                         JumpNode::time_to_fall(5);
                     mezure_seq.push(Box::new(SlideNode::new(scenes::MEZURE,
@@ -264,47 +212,6 @@ impl View {
                     mezure_seq.push(Box::new(JumpNode::new(scenes::MEZURE,
                                                            jump_dest,
                                                            time_to_fall)));
-                } else {
-                    mezure_pos = pos;
-                    let dest = self.platform_pt_for_pos(row, mezure_pos);
-                    mezure_seq.push(Box::new(SlideNode::new(scenes::MEZURE,
-                                                            dest,
-                                                            false,
-                                                            false,
-                                                            travel_time)));
-                }
-            } else if mezure_row < num_rows && mezure_row == row_2 + 1 {
-                let impact = if pos_2 > old_pos_2 && mezure_pos > old_pos_2 &&
-                    mezure_pos <= pos_2
-                {
-                    Some((Platform::travel_time(old_pos_2, mezure_pos - 1),
-                          min(BlameState::max_position_for_row(mezure_row),
-                              mezure_pos + 1)))
-                } else if pos_2 < old_pos_2 && mezure_pos >= pos_2 &&
-                           mezure_pos < old_pos_2
-                {
-                    Some((Platform::travel_time(old_pos_2, mezure_pos + 1),
-                          max(0, mezure_pos - 1)))
-                } else {
-                    None
-                };
-                if let Some((time_to_hit, new_mezure_pos)) = impact {
-                    mezure_pos = new_mezure_pos;
-                    mezure_row = state.fall_from(mezure_row, mezure_pos);
-                    let dest = if mezure_row < num_rows {
-                        self.platform_pt_for_pos(mezure_row, mezure_pos)
-                    } else {
-                        self.floor_pt_for_pos(mezure_pos)
-                    };
-                    let fall_dist = dest.y() - self.platform_top(row_2 + 1);
-                    let time_to_fall = JumpNode::time_to_fall(fall_dist + 5) +
-                        JumpNode::time_to_fall(5);
-                    mezure_seq.push(Box::new(WaitNode::new(time_to_hit)));
-                    let sound = Sound::character_collision();
-                    mezure_seq.push(Box::new(SoundNode::new(sound)));
-                    mezure_seq.push(Box::new(JumpNode::new(scenes::MEZURE,
-                                                           dest,
-                                                           time_to_fall)));
                 }
             }
             top_seq.push(Box::new(ParallelNode::new(vec![
@@ -315,9 +222,9 @@ impl View {
         }
 
         // If Mezure fell to the floor, get back on the starting platform:
-        if mezure_row == num_rows && original_mezure_row != num_rows {
+        if let Some(floor_pos) = shift.fell_to_floor {
             let time = 0.5 *
-                Platform::travel_time(mezure_pos, max_pos_for_last_row);
+                Platform::travel_time(floor_pos, max_pos_for_last_row);
             let dest = Point::new(408, 320);
             let slide =
                 SlideNode::new(scenes::MEZURE, dest, false, false, time);
@@ -326,62 +233,22 @@ impl View {
             let slide =
                 SlideNode::new(scenes::MEZURE, dest, false, false, 0.1);
             top_seq.push(Box::new(slide));
-            let dest = self.platform_pt(state, mezure_row);
+            let dest = self.platform_pt(state, num_rows);
             let slide =
                 SlideNode::new(scenes::MEZURE, dest, false, false, 0.1);
             top_seq.push(Box::new(slide));
         }
 
         // Make Mezure climb upwards:
-        if mezure_row == num_rows &&
-            state.get_position(last_row) == max_pos_for_last_row &&
-            state.get_position(last_row - 1) != max_pos_for_last_row
-        {
-            mezure_row -= 1;
+        for &mezure_row in shift.climbs.iter() {
             let dest = self.platform_pt(state, mezure_row);
             top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
             top_seq.push(Box::new(JumpNode::new(scenes::MEZURE, dest, 0.6)));
         }
-        if mezure_row < num_rows {
-            while mezure_row > 0 {
-                let pos_0 = state.get_position(mezure_row);
-                let pos_1 = state.get_position(mezure_row - 1);
-                if (pos_0 - pos_1).abs() != 1 {
-                    break;
-                }
-                if mezure_row >= 2 {
-                    let pos_2 = state.get_position(mezure_row - 2);
-                    if pos_2 == pos_1 || pos_2 == pos_0 {
-                        break;
-                    }
-                }
-                if mezure_row == last_row &&
-                    (pos_0 == max_pos_for_last_row ||
-                         pos_1 == max_pos_for_last_row) ||
-                    mezure_row == 1 && (pos_0 == 0 || pos_1 == 0)
-                {
-                    break;
-                }
-                mezure_row -= 1;
-                let dest = self.platform_pt(state, mezure_row);
-                top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
-                top_seq
-                    .push(Box::new(JumpNode::new(scenes::MEZURE, dest, 0.6)));
-            }
-        }
-        if mezure_row == 0 &&
-            state.get_position(0) == BlameState::max_position_for_row(0)
-        {
-            mezure_row -= 1;
-            let dest = self.platform_pt(state, mezure_row);
-            top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
-            top_seq.push(Box::new(JumpNode::new(scenes::MEZURE, dest, 0.6)));
-        }
-        state.set_mezure_row(mezure_row);
         self.core.push_undo((row_1,
-                             pos_1 - original_pos_1,
-                             pos_2 - original_pos_2,
-                             mezure_row - original_mezure_row));
+                             state.get_position(row_1) - original_pos_1,
+                             state.get_position(row_2) - original_pos_2,
+                             state.get_mezure_row() - original_mezure_row));
 
         // Start animation:
         self.animation =
@@ -441,7 +308,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (i32, i32, i32, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.shift_the_blame.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -514,7 +389,9 @@ impl PuzzleView for View {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.shift_the_blame",
+    english: "\
 Your goal is to help Mezure reach the ledge in the
 upper-right corner.
 
@@ -525,6 +402,7 @@ Mezure will jump up onto the next platform when
 it is adjacent.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
+words of wisdom.",
+};
 
 // ========================================================================= //
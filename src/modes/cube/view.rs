@@ -21,8 +21,8 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
-          Resources, Sprite};
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Message,
+          Point, Rect, Resources, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{CubeState, Direction, Game, PuzzleState};
 use super::scenes;
@@ -87,10 +87,25 @@ impl Element<Game, PuzzleCmd> for View {
         }
         action
     }
+
+    fn focus_targets(&self, game: &Game) -> Vec<FocusTarget> {
+        let state = &game.cube_tangle;
+        let mut targets = self.core.focus_targets(state);
+        targets.extend(self.grid.focus_targets(state));
+        targets
+    }
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (Direction, i32, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.cube_tangle.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -128,6 +143,23 @@ impl PuzzleView for View {
         self.core.begin_outro_scene();
     }
 
+    fn hint(&mut self, game: &mut Game) -> bool {
+        let state = &mut game.cube_tangle;
+        self.grid.clear_drag(state);
+        if let Some((dir, rank)) = state.hint() {
+            state.record_hint();
+            state.rotate_cubes(dir, rank, 1);
+            if state.is_solved() {
+                self.core.begin_outro_scene();
+            } else {
+                self.core.push_undo((dir, rank, 1));
+            }
+            true
+        } else {
+            false
+        }
+    }
+
     fn drain_queue(&mut self) {
         for (command, value) in self.core.drain_queue() {
             if command == 0 {
@@ -367,6 +399,23 @@ impl Element<CubeState, (Direction, i32, i32)> for CubeGrid {
             _ => Action::ignore(),
         }
     }
+
+    fn focus_targets(&self, state: &CubeState) -> Vec<FocusTarget> {
+        if state.is_solved() {
+            return Vec::new();
+        }
+        let mut targets = Vec::new();
+        for row in 0..4 {
+            for col in 0..4 {
+                let rect = Rect::new(self.left + col * CUBE_SIZE,
+                                     self.top + row * CUBE_SIZE,
+                                     CUBE_USIZE,
+                                     CUBE_USIZE);
+                targets.push(FocusTarget::new(rect));
+            }
+        }
+        targets
+    }
 }
 
 // ========================================================================= //
@@ -438,7 +487,9 @@ const LETTERS: &[(i32, i32, char)] = &[
     (3, 3, 'R'),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.cube_tangle",
+    english: "\
 Your goal is to arrange the front faces of the cubes in
 the large grid in the middle into the pattern shown on
 the small grid on the left.
@@ -447,6 +498,62 @@ Drag a cube on the large grid up, down, left, or right
 to rotate that whole row or column.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use elements::PuzzleView;
+    use gui::{Element, Event, Offscreen};
+    use save::{Direction, Game, PuzzleState};
+    use save::util::Tomlable;
+    use super::View;
+
+    #[test]
+    fn renders_initial_view() {
+        let mut offscreen = Offscreen::new(480, 320);
+        let mut game = Game::new();
+        game.cube_tangle.visit();
+        let mut view = {
+            let visible = offscreen.visible_rect();
+            View::new(&mut offscreen.resources(), visible, &game.cube_tangle)
+        };
+        // Let the screen fade-in finish before rendering:
+        for _ in 0..100 {
+            view.handle_event(&Event::ClockTick, &mut game);
+        }
+        offscreen.render(&game, &view);
+        offscreen.assert_matches_golden("cube_tangle");
+    }
+
+    #[test]
+    fn failed_hint_keeps_progress() {
+        let mut offscreen = Offscreen::new(480, 320);
+        let mut game = Game::new();
+        game.cube_tangle.visit();
+        let mut view = {
+            let visible = offscreen.visible_rect();
+            View::new(&mut offscreen.resources(), visible, &game.cube_tangle)
+        };
+        for &(dir, rank) in &[(Direction::East, 0),
+                              (Direction::South, 1),
+                              (Direction::West, 2),
+                              (Direction::North, 3),
+                              (Direction::East, 1),
+                              (Direction::South, 2),
+                              (Direction::West, 3),
+                              (Direction::North, 0)]
+        {
+            game.cube_tangle.rotate_cubes(dir, rank, 1);
+        }
+        assert!(game.cube_tangle.hint().is_none());
+        let before = game.cube_tangle.to_toml();
+        assert!(!view.hint(&mut game));
+        assert_eq!(game.cube_tangle.to_toml(), before);
+        assert_eq!(game.cube_tangle.hints_used(), 0);
+    }
+}
 
 // ========================================================================= //
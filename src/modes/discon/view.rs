@@ -19,7 +19,8 @@
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::lasers::{DangerSign, LaserCmd, LaserField};
-use gui::{Action, Canvas, Element, Event, Rect, Resources};
+use gui::{Action, Canvas, Element, Event, FocusTarget, Message, Rect,
+          Resources};
 use modes::SOLVED_INFO_TEXT;
 use save::{DisconState, Game, PuzzleState};
 use super::scenes;
@@ -80,7 +81,7 @@ impl Element<Game, PuzzleCmd> for View {
             let subaction = self.laser_field
                 .handle_event(event, state.grid_mut());
             if let Some(&cmd) = subaction.value() {
-                if self.laser_field.all_detectors_satisfied(state.grid()) {
+                if self.laser_field.beams().all_detectors_satisfied() {
                     state.mark_solved();
                     self.core.begin_outro_scene();
                     action = action.and_return(PuzzleCmd::Save);
@@ -95,10 +96,27 @@ impl Element<Game, PuzzleCmd> for View {
         }
         action
     }
+
+    fn focus_targets(&self, game: &Game) -> Vec<FocusTarget> {
+        let state = &game.disconnected;
+        let mut targets = self.core.focus_targets(state);
+        if self.box_open && !state.is_solved() {
+            targets.extend(self.laser_field.focus_targets(state.grid()));
+        }
+        targets
+    }
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = LaserCmd;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.disconnected.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -181,7 +199,9 @@ const LETTERS: &[((i32, i32), char)] = &[
     ((8, 3), 'E'),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.disconnected",
+    english: "\
 Your goal is to activate each detector on the right with
 the appropriate color of laser.
 
@@ -189,6 +209,7 @@ Drag mirrors with $M{your finger}{the mouse} to move their positions in
 the grid.  $M{Tap}{Click} mirrors to rotate them.
 
 $M{Tap}{Click} on a character in the scene to hear their words of
-wisdom.";
+wisdom.",
+};
 
 // ========================================================================= //
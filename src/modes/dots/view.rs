@@ -19,7 +19,8 @@
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::lasers::{DangerSign, LaserCmd, LaserField};
-use gui::{Action, Canvas, Element, Event, Rect, Resources};
+use gui::{Action, Canvas, Element, Event, FocusTarget, Message, Rect,
+          Resources};
 use modes::SOLVED_INFO_TEXT;
 use save::{DotsState, Game, PuzzleState};
 use super::scenes;
@@ -78,7 +79,7 @@ impl Element<Game, PuzzleCmd> for View {
             let subaction = self.laser_field
                 .handle_event(event, state.grid_mut());
             if let Some(&cmd) = subaction.value() {
-                if self.laser_field.all_detectors_satisfied(state.grid()) {
+                if self.laser_field.beams().all_detectors_satisfied() {
                     state.mark_solved();
                     self.core.begin_outro_scene();
                     action = action.and_return(PuzzleCmd::Save);
@@ -93,10 +94,27 @@ impl Element<Game, PuzzleCmd> for View {
         }
         action
     }
+
+    fn focus_targets(&self, game: &Game) -> Vec<FocusTarget> {
+        let state = &game.connect_the_dots;
+        let mut targets = self.core.focus_targets(state);
+        if self.box_open && !state.is_solved() {
+            targets.extend(self.laser_field.focus_targets(state.grid()));
+        }
+        targets
+    }
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = LaserCmd;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.connect_the_dots.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -175,7 +193,9 @@ const LETTERS: &[((i32, i32), char)] = &[
     ((8, 3), 'L'),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.connect_the_dots",
+    english: "\
 Your goal is to activate each detector on the right with
 the appropriate color of laser.
 
@@ -184,6 +204,7 @@ move their positions in the grid.  $M{Tap}{Click} objects to rotate
 them.
 
 $M{Tap}{Click} on a character in the scene to hear their words of
-wisdom.";
+wisdom.",
+};
 
 // ========================================================================= //
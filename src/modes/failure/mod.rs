@@ -20,6 +20,7 @@
 mod coords;
 mod scenes;
 mod view;
+mod worker;
 
 use gui::Window;
 use modes::{Mode, run_puzzle};
@@ -19,17 +19,17 @@
 
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
-use std::thread;
-use std::time;
+use toml;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
-          Resources, Sound, Sprite};
-use save::{Access, FailureState, Game, Location, PuzzleState};
-use save::pyramid::{Board, Coords, MAX_REMOVALS, Move, Team};
+use gui::{Action, Align, Canvas, Element, Event, FRAME_DELAY_MILLIS, Font,
+          Message, Point, Rect, Resources, Sound, Sprite};
+use save::{Access, FailureState, Game, Location, PuzzleState, UndoHistory};
+use save::pyramid::{Board, Coords, Difficulty, MAX_REMOVALS, Move, Team};
+use save::util::{Tomlable, to_table};
 use super::coords::{coords_to_pt, pt_to_coords};
 use super::scenes;
+use super::worker::SrbWorker;
 
 // ========================================================================= //
 
@@ -76,13 +76,60 @@ const DASHBOARD_CHIPS: &[(i32, i32, char, Location)] = &[
 // ========================================================================= //
 
 #[derive(Clone)]
-enum UndoRedo {
+pub enum UndoRedo {
     Place(Coords),
     Jumping(Coords),
     Jump(Coords, Coords),
     Remove(Vec<Coords>, Vec<Coords>),
 }
 
+impl Tomlable for UndoRedo {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        let kind = match *self {
+            UndoRedo::Place(coords) => {
+                table.insert("at".to_string(), coords.to_toml());
+                "place"
+            }
+            UndoRedo::Jumping(coords) => {
+                table.insert("at".to_string(), coords.to_toml());
+                "jumping"
+            }
+            UndoRedo::Jump(from, to) => {
+                table.insert("from".to_string(), from.to_toml());
+                table.insert("at".to_string(), to.to_toml());
+                "jump"
+            }
+            UndoRedo::Remove(ref yours, ref srbs) => {
+                table.insert("yours".to_string(), yours.to_toml());
+                table.insert("srbs".to_string(), srbs.to_toml());
+                "remove"
+            }
+        };
+        table.insert("kind".to_string(),
+                     toml::Value::String(kind.to_string()));
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> UndoRedo {
+        let mut table = to_table(value);
+        let at = Coords::pop_from_table(&mut table, "at");
+        match String::pop_from_table(&mut table, "kind").as_str() {
+            "jumping" => UndoRedo::Jumping(at),
+            "jump" => {
+                let from = Coords::pop_from_table(&mut table, "from");
+                UndoRedo::Jump(from, at)
+            }
+            "remove" => {
+                let yours = Vec::<Coords>::pop_from_table(&mut table, "yours");
+                let srbs = Vec::<Coords>::pop_from_table(&mut table, "srbs");
+                UndoRedo::Remove(yours, srbs)
+            }
+            _ => UndoRedo::Place(at),
+        }
+    }
+}
+
 // ========================================================================= //
 
 pub struct View {
@@ -257,6 +304,10 @@ impl Element<Game, PuzzleCmd> for View {
                     Some(&PyramidCmd::Lose) => {
                         self.core.begin_extra_scene(scenes::LOSE_GAME_SCENE);
                     }
+                    Some(&PyramidCmd::SetDifficulty(difficulty)) => {
+                        state.set_difficulty(difficulty);
+                        action.also_play_sound(Sound::device_rotate());
+                    }
                     Some(&PyramidCmd::PasswordHint(coords)) => {
                         self.core.begin_extra_scene(
                             scenes::hint_scene_for_coords(coords));
@@ -274,7 +325,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = UndoRedo;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.system_failure.is_solved() {
             INFO_BOX_TEXT_3
         } else if game.system_failure.mid_scene_is_done() {
@@ -284,6 +343,13 @@ impl PuzzleView for View {
         }
     }
 
+    fn restore_undo_history(&mut self, _history: &UndoHistory) {
+        // Entering this puzzle always rolls the board back to the start of
+        // the current turn, so any steps saved from partway through a turn no
+        // longer match the board; start over with no undo history instead.
+        self.core.clear_undo_redo();
+    }
+
     fn undo(&mut self, game: &mut Game) {
         let state = &mut game.system_failure;
         match self.core.pop_undo() {
@@ -541,6 +607,7 @@ const ANIM_JUMP_FRAMES: i32 = 12;
 const ANIM_REMOVE_FRAMES: i32 = ANIM_PLACE_FRAMES;
 const ANIM_FORMATION_SLOWDOWN: i32 = 2;
 const ANIM_VICTORY_SLOWDOWN: i32 = 3;
+const ANIM_THINKING_SLOWDOWN: i32 = 8;
 
 enum PyramidStep {
     YouReady { possible: HashSet<Coords> },
@@ -562,7 +629,7 @@ enum PyramidStep {
         formation: Vec<Coords>,
         so_far: Vec<Coords>,
     },
-    SrbThinking { result: Arc<Mutex<Option<Move>>> },
+    SrbThinking { anim: i32, worker: SrbWorker },
     SrbAnimatePlace {
         anim: i32,
         at: Coords,
@@ -615,25 +682,12 @@ impl PyramidStep {
                 team: Team::SRB,
             };
         }
-        let result = Arc::new(Mutex::new(None));
-        let step = PyramidStep::SrbThinking { result: result.clone() };
-        let board = state.board().clone();
-        thread::Builder::new()
-            .name("SrbThinking".to_string())
-            .spawn(move || {
-                let start = time::Instant::now();
-                let best = board.best_srb_move();
-                if cfg!(debug_assertions) {
-                    let end = time::Instant::now();
-                    let duration = end.duration_since(start);
-                    let millis = duration.as_secs() * 1000 +
-                        (duration.subsec_nanos() / 1_000_000) as u64;
-                    println!("Found best move in {}ms", millis);
-                }
-                *result.lock().unwrap() = Some(best);
-            })
-            .unwrap();
-        step
+        let worker = SrbWorker::start(state.board().clone(),
+                                      state.difficulty());
+        PyramidStep::SrbThinking {
+            anim: 0,
+            worker: worker,
+        }
     }
 
     fn hilighted_tiles(&self) -> HashSet<Coords> {
@@ -803,42 +857,56 @@ impl PyramidStep {
                 }
                 action.also_redraw();
             }
-            &mut PyramidStep::SrbThinking { ref result } => {
-                match result.lock().unwrap().take() {
-                    Some(Move::Place {
-                             at,
-                             formation,
-                             remove,
-                         }) => {
-                        state.board_mut().set_piece_at(at, Team::SRB);
-                        next = Some(PyramidStep::SrbAnimatePlace {
-                                        anim: 0,
-                                        at: at,
-                                        formation: formation,
-                                        to_remove: remove,
-                                    });
-                        action.also_redraw();
-                        action.also_play_sound(Sound::device_pickup())
-                    }
-                    Some(Move::Jump {
-                             from,
-                             to,
-                             formation,
-                             remove,
-                         }) => {
-                        state.board_mut().remove_piece(from);
-                        state.board_mut().set_piece_at(to, Team::SRB);
-                        next = Some(PyramidStep::SrbAnimateJump {
-                                        anim: 0,
-                                        from: from,
-                                        to: to,
-                                        formation: formation,
-                                        to_remove: remove,
-                                    });
-                        action.also_redraw();
-                        action.also_play_sound(Sound::small_jump());
+            &mut PyramidStep::SrbThinking {
+                ref mut anim,
+                ref worker,
+            } => {
+                *anim += 1;
+                if *anim % ANIM_THINKING_SLOWDOWN == 0 {
+                    action.also_redraw();
+                }
+                if *anim >= thinking_frames(state.difficulty()) {
+                    match worker.wait() {
+                        Some(Move::Place {
+                                 at,
+                                 formation,
+                                 remove,
+                             }) => {
+                            state.board_mut().set_piece_at(at, Team::SRB);
+                            next = Some(PyramidStep::SrbAnimatePlace {
+                                            anim: 0,
+                                            at: at,
+                                            formation: formation,
+                                            to_remove: remove,
+                                        });
+                            action.also_redraw();
+                            action.also_play_sound(Sound::device_pickup())
+                        }
+                        Some(Move::Jump {
+                                 from,
+                                 to,
+                                 formation,
+                                 remove,
+                             }) => {
+                            state.board_mut().remove_piece(from);
+                            state.board_mut().set_piece_at(to, Team::SRB);
+                            next = Some(PyramidStep::SrbAnimateJump {
+                                            anim: 0,
+                                            from: from,
+                                            to: to,
+                                            formation: formation,
+                                            to_remove: remove,
+                                        });
+                            action.also_redraw();
+                            action.also_play_sound(Sound::small_jump());
+                        }
+                        None => {
+                            // The search thread died somehow, so start over
+                            // rather than leave the SRB thinking forever.
+                            println!("SRB search failed; searching again.");
+                            next = Some(PyramidStep::srb_thinking(state));
+                        }
                     }
-                    None => {}
                 }
             }
             &mut PyramidStep::SrbAnimatePlace {
@@ -977,6 +1045,7 @@ enum PyramidCmd {
     Remove(Vec<Coords>, Vec<Coords>),
     Win,
     Lose,
+    SetDifficulty(Difficulty),
     PasswordHint(Coords),
 }
 
@@ -1004,10 +1073,16 @@ impl PyramidView {
     }
 
     fn draw_supply(&self, team: Team, board: &Board, canvas: &mut Canvas) {
-        let (supply, top_left, sprite_index) = match team {
+        let (supply, top_left, mut sprite_index) = match team {
             Team::You => (board.you_supply(), you_supply_pt(), 1),
             Team::SRB => (board.srb_supply(), srb_supply_pt(), 0),
         };
+        // While the SRB is thinking, its supply blinks.
+        if let PyramidStep::SrbThinking { anim, .. } = self.step {
+            if team == Team::SRB && (anim / ANIM_THINKING_SLOWDOWN) % 2 == 1 {
+                sprite_index += 2;
+            }
+        }
         if supply > 0 {
             canvas.draw_sprite(&self.chip_sprites[sprite_index], top_left);
             let pt = top_left + Point::new(16, 20);
@@ -1063,6 +1138,11 @@ impl Element<FailureState, PyramidCmd> for PyramidView {
         }
         self.draw_supply(Team::You, board, canvas);
         self.draw_supply(Team::SRB, board, canvas);
+        if !state.is_solved() {
+            let text = format!("Level: {}", state.difficulty().name());
+            let pt = difficulty_rect().center() + Point::new(0, 4);
+            canvas.draw_text(&self.font, Align::Center, pt, &text);
+        }
     }
 
     fn handle_event(&mut self, event: &Event, state: &mut FailureState)
@@ -1084,6 +1164,17 @@ impl Element<FailureState, PyramidCmd> for PyramidView {
                 action
             }
             &Event::MouseDown(pt) => {
+                // The difficulty can only be changed before the game starts.
+                if difficulty_rect().contains_point(pt) &&
+                    !state.is_solved() &&
+                    state.board().is_empty()
+                {
+                    if let PyramidStep::YouReady { .. } = self.step {
+                        let next = state.difficulty().next();
+                        let cmd = PyramidCmd::SetDifficulty(next);
+                        return Action::redraw().and_return(cmd);
+                    }
+                }
                 if let Some(coords) = pt_to_coords(pt) {
                     if state.is_solved() {
                         let cmd = PyramidCmd::PasswordHint(coords);
@@ -1137,6 +1228,16 @@ fn you_supply_pt() -> Point { Point::new(75, 48) }
 
 fn srb_supply_pt() -> Point { Point::new(469, 48) }
 
+fn difficulty_rect() -> Rect { Rect::new(445, 86, 80, 14) }
+
+/// Returns how many clock ticks after it starts thinking the SRB makes its
+/// move, which is when its time budget runs out.  The move is always made on
+/// that tick (waiting for the search to finish, if need be), so that the game
+/// plays out the same in a replay however long the search takes.
+fn thinking_frames(difficulty: Difficulty) -> i32 {
+    (difficulty.time_budget_millis() / FRAME_DELAY_MILLIS) as i32
+}
+
 fn interpolate(from: Point, to: Point, anim: i32, max_anim: i32) -> Point {
     let x = from.x() + (to.x() - from.x()) * anim / max_anim;
     let y = from.y() + (to.y() - from.y()) * anim / max_anim +
@@ -1146,11 +1247,16 @@ fn interpolate(from: Point, to: Point, anim: i32, max_anim: i32) -> Point {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT_1: &str = "\
+const INFO_BOX_TEXT_1: Message = Message {
+    id: "info.system_failure.1",
+    english: "\
 Return here later, after you have repaired
-more areas of the ship.";
+more areas of the ship.",
+};
 
-const INFO_BOX_TEXT_2: &str = "\
+const INFO_BOX_TEXT_2: Message = Message {
+    id: "info.system_failure.2",
+    english: "\
 Your goal is to beat the System Repair Bot at its own game.
 
 Each player starts out with a supply of 18 pieces to place, and
@@ -1163,19 +1269,32 @@ piece creates a line of four pieces of the same color, then you
 may remove two of your pieces from the board and put them
 back into your supply.
 
-$M{Tap}{Click} on a character in the scene to hear their words of wisdom.";
+Before the game begins, $M{tap}{click} on the level below the SRB's
+supply to change how hard it plays.
 
-const INFO_BOX_TEXT_3: &str = "\
-$M{Tap}{Click} on a tile to get a password hint.";
+$M{Tap}{Click} on a character in the scene to hear their words of wisdom.",
+};
+
+const INFO_BOX_TEXT_3: Message = Message {
+    id: "info.system_failure.3",
+    english: "\
+$M{Tap}{Click} on a tile to get a password hint.",
+};
 
 // ========================================================================= //
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use toml;
 
-    use save::Location;
-    use super::DASHBOARD_CHIPS;
+    use elements::PuzzleView;
+    use gui::Offscreen;
+    use save::{FailureState, Game, Location, PuzzleState};
+    use save::pyramid::{Coords, Difficulty, Team};
+    use save::util::Tomlable;
+    use super::{DASHBOARD_CHIPS, PyramidStep, UndoRedo, View,
+                thinking_frames};
 
     #[test]
     fn all_puzzles_represented_on_dashboard() {
@@ -1195,6 +1314,61 @@ mod tests {
                 locations);
     }
 
+    #[test]
+    fn undo_after_reloading_mid_turn() {
+        let mut offscreen = Offscreen::new(576, 384);
+        let visible = offscreen.visible_rect();
+        let at = Coords::new(0, 3);
+        let mut game = Game::new();
+        {
+            let state = &mut game.system_failure;
+            state.visit();
+            state.set_mid_scene_is_done(true);
+            state.board_mut().set_piece_at(at, Team::You);
+            state.commit_board();
+        }
+        // Remove a piece partway through a turn, and save the game then:
+        let mut view = View::new(&mut offscreen.resources(), visible, &game);
+        game.system_failure.board_mut().remove_piece(at);
+        view.core.push_undo(UndoRedo::Remove(vec![at], vec![at]));
+        game.set_undo_history(Location::SystemFailure, view.undo_history());
+        let mut game = Game::from_toml(game.to_toml());
+        // Reloading rolls the board back to the start of the turn, so undoing
+        // the removal must not try to put the piece back a second time.
+        game.system_failure.roll_back_board();
+        let mut view = View::new(&mut offscreen.resources(), visible, &game);
+        let history = game.undo_history(Location::SystemFailure);
+        assert!(!history.is_empty());
+        view.restore_undo_history(&history);
+        view.undo(&mut game);
+        let board = game.system_failure.board();
+        assert_eq!(board.piece_at(at), Some(Team::You));
+        assert_eq!(board.you_supply(), 17);
+        assert_eq!(board.srb_supply(), 18);
+    }
+
+    #[test]
+    fn srb_moves_after_fixed_number_of_ticks() {
+        let mut state = FailureState::from_toml(toml::Value::Boolean(false));
+        state.set_difficulty(Difficulty::Easy);
+        state.board_mut().set_piece_at(Coords::new(0, 3), Team::You);
+        let mut step = PyramidStep::srb_thinking(&mut state);
+        for _ in 1..thinking_frames(Difficulty::Easy) {
+            step.clock_tick(&mut state);
+            match step {
+                PyramidStep::SrbThinking { .. } => {}
+                _ => panic!("SRB moved early"),
+            }
+        }
+        step.clock_tick(&mut state);
+        match step {
+            PyramidStep::SrbAnimatePlace { at, .. } => {
+                assert_eq!(state.board().piece_at(at), Some(Team::SRB));
+            }
+            _ => panic!("SRB didn't move in time"),
+        }
+    }
+
     #[test]
     fn no_repeated_locations_on_dashboard() {
         let mut locations: HashSet<Location> = HashSet::new();
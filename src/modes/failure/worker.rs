@@ -0,0 +1,129 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use save::game_rng_is_seeded;
+use save::pyramid::{Board, Difficulty, Move, new_srb_rng};
+
+// ========================================================================= //
+
+/// Searches for the SRB's next move on a background thread, so that the
+/// puzzle view can keep animating while the SRB thinks.  Dropping the worker
+/// (e.g. because the player left the puzzle) cancels the search.
+pub struct SrbWorker {
+    receiver: Receiver<Move>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl SrbWorker {
+    pub fn start(board: Board, difficulty: Difficulty) -> SrbWorker {
+        let (sender, receiver) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let worker_cancel = cancel.clone();
+        // The RNG must be created on this thread, since it is seeded from
+        // the (thread-local) game logic RNG.
+        let mut rng = new_srb_rng();
+        // When recording or replaying a game, the SRB must make the same
+        // choices however fast the computer is, so it ignores its time
+        // budget and always searches to its full depth.
+        let deadline = if game_rng_is_seeded() {
+            None
+        } else {
+            let millis = difficulty.time_budget_millis() as u64;
+            Some(Instant::now() + Duration::from_millis(millis))
+        };
+        thread::Builder::new()
+            .name("SrbThinking".to_string())
+            .spawn(move || {
+                let start = Instant::now();
+                let best = board.best_srb_move(difficulty,
+                                               deadline,
+                                               &mut rng,
+                                               &worker_cancel);
+                if cfg!(debug_assertions) {
+                    let duration = start.elapsed();
+                    let millis = duration.as_secs() * 1000 +
+                        (duration.subsec_nanos() / 1_000_000) as u64;
+                    if best.is_some() {
+                        println!("Found best move in {}ms", millis);
+                    } else {
+                        println!("Search cancelled after {}ms", millis);
+                    }
+                }
+                if let Some(best) = best {
+                    // If the receiver is gone, the move is no longer wanted.
+                    let _ = sender.send(best);
+                }
+            })
+            .unwrap();
+        SrbWorker {
+            receiver: receiver,
+            cancel: cancel,
+        }
+    }
+
+    /// Blocks until the search has finished, and returns the SRB's chosen
+    /// move, or `None` if the search thread exited without choosing one.
+    pub fn wait(&self) -> Option<Move> { self.receiver.recv().ok() }
+}
+
+impl Drop for SrbWorker {
+    fn drop(&mut self) { self.cancel.store(true, Ordering::Relaxed); }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::mpsc;
+
+    use save::pyramid::{Board, Coords, Difficulty, Move, Team};
+    use super::SrbWorker;
+
+    #[test]
+    fn worker_delivers_move() {
+        let mut board = Board::new();
+        board.set_piece_at(Coords::new(0, 3), Team::You);
+        let worker = SrbWorker::start(board.clone(), Difficulty::Easy);
+        match worker.wait() {
+            Some(Move::Place { at, .. }) => assert!(board.can_place_at(at)),
+            mov => panic!("unexpected move: {:?}", mov),
+        }
+    }
+
+    #[test]
+    fn dead_worker_delivers_no_move() {
+        let (sender, receiver) = mpsc::channel();
+        drop(sender);
+        let worker = SrbWorker {
+            receiver: receiver,
+            cancel: Arc::new(AtomicBool::new(false)),
+        };
+        assert_eq!(worker.wait(), None);
+    }
+}
+
+// ========================================================================= //
@@ -21,7 +21,7 @@ use std::rc::Rc;
 
 use elements::{CrosswordView, FadeStyle, MovingStars, PuzzleCmd, PuzzleCore,
                PuzzleView, Scene};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound, Sprite};
 use modes::syzygy::Atlatl;
 use save::{CrosswordState, FinaleState, Game, PuzzleState, ValidChars};
@@ -149,7 +149,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, _game: &Game) -> &'static str { INFO_BOX_TEXT }
+    type UndoRedo = ();
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, _game: &Game) -> Message { INFO_BOX_TEXT }
 
     fn undo(&mut self, _game: &mut Game) {}
 
@@ -503,7 +511,10 @@ const COLUMN_LETTERS: [&[char]; 10] = [
     &['.', ' ', ' ', 'N', ' ', ' ', 'N', ' ', 'O', ' '],
 ];
 
-pub const INFO_BOX_TEXT: &str = "\
-Return to the map to select another scene.";
+pub const INFO_BOX_TEXT: Message = Message {
+    id: "info.finale",
+    english: "\
+Return to the map to select another scene.",
+};
 
 // ========================================================================= //
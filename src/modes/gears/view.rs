@@ -23,9 +23,10 @@ use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView, Scene};
 use elements::cutscene::{JumpNode, ParallelNode, QueueNode, SceneNode,
                          SequenceNode, SlideNode, SoundNode, WaitNode};
 use elements::shift::{ArrowPair, Platform};
-use gui::{Action, Canvas, Element, Event, Point, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, Message, Point, Rect, Resources,
+          Sound};
 use modes::SOLVED_INFO_TEXT;
-use save::{Game, GearsState, PuzzleState};
+use save::{CharacterMove, Game, GearsState, PuzzleState};
 use super::scenes;
 
 // ========================================================================= //
@@ -51,6 +52,7 @@ impl View {
         };
         core.add_extra_scene(scenes::compile_ugrent_midscene(resources));
         core.add_extra_scene(scenes::compile_yttris_midscene(resources));
+        let amount = GearsState::shift_amount;
         let mut view = View {
             core: core,
             animation: Scene::empty(),
@@ -66,14 +68,14 @@ impl View {
                 Platform::new(resources, (128, 280), state.get_position(7)),
             ],
             arrows: vec![
-                ArrowPair::new(resources, (80, 96), 0, 3),
-                ArrowPair::new(resources, (80, 128), 1, 3),
-                ArrowPair::new(resources, (80, 160), 2, 6),
-                ArrowPair::new(resources, (80, 192), 3, 3),
-                ArrowPair::new(resources, (480, 192), 4, 2),
-                ArrowPair::new(resources, (480, 224), 5, 7),
-                ArrowPair::new(resources, (480, 256), 6, 5),
-                ArrowPair::new(resources, (480, 288), 7, 3),
+                ArrowPair::new(resources, (80, 96), 0, amount(0)),
+                ArrowPair::new(resources, (80, 128), 1, amount(1)),
+                ArrowPair::new(resources, (80, 160), 2, amount(2)),
+                ArrowPair::new(resources, (80, 192), 3, amount(3)),
+                ArrowPair::new(resources, (480, 192), 4, amount(4)),
+                ArrowPair::new(resources, (480, 224), 5, amount(5)),
+                ArrowPair::new(resources, (480, 256), 6, amount(6)),
+                ArrowPair::new(resources, (480, 288), 7, amount(7)),
             ],
             platforms_and_arrows_visible: true,
         };
@@ -85,9 +87,10 @@ impl View {
     }
 
     fn set_override_row(&mut self, row: i32) {
+        let amount = GearsState::ugrent_shift_amount();
         for (index, arrow) in self.arrows.iter_mut().enumerate() {
             if index as i32 == row {
-                arrow.set_delta_override(Some(4));
+                arrow.set_delta_override(Some(amount));
             } else {
                 arrow.set_delta_override(None);
             }
@@ -131,71 +134,50 @@ impl View {
         }
     }
 
+    fn landing_pt(&self, row: i32, pos: i32) -> Point {
+        if row < GearsState::num_rows() {
+            self.platform_pt_for_pos(row, pos)
+        } else {
+            self.floor_pt_for_pos(pos)
+        }
+    }
+
     fn shift_platform(&mut self, state: &mut GearsState, row: i32,
-                      mut delta: i32) {
-        let num_rows = GearsState::num_rows() as i32;
-        let min_pos = GearsState::min_position_for_row(row);
-        let max_pos = GearsState::max_position_for_row(row);
+                      delta: i32) {
         let original_position = state.get_position(row);
         let original_ugrent_row = state.get_ugrent_row();
+        let shift = state.shift_platform(row, delta);
         let mut top_seq: Vec<Box<SceneNode>> = Vec::new();
 
         // Move platform:
-        let mut ugrent_row = state.get_ugrent_row();
-        let mut ugrent_pos = 0;
-        if ugrent_row >= 0 && ugrent_row < num_rows {
-            ugrent_pos = state.get_position(ugrent_row);
-        }
-        let mut pos = state.get_position(row);
-        while delta != 0 {
+        for leg in shift.legs.iter() {
             let mut platform_seq: Vec<Box<SceneNode>> = Vec::new();
             let mut ugrent_seq: Vec<Box<SceneNode>> = Vec::new();
-            let old_pos = pos;
-            pos += delta;
-            if pos < min_pos {
-                debug_assert!(delta < 0);
-                pos = min_pos;
-                delta = -(delta + (old_pos - min_pos));
-                debug_assert!(delta > 0);
-            } else if pos > max_pos {
-                debug_assert!(delta > 0);
-                pos = max_pos;
-                delta = -(delta - (max_pos - old_pos));
-                debug_assert!(delta < 0);
-            } else {
-                delta = 0;
-            }
+            let (_, old_pos, pos) = leg.platforms[0];
             let travel_time = Platform::travel_time(old_pos, pos);
             let sound = Sound::platform_shift((pos - old_pos).abs());
             platform_seq.push(Box::new(SoundNode::new(sound)));
             platform_seq.push(Box::new(QueueNode::new((row, pos))));
             platform_seq.push(Box::new(WaitNode::new(travel_time)));
-            if ugrent_row >= 0 && ugrent_row < num_rows &&
-                (row == ugrent_row - 1 ||
-                     ((row == 2 || row == 3) && row == ugrent_row - 2))
-            {
-                let impact = if pos > old_pos && ugrent_pos > old_pos &&
-                    ugrent_pos <= pos
-                {
-                    Some((Platform::travel_time(old_pos, ugrent_pos - 1),
-                          cmp::min(10, ugrent_pos + 1)))
-                } else if pos < old_pos && ugrent_pos >= pos &&
-                           ugrent_pos < old_pos
-                {
-                    Some((Platform::travel_time(old_pos, ugrent_pos + 1),
-                          cmp::max(0, ugrent_pos - 1)))
-                } else {
-                    None
-                };
-                if let Some((time_to_hit, new_ugrent_pos)) = impact {
-                    ugrent_pos = new_ugrent_pos;
-                    ugrent_row = state.fall_from(ugrent_row, ugrent_pos);
-                    let dest = if ugrent_row < num_rows {
-                        self.platform_pt_for_pos(ugrent_row, ugrent_pos)
-                    } else {
-                        self.floor_pt_for_pos(ugrent_pos)
-                    };
-                    let fall_dist = dest.y() - self.platform_top(row + 1);
+            match leg.character {
+                CharacterMove::Still => {}
+                CharacterMove::Ride { platform, pos } => {
+                    let dest = self.platform_pt_for_pos(platform, pos);
+                    ugrent_seq.push(Box::new(SlideNode::new(scenes::UGRENT,
+                                                            dest,
+                                                            false,
+                                                            false,
+                                                            travel_time)));
+                }
+                CharacterMove::Knocked {
+                    platform,
+                    hit_pos,
+                    pos,
+                    row,
+                } => {
+                    let time_to_hit = Platform::travel_time(old_pos, hit_pos);
+                    let dest = self.landing_pt(row, pos);
+                    let fall_dist = dest.y() - self.platform_top(platform + 1);
                     let time_to_fall = JumpNode::time_to_fall(fall_dist + 5) +
                         JumpNode::time_to_fall(5);
                     ugrent_seq.push(Box::new(WaitNode::new(time_to_hit)));
@@ -204,61 +186,19 @@ impl View {
                     ugrent_seq.push(Box::new(JumpNode::new(scenes::UGRENT,
                                                            dest,
                                                            time_to_fall)));
-                    ugrent_seq
-                        .push(Box::new(QueueNode::new((ugrent_row, -1))));
+                    ugrent_seq.push(Box::new(QueueNode::new((row, -1))));
                 }
-            } else if row == ugrent_row {
-                let barrier_pos_1 = if row > 0 {
-                    state.get_position(row - 1)
-                } else {
-                    5
-                };
-                let barrier_pos_2 = if row == 4 || row == 5 {
-                    state.get_position(row - 2)
-                } else {
-                    barrier_pos_1
-                };
-                let impact = if pos > old_pos {
-                    if barrier_pos_1 > old_pos && barrier_pos_1 <= pos {
-                        if barrier_pos_2 > old_pos &&
-                            barrier_pos_2 < barrier_pos_1
-                        {
-                            Some(barrier_pos_2 - 1)
-                        } else {
-                            Some(barrier_pos_1 - 1)
-                        }
-                    } else if barrier_pos_2 > old_pos && barrier_pos_2 <= pos {
-                        Some(barrier_pos_2 - 1)
-                    } else {
-                        None
-                    }
-                } else {
-                    if barrier_pos_1 < old_pos && barrier_pos_1 >= pos {
-                        if barrier_pos_2 < old_pos &&
-                            barrier_pos_2 > barrier_pos_1
-                        {
-                            Some(barrier_pos_2 + 1)
-                        } else {
-                            Some(barrier_pos_1 + 1)
-                        }
-                    } else if barrier_pos_2 < old_pos && barrier_pos_2 >= pos {
-                        Some(barrier_pos_2 + 1)
-                    } else {
-                        None
-                    }
-                };
-                if let Some(new_ugrent_pos) = impact {
-                    let time_to_hit = Platform::travel_time(ugrent_pos,
-                                                            new_ugrent_pos);
-                    ugrent_pos = new_ugrent_pos;
-                    ugrent_row = state.fall_from(ugrent_row, ugrent_pos);
-                    let slide_dest = self.platform_pt_for_pos(row, ugrent_pos);
-                    let jump_dest = if ugrent_row < num_rows {
-                        self.platform_pt_for_pos(ugrent_row, ugrent_pos)
-                    } else {
-                        self.floor_pt_for_pos(ugrent_pos)
-                    };
-                    let fall_dist = jump_dest.y() - self.platform_top(row);
+                CharacterMove::Pushed {
+                    platform,
+                    from_pos,
+                    pos,
+                    row,
+                } => {
+                    let time_to_hit = Platform::travel_time(from_pos, pos);
+                    let slide_dest = self.platform_pt_for_pos(platform, pos);
+                    let jump_dest = self.landing_pt(row, pos);
+                    let fall_dist =
+                        jump_dest.y() - self.platform_top(platform);
                     let time_to_fall = JumpNode::time_to_fall(fall_dist + 5) +
                         JumpNode::time_to_fall(5);
                     ugrent_seq.push(Box::new(SlideNode::new(scenes::UGRENT,
@@ -271,16 +211,7 @@ impl View {
                     ugrent_seq.push(Box::new(JumpNode::new(scenes::UGRENT,
                                                            jump_dest,
                                                            time_to_fall)));
-                    ugrent_seq
-                        .push(Box::new(QueueNode::new((ugrent_row, -1))));
-                } else {
-                    ugrent_pos = pos;
-                    let dest = self.platform_pt_for_pos(row, ugrent_pos);
-                    ugrent_seq.push(Box::new(SlideNode::new(scenes::UGRENT,
-                                                            dest,
-                                                            false,
-                                                            false,
-                                                            travel_time)));
+                    ugrent_seq.push(Box::new(QueueNode::new((row, -1))));
                 }
             }
             top_seq.push(Box::new(ParallelNode::new(vec![
@@ -288,82 +219,30 @@ impl View {
                 Box::new(SequenceNode::new(ugrent_seq)),
             ])));
         }
-        state.set_position(row, pos);
 
         // If Ugrent fell to the floor, get back on the starting platform:
-        if ugrent_row == num_rows && original_ugrent_row != num_rows {
-            let slide_time = 0.5 * Platform::travel_time(ugrent_pos, 0);
+        if let Some(floor_pos) = shift.fell_to_floor {
+            let slide_time = 0.5 * Platform::travel_time(floor_pos, 0);
             top_seq.push(Box::new(SlideNode::new(scenes::UGRENT,
                                                  self.floor_pt_for_pos(0),
                                                  false,
                                                  false,
                                                  slide_time)));
-            let dest = self.platform_pt(state, ugrent_row);
+            let dest = self.platform_pt(state, GearsState::num_rows());
             top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
             top_seq.push(Box::new(JumpNode::new(scenes::UGRENT, dest, 0.5)));
         }
 
         // Make Ugrent climb upwards:
-        let last_row = num_rows - 1;
-        let min_pos_for_last_row = GearsState::min_position_for_row(last_row);
-        if ugrent_row == num_rows &&
-            state.get_position(last_row) == min_pos_for_last_row &&
-            state.get_position(last_row - 1) != min_pos_for_last_row
-        {
-            ugrent_row -= 1;
+        for &ugrent_row in shift.climbs.iter() {
             let dest = self.platform_pt(state, ugrent_row);
             top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
             top_seq.push(Box::new(JumpNode::new(scenes::UGRENT, dest, 0.6)));
             top_seq.push(Box::new(QueueNode::new((ugrent_row, -1))));
         }
-        if ugrent_row < num_rows {
-            while ugrent_row > 0 {
-                let ugrent_pos = state.get_position(ugrent_row);
-                let pos_1 = state.get_position(ugrent_row - 1);
-                let pos_2 = if ugrent_row > 1 {
-                    state.get_position(ugrent_row - 2)
-                } else {
-                    5
-                };
-                let mut next_row = ugrent_row;
-                if (ugrent_pos - pos_1).abs() == 1 && pos_2 != pos_1 &&
-                    pos_2 != ugrent_pos &&
-                    ((ugrent_row != 5 && ugrent_row != 6) ||
-                         state.get_position(ugrent_row - 3) != pos_1)
-                {
-                    next_row = ugrent_row - 1;
-                } else if (ugrent_row == 4 || ugrent_row == 5) &&
-                           (ugrent_pos - pos_2).abs() == 1
-                {
-                    let pos_3 = state.get_position(ugrent_row - 3);
-                    if pos_3 != pos_2 && pos_3 != ugrent_pos {
-                        next_row = ugrent_row - 2;
-                    }
-                }
-                if next_row == ugrent_row {
-                    break;
-                }
-                ugrent_row = next_row;
-                let dest = self.platform_pt(state, ugrent_row);
-                top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
-                top_seq
-                    .push(Box::new(JumpNode::new(scenes::UGRENT, dest, 0.6)));
-                top_seq.push(Box::new(QueueNode::new((ugrent_row, -1))));
-            }
-        }
-        if ugrent_row == 0 &&
-            state.get_position(0) == GearsState::max_position_for_row(0)
-        {
-            ugrent_row -= 1;
-            let dest = self.platform_pt(state, ugrent_row);
-            top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
-            top_seq.push(Box::new(JumpNode::new(scenes::UGRENT, dest, 0.6)));
-            top_seq.push(Box::new(QueueNode::new((ugrent_row, -1))));
-        }
-        state.set_ugrent_row(ugrent_row);
         self.core.push_undo((row,
-                             pos - original_position,
-                             ugrent_row - original_ugrent_row));
+                             state.get_position(row) - original_position,
+                             state.get_ugrent_row() - original_ugrent_row));
 
         // Start animation:
         self.animation =
@@ -425,7 +304,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (i32, i32, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.shift_gears.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -497,7 +384,9 @@ impl PuzzleView for View {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.shift_gears",
+    english: "\
 Your goal is to help Ugrent reach the ledge in the
 upper-right corner.
 
@@ -510,6 +399,7 @@ Ugrent will jump up onto the next platform
 when it is adjacent.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
+words of wisdom.",
+};
 
 // ========================================================================= //
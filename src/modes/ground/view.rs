@@ -17,15 +17,14 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use std::cmp;
-
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView, Scene};
 use elements::cutscene::{JumpNode, ParallelNode, QueueNode, SceneNode,
                          SequenceNode, SlideNode, SoundNode, WaitNode};
 use elements::shift::{ArrowPair, Platform};
-use gui::{Action, Canvas, Element, Event, Point, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, Message, Point, Rect, Resources,
+          Sound};
 use modes::SOLVED_INFO_TEXT;
-use save::{Game, GroundState, PuzzleState};
+use save::{CharacterMove, Game, GroundState, PuzzleState};
 use super::scenes;
 
 // ========================================================================= //
@@ -51,6 +50,7 @@ impl View {
         };
         core.add_extra_scene(scenes::compile_elinsa_midscene(resources));
         core.add_extra_scene(scenes::compile_yttris_midscene(resources));
+        let amount = GroundState::shift_amount;
         let mut view = View {
             core: core,
             animation: Scene::empty(),
@@ -65,13 +65,13 @@ impl View {
                 Platform::new(resources, (160, 280), state.get_position(6)),
             ],
             arrows: vec![
-                ArrowPair::new(resources, (480, 96), 0, 8),
-                ArrowPair::new(resources, (480, 128), 1, 5),
-                ArrowPair::new(resources, (480, 160), 2, 6),
-                ArrowPair::new(resources, (480, 192), 3, 7),
-                ArrowPair::new(resources, (480, 224), 4, 5),
-                ArrowPair::new(resources, (480, 256), 5, 3),
-                ArrowPair::new(resources, (480, 288), 6, 4),
+                ArrowPair::new(resources, (480, 96), 0, amount(0)),
+                ArrowPair::new(resources, (480, 128), 1, amount(1)),
+                ArrowPair::new(resources, (480, 160), 2, amount(2)),
+                ArrowPair::new(resources, (480, 192), 3, amount(3)),
+                ArrowPair::new(resources, (480, 224), 4, amount(4)),
+                ArrowPair::new(resources, (480, 256), 5, amount(5)),
+                ArrowPair::new(resources, (480, 288), 6, amount(6)),
             ],
             platforms_and_arrows_visible: true,
         };
@@ -117,69 +117,50 @@ impl View {
         }
     }
 
+    fn landing_pt(&self, row: i32, pos: i32) -> Point {
+        if row < GroundState::num_rows() {
+            self.platform_pt_for_pos(row, pos)
+        } else {
+            self.floor_pt_for_pos(pos)
+        }
+    }
+
     fn shift_platform(&mut self, state: &mut GroundState, row: i32,
-                      mut delta: i32) {
-        let max_pos = GroundState::max_position();
-        let num_rows = GroundState::num_rows() as i32;
+                      delta: i32) {
         let original_position = state.get_position(row);
         let original_elinsa_row = state.get_elinsa_row();
+        let shift = state.shift_platform(row, delta);
         let mut top_seq: Vec<Box<SceneNode>> = Vec::new();
 
         // Move platform:
-        let mut elinsa_row = state.get_elinsa_row();
-        let mut elinsa_pos = 0;
-        if elinsa_row >= 0 && elinsa_row < num_rows {
-            elinsa_pos = state.get_position(elinsa_row);
-        }
-        let mut pos = state.get_position(row);
-        while delta != 0 {
+        for leg in shift.legs.iter() {
             let mut platform_seq: Vec<Box<SceneNode>> = Vec::new();
             let mut elinsa_seq: Vec<Box<SceneNode>> = Vec::new();
-            let old_pos = pos;
-            pos += delta;
-            if pos < 0 {
-                debug_assert!(delta < 0);
-                pos = 0;
-                delta = -(delta + old_pos);
-                debug_assert!(delta > 0);
-            } else if pos > max_pos {
-                debug_assert!(delta > 0);
-                pos = max_pos;
-                delta = -(delta - (max_pos - old_pos));
-                debug_assert!(delta < 0);
-            } else {
-                delta = 0;
-            }
+            let (_, old_pos, pos) = leg.platforms[0];
             let travel_time = Platform::travel_time(old_pos, pos);
             let sound = Sound::platform_shift((pos - old_pos).abs());
             platform_seq.push(Box::new(SoundNode::new(sound)));
             platform_seq.push(Box::new(QueueNode::new((row, pos))));
             platform_seq.push(Box::new(WaitNode::new(travel_time)));
-            if elinsa_row >= 0 && elinsa_row < num_rows &&
-                row == elinsa_row - 1
-            {
-                let impact = if pos > old_pos && elinsa_pos > old_pos &&
-                    elinsa_pos <= pos
-                {
-                    Some((Platform::travel_time(old_pos, elinsa_pos - 1),
-                          cmp::min(max_pos, elinsa_pos + 1)))
-                } else if pos < old_pos && elinsa_pos >= pos &&
-                           elinsa_pos < old_pos
-                {
-                    Some((Platform::travel_time(old_pos, elinsa_pos + 1),
-                          cmp::max(0, elinsa_pos - 1)))
-                } else {
-                    None
-                };
-                if let Some((time_to_hit, new_elinsa_pos)) = impact {
-                    elinsa_pos = new_elinsa_pos;
-                    elinsa_row = state.fall_from(elinsa_row, elinsa_pos);
-                    let dest = if elinsa_row < num_rows {
-                        self.platform_pt_for_pos(elinsa_row, elinsa_pos)
-                    } else {
-                        self.floor_pt_for_pos(elinsa_pos)
-                    };
-                    let fall_dist = dest.y() - self.platform_top(row + 1);
+            match leg.character {
+                CharacterMove::Still => {}
+                CharacterMove::Ride { platform, pos } => {
+                    let dest = self.platform_pt_for_pos(platform, pos);
+                    elinsa_seq.push(Box::new(SlideNode::new(scenes::ELINSA,
+                                                            dest,
+                                                            false,
+                                                            false,
+                                                            travel_time)));
+                }
+                CharacterMove::Knocked {
+                    platform,
+                    hit_pos,
+                    pos,
+                    row,
+                } => {
+                    let time_to_hit = Platform::travel_time(old_pos, hit_pos);
+                    let dest = self.landing_pt(row, pos);
+                    let fall_dist = dest.y() - self.platform_top(platform + 1);
                     let time_to_fall = JumpNode::time_to_fall(fall_dist + 5) +
                         JumpNode::time_to_fall(5);
                     elinsa_seq.push(Box::new(WaitNode::new(time_to_hit)));
@@ -189,35 +170,17 @@ impl View {
                                                            dest,
                                                            time_to_fall)));
                 }
-            } else if row == elinsa_row {
-                let impact = if row > 0 {
-                    let barrier_pos = state.get_position(row - 1);
-                    if pos > old_pos && barrier_pos > old_pos &&
-                        barrier_pos <= pos
-                    {
-                        Some(barrier_pos - 1)
-                    } else if pos < old_pos && barrier_pos < old_pos &&
-                               barrier_pos >= pos
-                    {
-                        Some(barrier_pos + 1)
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
-                if let Some(new_elinsa_pos) = impact {
-                    let time_to_hit = Platform::travel_time(elinsa_pos,
-                                                            new_elinsa_pos);
-                    elinsa_pos = new_elinsa_pos;
-                    elinsa_row = state.fall_from(elinsa_row, elinsa_pos);
-                    let slide_dest = self.platform_pt_for_pos(row, elinsa_pos);
-                    let jump_dest = if elinsa_row < num_rows {
-                        self.platform_pt_for_pos(elinsa_row, elinsa_pos)
-                    } else {
-                        self.floor_pt_for_pos(elinsa_pos)
-                    };
-                    let fall_dist = jump_dest.y() - self.platform_top(row);
+                CharacterMove::Pushed {
+                    platform,
+                    from_pos,
+                    pos,
+                    row,
+                } => {
+                    let time_to_hit = Platform::travel_time(from_pos, pos);
+                    let slide_dest = self.platform_pt_for_pos(platform, pos);
+                    let jump_dest = self.landing_pt(row, pos);
+                    let fall_dist =
+                        jump_dest.y() - self.platform_top(platform);
                     let time_to_fall = JumpNode::time_to_fall(fall_dist + 5) +
                         JumpNode::time_to_fall(5);
                     elinsa_seq.push(Box::new(SlideNode::new(scenes::ELINSA,
@@ -230,14 +193,6 @@ impl View {
                     elinsa_seq.push(Box::new(JumpNode::new(scenes::ELINSA,
                                                            jump_dest,
                                                            time_to_fall)));
-                } else {
-                    elinsa_pos = pos;
-                    let dest = self.platform_pt_for_pos(row, elinsa_pos);
-                    elinsa_seq.push(Box::new(SlideNode::new(scenes::ELINSA,
-                                                            dest,
-                                                            false,
-                                                            false,
-                                                            travel_time)));
                 }
             }
             top_seq.push(Box::new(ParallelNode::new(vec![
@@ -245,60 +200,29 @@ impl View {
                 Box::new(SequenceNode::new(elinsa_seq)),
             ])));
         }
-        state.set_position(row, pos);
 
         // If Elinsa fell to the floor, get back on the starting platform:
-        if elinsa_row == num_rows && original_elinsa_row != num_rows {
-            let slide_time = 0.5 * Platform::travel_time(elinsa_pos, 0);
+        if let Some(floor_pos) = shift.fell_to_floor {
+            let slide_time = 0.5 * Platform::travel_time(floor_pos, 0);
             top_seq.push(Box::new(SlideNode::new(scenes::ELINSA,
                                                  self.floor_pt_for_pos(0),
                                                  false,
                                                  false,
                                                  slide_time)));
-            let dest = self.platform_pt(state, elinsa_row);
+            let dest = self.platform_pt(state, GroundState::num_rows());
             top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
             top_seq.push(Box::new(JumpNode::new(scenes::ELINSA, dest, 0.5)));
         }
 
         // Make Elinsa climb upwards:
-        if elinsa_row == num_rows && state.get_position(num_rows - 1) == 0 &&
-            state.get_position(num_rows - 2) != 0
-        {
-            elinsa_row -= 1;
-            let dest = self.platform_pt(state, elinsa_row);
-            top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
-            top_seq.push(Box::new(JumpNode::new(scenes::ELINSA, dest, 0.6)));
-        }
-        if elinsa_row < num_rows {
-            while elinsa_row > 0 {
-                let pos_0 = state.get_position(elinsa_row);
-                let pos_1 = state.get_position(elinsa_row - 1);
-                if (pos_0 - pos_1).abs() != 1 {
-                    break;
-                }
-                if elinsa_row >= 2 {
-                    let pos_2 = state.get_position(elinsa_row - 2);
-                    if pos_2 == pos_1 || pos_2 == pos_0 {
-                        break;
-                    }
-                }
-                elinsa_row -= 1;
-                let dest = self.platform_pt(state, elinsa_row);
-                top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
-                top_seq
-                    .push(Box::new(JumpNode::new(scenes::ELINSA, dest, 0.6)));
-            }
-        }
-        if elinsa_row == 0 && state.get_position(0) == max_pos {
-            elinsa_row -= 1;
+        for &elinsa_row in shift.climbs.iter() {
             let dest = self.platform_pt(state, elinsa_row);
             top_seq.push(Box::new(SoundNode::new(Sound::small_jump())));
             top_seq.push(Box::new(JumpNode::new(scenes::ELINSA, dest, 0.6)));
         }
-        state.set_elinsa_row(elinsa_row);
         self.core.push_undo((row,
-                             pos - original_position,
-                             elinsa_row - original_elinsa_row));
+                             state.get_position(row) - original_position,
+                             state.get_elinsa_row() - original_elinsa_row));
 
         // Start animation:
         self.animation =
@@ -360,7 +284,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (i32, i32, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.shifting_ground.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -427,7 +359,9 @@ impl PuzzleView for View {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.shifting_ground",
+    english: "\
 Your goal is to help Elinsa reach the ledge in the
 upper-right corner.
 
@@ -438,6 +372,7 @@ Elinsa will jump up onto the next platform when
 it is adjacent.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
+words of wisdom.",
+};
 
 // ========================================================================= //
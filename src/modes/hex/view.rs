@@ -23,8 +23,8 @@ use std::f64::consts::{FRAC_1_PI, FRAC_PI_3};
 use std::rc::Rc;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
-          Resources, Sound, Sprite};
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Message,
+          Point, Rect, Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, HexState, PuzzleState};
 use super::scenes;
@@ -91,10 +91,25 @@ impl Element<Game, PuzzleCmd> for View {
         }
         action
     }
+
+    fn focus_targets(&self, game: &Game) -> Vec<FocusTarget> {
+        let state = &game.hex_spangled;
+        let mut targets = self.core.focus_targets(state);
+        targets.extend(self.wheels.focus_targets(state));
+        targets
+    }
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (usize, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.hex_spangled.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -256,6 +271,10 @@ impl Element<HexState, (usize, i32)> for HexWheels {
                     -> Action<(usize, i32)> {
         self.wheels.handle_event(event, state)
     }
+
+    fn focus_targets(&self, state: &HexState) -> Vec<FocusTarget> {
+        self.wheels.focus_targets(state)
+    }
 }
 
 // ========================================================================= //
@@ -344,6 +363,15 @@ impl Element<HexState, (usize, i32)> for HexWheel {
         }
         Action::ignore()
     }
+
+    fn focus_targets(&self, state: &HexState) -> Vec<FocusTarget> {
+        if state.is_solved() {
+            return Vec::new();
+        }
+        let mut rect = Rect::new(0, 0, 64, 64);
+        rect.center_on(self.center);
+        vec![FocusTarget::turn(rect)]
+    }
 }
 
 // ========================================================================= //
@@ -450,7 +478,9 @@ const LETTERS: &[(usize, char)] = &[
     (18, 'E'), (19, 'A'), (20, 'L'), (21, 'I'),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.hex_spangled",
+    english: "\
 Your goal is to arrange the colored tokens into
 the pattern shown on the right.
 
@@ -458,6 +488,7 @@ Drag any one of the wheels with $M{your finger}{the mouse} to
 rotate it.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
+words of wisdom.",
+};
 
 // ========================================================================= //
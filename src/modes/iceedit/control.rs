@@ -0,0 +1,158 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::io;
+use std::path::Path;
+
+use gui::{Element, Event, KeyboardFocus, Sound, Window};
+use modes::Mode;
+use save::ice::{IceEditor, load_layout, save_layout};
+use super::view::{Cmd, View};
+
+// ========================================================================= //
+
+/// Runs the level editor for ice-sliding puzzles on the layout file at
+/// `path` (which will be created when saving, if it doesn't exist yet).
+pub fn run_ice_editor(window: &mut Window, path: &Path) -> Mode {
+    let editor = match load_layout(path) {
+        Ok(layout) => IceEditor::new(layout),
+        Err(ref error) if error.kind() == io::ErrorKind::NotFound => {
+            IceEditor::empty()
+        }
+        Err(error) => {
+            println!("Failed to load {:?}: {}", path, error);
+            return Mode::Quit;
+        }
+    };
+    run_editor_view(window, path, editor, true)
+}
+
+/// Plays the custom ice-sliding puzzle in the layout file at `path` as a
+/// standalone bonus puzzle.
+pub fn run_bonus_ice_puzzle(window: &mut Window, path: &Path) -> Mode {
+    let mut editor = match load_layout(path) {
+        Ok(layout) => IceEditor::new(layout),
+        Err(error) => {
+            println!("Failed to load {:?}: {}", path, error);
+            return Mode::Quit;
+        }
+    };
+    if editor.layout().ice_blocks().is_empty() {
+        println!("Failed to load {:?}: layout has no blocks", path);
+        return Mode::Quit;
+    }
+    editor.start_playtest();
+    run_editor_view(window, path, editor, false)
+}
+
+fn run_editor_view(window: &mut Window, path: &Path,
+                   mut editor: IceEditor, editable: bool)
+                   -> Mode {
+    let mut view = {
+        let visible = window.visible_rect();
+        let view = View::new(&mut window.resources(),
+                             visible,
+                             &editor,
+                             editable);
+        KeyboardFocus::new(view, visible)
+    };
+    window.render(&editor, &view);
+    loop {
+        let mut action = match window.next_event() {
+            Event::Quit => {
+                save_if_changed(path, &editor);
+                return Mode::Quit;
+            }
+            event => view.handle_event(&event, &mut editor),
+        };
+        match action.value() {
+            Some(&Cmd::Back) => {
+                save_if_changed(path, &editor);
+                return Mode::Title;
+            }
+            Some(&Cmd::Playtest) => {
+                if editor.is_playtesting() {
+                    editor.stop_playtest();
+                    view.element_mut().set_status(String::new());
+                } else {
+                    editor.start_playtest();
+                    let status = solution_status(&editor);
+                    view.element_mut().set_status(status);
+                }
+            }
+            Some(&Cmd::Undo) => {
+                if editor.undo_slide() {
+                    action.also_play_sound(Sound::undo());
+                    view.element_mut().set_status(String::new());
+                }
+            }
+            Some(&Cmd::Reset) => {
+                action.also_play_sound(Sound::reset());
+                editor.start_playtest();
+                view.element_mut().set_status(String::new());
+            }
+            Some(&Cmd::Save) => {
+                let status = match save_layout(path, editor.layout()) {
+                    Ok(()) => {
+                        editor.mark_saved();
+                        format!("Saved. {}", solution_status(&editor))
+                    }
+                    Err(error) => format!("Failed to save: {}", error),
+                };
+                view.element_mut().set_status(status);
+            }
+            Some(&Cmd::Resize(delta_cols, delta_rows)) => {
+                if editor.resize(delta_cols, delta_rows) {
+                    view.element_mut()
+                        .rebuild_grid(&mut window.resources(), &editor);
+                }
+            }
+            Some(&Cmd::SetTool(_)) |
+            Some(&Cmd::NextShape) |
+            Some(&Cmd::Rotate) |
+            Some(&Cmd::Flip) |
+            None => {}
+        }
+        window.play_sounds(action.drain_sounds());
+        if action.value().is_some() {
+            view.element_mut().update(&editor);
+        }
+        if action.should_redraw() {
+            window.render(&editor, &view);
+        }
+    }
+}
+
+fn solution_status(editor: &IceEditor) -> String {
+    match editor.solution_length() {
+        Some(length) => format!("Solvable in {} moves.", length),
+        None => "No solution found.".to_string(),
+    }
+}
+
+/// Saves the layout before leaving the editor, so that work isn't lost.
+fn save_if_changed(path: &Path, editor: &IceEditor) {
+    if editor.has_unsaved_changes() {
+        if let Err(error) = save_layout(path, editor.layout()) {
+            println!("Failed to save {:?}: {}", path, error);
+        }
+    }
+}
+
+// ========================================================================= //
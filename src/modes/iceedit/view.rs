@@ -0,0 +1,321 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use num_integer::div_floor;
+use std::rc::Rc;
+
+use elements::DialogButton;
+use elements::ice::GridView;
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Keycode,
+          Point, Rect, Resources, Sound};
+use save::ice::{EditorTool, IceEditor, ObjectGrid};
+
+// ========================================================================= //
+
+const MARGIN: i32 = 16;
+const CELL_SIZE: i32 = 32;
+const BUTTON_WIDTH: u32 = 50;
+const BUTTON_HEIGHT: u32 = 20;
+const BUTTON_SPACING: i32 = 6;
+const ROW_HEIGHT: i32 = 26;
+
+const BACKGROUND_COLOR: (u8, u8, u8) = (32, 32, 48);
+const HIGHLIGHT_COLOR: (u8, u8, u8) = (255, 255, 128);
+
+// ========================================================================= //
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Cmd {
+    SetTool(EditorTool),
+    NextShape,
+    Rotate,
+    Flip,
+    Resize(i32, i32),
+    Playtest,
+    Undo,
+    Reset,
+    Save,
+    Back,
+}
+
+// ========================================================================= //
+
+/// The level editor screen.  When not `editable`, only the grid and the
+/// undo, reset, and back buttons are shown, for playing a bonus puzzle.
+pub struct View {
+    visible: Rect,
+    editable: bool,
+    font: Rc<Font>,
+    grid: GridView,
+    preview: GridView,
+    preview_rect: Rect,
+    tool_buttons: Vec<DialogButton<Cmd>>,
+    edit_buttons: Vec<DialogButton<Cmd>>,
+    main_buttons: Vec<DialogButton<Cmd>>,
+    painting: bool,
+    status: String,
+}
+
+impl View {
+    pub fn new(resources: &mut Resources, visible: Rect, editor: &IceEditor,
+               editable: bool)
+               -> View {
+        let right = visible.right() - MARGIN - BUTTON_WIDTH as i32;
+        let left = right - BUTTON_SPACING - BUTTON_WIDTH as i32;
+        let row_top = |row: i32| visible.top() + MARGIN + row * ROW_HEIGHT;
+        let button = |resources: &mut Resources, x: i32, y: i32, cmd: Cmd| {
+            let rect = Rect::new(x, y, BUTTON_WIDTH, BUTTON_HEIGHT);
+            DialogButton::new(resources, rect, String::new(), cmd)
+        };
+        let mut tool_buttons = Vec::new();
+        for (index, tool) in EditorTool::all().into_iter().enumerate() {
+            let x = if index % 2 == 0 { left } else { right };
+            let y = row_top(index as i32 / 2);
+            let mut button = button(resources, x, y, Cmd::SetTool(tool));
+            button.set_label(tool.label().to_string());
+            tool_buttons.push(button);
+        }
+        let edit_buttons = vec![
+            button(resources, left, row_top(4), Cmd::NextShape),
+            button(resources, right, row_top(4), Cmd::Rotate),
+            button(resources, left, row_top(5), Cmd::Flip),
+            button(resources, left, row_top(7), Cmd::Resize(-1, 0)),
+            button(resources, right, row_top(7), Cmd::Resize(1, 0)),
+            button(resources, left, row_top(8), Cmd::Resize(0, -1)),
+            button(resources, right, row_top(8), Cmd::Resize(0, 1)),
+        ];
+        let bottom = visible.bottom() - MARGIN - BUTTON_HEIGHT as i32;
+        let main_cmds = if editable {
+            vec![Cmd::Playtest, Cmd::Save, Cmd::Back]
+        } else {
+            vec![Cmd::Undo, Cmd::Reset, Cmd::Back]
+        };
+        let mut main_buttons = Vec::new();
+        let mut x = visible.right() - MARGIN - BUTTON_WIDTH as i32;
+        for cmd in main_cmds.into_iter().rev() {
+            main_buttons.push(button(resources, x, bottom, cmd));
+            x -= BUTTON_WIDTH as i32 + BUTTON_SPACING;
+        }
+        let preview_rect = {
+            let center_x = right + BUTTON_WIDTH as i32 / 2;
+            let center_y = row_top(5) + BUTTON_HEIGHT as i32 / 2;
+            Rect::new(center_x - CELL_SIZE / 2,
+                      center_y - CELL_SIZE / 2,
+                      CELL_SIZE as u32,
+                      CELL_SIZE as u32)
+        };
+        let preview_grid = ObjectGrid::new(1, 1);
+        let mut view = View {
+            visible: visible,
+            editable: editable,
+            font: resources.get_font("roman"),
+            grid: View::new_grid_view(resources, visible, editor),
+            preview: GridView::new(resources,
+                                   preview_rect.left(),
+                                   preview_rect.top(),
+                                   &preview_grid),
+            preview_rect: preview_rect,
+            tool_buttons: tool_buttons,
+            edit_buttons: edit_buttons,
+            main_buttons: main_buttons,
+            painting: false,
+            status: String::new(),
+        };
+        view.update(editor);
+        view
+    }
+
+    fn new_grid_view(resources: &mut Resources, visible: Rect,
+                     editor: &IceEditor)
+                     -> GridView {
+        GridView::new(resources,
+                      visible.left() + MARGIN,
+                      visible.top() + MARGIN,
+                      editor.layout())
+    }
+
+    /// Recreates the grid view to match the layout's current size.
+    pub fn rebuild_grid(&mut self, resources: &mut Resources,
+                        editor: &IceEditor) {
+        self.grid = View::new_grid_view(resources, self.visible, editor);
+    }
+
+    pub fn set_status(&mut self, status: String) { self.status = status; }
+
+    /// Refreshes the button labels to match the editor's current state, and
+    /// cancels any slide animation in progress.
+    pub fn update(&mut self, editor: &IceEditor) {
+        self.grid.reset_animation();
+        let playtesting = editor.is_playtesting();
+        for button in self.edit_buttons
+            .iter_mut()
+            .chain(self.main_buttons.iter_mut())
+        {
+            let label = match *button.value() {
+                Cmd::NextShape => "Shape",
+                Cmd::Rotate => "Turn",
+                Cmd::Flip => "Flip",
+                Cmd::Resize(-1, 0) => "Cols -",
+                Cmd::Resize(1, 0) => "Cols +",
+                Cmd::Resize(0, -1) => "Rows -",
+                Cmd::Resize(_, _) => "Rows +",
+                Cmd::Playtest => if playtesting { "Edit" } else { "Play" },
+                Cmd::Undo => "Undo",
+                Cmd::Reset => "Reset",
+                Cmd::Save => "Save",
+                Cmd::Back => "Back",
+                Cmd::SetTool(_) => continue,
+            };
+            button.set_label(label.to_string());
+        }
+    }
+
+    fn is_editing(&self, editor: &IceEditor) -> bool {
+        self.editable && !editor.is_playtesting()
+    }
+
+    fn grid_coords(&self, pt: Point) -> Point {
+        let col = div_floor(pt.x() - self.visible.left() - MARGIN, CELL_SIZE);
+        let row = div_floor(pt.y() - self.visible.top() - MARGIN, CELL_SIZE);
+        Point::new(col, row)
+    }
+
+    fn paint(&mut self, pt: Point, editor: &mut IceEditor) -> Action<Cmd> {
+        let coords = self.grid_coords(pt);
+        if editor.apply_tool(coords) {
+            self.status.clear();
+            Action::redraw().and_play_sound(Sound::device_drop())
+        } else {
+            Action::ignore()
+        }
+    }
+
+    fn draw_palette(&self, editor: &IceEditor, canvas: &mut Canvas) {
+        self.tool_buttons.draw(&(), canvas);
+        for button in self.tool_buttons.iter() {
+            if *button.value() == Cmd::SetTool(editor.tool()) {
+                let rect = button.rect();
+                canvas.draw_rect(HIGHLIGHT_COLOR,
+                                 Rect::new(rect.left() - 2,
+                                           rect.top() - 2,
+                                           rect.width() + 4,
+                                           rect.height() + 4));
+            }
+        }
+        self.edit_buttons.draw(&(), canvas);
+        let mut preview = ObjectGrid::new(1, 1);
+        let origin = Point::new(0, 0);
+        preview.set_object(origin, editor.tool_object());
+        if editor.tool() == EditorTool::Block {
+            preview.set_ice_block(origin, Some(editor.symbol()));
+        }
+        canvas.fill_rect((0, 0, 0), self.preview_rect);
+        self.preview.draw(&preview, canvas);
+        let (num_cols, num_rows) = editor.layout().size();
+        let left = self.tool_buttons[0].rect().left();
+        let baseline = self.tool_buttons[0].rect().top() + 6 * ROW_HEIGHT +
+            13;
+        canvas.draw_text(&self.font,
+                         Align::Left,
+                         Point::new(left, baseline),
+                         &format!("Size: {}x{}", num_cols, num_rows));
+    }
+}
+
+impl Element<IceEditor, Cmd> for View {
+    fn draw(&self, editor: &IceEditor, canvas: &mut Canvas) {
+        canvas.clear(BACKGROUND_COLOR);
+        self.grid.draw(editor.grid(), canvas);
+        if self.is_editing(editor) {
+            self.draw_palette(editor, canvas);
+        }
+        self.main_buttons.draw(&(), canvas);
+        let baseline = self.visible.bottom() - MARGIN - 6;
+        canvas.draw_text(&self.font,
+                         Align::Left,
+                         Point::new(self.visible.left() + MARGIN, baseline),
+                         &self.status);
+    }
+
+    fn handle_event(&mut self, event: &Event, editor: &mut IceEditor)
+                    -> Action<Cmd> {
+        match event {
+            &Event::KeyDown(Keycode::Escape, _) => {
+                return Action::redraw().and_return(Cmd::Back);
+            }
+            &Event::MouseUp => self.painting = false,
+            &Event::MouseDrag(pt) if self.painting => {
+                return self.paint(pt, editor);
+            }
+            _ => {}
+        }
+        let mut action = self.main_buttons.handle_event(event, &mut ());
+        if !action.should_stop() && self.is_editing(editor) {
+            action.merge(self.tool_buttons.handle_event(event, &mut ()));
+            action.merge(self.edit_buttons.handle_event(event, &mut ()));
+            match action.value() {
+                Some(&Cmd::SetTool(tool)) => editor.set_tool(tool),
+                Some(&Cmd::NextShape) => editor.next_shape(),
+                Some(&Cmd::Rotate) => editor.rotate(),
+                Some(&Cmd::Flip) => editor.flip(),
+                _ => {}
+            }
+            if action.value().is_none() {
+                if let &Event::MouseDown(pt) = event {
+                    if editor.layout().contains(self.grid_coords(pt)) {
+                        self.painting = true;
+                        action.merge(self.paint(pt, editor));
+                    }
+                }
+            }
+        } else if !action.should_stop() &&
+                   (event == &Event::ClockTick || !editor.is_solved())
+        {
+            let subaction = match editor.playtest_grid_mut() {
+                Some(grid) => self.grid.handle_event(event, grid),
+                None => Action::ignore(),
+            };
+            if let Some(&(coords, dir)) = subaction.value() {
+                if let Some(slide) = editor.slide_ice_block(coords, dir) {
+                    action.also_play_sound(Sound::device_slide());
+                    self.grid.animate_slide(&slide);
+                    if editor.is_solved() {
+                        action.also_play_sound(Sound::solve_puzzle_chime());
+                        self.status = "Solved!".to_string();
+                    }
+                }
+            }
+            action.merge(subaction.but_no_value());
+        }
+        action
+    }
+
+    fn focus_targets(&self, editor: &IceEditor) -> Vec<FocusTarget> {
+        let mut targets = self.main_buttons.focus_targets(&());
+        if self.is_editing(editor) {
+            targets.extend(self.tool_buttons.focus_targets(&()));
+            targets.extend(self.edit_buttons.focus_targets(&()));
+        } else {
+            targets.extend(self.grid.focus_targets(editor.grid()));
+        }
+        targets
+    }
+}
+
+// ========================================================================= //
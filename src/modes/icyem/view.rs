@@ -19,7 +19,7 @@
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::column::ColumnsView;
-use gui::{Action, Canvas, Element, Event, Rect, Resources};
+use gui::{Action, Canvas, Element, Event, Message, Rect, Resources};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, IcyEmState, PuzzleState};
 use super::scenes;
@@ -89,7 +89,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (usize, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.column_as_icy_em.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -139,7 +147,9 @@ impl PuzzleView for View {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.column_as_icy_em",
+    english: "\
 Your goal is to slide the columns of letters until the
 highlighted letters form two words horizontally
 across.  There is only one possible pair of words that
@@ -150,6 +160,7 @@ its letters.  Moving one column may also cause other
 columns to move at the same time.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
 // ========================================================================= //
@@ -17,7 +17,7 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use gui::{Element, Event, Window};
+use gui::{Element, Event, KeyboardFocus, Window};
 
 use super::view::View;
 
@@ -30,7 +30,9 @@ pub fn run_info_box<S, A, E: Element<S, A>>(window: &mut Window,
                                             -> bool {
     let mut view = {
         let visible = window.visible_rect();
-        View::new(&mut window.resources(), visible, original_view, text)
+        let view =
+            View::new(&mut window.resources(), visible, original_view, text);
+        KeyboardFocus::new(view, visible)
     };
     window.render(original_input, &view);
     loop {
@@ -19,7 +19,8 @@
 
 use std::marker::PhantomData;
 
-use gui::{Action, Canvas, Element, Event, Rect, Resources};
+use gui::{Action, Canvas, Element, Event, FocusTarget, Message, Rect,
+          Resources};
 use elements::DialogBox;
 
 // ========================================================================= //
@@ -53,13 +54,20 @@ impl<'a, S, A, E: Element<S, A>> Element<S, ()> for View<'a, A, E> {
     fn handle_event(&mut self, event: &Event, _: &mut S) -> Action<()> {
         self.dialog.handle_event(event, &mut ())
     }
+
+    fn focus_targets(&self, _: &S) -> Vec<FocusTarget> {
+        self.dialog.focus_targets(&())
+    }
 }
 
 // ========================================================================= //
 
-pub const SOLVED_INFO_TEXT: &str = "\
+pub const SOLVED_INFO_TEXT: Message = Message {
+    id: "info.solved",
+    english: "\
 This area has been repaired.
 
-Return to the map to select another scene.";
+Return to the map to select another scene.",
+};
 
 // ========================================================================= //
@@ -19,7 +19,7 @@
 
 use elements::{FadeStyle, ProgressBar, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::memory::{FLIP_SLOWDOWN, MemoryGridView, NextShapeView};
-use gui::{Action, Canvas, Element, Event, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, Message, Rect, Resources, Sound};
 use modes::SOLVED_INFO_TEXT;
 use save::{Direction, Game, JogState, PuzzleState};
 use super::scenes;
@@ -153,7 +153,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = ();
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.jog_your_memory.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -198,7 +206,9 @@ const LETTERS: &[(i32, i32, char)] = &[
     (0, 5, 'P'), (1, 5, 'E'), (2, 5, 'N'), (3, 5, 'D'),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.jog_your_memory",
+    english: "\
 Your goal is to place (and later remove) each group of tiles on
 the grid.
 
@@ -213,6 +223,7 @@ the tiles on the grid that had that symbol.  However, if you
 accidentally remove a tile that's still green, you will have to
 start over.
 
-$M{Tap}{Click} on a character in the scene to hear their words of wisdom.";
+$M{Tap}{Click} on a character in the scene to hear their words of wisdom.",
+};
 
 // ========================================================================= //
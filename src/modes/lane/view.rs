@@ -20,8 +20,8 @@
 use elements::{FadeStyle, Paragraph, ProgressBar, PuzzleCmd, PuzzleCore,
                PuzzleView};
 use elements::memory::{FLIP_SLOWDOWN, MemoryGridView, NextShapeView};
-use gui::{Action, Align, Canvas, Element, Event, Point, Rect, Resources,
-          Sound, Sprite};
+use gui::{Action, Align, Canvas, Element, Event, Message, Point, Rect,
+          Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Direction, Game, LaneState, PuzzleState};
 use super::scenes;
@@ -162,7 +162,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = ();
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.memory_lane.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -278,7 +286,9 @@ const LETTERS: &[(i32, i32, char)] = &[
     (4, 0, 'E'), (4, 1, 'N'), (4, 2, 'C'), (4, 3, 'E'),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.memory_lane",
+    english: "\
 Your goal is to place (and later remove) each group of tiles on
 the grid.
 
@@ -291,6 +301,7 @@ tiles on the grid that had that symbol to remove all of them.
 However, if you accidentally remove a tile with the wrong
 symbol,  you will have to start over.
 
-$M{Tap}{Click} on a character in the scene to hear their words of wisdom.";
+$M{Tap}{Click} on a character in the scene to hear their words of wisdom.",
+};
 
 // ========================================================================= //
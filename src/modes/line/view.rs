@@ -20,7 +20,7 @@
 use std::rc::Rc;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, LineState, PuzzleState};
@@ -122,7 +122,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = ();
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.cross_the_line.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -366,7 +374,9 @@ impl Element<LineState, ()> for AnswersDisplay {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.cross_the_line",
+    english: "\
 Your goal is to find the discrepancy between the
 two upper grids.
 
@@ -377,6 +387,7 @@ incorrectly, the grids will rescramble and you
 will have to try again.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
+words of wisdom.",
+};
 
 // ========================================================================= //
@@ -0,0 +1,71 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use gui::{Element, Event, KeyboardFocus, Window};
+use save::{Game, Location};
+
+use super::view::{Cmd, View};
+
+// ========================================================================= //
+
+/// Shows the dialogue log on top of `original_view`, starting with the
+/// transcript for `location` (or for the first location with any recorded
+/// dialogue, if `location` is the map).  Returns false if the player quit the
+/// game.
+pub fn run_log_box<A, E: Element<Game, A>>(window: &mut Window,
+                                           original_view: &E,
+                                           game: &mut Game,
+                                           location: Location)
+                                           -> bool {
+    let mut view = {
+        let visible = window.visible_rect();
+        let view = View::new(&mut window.resources(),
+                             visible,
+                             original_view,
+                             game,
+                             location);
+        KeyboardFocus::new(view, visible)
+    };
+    window.render(game, &view);
+    loop {
+        let mut action = match window.next_event() {
+            Event::Quit => return false,
+            event => view.handle_event(&event, game),
+        };
+        window.play_sounds(action.drain_sounds());
+        match action.value() {
+            Some(&Cmd::Done) => return true,
+            Some(&Cmd::Previous) => {
+                view.element_mut().go_to_previous(&mut window.resources(),
+                                                  game);
+            }
+            Some(&Cmd::Next) => {
+                view.element_mut().go_to_next(&mut window.resources(), game);
+            }
+            Some(&Cmd::ScrollUp) |
+            Some(&Cmd::ScrollDown) |
+            None => {}
+        }
+        if action.should_redraw() {
+            window.render(game, &view);
+        }
+    }
+}
+
+// ========================================================================= //
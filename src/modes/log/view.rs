@@ -0,0 +1,399 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::cmp;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use elements::{DialogButton, Paragraph, location_name};
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Keycode,
+          Message, Point, Rect, Resources, Sprite};
+use save::{Game, Location, TalkStyle};
+
+// ========================================================================= //
+
+const PANEL_WIDTH: u32 = 400;
+const PANEL_HEIGHT: u32 = 288;
+const MARGIN: i32 = 20;
+const TITLE_HEIGHT: i32 = 24;
+const SPEAKER_WIDTH: i32 = 60;
+const SCROLLBAR_WIDTH: u32 = 4;
+const ENTRY_SPACING: i32 = 10;
+const SCROLL_STEP: i32 = 16;
+const BUTTON_WIDTH: u32 = 50;
+const BUTTON_HEIGHT: u32 = 20;
+const BUTTON_SPACING: i32 = 6;
+
+const EMPTY_TITLE: Message = Message {
+    id: "log.title",
+    english: "Dialogue Log",
+};
+const EMPTY_TEXT: Message = Message {
+    id: "log.empty",
+    english: "$CNo dialogue has been recorded here yet.",
+};
+
+// ========================================================================= //
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Cmd {
+    Previous,
+    Next,
+    ScrollUp,
+    ScrollDown,
+    Done,
+}
+
+// ========================================================================= //
+
+struct Entry {
+    speaker: Option<Paragraph>,
+    paragraph: Paragraph,
+    top: i32,
+    height: i32,
+}
+
+// ========================================================================= //
+
+pub struct View<'a, A, E: 'a> {
+    original_view: &'a E,
+    rect: Rect,
+    text_rect: Rect,
+    bg_sprites: Vec<Sprite>,
+    font: Rc<Font>,
+    title: Paragraph,
+    buttons: Vec<DialogButton<Cmd>>,
+    locations: Vec<Location>,
+    index: usize,
+    entries: Vec<Entry>,
+    content_height: i32,
+    scroll: i32,
+    drag_from: Option<(i32, i32)>,
+    phantom: PhantomData<A>,
+}
+
+impl<'a, A, E> View<'a, A, E> {
+    pub fn new(resources: &mut Resources, visible: Rect,
+               original_view: &'a E, game: &Game, location: Location)
+               -> View<'a, A, E> {
+        let mut rect = Rect::new(0, 0, PANEL_WIDTH, PANEL_HEIGHT);
+        rect.center_on(visible.center());
+        let bottom = rect.bottom() - MARGIN - BUTTON_HEIGHT as i32;
+        let text_rect = Rect::new(rect.left() + MARGIN,
+                                  rect.top() + MARGIN + TITLE_HEIGHT,
+                                  PANEL_WIDTH - 2 * MARGIN as u32,
+                                  (bottom - BUTTON_SPACING - rect.top() -
+                                       MARGIN -
+                                       TITLE_HEIGHT) as u32);
+        let step = BUTTON_WIDTH as i32 + BUTTON_SPACING;
+        let left = rect.left() + MARGIN;
+        let right = rect.right() - MARGIN - BUTTON_WIDTH as i32;
+        let buttons = vec![
+            (left, "Prev", Cmd::Previous),
+            (left + step, "Next", Cmd::Next),
+            (right - 2 * step, "Up", Cmd::ScrollUp),
+            (right - step, "Down", Cmd::ScrollDown),
+            (right, "Done", Cmd::Done),
+        ];
+        let buttons = buttons
+            .into_iter()
+            .map(|(x, label, cmd)| {
+                let rect = Rect::new(x, bottom, BUTTON_WIDTH, BUTTON_HEIGHT);
+                DialogButton::new(resources, rect, label.to_string(), cmd)
+            })
+            .collect();
+        let mut locations: Vec<Location> = Location::all()
+            .iter()
+            .cloned()
+            .filter(|&loc| {
+                        loc != Location::Map &&
+                            (loc == location ||
+                                 !game.transcript(loc).is_empty())
+                    })
+            .collect();
+        let index = locations.iter().position(|&loc| loc == location);
+        if locations.is_empty() {
+            locations.push(Location::Map);
+        }
+        let mut view = View {
+            original_view: original_view,
+            rect: rect,
+            text_rect: text_rect,
+            bg_sprites: resources.get_sprites("dialog/box"),
+            font: resources.get_font("roman"),
+            title: Paragraph::new(resources, "roman", Align::Center, ""),
+            buttons: buttons,
+            locations: locations,
+            index: index.unwrap_or(0),
+            entries: Vec::new(),
+            content_height: 0,
+            scroll: 0,
+            drag_from: None,
+            phantom: PhantomData,
+        };
+        view.rebuild(resources, game);
+        view
+    }
+
+    /// Switches to the previous location with recorded dialogue.
+    pub fn go_to_previous(&mut self, resources: &mut Resources,
+                          game: &Game) {
+        let num_locations = self.locations.len();
+        self.index = (self.index + num_locations - 1) % num_locations;
+        self.rebuild(resources, game);
+    }
+
+    /// Switches to the next location with recorded dialogue.
+    pub fn go_to_next(&mut self, resources: &mut Resources, game: &Game) {
+        self.index = (self.index + 1) % self.locations.len();
+        self.rebuild(resources, game);
+    }
+
+    /// Lays out the transcript for the current location, scrolled to the
+    /// most recent line.
+    fn rebuild(&mut self, resources: &mut Resources, game: &Game) {
+        let location = self.locations[self.index];
+        let catalog = resources.catalog();
+        let title = if location == Location::Map {
+            catalog.message(EMPTY_TITLE).to_string()
+        } else {
+            location_name(resources, location)
+        };
+        self.title = Paragraph::new(resources, "roman", Align::Center, &title);
+        let min_height = self.font.height() as i32;
+        self.entries.clear();
+        let mut top = 0;
+        if location != Location::Map {
+            for line in game.transcript(location) {
+                let speaker = Paragraph::new(resources,
+                                             "roman",
+                                             Align::Left,
+                                             &line.speaker);
+                let paragraph = Paragraph::new(resources,
+                                               style_font(line.style),
+                                               Align::Left,
+                                               &line.text);
+                let height = cmp::max(paragraph.height() as i32, min_height);
+                self.entries.push(Entry {
+                                      speaker: Some(speaker),
+                                      paragraph: paragraph,
+                                      top: top,
+                                      height: height,
+                                  });
+                top += height + ENTRY_SPACING;
+            }
+        }
+        if self.entries.is_empty() {
+            let paragraph = Paragraph::new(resources,
+                                           "roman",
+                                           Align::Center,
+                                           catalog.message(EMPTY_TEXT));
+            let height = paragraph.height() as i32;
+            self.entries.push(Entry {
+                                  speaker: None,
+                                  paragraph: paragraph,
+                                  top: 0,
+                                  height: height,
+                              });
+            top = height + ENTRY_SPACING;
+        }
+        self.content_height = top - ENTRY_SPACING;
+        self.scroll = self.max_scroll();
+        self.drag_from = None;
+    }
+
+    fn max_scroll(&self) -> i32 {
+        cmp::max(0, self.content_height - self.text_rect.height() as i32)
+    }
+
+    fn scroll_to(&mut self, scroll: i32) -> Action<Cmd> {
+        let scroll = cmp::max(0, cmp::min(scroll, self.max_scroll()));
+        let changed = scroll != self.scroll;
+        self.scroll = scroll;
+        Action::redraw_if(changed).and_stop()
+    }
+
+    fn draw_panel(&self, canvas: &mut Canvas) {
+        let mut canvas = canvas.subcanvas(self.rect);
+        canvas.fill_rect((200, 200, 200),
+                         Rect::new(11,
+                                   11,
+                                   self.rect.width() - 22,
+                                   self.rect.height() - 22));
+        let right = self.rect.width() as i32 - 16;
+        let bottom = self.rect.height() as i32 - 16;
+        canvas.draw_sprite(&self.bg_sprites[0], Point::new(0, 0));
+        canvas.draw_sprite(&self.bg_sprites[2], Point::new(right, 0));
+        canvas.draw_sprite(&self.bg_sprites[5], Point::new(0, bottom));
+        canvas.draw_sprite(&self.bg_sprites[7], Point::new(right, bottom));
+        for col in 1..(right / 16) {
+            let x = 16 * col;
+            canvas.draw_sprite(&self.bg_sprites[1], Point::new(x, 0));
+            canvas.draw_sprite(&self.bg_sprites[6], Point::new(x, bottom));
+        }
+        for row in 1..(bottom / 16) {
+            let y = 16 * row;
+            canvas.draw_sprite(&self.bg_sprites[3], Point::new(0, y));
+            canvas.draw_sprite(&self.bg_sprites[4], Point::new(right, y));
+        }
+        let title_rect = Rect::new(MARGIN,
+                                   MARGIN,
+                                   self.rect.width() - 2 * MARGIN as u32,
+                                   TITLE_HEIGHT as u32);
+        self.title.draw(&mut canvas.subcanvas(title_rect));
+    }
+
+    fn draw_text(&self, canvas: &mut Canvas) {
+        let mut canvas = canvas.subcanvas(self.text_rect);
+        let width = self.text_rect.width() - SCROLLBAR_WIDTH;
+        let height = self.text_rect.height() as i32;
+        for entry in self.entries.iter() {
+            let top = entry.top - self.scroll;
+            if top + entry.height < 0 || top >= height {
+                continue;
+            }
+            if let Some(ref speaker) = entry.speaker {
+                let rect = Rect::new(0,
+                                     top,
+                                     SPEAKER_WIDTH as u32,
+                                     entry.height as u32);
+                speaker.draw(&mut canvas.subcanvas(rect));
+                let rect = Rect::new(SPEAKER_WIDTH,
+                                     top,
+                                     width - SPEAKER_WIDTH as u32,
+                                     entry.height as u32);
+                entry.paragraph.draw(&mut canvas.subcanvas(rect));
+            } else {
+                let rect = Rect::new(0, top, width, entry.height as u32);
+                entry.paragraph.draw(&mut canvas.subcanvas(rect));
+            }
+        }
+        if self.content_height > height {
+            let track = Rect::new(width as i32,
+                                  0,
+                                  SCROLLBAR_WIDTH,
+                                  height as u32);
+            canvas.fill_rect((160, 160, 160), track);
+            let thumb_top = self.scroll * height / self.content_height;
+            let thumb_height = cmp::max(SCROLLBAR_WIDTH as i32,
+                                        height * height /
+                                            self.content_height);
+            canvas.fill_rect((64, 64, 64),
+                             Rect::new(width as i32,
+                                       thumb_top,
+                                       SCROLLBAR_WIDTH,
+                                       thumb_height as u32));
+        }
+    }
+}
+
+impl<'a, S, A, E: Element<S, A>> Element<S, Cmd> for View<'a, A, E> {
+    fn draw(&self, state: &S, canvas: &mut Canvas) {
+        self.original_view.draw(state, canvas);
+        self.draw_panel(canvas);
+        self.draw_text(canvas);
+        self.buttons.draw(&(), canvas);
+    }
+
+    fn handle_event(&mut self, event: &Event, _: &mut S) -> Action<Cmd> {
+        let page = self.text_rect.height() as i32 - SCROLL_STEP;
+        match event {
+            &Event::MouseDown(pt) if !self.rect.contains_point(pt) => {
+                Action::redraw().and_return(Cmd::Done)
+            }
+            &Event::MouseDown(pt) if self.text_rect.contains_point(pt) => {
+                self.drag_from = Some((pt.y(), self.scroll));
+                Action::ignore().and_stop()
+            }
+            &Event::MouseDrag(pt) if self.drag_from.is_some() => {
+                let (start_y, start_scroll) = self.drag_from.unwrap();
+                self.scroll_to(start_scroll + start_y - pt.y())
+            }
+            &Event::MouseUp if self.drag_from.is_some() => {
+                self.drag_from = None;
+                Action::ignore().and_stop()
+            }
+            &Event::KeyDown(Keycode::Escape, _) |
+            &Event::KeyDown(Keycode::Return, _) => {
+                Action::redraw().and_return(Cmd::Done)
+            }
+            &Event::KeyDown(Keycode::Left, _) => {
+                Action::redraw().and_return(Cmd::Previous)
+            }
+            &Event::KeyDown(Keycode::Right, _) => {
+                Action::redraw().and_return(Cmd::Next)
+            }
+            &Event::KeyDown(Keycode::Up, _) => {
+                let scroll = self.scroll - SCROLL_STEP;
+                self.scroll_to(scroll)
+            }
+            &Event::KeyDown(Keycode::Down, _) => {
+                let scroll = self.scroll + SCROLL_STEP;
+                self.scroll_to(scroll)
+            }
+            &Event::KeyDown(Keycode::PageUp, _) => {
+                let scroll = self.scroll - page;
+                self.scroll_to(scroll)
+            }
+            &Event::KeyDown(Keycode::PageDown, _) => {
+                let scroll = self.scroll + page;
+                self.scroll_to(scroll)
+            }
+            &Event::KeyDown(Keycode::Home, _) => self.scroll_to(0),
+            &Event::KeyDown(Keycode::End, _) => {
+                let scroll = self.max_scroll();
+                self.scroll_to(scroll)
+            }
+            _ => {
+                let mut action = self.buttons.handle_event(event, &mut ());
+                match action.take_value() {
+                    Some(Cmd::ScrollUp) => {
+                        let scroll = self.scroll - page;
+                        action.merge(self.scroll_to(scroll));
+                    }
+                    Some(Cmd::ScrollDown) => {
+                        let scroll = self.scroll + page;
+                        action.merge(self.scroll_to(scroll));
+                    }
+                    Some(cmd) => action = action.and_return(cmd),
+                    None => {}
+                }
+                action
+            }
+        }
+    }
+
+    fn focus_targets(&self, _: &S) -> Vec<FocusTarget> {
+        self.buttons.focus_targets(&())
+    }
+}
+
+// ========================================================================= //
+
+fn style_font(style: TalkStyle) -> &'static str {
+    match style {
+        TalkStyle::Comm | TalkStyle::Thought => "italic",
+        TalkStyle::Evil => "evil",
+        TalkStyle::Good => "good",
+        TalkStyle::Normal => "roman",
+        TalkStyle::System => "system",
+    }
+}
+
+// ========================================================================= //
@@ -18,7 +18,7 @@
 // +--------------------------------------------------------------------------+
 
 use elements::{CrosswordView, FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Canvas, Element, Event, Rect, Resources};
+use gui::{Action, Canvas, Element, Event, Message, Rect, Resources};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, LogLevelState, PuzzleState};
 use super::scenes;
@@ -95,7 +95,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (i32, i32, char, char);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.log_level.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -129,6 +137,19 @@ impl PuzzleView for View {
         self.core.begin_outro_scene();
     }
 
+    fn hint(&mut self, game: &mut Game) -> bool {
+        let state = &mut game.log_level;
+        state.record_hint();
+        state.reveal_word();
+        self.crossword.reset_cursor();
+        if state.is_solved() {
+            self.core.begin_outro_scene();
+        } else {
+            self.core.clear_undo_redo();
+        }
+        true
+    }
+
     fn drain_queue(&mut self) {
         for entry in self.core.drain_queue() {
             match entry {
@@ -157,7 +178,9 @@ const OFFSETS_CLUES: &[(i32, &str)] = &[
     (0, "to separate words with symbols"),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.log_level",
+    english: "\
 Your goal is to fill in the crossword.
 
 $M{Tap}{Click} on a box to select it, then type in the
@@ -168,6 +191,7 @@ If the word won't fit, you may need to fudge
 the numbers.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
+words of wisdom.",
+};
 
 // ========================================================================= //
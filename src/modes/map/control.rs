@@ -17,8 +17,8 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use gui::{Element, Event, Window};
-use modes::{Mode, run_info_box};
+use gui::{Element, Event, KeyboardFocus, Music, Window};
+use modes::{Mode, run_info_box, run_log_box, run_settings_box};
 use save::SaveData;
 
 use super::view::{Cmd, INFO_BOX_TEXT, View};
@@ -26,16 +26,19 @@ use super::view::{Cmd, INFO_BOX_TEXT, View};
 // ========================================================================= //
 
 pub fn run_map_screen(window: &mut Window, save_data: &mut SaveData) -> Mode {
-    let game = save_data.game_mut();
     let mut view = {
         let visible_rect = window.visible_rect();
-        View::new(&mut window.resources(), visible_rect, game)
+        let view = View::new(&mut window.resources(),
+                             visible_rect,
+                             save_data.game_mut());
+        KeyboardFocus::new(view, visible_rect)
     };
-    window.render(game, &view);
+    window.play_music(Some(Music::map()));
+    window.render(save_data.game_mut(), &view);
     loop {
         let mut action = match window.next_event() {
             Event::Quit => return Mode::Quit,
-            event => view.handle_event(&event, game),
+            event => view.handle_event(&event, save_data.game_mut()),
         };
         window.play_sounds(action.drain_sounds());
         match action.value() {
@@ -43,17 +46,45 @@ pub fn run_map_screen(window: &mut Window, save_data: &mut SaveData) -> Mode {
                 return Mode::Title;
             }
             Some(&Cmd::ShowInfoBox) => {
-                if !run_info_box(window, &view, game, INFO_BOX_TEXT) {
+                let catalog = window.resources().catalog();
+                if !run_info_box(window,
+                                 view.element(),
+                                 save_data.game_mut(),
+                                 catalog.message(INFO_BOX_TEXT))
+                {
                     return Mode::Quit;
                 }
             }
+            Some(&Cmd::ShowLog(loc)) => {
+                if !run_log_box(window,
+                                view.element(),
+                                save_data.game_mut(),
+                                loc)
+                {
+                    return Mode::Quit;
+                }
+            }
+            Some(&Cmd::ShowSettings) => {
+                let mut prefs = save_data.prefs().clone();
+                if !run_settings_box(window,
+                                     view.element(),
+                                     save_data.game_mut(),
+                                     &mut prefs)
+                {
+                    return Mode::Quit;
+                }
+                *save_data.prefs_mut() = prefs;
+                if let Err(error) = save_data.save_to_disk() {
+                    println!("Failed to save game: {}", error);
+                }
+            }
             Some(&Cmd::GoToPuzzle(loc)) => {
                 return Mode::Location(loc);
             }
             None => {}
         }
         if action.should_redraw() {
-            window.render(game, &view);
+            window.render(save_data.game_mut(), &view);
         }
     }
 }
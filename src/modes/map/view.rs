@@ -21,15 +21,22 @@ use std::cmp::min;
 use std::collections::HashMap;
 use std::rc::Rc;
 
-use elements::{FadeStyle, Hud, HudCmd, HudInput, ScreenFade};
-use gui::{Action, Background, Canvas, Element, Event, Point, Rect, Resources,
-          Sprite};
+use elements::{DialogButton, FadeStyle, Hud, HudCmd, HudInput, ScreenFade};
+use gui::{Action, Align, Background, Canvas, Element, Event, FocusTarget, Font,
+          Message, Point, Rect, Resources, Sprite};
 use save::{Access, Game, Location};
 
 // ========================================================================= //
 
 const NODE_WIDTH: u32 = 28;
 const NODE_HEIGHT: u32 = 28;
+const HINT_MARK_SIZE: u32 = 7;
+const SETTINGS_BUTTON_WIDTH: u32 = 50;
+const SETTINGS_BUTTON_HEIGHT: u32 = 20;
+const SETTINGS_BUTTON_MARGIN: i32 = 4;
+const STATS_PANEL_MARGIN: i32 = 4;
+const STATS_TEXT_MARGIN: i32 = 5;
+const STATS_LINE_SPACING: i32 = 2;
 
 #[cfg_attr(rustfmt, rustfmt_skip)]
 const NODES: &[(Location, (i32, i32), bool)] = &[
@@ -82,6 +89,8 @@ const NODES: &[(Location, (i32, i32), bool)] = &[
 pub enum Cmd {
     ReturnToTitle,
     ShowInfoBox,
+    ShowLog(Location),
+    ShowSettings,
     GoToPuzzle(Location),
 }
 
@@ -90,6 +99,8 @@ pub enum Cmd {
 pub struct View {
     screen_fade: ScreenFade<Cmd>,
     hud: Hud,
+    settings_button: DialogButton<Cmd>,
+    stats_panel: StatsPanel,
     background: Rc<Background>,
     map_sprites: Vec<(Sprite, Point)>,
     nodes: Vec<PuzzleNode>,
@@ -333,11 +344,20 @@ impl View {
                 map_sprites.push((sprites[11].clone(), Point::new(272, 301)));
             }
         }
+        let settings_rect = Rect::new(visible.left() + SETTINGS_BUTTON_MARGIN,
+                                      visible.top() + SETTINGS_BUTTON_MARGIN,
+                                      SETTINGS_BUTTON_WIDTH,
+                                      SETTINGS_BUTTON_HEIGHT);
         View {
             screen_fade: ScreenFade::new(resources,
                                          FadeStyle::Radial,
                                          FadeStyle::Radial),
             hud: Hud::new(resources, visible, Location::Map),
+            settings_button: DialogButton::new(resources,
+                                               settings_rect,
+                                               "Settings".to_string(),
+                                               Cmd::ShowSettings),
+            stats_panel: StatsPanel::new(resources, visible),
             background: resources.get_background("map"),
             map_sprites: map_sprites,
             nodes: nodes,
@@ -349,20 +369,22 @@ impl View {
 
     fn hud_input(&self) -> HudInput {
         HudInput {
-            name: self.selected.unwrap_or(Location::Map).name(),
+            location: self.selected.unwrap_or(Location::Map),
             access: Access::Unvisited,
             is_paused: false,
             show_skip: false,
             active: self.screen_fade.is_transparent(),
             can_undo: false,
             can_redo: false,
+            has_timeline: false,
             can_reset: false,
+            can_hint: false,
         }
     }
 }
 
 impl Element<Game, Cmd> for View {
-    fn draw(&self, _: &Game, canvas: &mut Canvas) {
+    fn draw(&self, game: &Game, canvas: &mut Canvas) {
         canvas.clear(self.background.color());
         canvas.draw_background(&self.background);
         for &(ref sprite, point) in self.map_sprites.iter() {
@@ -375,7 +397,11 @@ impl Element<Game, Cmd> for View {
             canvas.fill_rect((192, 128, 0), rect);
         }
         self.nodes.draw(&self.selected, canvas);
+        if let Some(location) = self.selected {
+            self.stats_panel.draw(game, location, canvas);
+        }
         self.hud.draw(&self.hud_input(), canvas);
+        self.settings_button.draw(&(), canvas);
         self.screen_fade.draw(&(), canvas);
     }
 
@@ -393,9 +419,17 @@ impl Element<Game, Cmd> for View {
                              Some(&HudCmd::Info) => {
                                  subaction.but_return(Cmd::ShowInfoBox)
                              }
+                             Some(&HudCmd::Log) => {
+                                 let location =
+                                     self.selected.unwrap_or(Location::Map);
+                                 subaction.but_return(Cmd::ShowLog(location))
+                             }
                              _ => subaction.but_no_value(),
                          });
         }
+        if !action.should_stop() && self.screen_fade.is_transparent() {
+            action.merge(self.settings_button.handle_event(event, &mut ()));
+        }
         if !action.should_stop() {
             let subaction = self.nodes.handle_event(event, &mut self.selected);
             if let Some(&loc) = subaction.value() {
@@ -411,6 +445,16 @@ impl Element<Game, Cmd> for View {
         }
         action
     }
+
+    fn focus_targets(&self, _: &Game) -> Vec<FocusTarget> {
+        if !self.screen_fade.is_transparent() {
+            return Vec::new();
+        }
+        let mut targets = self.nodes.focus_targets(&self.selected);
+        targets.extend(self.hud.focus_targets(&self.hud_input()));
+        targets.extend(self.settings_button.focus_targets(&()));
+        targets
+    }
 }
 
 // ========================================================================= //
@@ -418,6 +462,7 @@ impl Element<Game, Cmd> for View {
 struct PuzzleNode {
     sprites: Vec<Sprite>,
     sprite_index: usize,
+    used_hints: bool,
     rect: Rect,
     loc: Location,
 }
@@ -426,7 +471,8 @@ impl PuzzleNode {
     fn new(resources: &mut Resources, rect: Rect, location: Location,
            game: &Game)
            -> PuzzleNode {
-        let sprite_index = if game.has_been_solved(location) {
+        let solved = game.has_been_solved(location);
+        let sprite_index = if solved {
             1
         } else if location == Location::SystemFailure &&
                    !game.system_failure.mid_scene_is_done()
@@ -438,6 +484,7 @@ impl PuzzleNode {
         PuzzleNode {
             sprites: resources.get_sprites("map/nodes"),
             sprite_index: sprite_index,
+            used_hints: solved && game.hints_used(location) > 0,
             rect: rect,
             loc: location,
         }
@@ -448,6 +495,16 @@ impl Element<Option<Location>, Location> for PuzzleNode {
     fn draw(&self, selected: &Option<Location>, canvas: &mut Canvas) {
         let top_left = self.rect.top_left();
         canvas.draw_sprite(&self.sprites[self.sprite_index], top_left);
+        if self.used_hints {
+            let left = self.rect.right() - HINT_MARK_SIZE as i32;
+            let top = self.rect.top();
+            canvas.fill_rect((96, 64, 0),
+                             Rect::new(left, top, HINT_MARK_SIZE,
+                                       HINT_MARK_SIZE));
+            canvas.fill_rect((255, 224, 0),
+                             Rect::new(left + 1, top + 1, HINT_MARK_SIZE - 2,
+                                       HINT_MARK_SIZE - 2));
+        }
         if *selected == Some(self.loc) {
             canvas.draw_sprite(&self.sprites[3], top_left);
         }
@@ -468,16 +525,87 @@ impl Element<Option<Location>, Location> for PuzzleNode {
             _ => Action::ignore(),
         }
     }
+
+    fn focus_targets(&self, _: &Option<Location>) -> Vec<FocusTarget> {
+        vec![FocusTarget::new(self.rect)]
+    }
 }
 
 // ========================================================================= //
 
-pub const INFO_BOX_TEXT: &str = "\
+struct StatsPanel {
+    font: Rc<Font>,
+    right: i32,
+    top: i32,
+}
+
+impl StatsPanel {
+    fn new(resources: &mut Resources, visible: Rect) -> StatsPanel {
+        StatsPanel {
+            font: resources.get_font("roman"),
+            right: visible.right() - STATS_PANEL_MARGIN,
+            top: visible.top() + STATS_PANEL_MARGIN,
+        }
+    }
+
+    fn draw(&self, game: &Game, location: Location, canvas: &mut Canvas) {
+        if !game.puzzle_state(location).has_been_visited() {
+            return;
+        }
+        let lines = game.stats(location).summary();
+        let line_height = self.font.height() as i32 + STATS_LINE_SPACING;
+        let text_width = lines
+            .iter()
+            .map(|line| self.font.text_width(line))
+            .max()
+            .unwrap_or(0);
+        let width = text_width + 2 * STATS_TEXT_MARGIN;
+        let height = lines.len() as i32 * line_height + 2 * STATS_TEXT_MARGIN;
+        let rect = Rect::new(self.right - width,
+                             self.top,
+                             width as u32,
+                             height as u32);
+        canvas.fill_rect((255, 255, 255), rect);
+        canvas.draw_rect((0, 0, 0),
+                         Rect::new(rect.x() + 1,
+                                   rect.y() + 1,
+                                   rect.width() - 2,
+                                   rect.height() - 2));
+        let left = rect.x() + STATS_TEXT_MARGIN;
+        let mut baseline = rect.y() + STATS_TEXT_MARGIN + self.font.baseline();
+        for line in lines.iter() {
+            canvas.draw_text(&self.font,
+                             Align::Left,
+                             Point::new(left, baseline),
+                             line);
+            baseline += line_height;
+        }
+    }
+}
+
+// ========================================================================= //
+
+pub const INFO_BOX_TEXT: Message = Message {
+    id: "info.map",
+    english: "\
 $M{Tap}{Click} on a system node to select it; $M{tap}{click} on it again to
 travel there.
 
 Nodes that still need to be repaired are marked in red.
-Repaired nodes are marked in green.";
+Repaired nodes are marked in green, with a yellow
+corner if any hints were used to repair them.
+
+Selecting a node you have visited shows your
+statistics for that area.  $M{Tap}{Click} the name box at the
+bottom of the screen to read back the dialogue you
+have seen there.
+
+With a keyboard, use the arrow keys or Tab to move the
+highlight, and Enter or Space to select.  Shift+arrow
+keys drag the highlighted item.  On a game controller,
+use the D-pad or stick to move, A to select, and hold X
+to drag.",
+};
 
 // ========================================================================= //
 
@@ -485,9 +613,32 @@ Repaired nodes are marked in green.";
 mod tests {
     use std::collections::{HashMap, HashSet};
 
-    use gui::Rect;
-    use save::Location;
-    use super::{NODES, NODE_HEIGHT, NODE_WIDTH};
+    use gui::{Element, Event, Offscreen, Rect};
+    use save::{Game, Location, PuzzleState};
+    use super::{NODES, NODE_HEIGHT, NODE_WIDTH, View};
+
+    #[test]
+    fn renders_map() {
+        let mut offscreen = Offscreen::new(480, 320);
+        let mut game = Game::new();
+        for &location in &[Location::Prolog,
+                           Location::Disconnected,
+                           Location::LogLevel]
+        {
+            game.solve_puzzle(location);
+        }
+        game.wrecked_angle.visit();
+        let mut view = {
+            let visible = offscreen.visible_rect();
+            View::new(&mut offscreen.resources(), visible, &game)
+        };
+        // Let the screen fade-in finish before rendering:
+        for _ in 0..100 {
+            view.handle_event(&Event::ClockTick, &mut game);
+        }
+        offscreen.render(&game, &view);
+        offscreen.assert_matches_golden("map");
+    }
 
     #[test]
     fn all_locations_represented_on_map() {
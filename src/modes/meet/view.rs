@@ -19,7 +19,8 @@
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::ice::GridView;
-use gui::{Action, Canvas, Element, Event, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, FocusTarget, Message, Rect,
+          Resources, Sound};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, MeetState, PuzzleState};
 use save::ice::BlockSlide;
@@ -90,10 +91,27 @@ impl Element<Game, PuzzleCmd> for View {
         }
         action
     }
+
+    fn focus_targets(&self, game: &Game) -> Vec<FocusTarget> {
+        let state = &game.ice_to_meet_you;
+        let mut targets = self.core.focus_targets(state);
+        if self.grid_visible && !state.is_solved() {
+            targets.extend(self.grid.focus_targets(state.grid()));
+        }
+        targets
+    }
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = BlockSlide;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.ice_to_meet_you.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -127,6 +145,26 @@ impl PuzzleView for View {
         self.core.begin_outro_scene();
     }
 
+    fn hint(&mut self, game: &mut Game) -> bool {
+        let state = &mut game.ice_to_meet_you;
+        let slide = match state.hint() {
+            Some((coords, dir)) => state.slide_ice_block(coords, dir),
+            None => None,
+        };
+        if let Some(slide) = slide {
+            state.record_hint();
+            self.grid.animate_slide(&slide);
+            if state.is_solved() {
+                self.core.begin_outro_scene();
+            } else {
+                self.core.push_undo(slide);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
     fn drain_queue(&mut self) {
         for (kind, value) in self.core.drain_queue() {
             if kind == 0 {
@@ -157,7 +195,9 @@ const LETTERS: &[((i32, i32), char)] = &[
     ((6, 2), 'E'),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.ice_to_meet_you",
+    english: "\
 Your goal is to slide the blocks of ice until each one
 covers its matching symbol on the grid.
 
@@ -165,6 +205,7 @@ Drag one of the ice blocks up, down, left, or right with
 $M{your finger}{the mouse} to slide it in that direction.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
 // ========================================================================= //
@@ -34,12 +34,14 @@ mod gears;
 mod ground;
 mod headed;
 mod hex;
+mod iceedit;
 mod icyem;
 mod info;
 mod jog;
 mod lane;
 mod levelup;
 mod line;
+mod log;
 mod loglevel;
 mod map;
 mod meet;
@@ -54,11 +56,13 @@ mod puzzle;
 mod right;
 mod sauce;
 mod serves;
+mod settings;
 mod simple;
 mod star;
 mod syrup;
 mod syzygy;
 mod they;
+mod timeline;
 mod title;
 mod tread;
 mod whatcha;
@@ -81,12 +85,14 @@ pub use self::gears::run_shift_gears;
 pub use self::ground::run_shifting_ground;
 pub use self::headed::run_level_headed;
 pub use self::hex::run_hex_spangled;
+pub use self::iceedit::{run_bonus_ice_puzzle, run_ice_editor};
 pub use self::icyem::run_column_as_icy_em;
 pub use self::info::{SOLVED_INFO_TEXT, run_info_box};
 pub use self::jog::run_jog_your_memory;
 pub use self::lane::run_memory_lane;
 pub use self::levelup::run_level_up;
 pub use self::line::run_cross_the_line;
+pub use self::log::run_log_box;
 pub use self::loglevel::run_log_level;
 pub use self::map::run_map_screen;
 pub use self::meet::run_ice_to_meet_you;
@@ -101,11 +107,13 @@ pub use self::puzzle::run_puzzle;
 pub use self::right::run_the_ice_is_right;
 pub use self::sauce::run_cross_sauce;
 pub use self::serves::run_if_memory_serves;
+pub use self::settings::{apply_prefs, run_settings_box};
 pub use self::simple::run_plane_and_simple;
 pub use self::star::run_star_crossed;
 pub use self::syrup::run_light_syrup;
 pub use self::syzygy::run_system_syzygy;
 pub use self::they::run_the_y_factor;
+pub use self::timeline::{TimelineChoice, run_timeline_box};
 pub use self::title::run_title_screen;
 pub use self::tread::run_tread_lightly;
 pub use self::whatcha::run_whatcha_column;
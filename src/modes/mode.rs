@@ -17,6 +17,8 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
+use std::path::PathBuf;
+
 use save::Location;
 
 // ========================================================================= //
@@ -24,6 +26,10 @@ use save::Location;
 pub enum Mode {
     Title,
     Location(Location),
+    /// The level editor for ice-sliding puzzles, editing the given file.
+    IceEditor(PathBuf),
+    /// A custom ice-sliding puzzle, loaded from the given file.
+    BonusPuzzle(PathBuf),
     Quit,
 }
 
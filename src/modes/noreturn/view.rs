@@ -26,7 +26,7 @@ use elements::cutscene::{JumpNode, ParallelNode, QueueNode, SceneNode,
                          SequenceNode, SetPosNode, ShakeNode, SlideNode,
                          SoundNode};
 use elements::cutscene::WaitNode;
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, NoReturnState, PuzzleState};
@@ -213,7 +213,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (usize, usize);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.point_of_no_return.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -589,7 +597,9 @@ const LETTERS: &[char] = &[
     'T',
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.point_of_no_return",
+    english: "\
 Your goal is to reorder the pieces of the walkway so that
 Yttris can cross from ``BEGIN'' to ``END'' while landing on
 each square exactly once.
@@ -603,6 +613,7 @@ When you're ready, $M{tap}{click} the green triangle button on the
 left to see if your solution works.
 
 $M{Tap}{Click} on a character in the scene to hear their words of
-wisdom.";
+wisdom.",
+};
 
 // ========================================================================= //
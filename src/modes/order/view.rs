@@ -22,7 +22,8 @@ use std::cmp;
 use std::collections::HashSet;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Canvas, Element, Event, Point, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, Message, Point, Rect, Resources,
+          Sound};
 use gui::Sprite;
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, OrderState, PuzzleState};
@@ -110,7 +111,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (usize, usize);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.point_of_order.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -346,13 +355,16 @@ impl TileDrag {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.point_of_order",
+    english: "\
 Your goal is to put each row of tiles in alphabetical order.
 
 Drag tiles left and right with $M{your finger}{the mouse} to reorder them.
 Once a row is ordered correctly, the next row will appear.
 
 $M{Tap}{Click} on a character in the scene to hear their words of
-wisdom.";
+wisdom.",
+};
 
 // ========================================================================= //
@@ -20,23 +20,62 @@
 use std::cmp::{max, min};
 use std::rc::Rc;
 use std::f64;
+use toml;
 
 use elements::{CrosswordView, FadeStyle, Paragraph, PuzzleCmd, PuzzleCore,
                PuzzleView, TalkPos};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, PasswordState, PuzzleState};
+use save::util::{Tomlable, to_table};
 use super::scenes;
 
 // ========================================================================= //
 
 #[derive(Clone, Copy)]
-enum UndoRedo {
+pub enum UndoRedo {
     Crossword(usize, i32, i32, char, char),
     Slider(i32, i32, i32),
 }
 
+impl Tomlable for UndoRedo {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        match *self {
+            UndoRedo::Crossword(index, row, col, old_ch, new_ch) => {
+                table.insert("crossword".to_string(), index.to_toml());
+                table.insert("row".to_string(), row.to_toml());
+                table.insert("col".to_string(), col.to_toml());
+                table.insert("old".to_string(), old_ch.to_toml());
+                table.insert("new".to_string(), new_ch.to_toml());
+            }
+            UndoRedo::Slider(col, old_offset, new_offset) => {
+                table.insert("col".to_string(), col.to_toml());
+                table.insert("old".to_string(), old_offset.to_toml());
+                table.insert("new".to_string(), new_offset.to_toml());
+            }
+        }
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> UndoRedo {
+        let mut table = to_table(value);
+        let col = i32::pop_from_table(&mut table, "col");
+        if let Some(index) = table.remove("crossword") {
+            let row = i32::pop_from_table(&mut table, "row");
+            let old_ch = char::pop_from_table(&mut table, "old");
+            let new_ch = char::pop_from_table(&mut table, "new");
+            let index = min(usize::from_toml(index), 5);
+            UndoRedo::Crossword(index, row, col, old_ch, new_ch)
+        } else {
+            let old_offset = i32::pop_from_table(&mut table, "old");
+            let new_offset = i32::pop_from_table(&mut table, "new");
+            UndoRedo::Slider(col, old_offset, new_offset)
+        }
+    }
+}
+
 // ========================================================================= //
 
 pub struct View {
@@ -242,7 +281,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = UndoRedo;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.password_file.is_solved() {
             SOLVED_INFO_TEXT
         } else if game.password_file.all_crosswords_done() {
@@ -506,7 +553,9 @@ const UGRENT_OFFS: &[(i32, &str)] =
 const RELYNG_OFFS: &[(i32, &str)] =
     &[(0, ""), (2, ""), (4, ""), (6, ""), (7, ""), (7, "")];
 
-const CROSSWORDS_INFO_BOX_TEXT: &str = "\
+const CROSSWORDS_INFO_BOX_TEXT: Message = Message {
+    id: "info.password_file.crosswords",
+    english: "\
 Your goal is to fill in all six crosswords.
 
 $M{Tap}{Click} on each of the six characters to reveal their crossword.
@@ -519,9 +568,12 @@ scenes in the game.  Be sure to check the $iSystem Failure$r
 area for hints on how to find all the words.
 
 (Note that for this puzzle, the reset button will affect only
-the currently-selected crossword, not any of the others.)";
+the currently-selected crossword, not any of the others.)",
+};
 
-const SLIDERS_INFO_BOX_TEXT: &str = "\
+const SLIDERS_INFO_BOX_TEXT: Message = Message {
+    id: "info.password_file.sliders",
+    english: "\
 Now that all six characters' crosswords have been filled in,
 you must drag the six columns up and down to form the final,
 six-letter password.
@@ -530,6 +582,7 @@ There are many possible words you could form this way, but
 only one is correct.
 
 (Note that at this point, the reset button will reset only these
-sliders, not the already-completed crosswords.)";
+sliders, not the already-completed crosswords.)",
+};
 
 // ========================================================================= //
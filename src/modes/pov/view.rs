@@ -19,12 +19,14 @@
 
 use std::cmp;
 use std::rc::Rc;
+use toml;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Canvas, Element, Event, FRAME_DELAY_MILLIS, Font, Point,
-          Rect, Resources, Sound, Sprite};
+use gui::{Action, Canvas, Element, Event, FRAME_DELAY_MILLIS, Font, Message,
+          Point, Rect, Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, PovState, PuzzleState};
+use save::util::{Tomlable, to_table};
 use super::scenes;
 
 // ========================================================================= //
@@ -35,6 +37,33 @@ pub enum PovCmd {
     Rotated((i32, i32)),
 }
 
+impl Tomlable for PovCmd {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        match *self {
+            PovCmd::Moved(from, to) => {
+                table.insert("from".to_string(), from.to_toml());
+                table.insert("to".to_string(), to.to_toml());
+            }
+            PovCmd::Rotated(coords) => {
+                table.insert("rotated".to_string(), coords.to_toml());
+            }
+        }
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> PovCmd {
+        let mut table = to_table(value);
+        if let Some(coords) = table.remove("rotated") {
+            PovCmd::Rotated(<(i32, i32)>::from_toml(coords))
+        } else {
+            let from = <(i32, i32)>::pop_from_table(&mut table, "from");
+            let to = <(i32, i32)>::pop_from_table(&mut table, "to");
+            PovCmd::Moved(from, to)
+        }
+    }
+}
+
 // ========================================================================= //
 
 pub struct View {
@@ -93,7 +122,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = PovCmd;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.point_of_view.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -402,7 +439,9 @@ impl Element<PovState, PovCmd> for PovGridView {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.point_of_view",
+    english: "\
 Your goal is to arrange the tiles in the grid so that the
 colors seen from the edges of the grid (as shown by
 the inner ring of indicators) match the goal pattern
@@ -412,6 +451,7 @@ Drag tiles with $M{your finger}{the mouse} to move their positions in
 the grid.  $M{Tap}{Click} tiles to rotate them.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
 // ========================================================================= //
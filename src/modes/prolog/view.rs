@@ -21,7 +21,7 @@ use std::rc::Rc;
 
 use elements::{FadeStyle, MovingStars, PuzzleCmd, PuzzleCore, PuzzleView,
                Scene};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sprite};
 use modes::attic::AtticGrid;
 use modes::wrecked::{WreckedDisplay, WreckedGrid};
@@ -193,7 +193,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, _game: &Game) -> &'static str { INFO_BOX_TEXT }
+    type UndoRedo = ();
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, _game: &Game) -> Message { INFO_BOX_TEXT }
 
     fn undo(&mut self, _game: &mut Game) {}
 
@@ -472,7 +480,10 @@ impl SpawnPoint {
 
 // ========================================================================= //
 
-pub const INFO_BOX_TEXT: &str = "\
-Return to the map to select another scene.";
+pub const INFO_BOX_TEXT: Message = Message {
+    id: "info.prolog",
+    english: "\
+Return to the map to select another scene.",
+};
 
 // ========================================================================= //
@@ -18,62 +18,162 @@
 // +--------------------------------------------------------------------------+
 
 use elements::{PuzzleCmd, PuzzleView};
-use gui::{Event, Window};
-use modes::{Mode, run_info_box};
-use save::{Location, SaveData};
+use gui::{Element, Event, FRAME_DELAY_MILLIS, KeyboardFocus, Message, Music,
+          Sound, Window};
+use modes::{Mode, TimelineChoice, run_info_box, run_log_box,
+            run_timeline_box};
+use save::{Game, Location, SaveData, UndoHistory};
+
+// ========================================================================= //
+
+const NO_HINT_TEXT: Message = Message {
+    id: "hint.unavailable",
+    english: "$CThe puzzle has strayed too far from any solution to find a \
+              hint.  Try undoing some moves, or resetting the puzzle.",
+};
 
 // ========================================================================= //
 
 pub fn run_puzzle<V: PuzzleView>(window: &mut Window,
-                                 save_data: &mut SaveData, mut view: V)
+                                 save_data: &mut SaveData, view: V)
                                  -> Mode {
-    view.drain_queue();
+    let mut view = KeyboardFocus::new(view, window.visible_rect());
+    view.element_mut().drain_queue();
     let location = {
         let game = save_data.game_mut();
         let location = game.location;
         game.puzzle_state_mut(location).revisit();
+        view.element_mut().restore_undo_history(&game.undo_history(location));
         window.render(game, &view);
         location
     };
+    window.play_music(Some(location_music(location)));
+    let mut tracker = StatsTracker::new(save_data.game_mut(), location);
+    let mut pointer = PointerTracker::new();
     loop {
-        let mut action = match window.next_event() {
-            Event::Quit => return Mode::Quit,
-            event => {
+        let event = window.next_event();
+        let pointer_x = pointer.update(&event);
+        let mut action = match event {
+            Event::Quit => {
+                tracker.update(&event, view.element(), save_data.game_mut());
+                record_dialogue(view.element_mut(), save_data.game_mut(),
+                                location);
+                record_undo_history(view.element(), save_data.game_mut(),
+                                    location);
+                return Mode::Quit;
+            }
+            _ => {
                 let action = view.handle_event(&event, save_data.game_mut());
-                view.drain_queue();
+                view.element_mut().drain_queue();
                 action
             }
         };
-        window.play_sounds(action.drain_sounds());
+        tracker.update(&event, view.element(), save_data.game_mut());
+        record_dialogue(view.element_mut(), save_data.game_mut(), location);
+        window.play_sounds(pan_sounds(action.drain_sounds(), pointer_x));
         match action.value() {
-            Some(&PuzzleCmd::Back) => return Mode::Location(Location::Map),
+            Some(&PuzzleCmd::Back) => {
+                record_undo_history(view.element(), save_data.game_mut(),
+                                    location);
+                return Mode::Location(Location::Map);
+            }
             Some(&PuzzleCmd::Info) => {
                 let game = save_data.game_mut();
                 game.ever_clicked_info = true;
-                let text = view.info_text(game);
-                if !run_info_box(window, &view, game, text) {
+                let catalog = window.resources().catalog();
+                let message = view.element().info_text(game);
+                let mut text = catalog.message(message).to_string();
+                if game.puzzle_state(location).is_solved() {
+                    text.push_str("\n\n");
+                    text.push_str(&game.stats(location).summary().join("\n"));
+                }
+                if !run_info_box(window, view.element(), game, &text) {
+                    record_undo_history(view.element(), game, location);
+                    return Mode::Quit;
+                }
+            }
+            Some(&PuzzleCmd::Log) => {
+                if !run_log_box(window,
+                                view.element(),
+                                save_data.game_mut(),
+                                location)
+                {
+                    record_undo_history(view.element(), save_data.game_mut(),
+                                        location);
                     return Mode::Quit;
                 }
             }
-            Some(&PuzzleCmd::Undo) => view.undo(save_data.game_mut()),
-            Some(&PuzzleCmd::Redo) => view.redo(save_data.game_mut()),
-            Some(&PuzzleCmd::Reset) => view.reset(save_data.game_mut()),
+            Some(&PuzzleCmd::Hint) => {
+                if view.element_mut().hint(save_data.game_mut()) {
+                    view.element_mut().drain_queue();
+                    record_undo_history(view.element(),
+                                        save_data.game_mut(),
+                                        location);
+                    if let Err(error) = save_data.save_to_disk() {
+                        println!("Failed to auto-save game: {}", error);
+                    }
+                } else {
+                    let game = save_data.game_mut();
+                    let text = window.resources().catalog()
+                        .message(NO_HINT_TEXT)
+                        .to_string();
+                    if !run_info_box(window, view.element(), game, &text) {
+                        record_undo_history(view.element(), game, location);
+                        return Mode::Quit;
+                    }
+                }
+            }
+            Some(&PuzzleCmd::Undo) => {
+                let game = save_data.game_mut();
+                game.stats_mut(location).undos += 1;
+                view.element_mut().undo(game);
+            }
+            Some(&PuzzleCmd::Redo) => {
+                view.element_mut().redo(save_data.game_mut())
+            }
+            Some(&PuzzleCmd::Reset) => {
+                let game = save_data.game_mut();
+                game.stats_mut(location).resets += 1;
+                view.element_mut().reset(game);
+            }
             Some(&PuzzleCmd::Replay) => {
-                save_data.game_mut().puzzle_state_mut(location).replay();
+                let game = save_data.game_mut();
+                game.puzzle_state_mut(location).replay();
+                game.set_undo_history(location, UndoHistory::default());
                 return Mode::Location(location);
             }
             Some(&PuzzleCmd::Solve) => {
-                view.solve(save_data.game_mut());
-                view.drain_queue();
+                view.element_mut().solve(save_data.game_mut());
+                view.element_mut().drain_queue();
+            }
+            Some(&PuzzleCmd::Timeline) => {
+                let game = save_data.game_mut();
+                let timeline = view.element().timeline();
+                let choice =
+                    run_timeline_box(window, view.element(), game, timeline);
+                match choice {
+                    TimelineChoice::Stay => {}
+                    TimelineChoice::JumpTo(node) => {
+                        jump_to_node(view.element_mut(), game, node);
+                    }
+                    TimelineChoice::Quit => {
+                        record_undo_history(view.element(), game, location);
+                        return Mode::Quit;
+                    }
+                }
             }
             Some(&PuzzleCmd::Next) => {
                 let mut next = location.next();
                 if !save_data.game_mut().is_unlocked(next) {
                     next = Location::Map;
                 }
+                record_undo_history(view.element(), save_data.game_mut(),
+                                    location);
                 return Mode::Location(next);
             }
             Some(&PuzzleCmd::Save) => {
+                record_undo_history(view.element(), save_data.game_mut(),
+                                    location);
                 if let Err(error) = save_data.save_to_disk() {
                     println!("Failed to auto-save game: {}", error);
                 }
@@ -87,3 +187,157 @@ pub fn run_puzzle<V: PuzzleView>(window: &mut Window,
 }
 
 // ========================================================================= //
+
+/// Returns the background music theme for the area that a location is in.
+fn location_music(location: Location) -> Music {
+    match location {
+        Location::Map => Music::map(),
+        Location::Prolog => Music::prolog(),
+        Location::ColumnAsIcyEm |
+        Location::IceToMeetYou |
+        Location::TheIceIsRight |
+        Location::ThreeBlindIce => Music::ice(),
+        Location::ALightInTheAttic |
+        Location::ConnectTheDots |
+        Location::Disconnected |
+        Location::LightSyrup |
+        Location::MissedConnections |
+        Location::PlaneAndSimple |
+        Location::PlaneAsDay |
+        Location::WreckedAngle => Music::circuits(),
+        Location::IfMemoryServes |
+        Location::JogYourMemory |
+        Location::LogLevel |
+        Location::MemoryLane |
+        Location::PasswordFile |
+        Location::FactOrFiction |
+        Location::TheYFactor => Music::memory(),
+        Location::SystemFailure | Location::SystemSyzygy => Music::failure(),
+        Location::Finale => Music::finale(),
+        _ => Music::machinery(),
+    }
+}
+
+/// Moves any dialogue spoken in the puzzle view since the last call into the
+/// location's saved transcript.
+fn record_dialogue<V: PuzzleView>(view: &mut V, game: &mut Game,
+                                  location: Location) {
+    for line in view.drain_transcript() {
+        game.record_dialogue(location, line);
+    }
+}
+
+/// Moves the puzzle view to the given node of its undo tree, by undoing and
+/// then redoing one move at a time.  Jumps aren't counted as undos in the
+/// puzzle's stats, since no moves are taken back for good.  If a step fails
+/// to move along the tree, the jump stops there.
+fn jump_to_node<V: PuzzleView>(view: &mut V, game: &mut Game, node: usize) {
+    let (undos, redos) = view.prepare_jump(node);
+    for step in 0..(undos + redos) {
+        let before = view.timeline().current();
+        if step < undos {
+            view.undo(game);
+        } else {
+            view.redo(game);
+        }
+        let after = view.timeline().current();
+        if after == before {
+            println!("Timeline jump stopped at node {} of {}.", after, node);
+            break;
+        }
+    }
+}
+
+/// Stores the puzzle view's undo tree in the location's save entry, so that
+/// it can be restored the next time the puzzle is entered.  A solved puzzle
+/// has nothing left to undo, so its history is dropped.
+fn record_undo_history<V: PuzzleView>(view: &V, game: &mut Game,
+                                      location: Location) {
+    let history = if game.puzzle_state(location).is_solved() {
+        UndoHistory::default()
+    } else {
+        view.undo_history()
+    };
+    game.set_undo_history(location, history);
+}
+
+// ========================================================================= //
+
+/// Remembers where the mouse was last seen, so that sounds made in response
+/// to mouse events can be panned to where the player is clicking.
+struct PointerTracker {
+    last_x: Option<i32>,
+}
+
+impl PointerTracker {
+    fn new() -> PointerTracker { PointerTracker { last_x: None } }
+
+    /// Returns the x-coordinate that sounds caused by the event should be
+    /// panned to, or `None` if the event has nothing to do with the mouse.
+    fn update(&mut self, event: &Event) -> Option<i32> {
+        match event {
+            &Event::MouseDown(pt) |
+            &Event::MouseDrag(pt) => {
+                self.last_x = Some(pt.x());
+                self.last_x
+            }
+            &Event::MouseUp => self.last_x,
+            _ => None,
+        }
+    }
+}
+
+/// Pans any sounds that the puzzle view didn't already position to the
+/// given x-coordinate, if any.
+fn pan_sounds(sounds: Vec<Sound>, x: Option<i32>) -> Vec<Sound> {
+    match x {
+        Some(x) => sounds.into_iter().map(|sound| sound.at_x(x)).collect(),
+        None => sounds,
+    }
+}
+
+// ========================================================================= //
+
+/// Adds the moves made and time spent in a puzzle view to the puzzle's
+/// statistics, and notes the date when the puzzle is first solved.
+struct StatsTracker {
+    location: Location,
+    was_solved: bool,
+    moves_counted: i32,
+    millis_uncounted: u32,
+}
+
+impl StatsTracker {
+    fn new(game: &Game, location: Location) -> StatsTracker {
+        StatsTracker {
+            location: location,
+            was_solved: game.has_been_solved(location),
+            moves_counted: 0,
+            millis_uncounted: 0,
+        }
+    }
+
+    /// Updates the puzzle's stats after an event.  Time spent is counted in
+    /// clock ticks rather than read from the system clock, so that replaying
+    /// recorded input produces the same stats.
+    fn update<V: PuzzleView>(&mut self, event: &Event, view: &V,
+                             game: &mut Game) {
+        let solved = !self.was_solved && game.has_been_solved(self.location);
+        let stats = game.stats_mut(self.location);
+        if event == &Event::ClockTick {
+            self.millis_uncounted += FRAME_DELAY_MILLIS;
+            let seconds = self.millis_uncounted / 1000;
+            self.millis_uncounted %= 1000;
+            stats.seconds = stats.seconds.saturating_add(seconds as i32);
+        }
+        let moves = view.moves_made();
+        stats.moves += moves - self.moves_counted;
+        self.moves_counted = moves;
+        if solved {
+            stats.mark_solved_today();
+            self.was_solved = true;
+        }
+    }
+}
+
+// ========================================================================= //
@@ -27,7 +27,15 @@ use self::view::View;
 
 // ========================================================================= //
 
+/// The catalog ID for translated answers and clues for this puzzle.
+const CLUES_ID: &str = "sauce.clues";
+
 pub fn run_cross_sauce(window: &mut Window, save_data: &mut SaveData) -> Mode {
+    let catalog = window.resources().catalog();
+    let clues = catalog.get_pairs(CLUES_ID);
+    if !save_data.game_mut().cross_sauce.localize_clues(clues) {
+        println!("Ignoring invalid {} in message catalog", CLUES_ID);
+    }
     let view = {
         let visible_rect = window.visible_rect();
         View::new(&mut window.resources(),
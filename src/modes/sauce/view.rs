@@ -19,7 +19,7 @@
 
 use elements::{FadeStyle, ProgressBar, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::cross::{ClueDisplay, InputDisplay};
-use gui::{Action, Canvas, Element, Event, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, Message, Rect, Resources, Sound};
 use modes::SOLVED_INFO_TEXT;
 use save::{Direction, Game, PuzzleState, SauceState};
 use super::scenes;
@@ -139,7 +139,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = ();
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.cross_sauce.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -190,7 +198,9 @@ impl PuzzleView for View {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.cross_sauce",
+    english: "\
 Your goal is to enter the two-word phrases
 hinted at by the clues.  Each pair of words will
 rhyme.
@@ -203,6 +213,7 @@ $M{Tap}{Click} the arrows to skip past a particular clue
 and come back to it later.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
+words of wisdom.",
+};
 
 // ========================================================================= //
@@ -0,0 +1,102 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use gui::{Element, Event, KeyboardFocus, Window};
+use save::Prefs;
+
+use super::view::{Cmd, View};
+
+// ========================================================================= //
+
+/// Applies the sound, text speed, and locale settings in `prefs` to the
+/// window.  (The
+/// fullscreen setting is not applied here, since it can be overridden by a
+/// command-line flag at startup.)
+pub fn apply_prefs(window: &mut Window, prefs: &Prefs) {
+    window.set_sound_gain(prefs.sound_gain());
+    window.set_music_gain(prefs.music_gain());
+    window.set_text_speed(prefs.text_speed());
+    window.set_locale(prefs.locale());
+    window.set_controller_map(prefs.controller_map().clone());
+}
+
+/// Shows the settings box on top of `original_view`.  Each change takes
+/// effect immediately and is written back into `prefs`.  Returns false if
+/// the player quit the game.
+pub fn run_settings_box<S, A, E: Element<S, A>>(window: &mut Window,
+                                                original_view: &E,
+                                                original_input: &mut S,
+                                                prefs: &mut Prefs)
+                                                -> bool {
+    let mut view = {
+        let visible = window.visible_rect();
+        let view =
+            View::new(&mut window.resources(), visible, original_view, prefs);
+        KeyboardFocus::new(view, visible)
+    };
+    window.render(original_input, &view);
+    loop {
+        let mut action = match window.next_event() {
+            Event::Quit => return false,
+            event => view.handle_event(&event, original_input),
+        };
+        window.play_sounds(action.drain_sounds());
+        match action.value() {
+            Some(&Cmd::Done) => return true,
+            Some(&Cmd::ToggleFullscreen) => {
+                let fullscreen = !prefs.fullscreen();
+                prefs.set_fullscreen(fullscreen);
+                window.set_fullscreen(fullscreen);
+            }
+            Some(&Cmd::VolumeDown) => {
+                let volume = prefs.volume() - VOLUME_STEP;
+                prefs.set_volume(volume);
+            }
+            Some(&Cmd::VolumeUp) => {
+                let volume = prefs.volume() + VOLUME_STEP;
+                prefs.set_volume(volume);
+            }
+            Some(&Cmd::MusicDown) => {
+                let volume = prefs.music_volume() - VOLUME_STEP;
+                prefs.set_music_volume(volume);
+            }
+            Some(&Cmd::MusicUp) => {
+                let volume = prefs.music_volume() + VOLUME_STEP;
+                prefs.set_music_volume(volume);
+            }
+            Some(&Cmd::ToggleMute) => {
+                let mute = !prefs.mute();
+                prefs.set_mute(mute);
+            }
+            Some(&Cmd::CycleTextSpeed) => prefs.cycle_text_speed(),
+            None => {}
+        }
+        if action.value().is_some() {
+            apply_prefs(window, prefs);
+            view.element_mut().update(prefs);
+        }
+        if action.should_redraw() {
+            window.render(original_input, &view);
+        }
+    }
+}
+
+const VOLUME_STEP: i32 = 10;
+
+// ========================================================================= //
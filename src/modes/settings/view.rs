@@ -0,0 +1,204 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use elements::DialogButton;
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Keycode,
+          Point, Rect, Resources, Sprite};
+use save::Prefs;
+
+// ========================================================================= //
+
+const PANEL_WIDTH: u32 = 256;
+const PANEL_HEIGHT: u32 = 202;
+const MARGIN: i32 = 20;
+const ROW_HEIGHT: i32 = 26;
+const BUTTON_WIDTH: u32 = 50;
+const BUTTON_HEIGHT: u32 = 20;
+const BUTTON_SPACING: i32 = 6;
+
+const FULLSCREEN_ROW: i32 = 0;
+const VOLUME_ROW: i32 = 1;
+const MUSIC_ROW: i32 = 2;
+const MUTE_ROW: i32 = 3;
+const TEXT_SPEED_ROW: i32 = 4;
+
+// ========================================================================= //
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Cmd {
+    ToggleFullscreen,
+    VolumeDown,
+    VolumeUp,
+    MusicDown,
+    MusicUp,
+    ToggleMute,
+    CycleTextSpeed,
+    Done,
+}
+
+// ========================================================================= //
+
+pub struct View<'a, A, E: 'a> {
+    original_view: &'a E,
+    rect: Rect,
+    bg_sprites: Vec<Sprite>,
+    font: Rc<Font>,
+    buttons: Vec<DialogButton<Cmd>>,
+    volume_label: String,
+    music_label: String,
+    phantom: PhantomData<A>,
+}
+
+impl<'a, A, E> View<'a, A, E> {
+    pub fn new(resources: &mut Resources, visible: Rect,
+               original_view: &'a E, prefs: &Prefs)
+               -> View<'a, A, E> {
+        let mut rect = Rect::new(0, 0, PANEL_WIDTH, PANEL_HEIGHT);
+        rect.center_on(visible.center());
+        let right = rect.right() - MARGIN - BUTTON_WIDTH as i32;
+        let left = right - BUTTON_SPACING - BUTTON_WIDTH as i32;
+        let bottom = rect.bottom() - MARGIN - BUTTON_HEIGHT as i32;
+        let row_top = |row: i32| rect.top() + MARGIN + row * ROW_HEIGHT;
+        let button_rect =
+            |x: i32, y: i32| Rect::new(x, y, BUTTON_WIDTH, BUTTON_HEIGHT);
+        let buttons = vec![
+            (button_rect(right, row_top(FULLSCREEN_ROW)),
+             Cmd::ToggleFullscreen),
+            (button_rect(left, row_top(VOLUME_ROW)), Cmd::VolumeDown),
+            (button_rect(right, row_top(VOLUME_ROW)), Cmd::VolumeUp),
+            (button_rect(left, row_top(MUSIC_ROW)), Cmd::MusicDown),
+            (button_rect(right, row_top(MUSIC_ROW)), Cmd::MusicUp),
+            (button_rect(right, row_top(MUTE_ROW)), Cmd::ToggleMute),
+            (button_rect(right, row_top(TEXT_SPEED_ROW)),
+             Cmd::CycleTextSpeed),
+            (button_rect(right, bottom), Cmd::Done),
+        ];
+        let buttons = buttons
+            .into_iter()
+            .map(|(rect, cmd)| {
+                     DialogButton::new(resources, rect, String::new(), cmd)
+                 })
+            .collect();
+        let mut view = View {
+            original_view: original_view,
+            rect: rect,
+            bg_sprites: resources.get_sprites("dialog/box"),
+            font: resources.get_font("roman"),
+            buttons: buttons,
+            volume_label: String::new(),
+            music_label: String::new(),
+            phantom: PhantomData,
+        };
+        view.update(prefs);
+        view
+    }
+
+    /// Refreshes the labels to match the current settings.
+    pub fn update(&mut self, prefs: &Prefs) {
+        self.volume_label = format!("Volume: {}%", prefs.volume());
+        self.music_label = format!("Music: {}%", prefs.music_volume());
+        let text_speed_label = if prefs.text_speed().is_infinite() {
+            "Instant".to_string()
+        } else {
+            format!("{}x", prefs.text_speed())
+        };
+        for button in self.buttons.iter_mut() {
+            let label = match *button.value() {
+                Cmd::ToggleFullscreen => {
+                    if prefs.fullscreen() { "On" } else { "Off" }
+                }
+                Cmd::VolumeDown | Cmd::MusicDown => "-",
+                Cmd::VolumeUp | Cmd::MusicUp => "+",
+                Cmd::ToggleMute => if prefs.mute() { "Muted" } else { "On" },
+                Cmd::CycleTextSpeed => &text_speed_label as &str,
+                Cmd::Done => "Done",
+            };
+            button.set_label(label.to_string());
+        }
+    }
+
+    fn draw_panel(&self, canvas: &mut Canvas) {
+        let mut canvas = canvas.subcanvas(self.rect);
+        canvas.fill_rect((200, 200, 200),
+                         Rect::new(11,
+                                   11,
+                                   self.rect.width() - 22,
+                                   self.rect.height() - 22));
+        let right = self.rect.width() as i32 - 16;
+        let bottom = self.rect.height() as i32 - 16;
+        canvas.draw_sprite(&self.bg_sprites[0], Point::new(0, 0));
+        canvas.draw_sprite(&self.bg_sprites[2], Point::new(right, 0));
+        canvas.draw_sprite(&self.bg_sprites[5], Point::new(0, bottom));
+        canvas.draw_sprite(&self.bg_sprites[7], Point::new(right, bottom));
+        for col in 1..(right / 16) {
+            let x = 16 * col;
+            canvas.draw_sprite(&self.bg_sprites[1], Point::new(x, 0));
+            canvas.draw_sprite(&self.bg_sprites[6], Point::new(x, bottom));
+        }
+        for row in 1..(bottom / 16) {
+            let y = 16 * row;
+            canvas.draw_sprite(&self.bg_sprites[3], Point::new(0, y));
+            canvas.draw_sprite(&self.bg_sprites[4], Point::new(right, y));
+        }
+        let labels = [
+            (FULLSCREEN_ROW, "Fullscreen:"),
+            (VOLUME_ROW, &self.volume_label as &str),
+            (MUSIC_ROW, &self.music_label as &str),
+            (MUTE_ROW, "Sound:"),
+            (TEXT_SPEED_ROW, "Text speed:"),
+        ];
+        for &(row, label) in labels.iter() {
+            let baseline = MARGIN + row * ROW_HEIGHT + 13;
+            canvas.draw_text(&self.font,
+                             Align::Left,
+                             Point::new(MARGIN, baseline),
+                             label);
+        }
+    }
+}
+
+impl<'a, S, A, E: Element<S, A>> Element<S, Cmd> for View<'a, A, E> {
+    fn draw(&self, state: &S, canvas: &mut Canvas) {
+        self.original_view.draw(state, canvas);
+        self.draw_panel(canvas);
+        self.buttons.draw(&(), canvas);
+    }
+
+    fn handle_event(&mut self, event: &Event, _: &mut S) -> Action<Cmd> {
+        match event {
+            &Event::MouseDown(pt) if !self.rect.contains_point(pt) => {
+                Action::redraw().and_return(Cmd::Done)
+            }
+            &Event::KeyDown(Keycode::Escape, _) |
+            &Event::KeyDown(Keycode::Return, _) => {
+                Action::redraw().and_return(Cmd::Done)
+            }
+            _ => self.buttons.handle_event(event, &mut ()),
+        }
+    }
+
+    fn focus_targets(&self, _: &S) -> Vec<FocusTarget> {
+        self.buttons.focus_targets(&())
+    }
+}
+
+// ========================================================================= //
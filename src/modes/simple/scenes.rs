@@ -17,8 +17,8 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use elements::{Ast, Scene, TalkPos, TalkStyle};
-use gui::{Rect, Resources, Sound};
+use elements::{Ast, Scene};
+use gui::{Rect, Resources};
 
 // ========================================================================= //
 
@@ -27,229 +27,280 @@ const YTTRIS: i32 = 1;
 
 // ========================================================================= //
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
 pub fn compile_intro_scene(resources: &mut Resources) -> Scene {
-    let ast = vec![
-        Ast::Seq(vec![
-            Ast::SetBg("plane_and_simple"),
-            Ast::Place(YTTRIS, "chars/yttris", 0, (444, 288)),
-            Ast::Wait(0.75),
-            Ast::Place(UGRENT, "chars/ugrent", 0, (-16, 304)),
-            Ast::Slide(UGRENT, (220, 304), false, true, 1.25),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                      "Hey there, Ugrent!\n\
-                       How's it going?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_lo()),
-            Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
-                      "Yttris.  You know anything about\n\
-                       the character mismatches in the\n\
-                       security checkpoint back there?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                      "Huh?  No.  What are\n\
-                       you talking about?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_lo()),
-            Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
-                      "Never mind.  What are\n\
-                       you working on here?"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                      "Oh, this thing?  This is the ship's\n\
-                       forward planar switchboard."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                      "That explosion shook all the\n\
-                       connectors loose, so now I have\n\
-                       to redo them all.  Booorrring!"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Slide(YTTRIS, (436, 288), true, true, 0.25),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                      "Oooh, maybe I should wire\n\
-                       them all up $ibackwards$r  this\n\
-                       time! $iThat$r  would be fun!"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Slide(UGRENT, (250, 304), true, true, 0.25),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
-                      "What!?  No!  Yttris, right\n\
-                       now we need to get the ship\n\
-                       back to normal.  This is no\n\
-                       time for improvising!"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                      "Aw, you're no fun."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_lo()),
-            Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
-                      "Look, the last thing we need\n\
-                       here is another explosion because\n\
-                       we didn't do things by the book."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Slide(UGRENT, (200, 304), true, true, 0.75),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
-                      "Let's just take this\n\
-                       thing in stages and do\n\
-                       them one at a time."),
-        ]),
-    ];
-    Ast::compile_scene(resources, ast)
+    Ast::load_scene(resources, "simple/intro")
 }
 
 // ========================================================================= //
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
 pub fn compile_ugrent_midscene(resources: &mut Resources) -> (i32, Scene) {
-    let ast = vec![
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
-                      "Each node has to connect\n\
-                       to each other node, so\n\
-                       there can only be so\n\
-                       many stages, right?"),
-        ]),
-    ];
-    (UGRENT, Ast::compile_scene(resources, ast))
+    (UGRENT, Ast::load_scene(resources, "simple/ugrent"))
 }
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
 pub fn compile_yttris_midscene(resources: &mut Resources) -> (i32, Scene) {
-    let ast = vec![
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                      "C'mon, this is an undirected\n\
-                       graph we're talking about.\n\
-                       Wiring it backwards doesn't\n\
-                       even make a difference!"),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_lo()),
-            Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
-                      "Look, let's just put\n\
-                       it back the same\n\
-                       way it was before."),
-        ]),
-    ];
-    (YTTRIS, Ast::compile_scene(resources, ast))
+    (YTTRIS, Ast::load_scene(resources, "simple/yttris"))
 }
 
 // ========================================================================= //
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
+/// Returns the `param`s for the outro script, which depend on how much of the
+/// screen is visible.
+fn outro_params(visible: Rect) -> [(&'static str, i32); 1] {
+    [("OFFSCREEN_X", visible.right() + 16)]
+}
+
 pub fn compile_outro_scene(resources: &mut Resources, visible: Rect) -> Scene {
-    let ast = vec![
-        Ast::Seq(vec![
-            Ast::Sound(Sound::solve_puzzle_chime()),
-            Ast::Wait(1.0),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                      "There!  All fixed."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Slide(UGRENT, (388, 304), true, true, 1.25),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NW,
-                      "Good.  Keep working on\n\
-                       repairs.  I need to continue\n\
-                       my security sweep."),
-        ]),
-        Ast::Par(vec![
+    let params = outro_params(visible);
+    Ast::load_scene_with_params(resources, "simple/outro", &params)
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use elements::{Ast, TalkPos, TalkStyle};
+    use gui::{Rect, Sound};
+    use super::{UGRENT, YTTRIS, outro_params};
+
+    #[test]
+    fn intro_script_matches_original() {
+        Ast::assert_script_matches("simple/intro", original_intro());
+    }
+
+    #[test]
+    fn ugrent_script_matches_original() {
+        Ast::assert_script_matches("simple/ugrent", original_ugrent());
+    }
+
+    #[test]
+    fn yttris_script_matches_original() {
+        Ast::assert_script_matches("simple/yttris", original_yttris());
+    }
+
+    #[test]
+    fn outro_script_matches_original() {
+        let visible = Rect::new(-32, -16, 640, 416);
+        Ast::assert_script_with_params_matches("simple/outro",
+                                               &outro_params(visible),
+                                               original_outro(visible));
+    }
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn original_intro() -> Vec<Ast> {
+        vec![
             Ast::Seq(vec![
-                Ast::Sound(Sound::small_jump()),
-                Ast::Jump(UGRENT, (436, 288), 0.5),
-                Ast::Sound(Sound::small_jump()),
-                Ast::Jump(UGRENT, (484, 272), 0.5),
-                Ast::Slide(UGRENT, (592, 272), false, false, 0.5),
-                Ast::SetPos(UGRENT, (visible.right() + 16, 272)),
+                Ast::SetBg("plane_and_simple"),
+                Ast::Place(YTTRIS, "chars/yttris", 0, (444, 288)),
+                Ast::Wait(0.75),
+                Ast::Place(UGRENT, "chars/ugrent", 0, (-16, 304)),
+                Ast::Slide(UGRENT, (220, 304), false, true, 1.25),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
+                          "Hey there, Ugrent!\n\
+                           How's it going?"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_lo()),
+                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
+                          "Yttris.  You know anything about\n\
+                           the character mismatches in the\n\
+                           security checkpoint back there?"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
+                          "Huh?  No.  What are\n\
+                           you talking about?"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_lo()),
+                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
+                          "Never mind.  What are\n\
+                           you working on here?"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
+                          "Oh, this thing?  This is the ship's\n\
+                           forward planar switchboard."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
+                          "That explosion shook all the\n\
+                           connectors loose, so now I have\n\
+                           to redo them all.  Booorrring!"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Slide(YTTRIS, (436, 288), true, true, 0.25),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
+                          "Oooh, maybe I should wire\n\
+                           them all up $ibackwards$r  this\n\
+                           time! $iThat$r  would be fun!"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Slide(UGRENT, (250, 304), true, true, 0.25),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
+                          "What!?  No!  Yttris, right\n\
+                           now we need to get the ship\n\
+                           back to normal.  This is no\n\
+                           time for improvising!"),
             ]),
             Ast::Seq(vec![
-                Ast::Wait(0.75),
                 Ast::Sound(Sound::talk_hi()),
                 Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
-                          "Will do!"),
-            ]),
-        ]),
-        Ast::Seq(vec![
-            Ast::Wait(0.5),
-            Ast::Sound(Sound::small_jump()),
-            Ast::Jump(YTTRIS, (388, 304), 0.5),
-            Ast::Slide(YTTRIS, (284, 304), true, true, 0.75),
-            Ast::Sound(Sound::talk_hi()),
-            Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NE,
-                      "He's gone!  Now's\n\
-                       my chance to flip\n\
-                       it all backwards!"),
-        ]),
-        Ast::Par(vec![
+                          "Aw, you're no fun."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_lo()),
+                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
+                          "Look, the last thing we need\n\
+                           here is another explosion because\n\
+                           we didn't do things by the book."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Slide(UGRENT, (200, 304), true, true, 0.75),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
+                          "Let's just take this\n\
+                           thing in stages and do\n\
+                           them one at a time."),
+            ]),
+        ]
+    }
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn original_ugrent() -> Vec<Ast> {
+        vec![
             Ast::Seq(vec![
                 Ast::Sound(Sound::talk_hi()),
-                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::W,
-                          "I heard that!"),
+                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
+                          "Each node has to connect\n\
+                           to each other node, so\n\
+                           there can only be so\n\
+                           many stages, right?"),
+            ]),
+        ]
+    }
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn original_yttris() -> Vec<Ast> {
+        vec![
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
+                          "C'mon, this is an undirected\n\
+                           graph we're talking about.\n\
+                           Wiring it backwards doesn't\n\
+                           even make a difference!"),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_lo()),
+                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NE,
+                          "Look, let's just put\n\
+                           it back the same\n\
+                           way it was before."),
+            ]),
+        ]
+    }
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn original_outro(visible: Rect) -> Vec<Ast> {
+        vec![
+            Ast::Seq(vec![
+                Ast::Sound(Sound::solve_puzzle_chime()),
+                Ast::Wait(1.0),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
+                          "There!  All fixed."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Slide(UGRENT, (388, 304), true, true, 1.25),
+                Ast::Sound(Sound::talk_hi()),
+                Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::NW,
+                          "Good.  Keep working on\n\
+                           repairs.  I need to continue\n\
+                           my security sweep."),
+            ]),
+            Ast::Par(vec![
+                Ast::Seq(vec![
+                    Ast::Sound(Sound::small_jump()),
+                    Ast::Jump(UGRENT, (436, 288), 0.5),
+                    Ast::Sound(Sound::small_jump()),
+                    Ast::Jump(UGRENT, (484, 272), 0.5),
+                    Ast::Slide(UGRENT, (592, 272), false, false, 0.5),
+                    Ast::SetPos(UGRENT, (visible.right() + 16, 272)),
+                ]),
+                Ast::Seq(vec![
+                    Ast::Wait(0.75),
+                    Ast::Sound(Sound::talk_hi()),
+                    Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NW,
+                              "Will do!"),
+                ]),
             ]),
             Ast::Seq(vec![
                 Ast::Wait(0.5),
+                Ast::Sound(Sound::small_jump()),
+                Ast::Jump(YTTRIS, (388, 304), 0.5),
+                Ast::Slide(YTTRIS, (284, 304), true, true, 0.75),
                 Ast::Sound(Sound::talk_hi()),
                 Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NE,
-                          "Haha!  I'm just\n\
-                           $ikidding$r, Ugrent!"),
-            ]),
-        ]),
-        Ast::Seq(vec![
-            Ast::Sound(Sound::talk_thought()),
-            Ast::Talk(YTTRIS, TalkStyle::Thought, TalkPos::NE,
-                      "No sense of\n\
-                       humor at all..."),
-        ]),
-        Ast::Seq(vec![
-            Ast::Remove(UGRENT),
-            Ast::Slide(YTTRIS, (-16, 304), true, false, 1.0),
-            Ast::Remove(YTTRIS),
-            Ast::Wait(1.0),
-            Ast::Queue(1, 7),
-            Ast::Wait(0.1),
-            Ast::Queue(0, 0),
-            Ast::Queue(1, 6),
-            Ast::Wait(0.1),
-            Ast::Queue(0, 1),
-            Ast::Queue(1, 5),
-            Ast::Wait(0.1),
-            Ast::Queue(0, 2),
-            Ast::Queue(1, 4),
-            Ast::Wait(0.1),
-            Ast::Queue(0, 3),
-            Ast::Queue(1, 3),
-            Ast::Wait(0.1),
-            Ast::Queue(0, 4),
-            Ast::Queue(1, 2),
-            Ast::Wait(0.1),
-            Ast::Queue(0, 5),
-            Ast::Queue(1, 1),
-            Ast::Wait(0.1),
-            Ast::Queue(1, 0),
-            Ast::Wait(1.0),
-        ]),
-    ];
-    Ast::compile_scene(resources, ast)
+                          "He's gone!  Now's\n\
+                           my chance to flip\n\
+                           it all backwards!"),
+            ]),
+            Ast::Par(vec![
+                Ast::Seq(vec![
+                    Ast::Sound(Sound::talk_hi()),
+                    Ast::Talk(UGRENT, TalkStyle::Normal, TalkPos::W,
+                              "I heard that!"),
+                ]),
+                Ast::Seq(vec![
+                    Ast::Wait(0.5),
+                    Ast::Sound(Sound::talk_hi()),
+                    Ast::Talk(YTTRIS, TalkStyle::Normal, TalkPos::NE,
+                              "Haha!  I'm just\n\
+                               $ikidding$r, Ugrent!"),
+                ]),
+            ]),
+            Ast::Seq(vec![
+                Ast::Sound(Sound::talk_thought()),
+                Ast::Talk(YTTRIS, TalkStyle::Thought, TalkPos::NE,
+                          "No sense of\n\
+                           humor at all..."),
+            ]),
+            Ast::Seq(vec![
+                Ast::Remove(UGRENT),
+                Ast::Slide(YTTRIS, (-16, 304), true, false, 1.0),
+                Ast::Remove(YTTRIS),
+                Ast::Wait(1.0),
+                Ast::Queue(1, 7),
+                Ast::Wait(0.1),
+                Ast::Queue(0, 0),
+                Ast::Queue(1, 6),
+                Ast::Wait(0.1),
+                Ast::Queue(0, 1),
+                Ast::Queue(1, 5),
+                Ast::Wait(0.1),
+                Ast::Queue(0, 2),
+                Ast::Queue(1, 4),
+                Ast::Wait(0.1),
+                Ast::Queue(0, 3),
+                Ast::Queue(1, 3),
+                Ast::Wait(0.1),
+                Ast::Queue(0, 4),
+                Ast::Queue(1, 2),
+                Ast::Wait(0.1),
+                Ast::Queue(0, 5),
+                Ast::Queue(1, 1),
+                Ast::Wait(0.1),
+                Ast::Queue(1, 0),
+                Ast::Wait(1.0),
+            ]),
+        ]
+    }
 }
 
 // ========================================================================= //
@@ -19,7 +19,8 @@
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::plane::{PlaneCmd, PlaneGridView};
-use gui::{Action, Canvas, Element, Event, Point, Rect, Resources, Sound};
+use gui::{Action, Canvas, Element, Event, FocusTarget, Message, Point, Rect,
+          Resources, Sound};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, PuzzleState, SimpleState};
 use super::scenes;
@@ -90,10 +91,27 @@ impl Element<Game, PuzzleCmd> for View {
         }
         action
     }
+
+    fn focus_targets(&self, game: &Game) -> Vec<FocusTarget> {
+        let state = &game.plane_and_simple;
+        let mut targets = self.core.focus_targets(state);
+        if !state.is_solved() {
+            targets.extend(self.grid.focus_targets(state.grid()));
+        }
+        targets
+    }
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = Vec<(Point, Point)>;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.plane_and_simple.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -156,7 +174,9 @@ const WORDS: &[(i32, i32, &[char])] = &[
     (1, 8, &['I', 'O', 'U', 'S', 'N', 'E', 'S', 'S']),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.plane_and_simple",
+    english: "\
 Your goal is to connect each purple node to each other
 purple node.
 
@@ -164,6 +184,7 @@ Drag across the grid with $M{your finger}{the mouse} to create or
 remove pipes between the nodes.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
 // ========================================================================= //
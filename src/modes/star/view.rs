@@ -22,7 +22,7 @@ use std::collections::HashSet;
 use std::rc::Rc;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, PuzzleState, StarState, WordDir};
@@ -99,7 +99,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = ();
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.star_crossed.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -419,7 +427,9 @@ impl Drag {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.star_crossed",
+    english: "\
 Your goal is to remove all 24 listed words from the grid.
 
 Search across, down, and diagonally within the grid of letters
@@ -429,6 +439,7 @@ remove them from the grid.  The remaining grid letters will
 fall into place.  Continue this process until the list is empty.
 
 $M{Tap}{Click} on a character in the scene to hear their words of
-wisdom.";
+wisdom.",
+};
 
 // ========================================================================= //
@@ -21,7 +21,7 @@ use std::cmp;
 use std::rc::Rc;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, PrimaryColor, PuzzleState, SyrupState};
@@ -114,7 +114,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (i32, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.light_syrup.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -393,7 +401,9 @@ fn tick_radius(lit: bool, radius: &mut i32) -> bool {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.light_syrup",
+    english: "\
 Your goal is to turn all twenty-one lights WHITE.
 
 $M{Tap}{Click} one of the lights to change the color of that
@@ -403,6 +413,7 @@ will toggle greenness, the third blueness, and then
 back to red.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
 // ========================================================================= //
@@ -18,6 +18,7 @@
 // +--------------------------------------------------------------------------+
 
 use std::rc::Rc;
+use toml;
 
 use elements;
 use elements::column::ColumnsView;
@@ -27,6 +28,7 @@ use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
           Resources, Sound, Sprite};
 use save::SyzygyState;
 use save::ice::BlockSlide;
+use save::util::{Tomlable, to_table};
 
 // ========================================================================= //
 
@@ -40,6 +42,36 @@ pub enum MezureCmd {
     Columns(usize, i32),
 }
 
+impl Tomlable for MezureCmd {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        match *self {
+            MezureCmd::Pipes(ref changes) => {
+                table.insert("pipes".to_string(), changes.to_toml());
+            }
+            MezureCmd::IceBlocks(ref slide) => {
+                table.insert("ice".to_string(), slide.to_toml());
+            }
+            MezureCmd::Columns(col, by) => {
+                table.insert("columns".to_string(), (col, by).to_toml());
+            }
+        }
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> MezureCmd {
+        let mut table = to_table(value);
+        if let Some(slide) = table.remove("ice") {
+            MezureCmd::IceBlocks(BlockSlide::from_toml(slide))
+        } else if let Some(columns) = table.remove("columns") {
+            let (col, by) = <(usize, i32)>::from_toml(columns);
+            MezureCmd::Columns(col, by)
+        } else {
+            MezureCmd::Pipes(Vec::pop_from_table(&mut table, "pipes"))
+        }
+    }
+}
+
 // ========================================================================= //
 
 pub struct MezureView {
@@ -86,7 +118,7 @@ impl MezureView {
         let positions = {
             let grid = state.mezure_laser_grid();
             self.laser_grid.recalculate_lasers(grid);
-            self.laser_grid.satisfied_detector_positions(grid)
+            self.laser_grid.beams().satisfied_detectors().clone()
         };
         state.set_mezure_satisfied_detectors(positions);
         for (index, &lit) in state.mezure_lights().iter().enumerate() {
@@ -129,9 +161,9 @@ impl Element<SyzygyState, MezureCmd> for MezureView {
         self.columns.draw(state.mezure_columns(), canvas);
         self.laser_grid.draw_immovables(state.mezure_laser_grid(), canvas);
         self.ice_grid.draw_objects(state.mezure_ice_grid(), canvas);
-        self.laser_grid.draw_lasers(canvas);
+        self.laser_grid.draw_lasers(state.mezure_laser_grid(), canvas);
         self.ice_grid.draw_ice_blocks(state.mezure_ice_grid(), canvas);
-        self.laser_grid.draw_sparks(canvas);
+        self.laser_grid.draw_sparks(state.mezure_laser_grid(), canvas);
         self.pipe_grid.draw(state.mezure_pipe_grid(), canvas);
         for column in 0..6 {
             let sprite_index = if state.mezure_satisfied()[column] {
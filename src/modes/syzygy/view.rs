@@ -19,15 +19,17 @@
 
 use std::cmp;
 use std::rc::Rc;
+use toml;
 
 use elements::{self, FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
 use elements::column::ColumnsView;
 use elements::lasers::{LaserCmd, LaserField};
 use elements::plane::{PlaneCmd, PlaneGridView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{self, Game, PuzzleState, SyzygyStage, SyzygyState};
+use save::util::{Tomlable, to_table};
 use super::mezure::{MezureCmd, MezureView};
 use super::relyng::LightsGrid;
 use super::scenes;
@@ -35,7 +37,7 @@ use super::scenes;
 // ========================================================================= //
 
 #[derive(Clone)]
-enum UndoRedo {
+pub enum UndoRedo {
     Yttris(usize, i32),
     Argony(save::ice::BlockSlide),
     Elinsa(Vec<(Point, Point)>),
@@ -44,6 +46,41 @@ enum UndoRedo {
     Mezure(MezureCmd),
 }
 
+impl Tomlable for UndoRedo {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        let (stage, value) = match *self {
+            UndoRedo::Yttris(col, by) => ("yttris", (col, by).to_toml()),
+            UndoRedo::Argony(ref slide) => ("argony", slide.to_toml()),
+            UndoRedo::Elinsa(ref changes) => ("elinsa", changes.to_toml()),
+            UndoRedo::Ugrent(ref cmd) => ("ugrent", cmd.to_toml()),
+            UndoRedo::Relyng(coords) => ("relyng", coords.to_toml()),
+            UndoRedo::Mezure(ref cmd) => ("mezure", cmd.to_toml()),
+        };
+        table.insert(stage.to_string(), value);
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> UndoRedo {
+        let table = to_table(value);
+        let (stage, value) = match table.into_iter().next() {
+            Some(entry) => entry,
+            None => return UndoRedo::Elinsa(Vec::new()),
+        };
+        match stage.as_str() {
+            "yttris" => {
+                let (col, by) = <(usize, i32)>::from_toml(value);
+                UndoRedo::Yttris(col, by)
+            }
+            "argony" => UndoRedo::Argony(Tomlable::from_toml(value)),
+            "ugrent" => UndoRedo::Ugrent(Tomlable::from_toml(value)),
+            "relyng" => UndoRedo::Relyng(Tomlable::from_toml(value)),
+            "mezure" => UndoRedo::Mezure(Tomlable::from_toml(value)),
+            _ => UndoRedo::Elinsa(Tomlable::from_toml(value)),
+        }
+    }
+}
+
 // ========================================================================= //
 
 const MAX_REVEAL: i32 = 144;
@@ -278,9 +315,7 @@ impl Element<Game, PuzzleCmd> for View {
                         self.ugrent
                             .handle_event(event, state.ugrent_grid_mut());
                     if let Some(&cmd) = subaction.value() {
-                        if self.ugrent
-                            .all_detectors_satisfied(state.ugrent_grid())
-                        {
+                        if self.ugrent.beams().all_detectors_satisfied() {
                             self.core.clear_undo_redo();
                             state.advance_stage();
                             self.core
@@ -330,7 +365,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = UndoRedo;
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.system_syzygy.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -730,7 +773,9 @@ impl Element<(), ()> for Atlatl {
 
 // ========================================================================= //
 
-const YTTRIS_INFO_BOX_TEXT: &str = "\
+const YTTRIS_INFO_BOX_TEXT: Message = Message {
+    id: "info.system_syzygy.yttris",
+    english: "\
 Your goal is to slide the columns of letters until the
 highlighted letters form a word horizontally across.
 There is only one possible word that can be formed.
@@ -740,9 +785,12 @@ its letters.  Moving one column may also cause other
 columns to move at the same time.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
-const ARGONY_INFO_BOX_TEXT: &str = "\
+const ARGONY_INFO_BOX_TEXT: Message = Message {
+    id: "info.system_syzygy.argony",
+    english: "\
 Your goal is to slide the blocks of ice until each one
 covers its matching symbol on the grid, in the same
 orientation and chirality.
@@ -751,9 +799,12 @@ Drag one of the ice blocks up, down, left, or right with
 $M{your finger}{the mouse} to slide it in that direction.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
-const ELINSA_INFO_BOX_TEXT: &str = "\
+const ELINSA_INFO_BOX_TEXT: Message = Message {
+    id: "info.system_syzygy.elinsa",
+    english: "\
 Your goal is to connect each red node to each blue
 node.  The purple node counts as both red and blue.
 
@@ -761,9 +812,12 @@ Drag across the grid with $M{your finger}{the mouse} to create or
 remove pipes between the nodes.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
-const UGRENT_INFO_BOX_TEXT: &str = "\
+const UGRENT_INFO_BOX_TEXT: Message = Message {
+    id: "info.system_syzygy.ugrent",
+    english: "\
 Your goal is to activate each detector on the right with
 the appropriate color of laser.
 
@@ -772,9 +826,12 @@ move their positions in the grid.  $M{Tap}{Click} objects to rotate
 them.
 
 $M{Tap}{Click} on a character in the scene to hear their words of
-wisdom.";
+wisdom.",
+};
 
-const RELYNG_INFO_BOX_TEXT: &str = "\
+const RELYNG_INFO_BOX_TEXT: Message = Message {
+    id: "info.system_syzygy.relyng",
+    english: "\
 Your goal is to turn all twenty lights OFF.
 
 $M{Tap}{Click} one of the lights to toggle that light and some
@@ -782,9 +839,13 @@ of the adjacent lights.  The pattern of adjacent
 lights toggled will change after each move.
 
 $M{Tap}{Click} on a character in the scene to hear their
-words of wisdom.";
-
-const MEZURE_INFO_BOX_TEXT: &str = "\
-Your goal is to form the final, missing word.";
+words of wisdom.",
+};
+
+const MEZURE_INFO_BOX_TEXT: Message = Message {
+    id: "info.system_syzygy.mezure",
+    english: "\
+Your goal is to form the final, missing word.",
+};
 
 // ========================================================================= //
@@ -0,0 +1,78 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use elements::Timeline;
+use gui::{Element, Event, KeyboardFocus, Window};
+
+use super::view::{Cmd, View};
+
+// ========================================================================= //
+
+/// What the player chose to do from the timeline overlay.
+pub enum TimelineChoice {
+    /// Close the overlay without changing anything.
+    Stay,
+    /// Jump to the given node of the undo tree.
+    JumpTo(usize),
+    /// Quit the game.
+    Quit,
+}
+
+// ========================================================================= //
+
+/// Shows the undo tree on top of `original_view`, and lets the player pick
+/// an earlier (or abandoned) puzzle state to jump to.
+pub fn run_timeline_box<S, A, E: Element<S, A>>(window: &mut Window,
+                                                original_view: &E,
+                                                original_input: &mut S,
+                                                timeline: Timeline)
+                                                -> TimelineChoice {
+    let mut view = {
+        let visible = window.visible_rect();
+        let view = View::new(&mut window.resources(),
+                             visible,
+                             original_view,
+                             timeline);
+        KeyboardFocus::new(view, visible)
+    };
+    window.render(original_input, &view);
+    loop {
+        let mut action = match window.next_event() {
+            Event::Quit => return TimelineChoice::Quit,
+            event => view.handle_event(&event, original_input),
+        };
+        window.play_sounds(action.drain_sounds());
+        match action.value() {
+            Some(&Cmd::Jump) => {
+                let view = view.element();
+                if view.selected() == view.timeline().current() {
+                    return TimelineChoice::Stay;
+                }
+                return TimelineChoice::JumpTo(view.selected());
+            }
+            Some(&Cmd::Cancel) => return TimelineChoice::Stay,
+            None => {}
+        }
+        if action.should_redraw() {
+            window.render(original_input, &view);
+        }
+    }
+}
+
+// ========================================================================= //
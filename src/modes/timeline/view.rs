@@ -0,0 +1,317 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::cmp;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use elements::{DialogButton, Paragraph, Timeline};
+use gui::{Action, Align, Canvas, Element, Event, FocusTarget, Font, Keycode,
+          Message, Point, Rect, Resources, Sound, Sprite};
+
+// ========================================================================= //
+
+const PANEL_WIDTH: u32 = 400;
+const PANEL_HEIGHT: u32 = 240;
+const MARGIN: i32 = 20;
+const TITLE_HEIGHT: i32 = 24;
+const CAPTION_HEIGHT: i32 = 16;
+const BUTTON_WIDTH: u32 = 50;
+const BUTTON_HEIGHT: u32 = 20;
+const BUTTON_SPACING: i32 = 6;
+
+const TREE_PADDING: i32 = 8;
+const MAX_NODE_SPACING: i32 = 16;
+const MAX_LANE_SPACING: i32 = 16;
+const NODE_SIZE: u32 = 4;
+const CURRENT_NODE_SIZE: u32 = 6;
+const SELECTION_SIZE: u32 = 10;
+const PICK_RADIUS: i32 = 8;
+
+const TREE_BG_COLOR: (u8, u8, u8) = (160, 160, 160);
+const BRANCH_COLOR: (u8, u8, u8) = (96, 96, 96);
+const NODE_COLOR: (u8, u8, u8) = (32, 32, 128);
+const CURRENT_COLOR: (u8, u8, u8) = (0, 160, 0);
+const SELECTION_COLOR: (u8, u8, u8) = (200, 0, 0);
+
+const TITLE: Message = Message {
+    id: "timeline.title",
+    english: "Undo Timeline",
+};
+
+// ========================================================================= //
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Cmd {
+    Jump,
+    Cancel,
+}
+
+// ========================================================================= //
+
+pub struct View<'a, A, E: 'a> {
+    original_view: &'a E,
+    rect: Rect,
+    tree_rect: Rect,
+    bg_sprites: Vec<Sprite>,
+    font: Rc<Font>,
+    title: Paragraph,
+    buttons: Vec<DialogButton<Cmd>>,
+    timeline: Timeline,
+    selected: usize,
+    phantom: PhantomData<A>,
+}
+
+impl<'a, A, E> View<'a, A, E> {
+    pub fn new(resources: &mut Resources, visible: Rect,
+               original_view: &'a E, timeline: Timeline)
+               -> View<'a, A, E> {
+        let mut rect = Rect::new(0, 0, PANEL_WIDTH, PANEL_HEIGHT);
+        rect.center_on(visible.center());
+        let bottom = rect.bottom() - MARGIN - BUTTON_HEIGHT as i32;
+        let tree_top = rect.top() + MARGIN + TITLE_HEIGHT;
+        let tree_rect = Rect::new(rect.left() + MARGIN,
+                                  tree_top,
+                                  PANEL_WIDTH - 2 * MARGIN as u32,
+                                  (bottom - BUTTON_SPACING - CAPTION_HEIGHT -
+                                       tree_top) as u32);
+        let right = rect.right() - MARGIN - BUTTON_WIDTH as i32;
+        let step = BUTTON_WIDTH as i32 + BUTTON_SPACING;
+        let buttons = vec![
+            (right - step, "Jump", Cmd::Jump),
+            (right, "Cancel", Cmd::Cancel),
+        ];
+        let buttons = buttons
+            .into_iter()
+            .map(|(x, label, cmd)| {
+                let rect = Rect::new(x, bottom, BUTTON_WIDTH, BUTTON_HEIGHT);
+                DialogButton::new(resources, rect, label.to_string(), cmd)
+            })
+            .collect();
+        let title = resources.catalog().message(TITLE).to_string();
+        let selected = timeline.current();
+        View {
+            original_view: original_view,
+            rect: rect,
+            tree_rect: tree_rect,
+            bg_sprites: resources.get_sprites("dialog/box"),
+            font: resources.get_font("roman"),
+            title: Paragraph::new(resources, "roman", Align::Center, &title),
+            buttons: buttons,
+            timeline: timeline,
+            selected: selected,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn timeline(&self) -> &Timeline { &self.timeline }
+
+    pub fn selected(&self) -> usize { self.selected }
+
+    fn select(&mut self, index: Option<usize>) -> Action<Cmd> {
+        match index {
+            Some(index) if index != self.selected => {
+                self.selected = index;
+                Action::redraw().and_stop()
+            }
+            _ => Action::ignore().and_stop(),
+        }
+    }
+
+    /// Returns where to draw the given node, spreading the tree out to fill
+    /// the tree area (but no further apart than the maximum spacing).
+    fn node_point(&self, index: usize) -> Point {
+        let node = &self.timeline.nodes()[index];
+        let width = self.tree_rect.width() as i32 - 2 * TREE_PADDING;
+        let height = self.tree_rect.height() as i32 - 2 * TREE_PADDING;
+        let cols = cmp::max(self.timeline.max_depth() as i32,
+                            cmp::max(1, width / MAX_NODE_SPACING));
+        let rows = cmp::max(self.timeline.num_lanes() as i32 - 1,
+                            cmp::max(1, height / MAX_LANE_SPACING));
+        Point::new(self.tree_rect.left() + TREE_PADDING +
+                       node.depth as i32 * width / cols,
+                   self.tree_rect.top() + TREE_PADDING +
+                       node.lane as i32 * height / rows)
+    }
+
+    fn node_at_point(&self, pt: Point) -> Option<usize> {
+        let mut best: Option<(i32, usize)> = None;
+        for index in 0..self.timeline.nodes().len() {
+            let delta = self.node_point(index) - pt;
+            let dist = cmp::max(delta.x().abs(), delta.y().abs());
+            if dist <= PICK_RADIUS &&
+                best.map(|(best_dist, _)| dist < best_dist).unwrap_or(true)
+            {
+                best = Some((dist, index));
+            }
+        }
+        best.map(|(_, index)| index)
+    }
+
+    fn draw_panel(&self, canvas: &mut Canvas) {
+        let mut canvas = canvas.subcanvas(self.rect);
+        canvas.fill_rect((200, 200, 200),
+                         Rect::new(11,
+                                   11,
+                                   self.rect.width() - 22,
+                                   self.rect.height() - 22));
+        let right = self.rect.width() as i32 - 16;
+        let bottom = self.rect.height() as i32 - 16;
+        canvas.draw_sprite(&self.bg_sprites[0], Point::new(0, 0));
+        canvas.draw_sprite(&self.bg_sprites[2], Point::new(right, 0));
+        canvas.draw_sprite(&self.bg_sprites[5], Point::new(0, bottom));
+        canvas.draw_sprite(&self.bg_sprites[7], Point::new(right, bottom));
+        for col in 1..(right / 16) {
+            let x = 16 * col;
+            canvas.draw_sprite(&self.bg_sprites[1], Point::new(x, 0));
+            canvas.draw_sprite(&self.bg_sprites[6], Point::new(x, bottom));
+        }
+        for row in 1..(bottom / 16) {
+            let y = 16 * row;
+            canvas.draw_sprite(&self.bg_sprites[3], Point::new(0, y));
+            canvas.draw_sprite(&self.bg_sprites[4], Point::new(right, y));
+        }
+        let title_rect = Rect::new(MARGIN,
+                                   MARGIN,
+                                   self.rect.width() - 2 * MARGIN as u32,
+                                   TITLE_HEIGHT as u32);
+        self.title.draw(&mut canvas.subcanvas(title_rect));
+    }
+
+    fn draw_tree(&self, canvas: &mut Canvas) {
+        canvas.fill_rect(TREE_BG_COLOR, self.tree_rect);
+        let mut canvas = canvas.clipped(self.tree_rect);
+        let nodes = self.timeline.nodes();
+        // Each branch leaves its parent vertically, then runs horizontally
+        // along its own lane.
+        for index in 1..nodes.len() {
+            let from = self.node_point(nodes[index].parent);
+            let to = self.node_point(index);
+            let top = cmp::min(from.y(), to.y());
+            canvas.fill_rect(BRANCH_COLOR,
+                             Rect::new(from.x() - 1,
+                                       top - 1,
+                                       2,
+                                       ((from.y() - to.y()).abs() + 2) as
+                                           u32));
+            canvas.fill_rect(BRANCH_COLOR,
+                             Rect::new(from.x() - 1,
+                                       to.y() - 1,
+                                       (to.x() - from.x() + 2) as u32,
+                                       2));
+        }
+        for index in 0..nodes.len() {
+            let size = if index == self.timeline.current() {
+                CURRENT_NODE_SIZE
+            } else {
+                NODE_SIZE
+            };
+            let mut rect = Rect::new(0, 0, size, size);
+            rect.center_on(self.node_point(index));
+            let color = if index == self.timeline.current() {
+                CURRENT_COLOR
+            } else {
+                NODE_COLOR
+            };
+            canvas.fill_rect(color, rect);
+        }
+        let mut rect = Rect::new(0, 0, SELECTION_SIZE, SELECTION_SIZE);
+        rect.center_on(self.node_point(self.selected));
+        canvas.draw_rect(SELECTION_COLOR, rect);
+    }
+
+    fn draw_caption(&self, canvas: &mut Canvas) {
+        let depth = self.timeline.nodes()[self.selected].depth;
+        let mut caption = if depth == 0 {
+            "Start".to_string()
+        } else {
+            format!("Move {}", depth)
+        };
+        if self.selected == self.timeline.current() {
+            caption.push_str(" (current)");
+        }
+        let start = Point::new(self.tree_rect.left(),
+                               self.tree_rect.bottom() + CAPTION_HEIGHT - 2);
+        canvas.draw_text(&self.font, Align::Left, start, &caption);
+    }
+}
+
+impl<'a, S, A, E: Element<S, A>> Element<S, Cmd> for View<'a, A, E> {
+    fn draw(&self, state: &S, canvas: &mut Canvas) {
+        self.original_view.draw(state, canvas);
+        self.draw_panel(canvas);
+        self.draw_tree(canvas);
+        self.draw_caption(canvas);
+        self.buttons.draw(&(), canvas);
+    }
+
+    fn handle_event(&mut self, event: &Event, _: &mut S) -> Action<Cmd> {
+        match event {
+            &Event::MouseDown(pt) if !self.rect.contains_point(pt) => {
+                Action::redraw().and_return(Cmd::Cancel)
+            }
+            &Event::MouseDown(pt) if self.tree_rect.contains_point(pt) => {
+                let index = self.node_at_point(pt);
+                self.select(index)
+            }
+            &Event::KeyDown(Keycode::Escape, _) => {
+                Action::redraw().and_return(Cmd::Cancel)
+            }
+            &Event::KeyDown(Keycode::Return, _) => jump_action(),
+            &Event::KeyDown(Keycode::Left, _) => {
+                let index = self.timeline.earlier(self.selected);
+                self.select(index)
+            }
+            &Event::KeyDown(Keycode::Right, _) => {
+                let index = self.timeline.later(self.selected);
+                self.select(index)
+            }
+            &Event::KeyDown(Keycode::Up, _) => {
+                let index = self.timeline.across(self.selected, true);
+                self.select(index)
+            }
+            &Event::KeyDown(Keycode::Down, _) => {
+                let index = self.timeline.across(self.selected, false);
+                self.select(index)
+            }
+            _ => {
+                let mut action = self.buttons.handle_event(event, &mut ());
+                match action.take_value() {
+                    Some(Cmd::Jump) => jump_action(),
+                    Some(Cmd::Cancel) => action.and_return(Cmd::Cancel),
+                    None => action,
+                }
+            }
+        }
+    }
+
+    fn focus_targets(&self, _: &S) -> Vec<FocusTarget> {
+        self.buttons.focus_targets(&())
+    }
+}
+
+// ========================================================================= //
+
+fn jump_action() -> Action<Cmd> {
+    let mut action = Action::redraw().and_return(Cmd::Jump);
+    action.also_play_sound(Sound::undo());
+    action
+}
+
+// ========================================================================= //
@@ -18,15 +18,17 @@
 // +--------------------------------------------------------------------------+
 
 use gui::{Element, Event, Window};
-use modes::{Mode, run_info_box};
+use modes::{Mode, run_info_box, run_settings_box};
 use save::SaveData;
 
-use super::view::{ABOUT_BOX_TEXT, Cmd, ConfirmEraseView, View};
+use super::view::{ABOUT_BOX_TEXT, Cmd, ConfirmEraseView, SlotPickerView,
+                  View};
 
 // ========================================================================= //
 
 pub fn run_title_screen(window: &mut Window, data: &mut SaveData) -> Mode {
     let mut view = View::new(&mut window.resources());
+    window.play_music(None);
     window.render(data, &view);
     loop {
         let mut action = match window.next_event() {
@@ -45,6 +47,26 @@ pub fn run_title_screen(window: &mut Window, data: &mut SaveData) -> Mode {
                 }
                 return Mode::Location(location);
             }
+            Some(&Cmd::ChooseSlot) => {
+                if !choose_slot(window, &view, data) {
+                    return Mode::Quit;
+                }
+                view.reset_buttons();
+                if let Err(error) = data.save_to_disk() {
+                    println!("Failed to save game: {}", error);
+                }
+            }
+            Some(&Cmd::ShowSettings) => {
+                let mut prefs = data.prefs().clone();
+                if !run_settings_box(window, &view, data, &mut prefs) {
+                    return Mode::Quit;
+                }
+                view.reset_buttons();
+                *data.prefs_mut() = prefs;
+                if let Err(error) = data.save_to_disk() {
+                    println!("Failed to save game: {}", error);
+                }
+            }
             Some(&Cmd::EraseGame) => {
                 let confirmed = match confirm_erase(window, &view, data) {
                     Confirmation::Confirm(value) => value,
@@ -59,7 +81,9 @@ pub fn run_title_screen(window: &mut Window, data: &mut SaveData) -> Mode {
                 }
             }
             Some(&Cmd::ShowAboutBox) => {
-                if !run_info_box(window, &view, data, ABOUT_BOX_TEXT) {
+                let catalog = window.resources().catalog();
+                let text = catalog.message(ABOUT_BOX_TEXT);
+                if !run_info_box(window, &view, data, text) {
                     return Mode::Quit;
                 }
                 view.reset_buttons();
@@ -75,6 +99,34 @@ pub fn run_title_screen(window: &mut Window, data: &mut SaveData) -> Mode {
 
 // ========================================================================= //
 
+/// Lets the player pick which save slot to use (and rename slots).  Returns
+/// false if the player quit the game.
+fn choose_slot(window: &mut Window, title_view: &View, data: &mut SaveData)
+               -> bool {
+    let mut view = {
+        let visible = window.visible_rect();
+        SlotPickerView::new(&mut window.resources(), visible, title_view, data)
+    };
+    window.render(data, &view);
+    loop {
+        let mut action = match window.next_event() {
+            Event::Quit => return false,
+            event => view.handle_event(&event, data),
+        };
+        window.play_sounds(action.drain_sounds());
+        if let Some(&choice) = action.value() {
+            if let Some(index) = choice {
+                data.select_slot(index);
+            }
+            return true;
+        } else if action.should_redraw() {
+            window.render(data, &view);
+        }
+    }
+}
+
+// ========================================================================= //
+
 enum Confirmation {
     Confirm(bool),
     Quit,
@@ -84,7 +136,11 @@ fn confirm_erase(window: &mut Window, title_view: &View, data: &mut SaveData)
                  -> Confirmation {
     let mut view = {
         let visible = window.visible_rect();
-        ConfirmEraseView::new(&mut window.resources(), visible, title_view)
+        let slot_name = data.slot_name(data.current_slot());
+        ConfirmEraseView::new(&mut window.resources(),
+                              visible,
+                              title_view,
+                              slot_name)
     };
     window.render(data, &view);
     loop {
@@ -19,16 +19,18 @@
 
 use std::rc::Rc;
 
-use gui::{Action, Align, Background, Canvas, Element, Event, Font, Point,
-          Rect, Resources, Sound, Sprite};
+use gui::{Action, Align, Background, Canvas, Element, Event, Font, Keycode,
+          Message, Point, Rect, Resources, Sound, Sprite};
 use elements::{DialogBox, FadeStyle, ScreenFade};
-use save::SaveData;
+use save::{Game, Location, MAX_SLOT_NAME_LEN, SaveData};
 
 // ========================================================================= //
 
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum Cmd {
     StartGame,
+    ChooseSlot,
+    ShowSettings,
     EraseGame,
     ShowAboutBox,
     Quit,
@@ -46,6 +48,7 @@ pub struct View {
     buttons: Vec<Button>,
     title_font_1: Rc<Font>,
     title_font_2: Rc<Font>,
+    slot_font: Rc<Font>,
 }
 
 impl View {
@@ -54,6 +57,7 @@ impl View {
         let upper_y = 226;
         let lower_y = 302;
         let spacing = 128;
+        let upper_spacing = 112;
         View {
             screen_fade: ScreenFade::new(resources,
                                          FadeStyle::Uniform,
@@ -65,8 +69,14 @@ impl View {
             ship_sprites: resources.get_sprites("title/ship"),
             buttons: vec![
                 Button::new(resources,
-                            Point::new(center_x, upper_y),
+                            Point::new(center_x - upper_spacing, upper_y),
                             Cmd::StartGame),
+                Button::new(resources,
+                            Point::new(center_x, upper_y),
+                            Cmd::ChooseSlot),
+                Button::new(resources,
+                            Point::new(center_x + upper_spacing, upper_y),
+                            Cmd::ShowSettings),
                 Button::new(resources,
                             Point::new(center_x - spacing, lower_y),
                             Cmd::ShowAboutBox),
@@ -79,6 +89,7 @@ impl View {
             ],
             title_font_1: resources.get_font("title1"),
             title_font_2: resources.get_font("title2"),
+            slot_font: resources.get_font("roman"),
         }
     }
 
@@ -111,6 +122,10 @@ impl Element<SaveData, Cmd> for View {
                          Align::Center,
                          Point::new(288, 165),
                          "SYZYGY");
+        canvas.draw_text(&self.slot_font,
+                         Align::Center,
+                         Point::new(288, 258),
+                         data.slot_name(data.current_slot()));
         self.buttons.draw(data, canvas);
         self.screen_fade.draw(&(), canvas);
     }
@@ -169,6 +184,8 @@ impl Element<SaveData, Cmd> for Button {
                 };
                 (0, label, 0)
             }
+            Cmd::ChooseSlot => (2, "Save Slots", 0),
+            Cmd::ShowSettings => (2, "Settings", 0),
             Cmd::ShowAboutBox => (2, "About", 4),
             Cmd::EraseGame => {
                 if data.game().is_none() {
@@ -208,7 +225,9 @@ impl Element<SaveData, Cmd> for Button {
 // ========================================================================= //
 
 #[cfg_attr(rustfmt, rustfmt_skip)]
-pub const ABOUT_BOX_TEXT: &str = concat!("\
+pub const ABOUT_BOX_TEXT: Message = Message {
+    id: "title.about",
+    english: concat!("\
 $C$f{block}SYSTEM SYZYGY$r$Rv", env!("CARGO_PKG_VERSION"), " $L\n\
 \n\
 Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>\n\
@@ -222,7 +241,8 @@ modify it under the terms of the GNU General Public License as\n\
 published by the Free Software Foundation, either version 3 of\n\
 the License, or (at your option) any later version.\n\
 \n\
-$CThanks for playing!");
+$CThanks for playing!"),
+};
 
 // ========================================================================= //
 
@@ -233,12 +253,14 @@ pub struct ConfirmEraseView<'a> {
 
 impl<'a> ConfirmEraseView<'a> {
     pub fn new(resources: &mut Resources, visible: Rect,
-               title_view: &'a View)
+               title_view: &'a View, slot_name: &str)
                -> ConfirmEraseView<'a> {
-        let text = "Really erase game data?\nAll progress will be lost!";
+        let text = format!("Really erase game data in {}?\n\
+                            All progress will be lost!",
+                           slot_name);
         let buttons =
             vec![("Cancel".to_string(), false), ("Erase".to_string(), true)];
-        let dialog = DialogBox::new(resources, visible, text, buttons);
+        let dialog = DialogBox::new(resources, visible, &text, buttons);
         ConfirmEraseView {
             title_view: title_view,
             dialog: dialog,
@@ -259,3 +281,237 @@ impl<'a> Element<SaveData, bool> for ConfirmEraseView<'a> {
 }
 
 // ========================================================================= //
+
+const SLOT_PICKER_WIDTH: u32 = 320;
+const SLOT_ROW_HEIGHT: u32 = 40;
+const SLOT_ROW_SPACING: i32 = 4;
+const SLOT_PICKER_MARGIN: i32 = 12;
+const SLOT_PICKER_HEADER_HEIGHT: i32 = 36;
+
+pub struct SlotPickerView<'a> {
+    title_view: &'a View,
+    font: Rc<Font>,
+    rect: Rect,
+    num_slots: usize,
+    selected: usize,
+    name: String,
+    original_names: Vec<String>,
+}
+
+impl<'a> SlotPickerView<'a> {
+    pub fn new(resources: &mut Resources, visible: Rect,
+               title_view: &'a View, data: &SaveData)
+               -> SlotPickerView<'a> {
+        let num_slots = data.num_slots();
+        let height = 2 * SLOT_PICKER_MARGIN + SLOT_PICKER_HEADER_HEIGHT +
+            num_slots as i32 * (SLOT_ROW_HEIGHT as i32 + SLOT_ROW_SPACING) -
+            SLOT_ROW_SPACING;
+        let mut rect = Rect::new(0, 0, SLOT_PICKER_WIDTH, height as u32);
+        rect.center_on(visible.center());
+        let selected = data.current_slot();
+        SlotPickerView {
+            title_view: title_view,
+            font: resources.get_font("roman"),
+            rect: rect,
+            num_slots: num_slots,
+            selected: selected,
+            name: data.slot_name(selected).to_string(),
+            original_names: (0..num_slots)
+                .map(|index| data.slot_name(index).to_string())
+                .collect(),
+        }
+    }
+
+    fn row_rect(&self, index: usize) -> Rect {
+        let top = self.rect.top() + SLOT_PICKER_MARGIN +
+            SLOT_PICKER_HEADER_HEIGHT +
+            index as i32 * (SLOT_ROW_HEIGHT as i32 + SLOT_ROW_SPACING);
+        Rect::new(self.rect.left() + SLOT_PICKER_MARGIN,
+                  top,
+                  SLOT_PICKER_WIDTH - 2 * SLOT_PICKER_MARGIN as u32,
+                  SLOT_ROW_HEIGHT)
+    }
+
+    fn select(&mut self, index: usize, data: &mut SaveData) {
+        data.set_slot_name(self.selected, &self.name);
+        self.selected = index;
+        self.name = data.slot_name(index).to_string();
+    }
+
+    fn finish(&mut self, data: &mut SaveData) -> Action<Option<usize>> {
+        data.set_slot_name(self.selected, &self.name);
+        Action::redraw().and_return(Some(self.selected))
+    }
+
+    /// Puts back the names that the slots had when the picker was opened,
+    /// discarding any renaming done since.
+    fn cancel(&mut self, data: &mut SaveData) -> Action<Option<usize>> {
+        for (index, name) in self.original_names.iter().enumerate() {
+            data.set_slot_name(index, name);
+        }
+        Action::redraw().and_return(None)
+    }
+}
+
+impl<'a> Element<SaveData, Option<usize>> for SlotPickerView<'a> {
+    fn draw(&self, data: &SaveData, canvas: &mut Canvas) {
+        self.title_view.draw(data, canvas);
+        canvas.fill_rect((64, 64, 64), self.rect);
+        canvas.fill_rect((200, 200, 200),
+                         Rect::new(self.rect.left() + 2,
+                                   self.rect.top() + 2,
+                                   self.rect.width() - 4,
+                                   self.rect.height() - 4));
+        let center_x = self.rect.left() + self.rect.width() as i32 / 2;
+        let top = self.rect.top() + SLOT_PICKER_MARGIN;
+        canvas.draw_text(&self.font,
+                         Align::Center,
+                         Point::new(center_x, top + 11),
+                         "Choose a save slot:");
+        canvas.draw_text(&self.font,
+                         Align::Center,
+                         Point::new(center_x, top + 25),
+                         "(Type to rename the selected slot.)");
+        for index in 0..self.num_slots {
+            let rect = self.row_rect(index);
+            let (color, name) = if index == self.selected {
+                ((255, 255, 255), format!("{}_", self.name))
+            } else {
+                ((160, 160, 160), data.slot_name(index).to_string())
+            };
+            canvas.fill_rect(color, rect);
+            canvas.draw_rect((64, 64, 64), rect);
+            let left = rect.left() + 6;
+            canvas.draw_text(&self.font,
+                             Align::Left,
+                             Point::new(left, rect.top() + 15),
+                             &name);
+            canvas.draw_text(&self.font,
+                             Align::Left,
+                             Point::new(left, rect.top() + 31),
+                             &slot_summary(data.slot_game(index)));
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, data: &mut SaveData)
+                    -> Action<Option<usize>> {
+        match event {
+            &Event::MouseDown(pt) if !self.rect.contains_point(pt) => {
+                self.cancel(data)
+            }
+            &Event::MouseDown(pt) => {
+                for index in 0..self.num_slots {
+                    if self.row_rect(index).contains_point(pt) {
+                        if index == self.selected {
+                            return self.finish(data)
+                                .and_play_sound(Sound::beep());
+                        }
+                        self.select(index, data);
+                        return Action::redraw().and_play_sound(Sound::beep());
+                    }
+                }
+                Action::ignore()
+            }
+            &Event::KeyDown(Keycode::Return, _) => self.finish(data),
+            &Event::KeyDown(Keycode::Escape, _) => self.cancel(data),
+            &Event::KeyDown(Keycode::Backspace, _) => {
+                Action::redraw_if(self.name.pop().is_some())
+            }
+            &Event::TextInput(ref text) => {
+                let mut changed = false;
+                for chr in text.chars() {
+                    if self.name.chars().count() < MAX_SLOT_NAME_LEN &&
+                        !chr.is_control()
+                    {
+                        self.name.push(chr);
+                        changed = true;
+                    }
+                }
+                Action::redraw_if(changed)
+            }
+            _ => Action::ignore(),
+        }
+    }
+}
+
+/// Returns a one-line description of the progress made in a save slot.
+fn slot_summary(game: Option<&Game>) -> String {
+    if let Some(game) = game {
+        let puzzles: Vec<Location> = Location::all()
+            .iter()
+            .cloned()
+            .filter(|&loc| loc != Location::Map)
+            .collect();
+        let num_solved = puzzles
+            .iter()
+            .filter(|&&loc| game.has_been_solved(loc))
+            .count();
+        format!("{}/{} solved, at {}",
+                num_solved,
+                puzzles.len(),
+                game.location.name())
+    } else {
+        "Empty".to_string()
+    }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use gui::{Element, Event, KeyMod, Keycode, Offscreen, Point};
+    use save::SaveData;
+    use super::{SlotPickerView, View};
+
+    /// Types a character into the slot picker's selected slot name, then
+    /// sends `event`, and returns the slot's name from before and after.
+    fn rename_then(event: Event) -> (String, String) {
+        let mut offscreen = Offscreen::new(576, 384);
+        let visible = offscreen.visible_rect();
+        let mut data = SaveData::new(PathBuf::from("unused.toml"));
+        let original = data.slot_name(0).to_string();
+        let title_view = View::new(&mut offscreen.resources());
+        let mut picker = SlotPickerView::new(&mut offscreen.resources(),
+                                             visible,
+                                             &title_view,
+                                             &data);
+        picker.handle_event(&Event::TextInput("!".to_string()), &mut data);
+        picker.handle_event(&event, &mut data);
+        (original, data.slot_name(0).to_string())
+    }
+
+    #[test]
+    fn renders_title_screen() {
+        let mut offscreen = Offscreen::new(576, 384);
+        let mut data = SaveData::new(PathBuf::from("unused.toml"));
+        data.start_new_game();
+        let mut view = View::new(&mut offscreen.resources());
+        // Let the screen fade-in finish before rendering:
+        for _ in 0..100 {
+            view.handle_event(&Event::ClockTick, &mut data);
+        }
+        offscreen.render(&data, &view);
+        offscreen.assert_matches_golden("title_screen");
+    }
+
+    #[test]
+    fn return_keeps_rename() {
+        let event = Event::KeyDown(Keycode::Return, KeyMod::none());
+        let (original, renamed) = rename_then(event);
+        assert_eq!(renamed, format!("{}!", original));
+    }
+
+    #[test]
+    fn cancel_restores_old_name() {
+        let event = Event::KeyDown(Keycode::Escape, KeyMod::none());
+        let (original, renamed) = rename_then(event);
+        assert_eq!(renamed, original);
+        let event = Event::MouseDown(Point::new(0, 0));
+        let (original, renamed) = rename_then(event);
+        assert_eq!(renamed, original);
+    }
+}
+
+// ========================================================================= //
@@ -21,7 +21,7 @@ use std::cmp;
 use std::rc::Rc;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sound, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Game, PuzzleState, TreadState};
@@ -132,7 +132,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (i32, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.tread_lightly.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -385,7 +393,9 @@ fn tick_radius<A>(lit: bool, radius: &mut i32, max: i32) -> Action<A> {
 
 // ========================================================================= //
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.tread_lightly",
+    english: "\
 Your goal is to turn all thirty lights OFF by placing seven
 letters onto the grid.
 
@@ -398,6 +408,7 @@ letter, you will need to use the reset button or the
 undo button to back up and try again.
 
 $M{Tap}{Click} on a character in the scene to hear their words of
-wisdom.";
+wisdom.",
+};
 
 // ========================================================================= //
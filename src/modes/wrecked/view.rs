@@ -21,7 +21,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use elements::{FadeStyle, PuzzleCmd, PuzzleCore, PuzzleView};
-use gui::{Action, Align, Canvas, Element, Event, Font, Point, Rect,
+use gui::{Action, Align, Canvas, Element, Event, Font, Message, Point, Rect,
           Resources, Sprite};
 use modes::SOLVED_INFO_TEXT;
 use save::{Direction, Game, PuzzleState, WreckedState};
@@ -94,7 +94,15 @@ impl Element<Game, PuzzleCmd> for View {
 }
 
 impl PuzzleView for View {
-    fn info_text(&self, game: &Game) -> &'static str {
+    type UndoRedo = (Direction, i32, i32);
+
+    fn core(&self) -> &PuzzleCore<Self::UndoRedo> { &self.core }
+
+    fn core_mut(&mut self) -> &mut PuzzleCore<Self::UndoRedo> {
+        &mut self.core
+    }
+
+    fn info_text(&self, game: &Game) -> Message {
         if game.wrecked_angle.is_solved() {
             SOLVED_INFO_TEXT
         } else {
@@ -438,7 +446,9 @@ const LETTERS: &[(i32, i32, char)] = &[
     (5, 5, 'M'),
 ];
 
-const INFO_BOX_TEXT: &str = "\
+const INFO_BOX_TEXT: Message = Message {
+    id: "info.wrecked_angle",
+    english: "\
 Your goal is to arrange the large grid on the left into
 the pattern shown on the small grid on the right.
 
@@ -446,6 +456,7 @@ Drag a tile on the large grid up, down, left, or right
 to shift that whole row or column.
 
 $M{Tap}{Click} on a character in the scene to hear their words
-of wisdom.";
+of wisdom.",
+};
 
 // ========================================================================= //
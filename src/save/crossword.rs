@@ -133,6 +133,20 @@ impl CrosswordState {
         }
         self.is_initial = all_spaces(&self.words);
     }
+
+    /// Fills in the first word that doesn't yet match `solved`, and returns
+    /// its row, or returns `None` if every word already matches.
+    pub fn reveal_word(&mut self, solved: &[&str]) -> Option<i32> {
+        for (row, word) in solved.iter().enumerate() {
+            let chars: Vec<char> = word.chars().collect();
+            if self.words[row] != chars {
+                self.words[row] = chars;
+                self.is_initial = false;
+                return Some(row as i32);
+            }
+        }
+        None
+    }
 }
 
 // ========================================================================= //
@@ -19,32 +19,49 @@
 
 use std::fs;
 use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use super::game::Game;
 use super::prefs::Prefs;
 use toml;
 
-use super::util::to_table;
+use super::util::{Tomlable, pop_array, to_table};
+use super::version::{migrate_to_current, set_current_version};
 
 // ========================================================================= //
 
+const CURRENT_SLOT_KEY: &str = "current_slot";
 const GAME_KEY: &str = "game";
+const NAME_KEY: &str = "name";
 const PREFS_KEY: &str = "prefs";
+const SLOTS_KEY: &str = "slots";
+
+/// How many separate games can be saved at once.
+const NUM_SLOTS: usize = 3;
+
+/// The maximum number of characters in a save slot's name.
+pub const MAX_SLOT_NAME_LEN: usize = 16;
+
+/// How many previous saves to keep around (as `save_data.toml.1`,
+/// `save_data.toml.2`, etc., from newest to oldest) in case the main save
+/// file gets corrupted.
+const NUM_BACKUPS: usize = 3;
 
 // ========================================================================= //
 
 pub struct SaveData {
-    path: PathBuf,
+    path: Option<PathBuf>,
     prefs: Prefs,
-    game: Option<Game>,
+    slots: Vec<SaveSlot>,
+    current_slot: usize,
 }
 
 impl SaveData {
     pub fn new(path: PathBuf) -> SaveData {
         SaveData {
-            path: path,
+            path: Some(path),
             prefs: Prefs::with_defaults(),
-            game: None,
+            slots: (0..NUM_SLOTS).map(SaveSlot::new).collect(),
+            current_slot: 0,
         }
     }
 
@@ -56,38 +73,67 @@ impl SaveData {
         {
             data.prefs = Prefs::from_toml(prefs);
         }
-        if let Some(game) = table.remove(GAME_KEY) {
-            data.game = Some(Game::from_toml(game));
+        let slots = pop_array(&mut table, SLOTS_KEY);
+        for (index, value) in slots.into_iter().take(NUM_SLOTS).enumerate() {
+            data.slots[index] = SaveSlot::from_toml(index, value);
         }
+        let current_slot = u32::pop_from_table(&mut table, CURRENT_SLOT_KEY);
+        data.current_slot = (current_slot as usize).min(NUM_SLOTS - 1);
         data
     }
 
     fn to_toml(&self) -> toml::Value {
         let mut table = toml::value::Table::new();
+        set_current_version(&mut table);
         table.insert(PREFS_KEY.to_string(), self.prefs.to_toml());
-        if let Some(ref game) = self.game {
-            table.insert(GAME_KEY.to_string(), game.to_toml());
-        }
+        table.insert(CURRENT_SLOT_KEY.to_string(),
+                     toml::Value::Integer(self.current_slot as i64));
+        let slots = self.slots.iter().map(SaveSlot::to_toml).collect();
+        table.insert(SLOTS_KEY.to_string(), toml::Value::Array(slots));
         toml::Value::Table(table)
     }
 
+    /// Writes the save data to disk.  The new data is first written to a
+    /// temporary file and then renamed over the old save file, so that a
+    /// crash partway through can't leave a half-written save file behind.
+    /// The previous save file is kept as the newest backup.  Does nothing if
+    /// `set_save_path` was given `None`.
     pub fn save_to_disk(&mut self) -> io::Result<()> {
+        let path = match self.path {
+            Some(ref path) => path,
+            None => return Ok(()),
+        };
         let string = self.to_toml().to_string();
-        fs::create_dir_all(self.path.parent().unwrap())?;
-        let mut file = fs::File::create(&self.path)?;
-        file.write_all(string.as_bytes())?;
+        fs::create_dir_all(path.parent().unwrap())?;
+        rotate_backups(path)?;
+        let temp_path = sibling_path(path, ".tmp");
+        {
+            let mut file = fs::File::create(&temp_path)?;
+            file.write_all(string.as_bytes())?;
+            file.sync_all()?;
+        }
+        fs::rename(&temp_path, path)?;
         if cfg!(debug_assertions) {
             println!("Saved game to disk.");
         }
         Ok(())
     }
 
-    fn load_from_disk(path: PathBuf) -> io::Result<SaveData> {
-        let mut file = fs::File::open(&path)?;
+    fn load_from_disk(path: PathBuf, file_path: &Path)
+                      -> io::Result<SaveData> {
+        let mut file = fs::File::open(file_path)?;
         let mut string = String::new();
         file.read_to_string(&mut string)?;
+        SaveData::from_string(path, &string)
+    }
+
+    fn from_string(path: PathBuf, string: &str) -> io::Result<SaveData> {
         match string.parse::<toml::Value>() {
-            Ok(value) => Ok(SaveData::from_toml(path, to_table(value))),
+            Ok(value) => {
+                let mut table = to_table(value);
+                migrate_to_current(&mut table)?;
+                Ok(SaveData::from_toml(path, table))
+            }
             Err(_) => {
                 Err(io::Error::new(io::ErrorKind::InvalidData,
                                    "failed to parse toml"))
@@ -95,42 +141,304 @@ impl SaveData {
         }
     }
 
+    /// Loads the save file at the given path.  If the save file can't be
+    /// loaded, falls back to the newest backup that can be; if there are no
+    /// save files at all, creates a new one.
     pub fn load_or_create(path: PathBuf) -> io::Result<SaveData> {
-        if path.is_file() {
-            SaveData::load_from_disk(path)
-        } else {
+        let candidates: Vec<PathBuf> = Some(path.clone())
+            .into_iter()
+            .chain((1..(NUM_BACKUPS + 1)).map(|n| backup_path(&path, n)))
+            .filter(|candidate| candidate.is_file())
+            .collect();
+        if candidates.is_empty() {
             let mut data = SaveData::new(path);
             data.save_to_disk()?;
-            Ok(data)
+            return Ok(data);
+        }
+        let mut first_error = None;
+        for candidate in candidates.iter() {
+            match SaveData::load_from_disk(path.clone(), candidate) {
+                Ok(data) => {
+                    if first_error.is_some() {
+                        println!("Recovered save data from {:?}.", candidate);
+                    }
+                    return Ok(data);
+                }
+                Err(error) => {
+                    println!("Failed to load {:?}: {}", candidate, error);
+                    first_error = first_error.or(Some(error));
+                }
+            }
         }
+        Err(first_error.unwrap())
+    }
+
+    /// Makes `save_to_disk` write to the given path from now on (instead of
+    /// the path the data was loaded from), or write nothing if it's `None`.
+    pub fn set_save_path(&mut self, path: Option<PathBuf>) {
+        self.path = path;
     }
 
     pub fn prefs(&self) -> &Prefs { &self.prefs }
 
-    #[allow(dead_code)]
     pub fn prefs_mut(&mut self) -> &mut Prefs { &mut self.prefs }
 
-    pub fn game(&self) -> Option<&Game> {
-        match self.game {
-            Some(ref game) => Some(game),
-            None => None,
+    pub fn num_slots(&self) -> usize { self.slots.len() }
+
+    pub fn current_slot(&self) -> usize { self.current_slot }
+
+    pub fn select_slot(&mut self, index: usize) {
+        assert!(index < self.slots.len());
+        self.current_slot = index;
+    }
+
+    pub fn slot_name(&self, index: usize) -> &str { &self.slots[index].name }
+
+    /// Renames the given save slot, truncating the name if it's too long.
+    /// An empty name reverts the slot to its default name.
+    pub fn set_slot_name(&mut self, index: usize, name: &str) {
+        if name.is_empty() {
+            self.slots[index].name = SaveSlot::default_name(index);
+            return;
         }
+        self.slots[index].name =
+            name.chars().take(MAX_SLOT_NAME_LEN).collect();
     }
 
+    pub fn slot_game(&self, index: usize) -> Option<&Game> {
+        self.slots[index].game.as_ref()
+    }
+
+    pub fn game(&self) -> Option<&Game> { self.slot_game(self.current_slot) }
+
     pub fn game_mut(&mut self) -> &mut Game {
-        if let Some(ref mut game) = self.game {
-            game
-        } else {
-            self.start_new_game()
+        if self.slots[self.current_slot].game.is_none() {
+            self.start_new_game();
         }
+        self.slots[self.current_slot].game.as_mut().unwrap()
     }
 
     pub fn start_new_game(&mut self) -> &mut Game {
-        self.game = Some(Game::new());
-        self.game.as_mut().unwrap()
+        let slot = &mut self.slots[self.current_slot];
+        slot.game = Some(Game::new());
+        slot.game.as_mut().unwrap()
+    }
+
+    pub fn erase_game(&mut self) { self.slots[self.current_slot].game = None; }
+}
+
+// ========================================================================= //
+
+struct SaveSlot {
+    name: String,
+    game: Option<Game>,
+}
+
+impl SaveSlot {
+    fn new(index: usize) -> SaveSlot {
+        SaveSlot {
+            name: SaveSlot::default_name(index),
+            game: None,
+        }
     }
 
-    pub fn erase_game(&mut self) { self.game = None; }
+    fn default_name(index: usize) -> String { format!("Slot {}", index + 1) }
+
+    fn from_toml(index: usize, value: toml::Value) -> SaveSlot {
+        let mut table = to_table(value);
+        let mut name = String::pop_from_table(&mut table, NAME_KEY);
+        if name.is_empty() {
+            name = SaveSlot::default_name(index);
+        }
+        SaveSlot {
+            name: name.chars().take(MAX_SLOT_NAME_LEN).collect(),
+            game: table.remove(GAME_KEY).map(Game::from_toml),
+        }
+    }
+
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        table.insert(NAME_KEY.to_string(), self.name.to_toml());
+        if let Some(ref game) = self.game {
+            table.insert(GAME_KEY.to_string(), game.to_toml());
+        }
+        toml::Value::Table(table)
+    }
+}
+
+// ========================================================================= //
+
+/// Returns the path of the `n`th most recent backup of the given save file,
+/// where `n` starts at 1.
+fn backup_path(path: &Path, n: usize) -> PathBuf {
+    sibling_path(path, &format!(".{}", n))
+}
+
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Shifts each backup of the given save file back by one (discarding the
+/// oldest), and then copies the current save file (if any) into the newest
+/// backup slot.
+fn rotate_backups(path: &Path) -> io::Result<()> {
+    for n in (1..NUM_BACKUPS).rev() {
+        let backup = backup_path(path, n);
+        if backup.is_file() {
+            fs::rename(&backup, backup_path(path, n + 1))?;
+        }
+    }
+    if path.is_file() {
+        fs::copy(path, backup_path(path, 1))?;
+    }
+    Ok(())
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use std::process;
+    use toml;
+
+    use save::{Access, Location, PuzzleState};
+    use save::util::{Tomlable, to_table};
+    use super::{NUM_BACKUPS, SaveData, backup_path};
+
+    const SAVE_V0: &str = include_str!("testdata/save_v0.toml");
+    const SAVE_V1: &str = include_str!("testdata/save_v1.toml");
+
+    #[test]
+    fn load_version_0_save() {
+        let data = SaveData::from_string(PathBuf::new(), SAVE_V0).unwrap();
+        assert!(!data.prefs().fullscreen());
+        assert_eq!(data.current_slot(), 0);
+        assert!(data.slot_game(1).is_none());
+        let game = data.game().unwrap();
+        assert_eq!(game.location, Location::CubeTangle);
+        assert!(game.ever_clicked_info);
+        assert_eq!(game.prolog.access(), Access::Solved);
+        assert_eq!(game.disconnected.access(), Access::Solved);
+        assert_eq!(game.log_level.access(), Access::Replaying);
+        assert_eq!(game.wrecked_angle.access(), Access::Solved);
+        assert_eq!(game.shifting_ground.access(), Access::Solved);
+        assert_eq!(game.cube_tangle.access(), Access::Unsolved);
+        assert_eq!(game.the_y_factor.access(), Access::Unvisited);
+        let mut cube = to_table(game.cube_tangle.to_toml());
+        assert_eq!(Vec::<i32>::pop_from_table(&mut cube, "grid"),
+                   vec![16, 23, 18, 12, 15, 9, 17, 22, 21, 3, 8, 6, 19, 1,
+                        4, 11]);
+    }
+
+    #[test]
+    fn load_version_1_save() {
+        let data = SaveData::from_string(PathBuf::new(), SAVE_V1).unwrap();
+        assert!(data.prefs().fullscreen());
+        assert_eq!(data.current_slot(), 0);
+        assert!(data.slot_game(1).is_none());
+        let game = data.game().unwrap();
+        assert_eq!(game.location, Location::Map);
+        assert!(game.ever_clicked_info);
+        assert_eq!(game.log_level.access(), Access::Solved);
+        assert_eq!(game.cube_tangle.access(), Access::Solved);
+        assert_eq!(game.hex_spangled.access(), Access::Unvisited);
+        assert_eq!(game.the_y_factor.access(), Access::Unsolved);
+    }
+
+    #[test]
+    fn saved_data_is_current_version() {
+        let data = SaveData::from_string(PathBuf::new(), SAVE_V0).unwrap();
+        let string = data.to_toml().to_string();
+        let value = string.parse::<toml::Value>().unwrap();
+        let reloaded = SaveData::from_string(PathBuf::new(), &string)
+            .unwrap();
+        assert_eq!(to_table(reloaded.to_toml()), to_table(value));
+    }
+
+    #[test]
+    fn slots_are_independent() {
+        let mut data = SaveData::new(PathBuf::new());
+        data.start_new_game().location = Location::CubeTangle;
+        data.select_slot(2);
+        assert!(data.game().is_none());
+        data.start_new_game().location = Location::HexSpangled;
+        data.set_slot_name(2, "Somebody Else");
+        data.select_slot(1);
+        data.set_slot_name(1, "");
+
+        let string = data.to_toml().to_string();
+        let data = SaveData::from_string(PathBuf::new(), &string).unwrap();
+        assert_eq!(data.current_slot(), 1);
+        assert!(data.game().is_none());
+        assert_eq!(data.slot_name(0), "Slot 1");
+        assert_eq!(data.slot_game(0).unwrap().location,
+                   Location::CubeTangle);
+        assert_eq!(data.slot_name(1), "Slot 2");
+        assert_eq!(data.slot_name(2), "Somebody Else");
+        assert_eq!(data.slot_game(2).unwrap().location,
+                   Location::HexSpangled);
+    }
+
+    #[test]
+    fn reject_save_from_newer_version() {
+        let string = "version = 999\n\n[game]\nlocation = \"prolog\"\n";
+        assert!(SaveData::from_string(PathBuf::new(), string).is_err());
+    }
+
+    fn test_save_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir()
+            .join(format!("syzygy-test-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("save_data.toml")
+    }
+
+    #[test]
+    fn save_keeps_backups() {
+        let path = test_save_path("backups");
+        let mut data = SaveData::load_or_create(path.clone()).unwrap();
+        for _ in 0..(NUM_BACKUPS + 2) {
+            data.save_to_disk().unwrap();
+        }
+        assert!(path.is_file());
+        for n in 1..(NUM_BACKUPS + 1) {
+            assert!(backup_path(&path, n).is_file());
+        }
+        assert!(!backup_path(&path, NUM_BACKUPS + 1).exists());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn load_falls_back_to_backup() {
+        let path = test_save_path("fallback");
+        let mut data = SaveData::load_or_create(path.clone()).unwrap();
+        data.game_mut().location = Location::CubeTangle;
+        data.save_to_disk().unwrap();
+        data.game_mut().location = Location::HexSpangled;
+        data.save_to_disk().unwrap();
+        fs::File::create(&path)
+            .unwrap()
+            .write_all(b"[game\nlocation = ")
+            .unwrap();
+
+        let data = SaveData::load_or_create(path.clone()).unwrap();
+        assert_eq!(data.game().unwrap().location, Location::CubeTangle);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn load_fails_if_nothing_parses() {
+        let path = test_save_path("corrupt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::File::create(&path).unwrap().write_all(b"[game").unwrap();
+        assert!(SaveData::load_or_create(path.clone()).is_err());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
 }
 
 // ========================================================================= //
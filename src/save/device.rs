@@ -17,7 +17,7 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use toml;
 
 use save::{Direction, MixedColor};
@@ -211,6 +211,174 @@ impl DeviceGrid {
         }
         false
     }
+
+    /// Traces every laser beam from the grid's emitters through its devices,
+    /// without regard to how the beams will be drawn.
+    pub fn trace_lasers(&self) -> LaserBeams {
+        let mut beams = LaserBeams::default();
+        let mut queue: VecDeque<((i32, i32), Direction, MixedColor)> =
+            VecDeque::new();
+        for row in 0..self.num_rows {
+            for col in 0..self.num_cols {
+                match self.get(col, row) {
+                    Some((Device::Emitter(color), dir)) => {
+                        if color != MixedColor::Black {
+                            let coords = (col, row);
+                            beams.segments.insert((coords, dir), color);
+                            queue.push_back((coords, dir, color));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        while let Some((coords, laser_dir, color)) = queue.pop_front() {
+            let delta = laser_dir.delta();
+            let next = (coords.0 + delta.x(), coords.1 + delta.y());
+            let anti_dir = laser_dir.opposite();
+            if beams.segments.contains_key(&(next, anti_dir)) {
+                if !beams.sparks.contains_key(&(next, anti_dir)) {
+                    beams.sparks.insert((coords, laser_dir), Spark::Edge);
+                }
+                continue;
+            }
+            match self.get(next.0, next.1) {
+                Some((Device::Wall, _)) |
+                Some((Device::Emitter(_), _)) => {
+                    beams.sparks.insert((coords, laser_dir), Spark::Edge);
+                }
+                Some((Device::Channel, ch_dir))
+                    if !ch_dir.is_parallel_to(laser_dir) => {
+                    beams.sparks.insert((coords, laser_dir), Spark::Edge);
+                }
+                Some((Device::Channel, _)) |
+                Some((Device::CrossChannel, _)) |
+                None => {
+                    beams.segments.insert((next, anti_dir), color);
+                    beams.segments.insert((next, laser_dir), color);
+                    queue.push_back((next, laser_dir, color));
+                }
+                Some((Device::Detector(det_color), det_dir)) => {
+                    if det_dir == anti_dir {
+                        beams.segments.insert((next, anti_dir), color);
+                        if det_color != color {
+                            beams.sparks
+                                .insert((next, anti_dir), Spark::Device);
+                        }
+                    } else {
+                        beams.sparks.insert((coords, laser_dir), Spark::Edge);
+                    }
+                }
+                Some((Device::Mirror, mir_dir)) => {
+                    let mut reflect_dir = match anti_dir {
+                        Direction::East => Direction::South,
+                        Direction::South => Direction::East,
+                        Direction::West => Direction::North,
+                        Direction::North => Direction::West,
+                    };
+                    if mir_dir.is_vertical() {
+                        reflect_dir = reflect_dir.opposite();
+                    }
+                    beams.segments.insert((next, anti_dir), color);
+                    beams.segments.insert((next, reflect_dir), color);
+                    queue.push_back((next, reflect_dir, color));
+                }
+                Some((Device::Splitter, split_dir)) => {
+                    beams.segments.insert((next, anti_dir), color);
+                    if split_dir == laser_dir {
+                        let left_dir = laser_dir.rotated_ccw();
+                        let right_dir = laser_dir.rotated_cw();
+                        beams.segments.insert((next, left_dir), color);
+                        beams.segments.insert((next, right_dir), color);
+                        beams.sparks.remove(&(next, left_dir));
+                        beams.sparks.remove(&(next, right_dir));
+                        queue.push_back((next, left_dir, color));
+                        queue.push_back((next, right_dir, color));
+                    } else {
+                        beams.sparks.insert((next, anti_dir), Spark::Device);
+                    }
+                }
+                Some((Device::Mixer, mixer_dir)) => {
+                    beams.segments.insert((next, anti_dir), color);
+                    if mixer_dir.is_parallel_to(laser_dir) {
+                        beams.sparks.insert((next, anti_dir), Spark::Device);
+                    } else if let Some(&other) =
+                        beams.segments.get(&(next, laser_dir))
+                    {
+                        let output = mixer_output(color, other);
+                        beams.segments.insert((next, mixer_dir), output);
+                        beams.sparks.remove(&(next, mixer_dir));
+                        queue.push_back((next, mixer_dir, output));
+                    }
+                }
+            }
+        }
+        beams.all_satisfied = true;
+        for row in 0..self.num_rows {
+            for col in 0..self.num_cols {
+                if let Some((Device::Detector(color), dir)) =
+                    self.get(col, row)
+                {
+                    if beams.color_at(col, row, dir) == Some(color) {
+                        beams.satisfied.insert((col, row));
+                    } else {
+                        beams.all_satisfied = false;
+                    }
+                }
+            }
+        }
+        beams
+    }
+}
+
+// ========================================================================= //
+
+/// Where, within a grid cell, a blocked laser beam throws off sparks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Spark {
+    /// The beam was stopped at the edge of the cell, before it could reach
+    /// the next one.
+    Edge,
+    /// The beam reached the device in the cell, but the device doesn't
+    /// accept light from that side (or of that color).
+    Device,
+}
+
+/// The laser beams in a `DeviceGrid`, as traced by
+/// `DeviceGrid::trace_lasers`.  Beams are made up of half-cell segments; the
+/// segment at `((col, row), dir)` runs from the middle of that cell to its
+/// edge in direction `dir`.
+#[derive(Clone, Debug, Default)]
+pub struct LaserBeams {
+    segments: HashMap<((i32, i32), Direction), MixedColor>,
+    sparks: HashMap<((i32, i32), Direction), Spark>,
+    satisfied: HashSet<(i32, i32)>,
+    all_satisfied: bool,
+}
+
+impl LaserBeams {
+    pub fn segments(&self) -> &HashMap<((i32, i32), Direction), MixedColor> {
+        &self.segments
+    }
+
+    pub fn sparks(&self) -> &HashMap<((i32, i32), Direction), Spark> {
+        &self.sparks
+    }
+
+    /// Returns the color of the beam segment between the middle of the given
+    /// cell and its edge in the given direction, if any.
+    pub fn color_at(&self, col: i32, row: i32, dir: Direction)
+                    -> Option<MixedColor> {
+        self.segments.get(&((col, row), dir)).cloned()
+    }
+
+    /// Returns the positions of all detectors that are receiving a beam of
+    /// their own color.
+    pub fn satisfied_detectors(&self) -> &HashSet<(i32, i32)> {
+        &self.satisfied
+    }
+
+    pub fn all_detectors_satisfied(&self) -> bool { self.all_satisfied }
 }
 
 // ========================================================================= //
@@ -316,11 +484,28 @@ impl Tomlable for Device {
 
 // ========================================================================= //
 
+/// Returns the color of light that a mixer emits when it receives the two
+/// given colors on its two sides.
+fn mixer_output(color1: MixedColor, color2: MixedColor) -> MixedColor {
+    let red = (color1.has_red() && color2.has_red()) ||
+        (color1.has_green() && color2.has_blue()) ||
+        (color1.has_blue() && color2.has_green());
+    let green = (color1.has_green() && color2.has_green()) ||
+        (color1.has_red() && color2.has_blue()) ||
+        (color1.has_blue() && color2.has_red());
+    let blue = (color1.has_blue() && color2.has_blue()) ||
+        (color1.has_red() && color2.has_green()) ||
+        (color1.has_green() && color2.has_red());
+    MixedColor::from_rgb(red, green, blue)
+}
+
+// ========================================================================= //
+
 #[cfg(test)]
 mod tests {
-    use save::Direction;
+    use save::{Direction, MixedColor};
     use save::util::{Tomlable, to_array};
-    use super::{Device, DeviceGrid};
+    use super::{Device, DeviceGrid, Spark, mixer_output};
 
     #[test]
     fn device_toml_round_trip() {
@@ -341,6 +526,353 @@ mod tests {
         let result = DeviceGrid::from_toml(to_array(grid.to_toml()), &default);
         assert_eq!(result.grid, grid.grid);
     }
+
+    /// Returns the cell one step from `(col, row)` in direction `dir`.
+    fn step((col, row): (i32, i32), dir: Direction) -> (i32, i32) {
+        let delta = dir.delta();
+        (col + delta.x(), row + delta.y())
+    }
+
+    /// Returns a 3x3 grid with the given device in the center cell and a red
+    /// emitter next to it, firing a beam into the center in direction `dir`.
+    fn grid_with_center(device: Device, device_dir: Direction,
+                        dir: Direction)
+                        -> DeviceGrid {
+        let mut grid = DeviceGrid::new(3, 3);
+        grid.set(1, 1, device, device_dir);
+        let (col, row) = step((1, 1), dir.opposite());
+        grid.set(col, row, Device::Emitter(MixedColor::Red), dir);
+        grid
+    }
+
+    #[test]
+    fn beam_crosses_empty_cells_until_blocked() {
+        let mut grid = DeviceGrid::new(5, 1);
+        grid.set(0, 0, Device::Emitter(MixedColor::Cyan), Direction::East);
+        grid.set(3, 0, Device::Wall, Direction::East);
+        let beams = grid.trace_lasers();
+        let cyan = Some(MixedColor::Cyan);
+        assert_eq!(beams.color_at(0, 0, Direction::East), cyan);
+        assert_eq!(beams.color_at(0, 0, Direction::West), None);
+        for col in 1..3 {
+            assert_eq!(beams.color_at(col, 0, Direction::West), cyan);
+            assert_eq!(beams.color_at(col, 0, Direction::East), cyan);
+        }
+        assert_eq!(beams.segments().len(), 5);
+        assert_eq!(beams.sparks().len(), 1);
+        assert_eq!(beams.sparks().get(&((2, 0), Direction::East)),
+                   Some(&Spark::Edge));
+    }
+
+    #[test]
+    fn beam_is_blocked_by_grid_edge_and_emitters() {
+        let mut grid = DeviceGrid::new(3, 2);
+        grid.set(0, 0, Device::Emitter(MixedColor::Red), Direction::East);
+        grid.set(2, 0, Device::Emitter(MixedColor::Blue), Direction::South);
+        let beams = grid.trace_lasers();
+        assert_eq!(beams.sparks().get(&((1, 0), Direction::East)),
+                   Some(&Spark::Edge));
+        assert_eq!(beams.color_at(2, 1, Direction::North),
+                   Some(MixedColor::Blue));
+        assert_eq!(beams.sparks().get(&((2, 1), Direction::South)),
+                   Some(&Spark::Edge));
+        assert_eq!(beams.sparks().len(), 2);
+    }
+
+    #[test]
+    fn black_emitters_emit_nothing() {
+        let mut grid = DeviceGrid::new(2, 1);
+        grid.set(0, 0, Device::Emitter(MixedColor::Black), Direction::East);
+        let beams = grid.trace_lasers();
+        assert!(beams.segments().is_empty());
+        assert!(beams.sparks().is_empty());
+    }
+
+    #[test]
+    fn opposing_beams_spark_where_they_meet() {
+        let mut grid = DeviceGrid::new(4, 1);
+        grid.set(0, 0, Device::Emitter(MixedColor::Red), Direction::East);
+        grid.set(3, 0, Device::Emitter(MixedColor::Green), Direction::West);
+        let beams = grid.trace_lasers();
+        assert_eq!(beams.color_at(1, 0, Direction::East),
+                   Some(MixedColor::Red));
+        assert_eq!(beams.color_at(2, 0, Direction::West),
+                   Some(MixedColor::Green));
+        assert_eq!(beams.sparks().len(), 1);
+        assert_eq!(beams.sparks().get(&((1, 0), Direction::East)),
+                   Some(&Spark::Edge));
+    }
+
+    #[test]
+    fn mirror_reflections() {
+        // (mirror direction, incoming beam direction, reflected direction)
+        let table = [
+            (Direction::East, Direction::East, Direction::North),
+            (Direction::East, Direction::South, Direction::West),
+            (Direction::East, Direction::West, Direction::South),
+            (Direction::East, Direction::North, Direction::East),
+            (Direction::South, Direction::East, Direction::South),
+            (Direction::South, Direction::South, Direction::East),
+            (Direction::South, Direction::West, Direction::North),
+            (Direction::South, Direction::North, Direction::West),
+        ];
+        for &(mir_dir, dir, out_dir) in table.iter() {
+            for &mir_dir in &[mir_dir, mir_dir.opposite()] {
+                let grid = grid_with_center(Device::Mirror, mir_dir, dir);
+                let beams = grid.trace_lasers();
+                let red = Some(MixedColor::Red);
+                assert_eq!(beams.color_at(1, 1, dir.opposite()), red);
+                assert_eq!(beams.color_at(1, 1, out_dir), red);
+                assert_eq!(beams.color_at(1, 1, dir), None);
+                assert_eq!(beams.color_at(1, 1, out_dir.opposite()), None);
+                let (col, row) = step((1, 1), out_dir);
+                assert_eq!(beams.color_at(col, row, out_dir.opposite()), red);
+                assert_eq!(beams.color_at(col, row, out_dir), red);
+                assert_eq!(beams.sparks().len(), 1);
+                assert_eq!(beams.sparks().get(&((col, row), out_dir)),
+                           Some(&Spark::Edge));
+            }
+        }
+    }
+
+    #[test]
+    fn splitter_facing_away_splits_beam() {
+        for dir in Direction::all() {
+            let grid = grid_with_center(Device::Splitter, dir, dir);
+            let beams = grid.trace_lasers();
+            let red = Some(MixedColor::Red);
+            assert_eq!(beams.color_at(1, 1, dir.opposite()), red);
+            assert_eq!(beams.color_at(1, 1, dir), None);
+            for &out_dir in &[dir.rotated_cw(), dir.rotated_ccw()] {
+                assert_eq!(beams.color_at(1, 1, out_dir), red);
+                let next = step((1, 1), out_dir);
+                assert_eq!(beams.color_at(next.0, next.1, out_dir), red);
+                assert_eq!(beams.sparks().get(&(next, out_dir)),
+                           Some(&Spark::Edge));
+            }
+            assert_eq!(beams.sparks().len(), 2);
+        }
+    }
+
+    #[test]
+    fn splitter_rejects_beams_from_back_and_sides() {
+        for dir in Direction::all() {
+            for &split_dir in &[dir.opposite(),
+                                dir.rotated_cw(),
+                                dir.rotated_ccw()]
+            {
+                let grid = grid_with_center(Device::Splitter, split_dir, dir);
+                let beams = grid.trace_lasers();
+                assert_eq!(beams.color_at(1, 1, dir.opposite()),
+                           Some(MixedColor::Red));
+                assert_eq!(beams.segments().len(), 2);
+                assert_eq!(beams.sparks().len(), 1);
+                assert_eq!(beams.sparks().get(&((1, 1), dir.opposite())),
+                           Some(&Spark::Device));
+            }
+        }
+    }
+
+    #[test]
+    fn splitter_output_clears_earlier_sparks() {
+        // The blue beam reaches the splitter's side first and is rejected,
+        // but then the red beam gets split out through that same side, and
+        // collides with the blue beam at the edge of the cell instead.
+        let mut grid = DeviceGrid::new(3, 3);
+        grid.set(1, 0, Device::Emitter(MixedColor::Blue), Direction::South);
+        grid.set(0, 2, Device::Emitter(MixedColor::Red), Direction::North);
+        grid.set(0, 1, Device::Mirror, Direction::East);
+        grid.set(1, 1, Device::Splitter, Direction::East);
+        let beams = grid.trace_lasers();
+        assert_eq!(beams.color_at(1, 1, Direction::North),
+                   Some(MixedColor::Red));
+        assert_eq!(beams.sparks().get(&((1, 1), Direction::North)),
+                   Some(&Spark::Edge));
+        assert_eq!(beams.color_at(1, 1, Direction::South),
+                   Some(MixedColor::Red));
+    }
+
+    #[test]
+    fn mixer_output_colors() {
+        let primaries =
+            [MixedColor::Red, MixedColor::Green, MixedColor::Blue];
+        for &color in primaries.iter() {
+            assert_eq!(mixer_output(color, color), color);
+        }
+        assert_eq!(mixer_output(MixedColor::Red, MixedColor::Green),
+                   MixedColor::Blue);
+        assert_eq!(mixer_output(MixedColor::Green, MixedColor::Blue),
+                   MixedColor::Red);
+        assert_eq!(mixer_output(MixedColor::Blue, MixedColor::Red),
+                   MixedColor::Green);
+        assert_eq!(mixer_output(MixedColor::Cyan, MixedColor::Red),
+                   MixedColor::Cyan);
+        assert_eq!(mixer_output(MixedColor::White, MixedColor::Red),
+                   MixedColor::White);
+        for color1 in MixedColor::all() {
+            assert_eq!(mixer_output(color1, MixedColor::Black),
+                       MixedColor::Black);
+            for color2 in MixedColor::all() {
+                assert_eq!(mixer_output(color1, color2),
+                           mixer_output(color2, color1));
+            }
+        }
+    }
+
+    #[test]
+    fn mixer_combines_beams_from_both_sides() {
+        for mixer_dir in Direction::all() {
+            let left = mixer_dir.rotated_ccw();
+            let right = mixer_dir.rotated_cw();
+            let mut grid = DeviceGrid::new(5, 5);
+            grid.set(2, 2, Device::Mixer, mixer_dir);
+            let (col, row) = step(step((2, 2), left), left);
+            grid.set(col, row, Device::Emitter(MixedColor::Red), right);
+            let (col, row) = step(step((2, 2), right), right);
+            grid.set(col, row, Device::Emitter(MixedColor::Green), left);
+            let (col, row) = step(step((2, 2), mixer_dir), mixer_dir);
+            grid.set(col,
+                     row,
+                     Device::Detector(MixedColor::Blue),
+                     mixer_dir.opposite());
+            let beams = grid.trace_lasers();
+            assert_eq!(beams.color_at(2, 2, left), Some(MixedColor::Red));
+            assert_eq!(beams.color_at(2, 2, right), Some(MixedColor::Green));
+            assert_eq!(beams.color_at(2, 2, mixer_dir),
+                       Some(MixedColor::Blue));
+            assert_eq!(beams.color_at(2, 2, mixer_dir.opposite()), None);
+            assert!(beams.sparks().is_empty());
+            assert!(beams.satisfied_detectors().contains(&(col, row)));
+            assert!(beams.all_detectors_satisfied());
+        }
+    }
+
+    #[test]
+    fn mixer_needs_beams_on_both_sides() {
+        for mixer_dir in Direction::all() {
+            for &dir in &[mixer_dir.rotated_cw(), mixer_dir.rotated_ccw()] {
+                let grid = grid_with_center(Device::Mixer, mixer_dir, dir);
+                let beams = grid.trace_lasers();
+                assert_eq!(beams.color_at(1, 1, dir.opposite()),
+                           Some(MixedColor::Red));
+                assert_eq!(beams.color_at(1, 1, mixer_dir), None);
+                assert_eq!(beams.segments().len(), 2);
+                assert!(beams.sparks().is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn mixer_rejects_beams_from_front_and_back() {
+        for dir in Direction::all() {
+            for &mixer_dir in &[dir, dir.opposite()] {
+                let grid = grid_with_center(Device::Mixer, mixer_dir, dir);
+                let beams = grid.trace_lasers();
+                assert_eq!(beams.color_at(1, 1, dir.opposite()),
+                           Some(MixedColor::Red));
+                assert_eq!(beams.segments().len(), 2);
+                assert_eq!(beams.sparks().len(), 1);
+                assert_eq!(beams.sparks().get(&((1, 1), dir.opposite())),
+                           Some(&Spark::Device));
+            }
+        }
+    }
+
+    #[test]
+    fn channels_pass_only_parallel_beams() {
+        for dir in Direction::all() {
+            for ch_dir in Direction::all() {
+                let grid = grid_with_center(Device::Channel, ch_dir, dir);
+                let beams = grid.trace_lasers();
+                let (col, row) = step((1, 1), dir.opposite());
+                if ch_dir.is_parallel_to(dir) {
+                    assert_eq!(beams.color_at(1, 1, dir.opposite()),
+                               Some(MixedColor::Red));
+                    assert_eq!(beams.color_at(1, 1, dir),
+                               Some(MixedColor::Red));
+                    assert_eq!(beams.sparks().get(&((col, row), dir)), None);
+                } else {
+                    assert_eq!(beams.color_at(1, 1, dir.opposite()), None);
+                    assert_eq!(beams.segments().len(), 1);
+                    assert_eq!(beams.sparks().len(), 1);
+                    assert_eq!(beams.sparks().get(&((col, row), dir)),
+                               Some(&Spark::Edge));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cross_channels_pass_crossing_beams() {
+        let mut grid = DeviceGrid::new(3, 3);
+        grid.set(1, 1, Device::CrossChannel, Direction::East);
+        grid.set(0, 1, Device::Emitter(MixedColor::Red), Direction::East);
+        grid.set(1, 0, Device::Emitter(MixedColor::Blue), Direction::South);
+        let beams = grid.trace_lasers();
+        assert_eq!(beams.color_at(1, 1, Direction::West),
+                   Some(MixedColor::Red));
+        assert_eq!(beams.color_at(1, 1, Direction::East),
+                   Some(MixedColor::Red));
+        assert_eq!(beams.color_at(1, 1, Direction::North),
+                   Some(MixedColor::Blue));
+        assert_eq!(beams.color_at(1, 1, Direction::South),
+                   Some(MixedColor::Blue));
+        assert_eq!(beams.color_at(2, 1, Direction::East),
+                   Some(MixedColor::Red));
+        assert_eq!(beams.color_at(1, 2, Direction::South),
+                   Some(MixedColor::Blue));
+        for dir in Direction::all() {
+            let grid = grid_with_center(Device::CrossChannel, dir, dir);
+            let beams = grid.trace_lasers();
+            assert_eq!(beams.color_at(1, 1, dir), Some(MixedColor::Red));
+        }
+    }
+
+    #[test]
+    fn detectors_need_matching_color_and_direction() {
+        for dir in Direction::all() {
+            for color in MixedColor::all() {
+                let grid = grid_with_center(Device::Detector(color),
+                                            dir.opposite(),
+                                            dir);
+                let beams = grid.trace_lasers();
+                assert_eq!(beams.color_at(1, 1, dir.opposite()),
+                           Some(MixedColor::Red));
+                let matches = color == MixedColor::Red;
+                assert_eq!(beams.all_detectors_satisfied(), matches);
+                assert_eq!(beams.satisfied_detectors().contains(&(1, 1)),
+                           matches);
+                if matches {
+                    assert!(beams.sparks().is_empty());
+                } else {
+                    assert_eq!(beams.sparks().get(&((1, 1), dir.opposite())),
+                               Some(&Spark::Device));
+                }
+            }
+            for &det_dir in &[dir, dir.rotated_cw(), dir.rotated_ccw()] {
+                let grid = grid_with_center(Device::Detector(MixedColor::Red),
+                                            det_dir,
+                                            dir);
+                let beams = grid.trace_lasers();
+                assert!(!beams.all_detectors_satisfied());
+                assert!(beams.satisfied_detectors().is_empty());
+                assert_eq!(beams.color_at(1, 1, dir.opposite()), None);
+                let coords = step((1, 1), dir.opposite());
+                assert_eq!(beams.sparks().get(&(coords, dir)),
+                           Some(&Spark::Edge));
+            }
+        }
+    }
+
+    #[test]
+    fn grid_without_detectors_is_satisfied() {
+        let mut grid = DeviceGrid::new(2, 2);
+        assert!(grid.trace_lasers().all_detectors_satisfied());
+        grid.set(1, 1, Device::Detector(MixedColor::Red), Direction::West);
+        assert!(!grid.trace_lasers().all_detectors_satisfied());
+        grid.set(0, 1, Device::Emitter(MixedColor::Red), Direction::East);
+        assert!(grid.trace_lasers().all_detectors_satisfied());
+    }
 }
 
 // ========================================================================= //
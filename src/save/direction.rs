@@ -115,7 +115,6 @@ impl Direction {
         }
     }
 
-    #[cfg(test)]
     pub fn all() -> Vec<Direction> {
         vec![
             Direction::East,
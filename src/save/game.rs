@@ -17,6 +17,7 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
+use std::collections::HashMap;
 use toml;
 
 use save::{AtticState, AutoState, BlackState, BlameState, BlindState,
@@ -25,15 +26,19 @@ use save::{AtticState, AutoState, BlackState, BlameState, BlindState,
            HeadedState, HexState, IcyEmState, JogState, LaneState,
            LevelUpState, LineState, Location, LogLevelState, MeetState,
            MissedState, NoReturnState, OrderState, PasswordState, PovState,
-           PrologState, PuzzleState, RightState, SauceState, ServesState,
-           SimpleState, StarState, SyrupState, SyzygyState, TheYState,
-           TreadState, WhatchaState, WreckedState};
-use save::util::{Tomlable, to_table};
+           PrologState, PuzzleState, PuzzleStats, RightState, SauceState,
+           ServesState, SimpleState, StarState, SyrupState, SyzygyState,
+           TheYState, Transcript, TranscriptLine, TreadState, UndoHistory,
+           WhatchaState, WreckedState};
+use save::util::{Tomlable, pop_table, to_table};
 
 // ========================================================================= //
 
 const EVER_CLICKED_INFO_KEY: &str = "ever_clicked_info";
 const LOCATION_KEY: &str = "location";
+const STATS_KEY: &str = "stats";
+const TRANSCRIPTS_KEY: &str = "transcripts";
+const UNDO_HISTORIES_KEY: &str = "undo_histories";
 
 // ========================================================================= //
 
@@ -81,6 +86,9 @@ pub struct Game {
     pub wrecked_angle: WreckedState,
     pub finale: FinaleState,
     pub ever_clicked_info: bool,
+    stats: HashMap<Location, PuzzleStats>,
+    transcripts: HashMap<Location, Transcript>,
+    undo_histories: HashMap<Location, UndoHistory>,
 }
 
 impl Game {
@@ -93,6 +101,31 @@ impl Game {
     pub fn from_toml(value: toml::Value) -> Game {
         let mut table = to_table(value);
         let table_ref = &mut table;
+        let mut stats = HashMap::new();
+        for (key, value) in pop_table(table_ref, STATS_KEY) {
+            if let Some(location) = Location::from_key(&key) {
+                if location != Location::Map {
+                    stats.insert(location, PuzzleStats::from_toml(value));
+                }
+            }
+        }
+        let mut transcripts = HashMap::new();
+        for (key, value) in pop_table(table_ref, TRANSCRIPTS_KEY) {
+            if let Some(location) = Location::from_key(&key) {
+                if location != Location::Map {
+                    transcripts.insert(location, Transcript::from_toml(value));
+                }
+            }
+        }
+        let mut undo_histories = HashMap::new();
+        for (key, value) in pop_table(table_ref, UNDO_HISTORIES_KEY) {
+            if let Some(location) = Location::from_key(&key) {
+                if location != Location::Map {
+                    let history = UndoHistory::from_toml(value);
+                    undo_histories.insert(location, history);
+                }
+            }
+        }
         Game {
             location: Location::pop_from_table(table_ref, LOCATION_KEY),
             prolog: PrologState::pop_from_game_table(table_ref),
@@ -138,6 +171,9 @@ impl Game {
             finale: FinaleState::pop_from_game_table(table_ref),
             ever_clicked_info: bool::pop_from_table(table_ref,
                                                     EVER_CLICKED_INFO_KEY),
+            stats: stats,
+            transcripts: transcripts,
+            undo_histories: undo_histories,
         }
     }
 
@@ -155,6 +191,36 @@ impl Game {
         }
         table.insert(EVER_CLICKED_INFO_KEY.to_string(),
                      toml::Value::Boolean(self.ever_clicked_info));
+        let mut stats = toml::value::Table::new();
+        for (&location, puzzle_stats) in self.stats.iter() {
+            if !puzzle_stats.is_empty() {
+                stats.insert(location.key().to_string(),
+                             puzzle_stats.to_toml());
+            }
+        }
+        if !stats.is_empty() {
+            table.insert(STATS_KEY.to_string(), toml::Value::Table(stats));
+        }
+        let mut transcripts = toml::value::Table::new();
+        for (&location, transcript) in self.transcripts.iter() {
+            if !transcript.is_empty() {
+                transcripts.insert(location.key().to_string(),
+                                   transcript.to_toml());
+            }
+        }
+        if !transcripts.is_empty() {
+            table.insert(TRANSCRIPTS_KEY.to_string(),
+                         toml::Value::Table(transcripts));
+        }
+        let mut undo_histories = toml::value::Table::new();
+        for (&location, history) in self.undo_histories.iter() {
+            undo_histories.insert(location.key().to_string(),
+                                  history.to_toml());
+        }
+        if !undo_histories.is_empty() {
+            table.insert(UNDO_HISTORIES_KEY.to_string(),
+                         toml::Value::Table(undo_histories));
+        }
         toml::Value::Table(table)
     }
 
@@ -166,6 +232,113 @@ impl Game {
         self.puzzle_state(location).has_been_solved()
     }
 
+    pub fn hints_used(&self, location: Location) -> i32 {
+        self.puzzle_state(location).hints_used()
+    }
+
+    pub fn stats(&self, location: Location) -> PuzzleStats {
+        self.stats.get(&location).cloned().unwrap_or_default()
+    }
+
+    pub fn stats_mut(&mut self, location: Location) -> &mut PuzzleStats {
+        assert!(location != Location::Map, "no PuzzleStats for Map");
+        self.stats.entry(location).or_insert_with(PuzzleStats::default)
+    }
+
+    /// Returns the lines of dialogue that the player has seen so far at the
+    /// given location.
+    pub fn transcript(&self, location: Location) -> &[TranscriptLine] {
+        match self.transcripts.get(&location) {
+            Some(transcript) => transcript.lines(),
+            None => &[],
+        }
+    }
+
+    pub fn record_dialogue(&mut self, location: Location,
+                           line: TranscriptLine) {
+        assert!(location != Location::Map, "no Transcript for Map");
+        self.transcripts
+            .entry(location)
+            .or_insert_with(Transcript::default)
+            .record(line);
+    }
+
+    /// Returns the saved undo tree for the given location's puzzle.
+    pub fn undo_history(&self, location: Location) -> UndoHistory {
+        self.undo_histories.get(&location).cloned().unwrap_or_default()
+    }
+
+    pub fn set_undo_history(&mut self, location: Location,
+                            history: UndoHistory) {
+        assert!(location != Location::Map, "no UndoHistory for Map");
+        if history.is_empty() {
+            self.undo_histories.remove(&location);
+        } else {
+            self.undo_histories.insert(location, history);
+        }
+    }
+
+    /// Marks the given location as solved, jumping its puzzle state straight
+    /// to the solution.  Does nothing if the location has already been
+    /// solved.
+    pub fn solve_puzzle(&mut self, loc: Location) {
+        if loc == Location::Map || self.has_been_solved(loc) {
+            return;
+        }
+        // The saved undo history no longer applies to the solved state.
+        self.undo_histories.remove(&loc);
+        match loc {
+            Location::Map => unreachable!(),
+            // The Prolog and Finale are just cutscenes; "visiting" them is
+            // what marks them solved.
+            Location::Prolog => self.prolog.visit(),
+            Location::ALightInTheAttic => self.a_light_in_the_attic.solve(),
+            Location::AutofacTour => self.autofac_tour.solve(),
+            Location::BlackAndBlue => self.black_and_blue.solve(),
+            Location::ColumnAsIcyEm => self.column_as_icy_em.solve(),
+            Location::ConnectTheDots => self.connect_the_dots.solve(),
+            Location::CrossSauce => self.cross_sauce.solve(),
+            Location::CrossTheLine => self.cross_the_line.solve(),
+            Location::CubeTangle => self.cube_tangle.solve(),
+            Location::Disconnected => self.disconnected.solve(),
+            Location::DoubleCross => self.double_cross.solve(),
+            Location::FactOrFiction => self.fact_or_fiction.solve(),
+            Location::HexSpangled => self.hex_spangled.solve(),
+            Location::IceToMeetYou => self.ice_to_meet_you.solve(),
+            Location::IfMemoryServes => self.if_memory_serves.solve(),
+            Location::JogYourMemory => self.jog_your_memory.solve(),
+            Location::LevelHeaded => self.level_headed.solve(),
+            Location::LevelUp => self.level_up.solve(),
+            Location::LightSyrup => self.light_syrup.solve(),
+            Location::LogLevel => self.log_level.solve(),
+            Location::MemoryLane => self.memory_lane.solve(),
+            Location::MissedConnections => self.missed_connections.solve(),
+            Location::PasswordFile => self.password_file.solve(),
+            Location::PlaneAndSimple => self.plane_and_simple.solve(),
+            Location::PlaneAsDay => self.plane_as_day.solve(),
+            Location::PointOfNoReturn => self.point_of_no_return.solve(),
+            Location::PointOfOrder => self.point_of_order.solve(),
+            Location::PointOfView => self.point_of_view.solve(),
+            Location::ShiftGears => self.shift_gears.solve(),
+            Location::ShiftTheBlame => self.shift_the_blame.solve(),
+            Location::ShiftingGround => self.shifting_ground.solve(),
+            Location::StarCrossed => self.star_crossed.solve(),
+            Location::SystemFailure => self.system_failure.solve(),
+            Location::SystemSyzygy => {
+                while !self.system_syzygy.is_solved() {
+                    self.system_syzygy.solve_stage();
+                }
+            }
+            Location::TheIceIsRight => self.the_ice_is_right.solve(),
+            Location::TheYFactor => self.the_y_factor.solve(),
+            Location::ThreeBlindIce => self.three_blind_ice.solve(),
+            Location::TreadLightly => self.tread_lightly.solve(),
+            Location::WhatchaColumn => self.whatcha_column.solve(),
+            Location::WreckedAngle => self.wrecked_angle.solve(),
+            Location::Finale => self.finale.visit(),
+        }
+    }
+
     pub fn puzzle_state(&self, loc: Location) -> &PuzzleState {
         match loc {
             Location::Map => panic!("no PuzzleState for Map"),
@@ -265,7 +438,8 @@ impl Game {
 
 #[cfg(test)]
 mod tests {
-    use save::{Access, Location, PuzzleState};
+    use save::{Access, Location, PuzzleState, TalkStyle, TranscriptLine,
+               UndoHistory, UndoHistoryNode};
     use super::Game;
 
     #[test]
@@ -275,6 +449,81 @@ mod tests {
         assert!(!game.ever_clicked_info);
         assert_eq!(game.prolog.access(), Access::Unvisited);
     }
+
+    #[test]
+    fn solve_every_puzzle() {
+        let mut game = Game::new();
+        for &location in Location::all() {
+            if location != Location::Map {
+                game.solve_puzzle(location);
+                assert!(game.has_been_solved(location),
+                        "{:?} was not solved",
+                        location);
+            }
+        }
+    }
+
+    #[test]
+    fn stats_round_trip() {
+        let mut game = Game::new();
+        game.stats_mut(Location::CubeTangle).moves = 17;
+        game.stats_mut(Location::CubeTangle).first_solved = Some(17500);
+        game.stats_mut(Location::LogLevel).undos = 3;
+        game.stats_mut(Location::Prolog);
+
+        let game = Game::from_toml(game.to_toml());
+        assert_eq!(game.stats(Location::CubeTangle).moves, 17);
+        assert_eq!(game.stats(Location::CubeTangle).first_solved,
+                   Some(17500));
+        assert_eq!(game.stats(Location::LogLevel).undos, 3);
+        assert!(game.stats(Location::Prolog).is_empty());
+        assert!(game.stats(Location::HexSpangled).is_empty());
+    }
+
+    #[test]
+    fn transcripts_round_trip() {
+        let mut game = Game::new();
+        let line = TranscriptLine {
+            speaker: "Elinsa".to_string(),
+            style: TalkStyle::Normal,
+            text: "Huh?".to_string(),
+            scene: "cube_tangle/outro".to_string(),
+            line: 1,
+        };
+        game.record_dialogue(Location::CubeTangle, line.clone());
+
+        let game = Game::from_toml(game.to_toml());
+        assert_eq!(game.transcript(Location::CubeTangle), &[line]);
+        assert!(game.transcript(Location::HexSpangled).is_empty());
+    }
+
+    #[test]
+    fn undo_histories_round_trip() {
+        let mut game = Game::new();
+        let node = |parent, change, redo_child| {
+            UndoHistoryNode {
+                parent: parent,
+                change: change,
+                redo_child: redo_child,
+            }
+        };
+        let history = UndoHistory::new(&[node(0, None, Some(1)),
+                                         node(0, Some((1, 2)), Some(2)),
+                                         node(1, Some((3, 4)), None)],
+                                       1);
+        game.set_undo_history(Location::CubeTangle, history.clone());
+        game.set_undo_history(Location::HexSpangled,
+                              UndoHistory::new(&[node(0, None, Some(1)),
+                                                 node(0, Some((7, 8)), None)],
+                                               1));
+        game.set_undo_history(Location::HexSpangled, UndoHistory::default());
+
+        let mut game = Game::from_toml(game.to_toml());
+        assert_eq!(game.undo_history(Location::CubeTangle), history);
+        assert!(game.undo_history(Location::HexSpangled).is_empty());
+        game.solve_puzzle(Location::CubeTangle);
+        assert!(game.undo_history(Location::CubeTangle).is_empty());
+    }
 }
 
 // ========================================================================= //
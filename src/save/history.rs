@@ -0,0 +1,371 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use toml;
+
+use save::util::{Tomlable, pop_array, to_table};
+
+// ========================================================================= //
+
+const NODES_KEY: &str = "nodes";
+const CURRENT_KEY: &str = "current";
+const PARENT_KEY: &str = "parent";
+const CHANGE_KEY: &str = "change";
+const REDO_CHILD_KEY: &str = "redo";
+
+/// The most nodes to save for each puzzle's undo tree.
+const MAX_SAVED_NODES: usize = 500;
+
+/// When a tree has too many nodes to save, the most moves before (or redos
+/// after) the current node to keep.
+const MAX_SAVED_DEPTH: usize = 100;
+
+// ========================================================================= //
+
+/// One node of a saved undo tree.  Node 0 is the position that the history
+/// starts from, and is the only node with no change; every other node comes
+/// after its parent in the list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UndoHistoryNode<U> {
+    pub parent: usize,
+    pub change: Option<U>,
+    /// The child that a redo from this node steps to, if any.
+    pub redo_child: Option<usize>,
+}
+
+/// A puzzle view's undo tree, saved so that it survives leaving the puzzle
+/// or quitting the game.  The changes are stored as TOML, so that the save
+/// data doesn't need to know what each puzzle's changes look like.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UndoHistory {
+    nodes: Vec<UndoHistoryNode<toml::Value>>,
+    current: usize,
+}
+
+impl UndoHistory {
+    /// Captures the given tree, whose current node is `current`.  If the tree
+    /// is too big, its oldest parts are dropped.
+    pub fn new<U: Tomlable>(nodes: &[UndoHistoryNode<U>], current: usize)
+                            -> UndoHistory {
+        let (nodes, current) = trim(nodes, current, Tomlable::to_toml);
+        UndoHistory {
+            nodes: nodes,
+            current: current,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool { self.nodes.len() <= 1 }
+
+    pub fn nodes<U: Tomlable>(&self) -> Vec<UndoHistoryNode<U>> {
+        self.nodes
+            .iter()
+            .map(|node| {
+                     UndoHistoryNode {
+                         parent: node.parent,
+                         change: node.change.clone().map(U::from_toml),
+                         redo_child: node.redo_child,
+                     }
+                 })
+            .collect()
+    }
+
+    /// Returns the index of the current node.
+    pub fn current(&self) -> usize { self.current }
+}
+
+/// Copies the nodes to save from a tree, converting their changes with
+/// `convert`, and returns them along with the new index of the current node.
+/// If the tree has too many nodes, the saved tree starts at most
+/// `MAX_SAVED_DEPTH` moves before the current node, keeps at most that many
+/// moves of the redo line, and fills the rest of its room with the most
+/// recently made branches.
+fn trim<U, V, F>(nodes: &[UndoHistoryNode<U>], current: usize, convert: F)
+                 -> (Vec<UndoHistoryNode<V>>, usize)
+where
+    F: Fn(&U) -> V,
+{
+    if nodes.is_empty() {
+        return (Vec::new(), 0);
+    }
+    let mut keep = vec![false; nodes.len()];
+    let mut root = 0;
+    if nodes.len() <= MAX_SAVED_NODES {
+        keep = vec![true; nodes.len()];
+    } else {
+        root = current;
+        let mut index = current;
+        for _ in 0..MAX_SAVED_DEPTH {
+            keep[index] = true;
+            if index == 0 {
+                break;
+            }
+            index = nodes[index].parent;
+            root = index;
+        }
+        keep[root] = true;
+        let mut index = current;
+        for _ in 0..MAX_SAVED_DEPTH {
+            match nodes[index].redo_child {
+                Some(child) => {
+                    keep[child] = true;
+                    index = child;
+                }
+                None => break,
+            }
+        }
+        let mut num_kept = keep.iter().filter(|&&kept| kept).count();
+        for newest in (0..nodes.len()).rev() {
+            // Add this node along with any of its ancestors that aren't yet
+            // kept, as long as it is within the root's subtree and fits.
+            let mut branch = Vec::new();
+            let mut index = newest;
+            while !keep[index] && index != 0 {
+                branch.push(index);
+                index = nodes[index].parent;
+            }
+            if keep[index] && num_kept + branch.len() <= MAX_SAVED_NODES {
+                num_kept += branch.len();
+                for index in branch.into_iter() {
+                    keep[index] = true;
+                }
+            }
+        }
+    }
+    // Since every node comes after its parent, the root is the first node
+    // kept, and the kept nodes can be renumbered in order.
+    let mut new_index = vec![0; nodes.len()];
+    let mut num_kept = 0;
+    for (index, &kept) in keep.iter().enumerate() {
+        if kept {
+            new_index[index] = num_kept;
+            num_kept += 1;
+        }
+    }
+    let saved = nodes.iter()
+        .enumerate()
+        .filter(|&(index, _)| keep[index])
+        .map(|(index, node)| {
+            let (parent, change) = if index == root {
+                (0, None)
+            } else {
+                (new_index[node.parent], node.change.as_ref().map(&convert))
+            };
+            let redo_child = match node.redo_child {
+                Some(child) if keep[child] => Some(new_index[child]),
+                _ => None,
+            };
+            UndoHistoryNode {
+                parent: parent,
+                change: change,
+                redo_child: redo_child,
+            }
+        })
+        .collect();
+    (saved, new_index[current])
+}
+
+/// Returns true if the nodes form a tree that `UndoHistory` could have
+/// saved.
+fn is_valid_tree<U>(nodes: &[UndoHistoryNode<U>], current: usize) -> bool {
+    if nodes.is_empty() {
+        return current == 0;
+    }
+    current < nodes.len() &&
+        nodes.iter().enumerate().all(|(index, node)| {
+            let valid_parent = if index == 0 {
+                node.parent == 0 && node.change.is_none()
+            } else {
+                node.parent < index && node.change.is_some()
+            };
+            valid_parent &&
+                node.redo_child.map_or(true, |child| {
+                    child > index && child < nodes.len() &&
+                        nodes[child].parent == index
+                })
+        })
+}
+
+impl Tomlable for UndoHistory {
+    fn to_toml(&self) -> toml::Value {
+        let nodes = self.nodes
+            .iter()
+            .map(|node| {
+                let mut table = toml::value::Table::new();
+                if let Some(ref change) = node.change {
+                    table.insert(PARENT_KEY.to_string(),
+                                 node.parent.to_toml());
+                    table.insert(CHANGE_KEY.to_string(), change.clone());
+                }
+                if let Some(child) = node.redo_child {
+                    table.insert(REDO_CHILD_KEY.to_string(), child.to_toml());
+                }
+                toml::Value::Table(table)
+            })
+            .collect();
+        let mut table = toml::value::Table::new();
+        table.insert(NODES_KEY.to_string(), toml::Value::Array(nodes));
+        table.insert(CURRENT_KEY.to_string(), self.current.to_toml());
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> UndoHistory {
+        let mut table = to_table(value);
+        let nodes: Vec<UndoHistoryNode<toml::Value>> =
+            pop_array(&mut table, NODES_KEY)
+                .into_iter()
+                .map(|value| {
+                    let mut table = to_table(value);
+                    UndoHistoryNode {
+                        parent: usize::pop_from_table(&mut table, PARENT_KEY),
+                        change: table.remove(CHANGE_KEY),
+                        redo_child: table.remove(REDO_CHILD_KEY)
+                            .map(usize::from_toml),
+                    }
+                })
+                .collect();
+        let current = usize::pop_from_table(&mut table, CURRENT_KEY);
+        if !is_valid_tree(&nodes, current) {
+            return UndoHistory::default();
+        }
+        let (nodes, current) = trim(&nodes, current, Clone::clone);
+        UndoHistory {
+            nodes: nodes,
+            current: current,
+        }
+    }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use toml;
+
+    use save::util::Tomlable;
+    use super::{MAX_SAVED_DEPTH, MAX_SAVED_NODES, UndoHistory,
+                UndoHistoryNode};
+
+    fn node(parent: usize, change: Option<char>, redo_child: Option<usize>)
+            -> UndoHistoryNode<char> {
+        UndoHistoryNode {
+            parent: parent,
+            change: change,
+            redo_child: redo_child,
+        }
+    }
+
+    /// Returns a tree with a single line of `len` changes, where each node's
+    /// change is its own index.
+    fn line(len: usize) -> Vec<UndoHistoryNode<usize>> {
+        (0..(len + 1))
+            .map(|index| {
+                UndoHistoryNode {
+                    parent: index.saturating_sub(1),
+                    change: if index == 0 { None } else { Some(index) },
+                    redo_child: if index < len {
+                        Some(index + 1)
+                    } else {
+                        None
+                    },
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        // Node 2 is a branch that was undone before making node 3.
+        let nodes = vec![
+            node(0, None, Some(1)),
+            node(0, Some('a'), Some(3)),
+            node(1, Some('b'), None),
+            node(1, Some('c'), Some(4)),
+            node(3, Some('d'), None),
+        ];
+        let history = UndoHistory::new(&nodes, 3);
+        let history = UndoHistory::from_toml(history.to_toml());
+        assert_eq!(history.nodes::<char>(), nodes);
+        assert_eq!(history.current(), 3);
+        assert!(!history.is_empty());
+        assert!(UndoHistory::new::<char>(&[], 0).is_empty());
+        assert!(UndoHistory::new(&[node(0, None, None)], 0).is_empty());
+    }
+
+    #[test]
+    fn invalid_tree_is_dropped() {
+        let valid = UndoHistory::new(&[node(0, None, Some(1)),
+                                       node(0, Some('a'), None)],
+                                     1);
+        let mut toml = valid.to_toml();
+        toml.as_table_mut()
+            .unwrap()
+            .insert("current".to_string(), toml::Value::Integer(2));
+        assert_eq!(UndoHistory::from_toml(toml), UndoHistory::default());
+        let invalid = UndoHistory::new(&[node(0, None, Some(1)),
+                                         node(1, Some('a'), None)],
+                                       0);
+        let toml = invalid.to_toml();
+        assert_eq!(UndoHistory::from_toml(toml), UndoHistory::default());
+    }
+
+    #[test]
+    fn keeps_most_recent_part_of_long_line() {
+        let len = MAX_SAVED_NODES + 10;
+        let history = UndoHistory::new(&line(len), len - 5);
+        let nodes = history.nodes::<usize>();
+        // The saved tree starts MAX_SAVED_DEPTH moves before the current
+        // node, and keeps the five redos after it.
+        assert_eq!(nodes.len(), MAX_SAVED_DEPTH + 6);
+        assert_eq!(history.current(), MAX_SAVED_DEPTH);
+        assert_eq!(nodes[0].change, None);
+        assert_eq!(nodes[1].change, Some(len - 5 - MAX_SAVED_DEPTH + 1));
+        assert_eq!(nodes[MAX_SAVED_DEPTH].change, Some(len - 5));
+        assert_eq!(nodes.last().unwrap().change, Some(len));
+        assert_eq!(nodes.last().unwrap().redo_child, None);
+    }
+
+    #[test]
+    fn keeps_newest_branches_that_fit() {
+        // A line of redos from the current node, plus more one-change
+        // branches off of the root than there is room for.
+        let mut nodes = line(MAX_SAVED_DEPTH + 1);
+        let num_branches = MAX_SAVED_NODES - nodes.len() + 2;
+        for branch in 0..num_branches {
+            nodes.push(UndoHistoryNode {
+                           parent: 0,
+                           change: Some(1000 + branch),
+                           redo_child: None,
+                       });
+        }
+        let history = UndoHistory::new(&nodes, 1);
+        let saved = history.nodes::<usize>();
+        assert_eq!(saved.len(), MAX_SAVED_NODES);
+        assert_eq!(history.current(), 1);
+        assert_eq!(saved[MAX_SAVED_DEPTH + 1].change,
+                   Some(MAX_SAVED_DEPTH + 1));
+        // The two oldest branches are dropped to make room for the rest.
+        assert!(!saved.iter().any(|node| node.change == Some(1000)));
+        assert!(!saved.iter().any(|node| node.change == Some(1001)));
+        assert_eq!(saved[MAX_SAVED_DEPTH + 2].change, Some(1002));
+        assert_eq!(saved.last().unwrap().change,
+                   Some(1000 + num_branches - 1));
+    }
+}
+
+// ========================================================================= //
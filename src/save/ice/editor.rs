@@ -0,0 +1,430 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use toml;
+
+use gui::Point;
+use save::Direction;
+use save::ice::{BlockSlide, MAX_LAYOUT_COLS, MAX_LAYOUT_ROWS, Object,
+                ObjectGrid, Symbol, Transform};
+use save::solver::{HINT_MAX_STATES, shortest_solution};
+use save::util::to_table;
+
+// ========================================================================= //
+
+/// The size of the grid that the level editor starts with for a new layout.
+const DEFAULT_NUM_COLS: usize = 7;
+const DEFAULT_NUM_ROWS: usize = 5;
+
+// ========================================================================= //
+
+/// Reads a grid layout file written by `save_layout`.
+pub fn load_layout(path: &Path) -> io::Result<ObjectGrid> {
+    let mut file = fs::File::open(path)?;
+    let mut string = String::new();
+    file.read_to_string(&mut string)?;
+    let table = match string.parse::<toml::Value>() {
+        Ok(value) => to_table(value),
+        Err(_) => {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                      "failed to parse toml"));
+        }
+    };
+    ObjectGrid::from_layout_toml(table)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// Writes the grid's layout to a file, in the format of
+/// `ObjectGrid::to_layout_toml`.
+pub fn save_layout(path: &Path, grid: &ObjectGrid) -> io::Result<()> {
+    let string = grid.to_layout_toml().to_string();
+    let mut file = fs::File::create(path)?;
+    file.write_all(string.as_bytes())?;
+    Ok(())
+}
+
+// ========================================================================= //
+
+/// What clicking on a grid cell does in the level editor.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EditorTool {
+    Erase,
+    Wall,
+    Gap,
+    Rotator,
+    Reflector,
+    PushPop,
+    Goal,
+    Block,
+}
+
+impl EditorTool {
+    pub fn all() -> Vec<EditorTool> {
+        vec![
+            EditorTool::Erase,
+            EditorTool::Wall,
+            EditorTool::Gap,
+            EditorTool::Rotator,
+            EditorTool::Reflector,
+            EditorTool::PushPop,
+            EditorTool::Goal,
+            EditorTool::Block,
+        ]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EditorTool::Erase => "Erase",
+            EditorTool::Wall => "Wall",
+            EditorTool::Gap => "Gap",
+            EditorTool::Rotator => "Rotate",
+            EditorTool::Reflector => "Reflect",
+            EditorTool::PushPop => "Push",
+            EditorTool::Goal => "Goal",
+            EditorTool::Block => "Block",
+        }
+    }
+}
+
+/// The number of different symbol shapes that the editor can cycle through.
+const NUM_SYMBOL_SHAPES: usize = 9;
+
+/// Returns the untransformed symbol for the given shape index.
+fn base_symbol(shape: usize) -> Symbol {
+    let identity = Transform::identity();
+    match shape {
+        0 => Symbol::RedTriangle(Direction::North),
+        1 => Symbol::GreenSquare,
+        2 => Symbol::BlueCircle,
+        3 => Symbol::YellowRhombus(false, false),
+        4 => Symbol::PurpleCheckmark(identity),
+        5 => Symbol::CyanQ(identity),
+        6 => Symbol::CyanU(identity),
+        7 => Symbol::CyanA(identity),
+        _ => Symbol::Mirror(false),
+    }
+}
+
+// ========================================================================= //
+
+/// The state of the ice-puzzle level editor: the layout being edited, the
+/// currently selected tool, and (while playtesting) a copy of the layout
+/// that the designer can slide blocks around in.
+pub struct IceEditor {
+    layout: ObjectGrid,
+    tool: EditorTool,
+    shape: usize,
+    transform: Transform,
+    playtest: Option<ObjectGrid>,
+    slides: Vec<BlockSlide>,
+    unsaved: bool,
+}
+
+impl IceEditor {
+    pub fn new(layout: ObjectGrid) -> IceEditor {
+        IceEditor {
+            layout: layout,
+            tool: EditorTool::Wall,
+            shape: 0,
+            transform: Transform::identity(),
+            playtest: None,
+            slides: Vec::new(),
+            unsaved: false,
+        }
+    }
+
+    /// Starts editing a new, empty layout.
+    pub fn empty() -> IceEditor {
+        IceEditor::new(ObjectGrid::new(DEFAULT_NUM_COLS, DEFAULT_NUM_ROWS))
+    }
+
+    pub fn layout(&self) -> &ObjectGrid { &self.layout }
+
+    /// Returns true if the layout has changed since it was last saved.
+    pub fn has_unsaved_changes(&self) -> bool { self.unsaved }
+
+    pub fn mark_saved(&mut self) { self.unsaved = false; }
+
+    pub fn tool(&self) -> EditorTool { self.tool }
+
+    pub fn set_tool(&mut self, tool: EditorTool) { self.tool = tool; }
+
+    /// Returns the symbol that the goal and block tools currently place.
+    pub fn symbol(&self) -> Symbol {
+        base_symbol(self.shape).transformed(self.transform)
+    }
+
+    pub fn next_shape(&mut self) {
+        self.shape = (self.shape + 1) % NUM_SYMBOL_SHAPES;
+    }
+
+    pub fn rotate(&mut self) { self.transform = self.transform.rotated_cw(); }
+
+    pub fn flip(&mut self) { self.transform = self.transform.flipped_horz(); }
+
+    /// Returns the object that the current tool places, if any.  Reflectors
+    /// and push-pops are oriented by the current transform, just like
+    /// symbols are.
+    pub fn tool_object(&self) -> Option<Object> {
+        match self.tool {
+            EditorTool::Erase | EditorTool::Block => None,
+            EditorTool::Wall => Some(Object::Wall),
+            EditorTool::Gap => Some(Object::Gap),
+            EditorTool::Rotator => Some(Object::Rotator),
+            EditorTool::Reflector => {
+                let vertical = self.transform.apply_to_vertical(false);
+                Some(Object::Reflector(vertical))
+            }
+            EditorTool::PushPop => {
+                let dir = self.transform.apply_to_direction(Direction::East);
+                Some(Object::PushPop(dir))
+            }
+            EditorTool::Goal => Some(Object::Goal(self.symbol())),
+        }
+    }
+
+    /// Applies the current tool to the given cell of the layout.  Returns
+    /// true if the layout changed.
+    pub fn apply_tool(&mut self, coords: Point) -> bool {
+        if self.playtest.is_some() || !self.layout.contains(coords) {
+            return false;
+        }
+        let old_object = self.layout.objects().get(&coords).cloned();
+        let old_block = self.layout.ice_blocks().get(&coords).cloned();
+        let (object, block) = match self.tool {
+            EditorTool::Erase => (None, None),
+            EditorTool::Block => {
+                // Blocks can't sit on top of walls, gaps, or push-pops.
+                let object = match old_object {
+                    Some(Object::Wall) |
+                    Some(Object::Gap) |
+                    Some(Object::PushPop(_)) => None,
+                    other => other,
+                };
+                (object, Some(self.symbol()))
+            }
+            _ => {
+                let object = self.tool_object();
+                let block = match object {
+                    Some(Object::Wall) |
+                    Some(Object::Gap) |
+                    Some(Object::PushPop(_)) => None,
+                    _ => old_block,
+                };
+                (object, block)
+            }
+        };
+        if object == old_object && block == old_block {
+            return false;
+        }
+        self.layout.set_object(coords, object);
+        self.layout.set_ice_block(coords, block);
+        self.unsaved = true;
+        true
+    }
+
+    /// Grows or shrinks the layout by the given number of columns and rows,
+    /// within the allowed limits.  Returns true if the size changed.
+    pub fn resize(&mut self, delta_cols: i32, delta_rows: i32) -> bool {
+        if self.playtest.is_some() {
+            return false;
+        }
+        let (num_cols, num_rows) = self.layout.size();
+        let new_cols = (num_cols + delta_cols).max(1).min(MAX_LAYOUT_COLS);
+        let new_rows = (num_rows + delta_rows).max(1).min(MAX_LAYOUT_ROWS);
+        if (new_cols, new_rows) == (num_cols, num_rows) {
+            return false;
+        }
+        self.layout = self.layout.resized(new_cols, new_rows);
+        self.unsaved = true;
+        true
+    }
+
+    /// Returns the number of moves in the shortest solution to the layout,
+    /// or `None` if no solution could be found.
+    pub fn solution_length(&self) -> Option<usize> {
+        if self.layout.ice_blocks().is_empty() {
+            return None;
+        }
+        shortest_solution(&self.layout, HINT_MAX_STATES)
+            .map(|solution| solution.len())
+    }
+
+    pub fn is_playtesting(&self) -> bool { self.playtest.is_some() }
+
+    /// Starts (or restarts) playtesting from the layout's initial state.
+    pub fn start_playtest(&mut self) {
+        self.playtest = Some(self.layout.clone());
+        self.slides.clear();
+    }
+
+    pub fn stop_playtest(&mut self) {
+        self.playtest = None;
+        self.slides.clear();
+    }
+
+    /// Returns the grid to display: the playtest grid while playtesting, or
+    /// the layout otherwise.
+    pub fn grid(&self) -> &ObjectGrid {
+        self.playtest.as_ref().unwrap_or(&self.layout)
+    }
+
+    /// Returns the playtest grid, or `None` when not playtesting.
+    pub fn playtest_grid_mut(&mut self) -> Option<&mut ObjectGrid> {
+        self.playtest.as_mut()
+    }
+
+    /// Slides a block in the playtest grid.  Does nothing (and returns
+    /// `None`) when not playtesting.
+    pub fn slide_ice_block(&mut self, coords: Point, dir: Direction)
+                           -> Option<BlockSlide> {
+        let slide = self.playtest
+            .as_mut()
+            .and_then(|grid| grid.slide_ice_block(coords, dir));
+        if let Some(ref slide) = slide {
+            self.slides.push(slide.clone());
+        }
+        slide
+    }
+
+    /// Undoes the most recent slide made while playtesting.  Returns false
+    /// if there was nothing to undo.
+    pub fn undo_slide(&mut self) -> bool {
+        if let Some(ref mut grid) = self.playtest {
+            if let Some(slide) = self.slides.pop() {
+                grid.undo_slide(&slide);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns true if playtesting and every block is on a matching goal.
+    pub fn is_solved(&self) -> bool {
+        self.playtest.as_ref().map_or(false, ObjectGrid::all_blocks_on_goals)
+    }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use gui::Point;
+    use save::Direction;
+    use save::ice::{Object, Symbol};
+    use super::{EditorTool, IceEditor};
+
+    #[test]
+    fn place_and_erase() {
+        let mut editor = IceEditor::empty();
+        let coords = Point::new(1, 1);
+        editor.set_tool(EditorTool::Wall);
+        assert!(editor.apply_tool(coords));
+        assert!(!editor.apply_tool(coords));
+        assert_eq!(editor.layout().objects().get(&coords),
+                   Some(&Object::Wall));
+        assert!(editor.has_unsaved_changes());
+        editor.mark_saved();
+        editor.set_tool(EditorTool::Erase);
+        assert!(editor.apply_tool(coords));
+        assert!(editor.layout().objects().is_empty());
+        assert!(editor.has_unsaved_changes());
+        assert!(!editor.apply_tool(Point::new(-1, 0)));
+    }
+
+    #[test]
+    fn blocks_replace_solid_objects() {
+        let mut editor = IceEditor::empty();
+        let coords = Point::new(2, 3);
+        editor.set_tool(EditorTool::Goal);
+        editor.apply_tool(coords);
+        editor.set_tool(EditorTool::Block);
+        editor.apply_tool(coords);
+        // A block can sit on a goal...
+        assert!(editor.layout().objects().contains_key(&coords));
+        assert_eq!(editor.layout().ice_blocks().get(&coords),
+                   Some(&editor.symbol()));
+        // ...but placing a wall removes it.
+        editor.set_tool(EditorTool::Wall);
+        editor.apply_tool(coords);
+        assert!(editor.layout().ice_blocks().is_empty());
+    }
+
+    #[test]
+    fn transform_orients_tools() {
+        let mut editor = IceEditor::empty();
+        assert_eq!(editor.symbol(), Symbol::RedTriangle(Direction::North));
+        editor.rotate();
+        assert_eq!(editor.symbol(), Symbol::RedTriangle(Direction::East));
+        editor.set_tool(EditorTool::PushPop);
+        assert_eq!(editor.tool_object(),
+                   Some(Object::PushPop(Direction::South)));
+        editor.set_tool(EditorTool::Reflector);
+        assert_eq!(editor.tool_object(), Some(Object::Reflector(true)));
+        editor.next_shape();
+        assert_eq!(editor.symbol(), Symbol::GreenSquare);
+    }
+
+    #[test]
+    fn playtest_leaves_layout_alone() {
+        let mut editor = IceEditor::empty();
+        editor.set_tool(EditorTool::Block);
+        editor.apply_tool(Point::new(0, 0));
+        editor.set_tool(EditorTool::Goal);
+        editor.apply_tool(Point::new(4, 0));
+        // Without anything to stop the block on the goal, there's no
+        // solution.
+        assert_eq!(editor.solution_length(), None);
+        editor.set_tool(EditorTool::Wall);
+        editor.apply_tool(Point::new(5, 0));
+        assert_eq!(editor.solution_length(), Some(1));
+        editor.mark_saved();
+        editor.start_playtest();
+        assert!(!editor.apply_tool(Point::new(2, 2)));
+        assert!(!editor.resize(1, 0));
+        let slide = editor.slide_ice_block(Point::new(0, 0), Direction::East);
+        assert!(slide.is_some());
+        assert!(editor.is_solved());
+        assert!(editor.grid().ice_blocks().contains_key(&Point::new(4, 0)));
+        assert!(editor.undo_slide());
+        assert!(!editor.undo_slide());
+        assert!(!editor.is_solved());
+        assert!(editor.grid().ice_blocks().contains_key(&Point::new(0, 0)));
+        editor.stop_playtest();
+        assert!(editor.layout()
+                    .ice_blocks()
+                    .contains_key(&Point::new(0, 0)));
+        assert!(!editor.has_unsaved_changes());
+    }
+
+    #[test]
+    fn resize_within_limits() {
+        let mut editor = IceEditor::empty();
+        assert!(editor.resize(1, -1));
+        assert_eq!(editor.layout().size(), (8, 4));
+        assert!(editor.resize(100, 100));
+        assert_eq!(editor.layout().size(), (10, 8));
+        assert!(!editor.resize(1, 1));
+    }
+}
+
+// ========================================================================= //
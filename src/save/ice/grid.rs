@@ -23,6 +23,7 @@ use toml;
 use gui::Point;
 use save::Direction;
 use save::ice::{Symbol, Transform};
+use save::solver::Solvable;
 use save::util::{Tomlable, pop_array, to_table};
 
 // ========================================================================= //
@@ -33,6 +34,20 @@ const COL_KEY: &str = "col";
 const ROW_KEY: &str = "row";
 const DIRECTION_KEY: &str = "direction";
 const SYMBOL_KEY: &str = "symbol";
+const NUM_COLS_KEY: &str = "num_cols";
+const NUM_ROWS_KEY: &str = "num_rows";
+const OBJECTS_KEY: &str = "objects";
+const KIND_KEY: &str = "kind";
+const VERTICAL_KEY: &str = "vertical";
+const FROM_KEY: &str = "from";
+const TO_KEY: &str = "to";
+const PUSHED_KEY: &str = "pushed";
+const TRANSFORM_KEY: &str = "transform";
+
+/// The largest number of columns that a grid layout may have.
+pub const MAX_LAYOUT_COLS: i32 = 10;
+/// The largest number of rows that a grid layout may have.
+pub const MAX_LAYOUT_ROWS: i32 = 8;
 
 // ========================================================================= //
 
@@ -64,6 +79,32 @@ impl BlockSlide {
     pub fn transform(&self) -> Transform { self.transform }
 }
 
+impl Tomlable for BlockSlide {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        table.insert(FROM_KEY.to_string(), self.from.to_toml());
+        table.insert(DIRECTION_KEY.to_string(), self.direction.to_toml());
+        table.insert(TO_KEY.to_string(), self.to.to_toml());
+        if let Some(pushed) = self.pushed {
+            table.insert(PUSHED_KEY.to_string(), pushed.to_toml());
+        }
+        table.insert(TRANSFORM_KEY.to_string(), self.transform.to_toml());
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> BlockSlide {
+        let mut table = to_table(value);
+        let pushed = table.remove(PUSHED_KEY).map(Point::from_toml);
+        BlockSlide {
+            from: Point::pop_from_table(&mut table, FROM_KEY),
+            direction: Direction::pop_from_table(&mut table, DIRECTION_KEY),
+            to: Point::pop_from_table(&mut table, TO_KEY),
+            pushed: pushed,
+            transform: Transform::pop_from_table(&mut table, TRANSFORM_KEY),
+        }
+    }
+}
+
 // ========================================================================= //
 
 #[derive(Clone)]
@@ -169,8 +210,156 @@ impl ObjectGrid {
         toml::Value::Table(table)
     }
 
+    /// Writes out the complete layout of the grid: its size and all of its
+    /// objects, as well as the blocks and push-pops written by `to_toml`.
+    /// This is the format used for custom puzzles made in the level editor.
+    pub fn to_layout_toml(&self) -> toml::Value {
+        let mut table = to_table(self.to_toml());
+        table.insert(NUM_COLS_KEY.to_string(),
+                     toml::Value::Integer(self.num_cols as i64));
+        table.insert(NUM_ROWS_KEY.to_string(),
+                     toml::Value::Integer(self.num_rows as i64));
+        let mut objects: Vec<(Point, Object)> = self.objects
+            .iter()
+            .map(|(&coords, &object)| (coords, object))
+            .collect();
+        objects.sort_by_key(|&(coords, _)| (coords.y(), coords.x()));
+        let mut objects_toml = toml::value::Array::new();
+        for (coords, object) in objects.into_iter() {
+            let mut object_toml = toml::value::Table::new();
+            let kind = match object {
+                Object::Gap => "gap",
+                Object::Wall => "wall",
+                Object::PushPop(_) => continue,
+                Object::Rotator => "rotator",
+                Object::Reflector(vertical) => {
+                    object_toml.insert(VERTICAL_KEY.to_string(),
+                                       toml::Value::Boolean(vertical));
+                    "reflector"
+                }
+                Object::Goal(symbol) => {
+                    object_toml.insert(SYMBOL_KEY.to_string(),
+                                       symbol.to_toml());
+                    "goal"
+                }
+            };
+            object_toml.insert(COL_KEY.to_string(),
+                               toml::Value::Integer(coords.x() as i64));
+            object_toml.insert(ROW_KEY.to_string(),
+                               toml::Value::Integer(coords.y() as i64));
+            object_toml.insert(KIND_KEY.to_string(),
+                               toml::Value::String(kind.to_string()));
+            objects_toml.push(toml::Value::Table(object_toml));
+        }
+        table.insert(OBJECTS_KEY.to_string(),
+                     toml::Value::Array(objects_toml));
+        toml::Value::Table(table)
+    }
+
+    /// Reads a complete grid layout, as written by `to_layout_toml`.  Unlike
+    /// `from_toml`, there's no default grid to fall back on, so a malformed
+    /// layout is an error.
+    pub fn from_layout_toml(mut table: toml::value::Table)
+                            -> Result<ObjectGrid, String> {
+        let num_cols = i32::pop_from_table(&mut table, NUM_COLS_KEY);
+        let num_rows = i32::pop_from_table(&mut table, NUM_ROWS_KEY);
+        if (num_cols < 1 || num_cols > MAX_LAYOUT_COLS) ||
+            (num_rows < 1 || num_rows > MAX_LAYOUT_ROWS)
+        {
+            return Err(format!("invalid grid size: {}x{}",
+                               num_cols,
+                               num_rows));
+        }
+        let mut grid = ObjectGrid::new(num_cols as usize, num_rows as usize);
+        for object_toml in pop_array(&mut table, OBJECTS_KEY).into_iter() {
+            let mut object_toml = to_table(object_toml);
+            let coords = grid.pop_coords(&mut object_toml)?;
+            let kind = String::pop_from_table(&mut object_toml, KIND_KEY);
+            let object = match kind.as_str() {
+                "gap" => Object::Gap,
+                "wall" => Object::Wall,
+                "rotator" => Object::Rotator,
+                "reflector" => {
+                    let key = VERTICAL_KEY;
+                    Object::Reflector(bool::pop_from_table(&mut object_toml,
+                                                           key))
+                }
+                "goal" => {
+                    let key = SYMBOL_KEY;
+                    Object::Goal(Symbol::pop_from_table(&mut object_toml,
+                                                        key))
+                }
+                _ => return Err(format!("invalid object kind: {:?}", kind)),
+            };
+            grid.insert_object(coords, object)?;
+        }
+        for pp_toml in pop_array(&mut table, PUSH_POPS_KEY).into_iter() {
+            let mut pp_toml = to_table(pp_toml);
+            let coords = grid.pop_coords(&mut pp_toml)?;
+            let dir = Direction::pop_from_table(&mut pp_toml, DIRECTION_KEY);
+            grid.insert_object(coords, Object::PushPop(dir))?;
+        }
+        for block_toml in pop_array(&mut table, BLOCKS_KEY).into_iter() {
+            let mut block_toml = to_table(block_toml);
+            let coords = grid.pop_coords(&mut block_toml)?;
+            let symbol = Symbol::pop_from_table(&mut block_toml, SYMBOL_KEY);
+            if grid.ice_blocks.contains_key(&coords) {
+                return Err(format!("more than one block at ({}, {})",
+                                   coords.x(),
+                                   coords.y()));
+            }
+            grid.ice_blocks.insert(coords, symbol);
+        }
+        Ok(grid)
+    }
+
+    fn pop_coords(&self, table: &mut toml::value::Table)
+                  -> Result<Point, String> {
+        let col = i32::pop_from_table(table, COL_KEY);
+        let row = i32::pop_from_table(table, ROW_KEY);
+        if self.contains(Point::new(col, row)) {
+            Ok(Point::new(col, row))
+        } else {
+            Err(format!("position out of bounds: ({}, {})", col, row))
+        }
+    }
+
+    fn insert_object(&mut self, coords: Point, object: Object)
+                     -> Result<(), String> {
+        if self.objects.contains_key(&coords) {
+            return Err(format!("more than one object at ({}, {})",
+                               coords.x(),
+                               coords.y()));
+        }
+        self.objects.insert(coords, object);
+        Ok(())
+    }
+
     pub fn size(&self) -> (i32, i32) { (self.num_cols, self.num_rows) }
 
+    /// Returns true if the given coordinates lie within the grid.
+    pub fn contains(&self, coords: Point) -> bool {
+        (coords.x() >= 0 && coords.x() < self.num_cols) &&
+            (coords.y() >= 0 && coords.y() < self.num_rows)
+    }
+
+    /// Returns a copy of this grid with a new size, dropping any objects or
+    /// blocks that no longer fit.
+    pub fn resized(&self, num_cols: i32, num_rows: i32) -> ObjectGrid {
+        let mut grid = ObjectGrid::new(num_cols as usize, num_rows as usize);
+        for (&coords, &object) in self.objects.iter() {
+            if grid.contains(coords) {
+                grid.objects.insert(coords, object);
+            }
+        }
+        for (&coords, &symbol) in self.ice_blocks.iter() {
+            if grid.contains(coords) {
+                grid.ice_blocks.insert(coords, symbol);
+            }
+        }
+        grid
+    }
+
     pub fn is_modified(&self) -> bool { self.is_modified }
 
     pub fn add_object(&mut self, col: i32, row: i32, obj: Object) {
@@ -183,6 +372,17 @@ impl ObjectGrid {
 
     pub fn objects(&self) -> &HashMap<Point, Object> { &self.objects }
 
+    /// Places the given object at the given coordinates (replacing any
+    /// object already there), or clears the object there if `object` is
+    /// `None`.
+    pub fn set_object(&mut self, coords: Point, object: Option<Object>) {
+        debug_assert!(self.contains(coords));
+        match object {
+            Some(object) => self.objects.insert(coords, object),
+            None => self.objects.remove(&coords),
+        };
+    }
+
     pub fn add_ice_block(&mut self, col: i32, row: i32, symbol: Symbol) {
         debug_assert!(col >= 0 && col < self.num_cols);
         debug_assert!(row >= 0 && row < self.num_rows);
@@ -193,6 +393,17 @@ impl ObjectGrid {
 
     pub fn ice_blocks(&self) -> &HashMap<Point, Symbol> { &self.ice_blocks }
 
+    /// Places an ice block with the given symbol at the given coordinates
+    /// (replacing any block already there), or clears the block there if
+    /// `symbol` is `None`.
+    pub fn set_ice_block(&mut self, coords: Point, symbol: Option<Symbol>) {
+        debug_assert!(self.contains(coords));
+        match symbol {
+            Some(symbol) => self.ice_blocks.insert(coords, symbol),
+            None => self.ice_blocks.remove(&coords),
+        };
+    }
+
     pub fn slide_ice_block(&mut self, coords: Point, slide_dir: Direction)
                            -> Option<BlockSlide> {
         if let Some(symbol) = self.ice_blocks.remove(&coords) {
@@ -305,9 +516,44 @@ impl ObjectGrid {
     }
 }
 
+impl Solvable for ObjectGrid {
+    type Move = (Point, Direction);
+    type Key = (Vec<(i32, i32, Symbol)>, Vec<(i32, i32, Object)>);
+
+    fn search_key(&self) -> Self::Key {
+        let mut blocks: Vec<(i32, i32, Symbol)> = self.ice_blocks
+            .iter()
+            .map(|(coords, &symbol)| (coords.x(), coords.y(), symbol))
+            .collect();
+        blocks.sort_by_key(|&(x, y, _)| (x, y));
+        let mut objects: Vec<(i32, i32, Object)> = self.objects
+            .iter()
+            .map(|(coords, &object)| (coords.x(), coords.y(), object))
+            .collect();
+        objects.sort_by_key(|&(x, y, _)| (x, y));
+        (blocks, objects)
+    }
+
+    fn legal_moves(&self) -> Vec<(Point, Direction)> {
+        let mut moves = Vec::new();
+        for &coords in self.ice_blocks.keys() {
+            for dir in Direction::all() {
+                moves.push((coords, dir));
+            }
+        }
+        moves
+    }
+
+    fn apply_move(&mut self, &(coords, dir): &(Point, Direction)) {
+        self.slide_ice_block(coords, dir);
+    }
+
+    fn is_goal(&self) -> bool { self.all_blocks_on_goals() }
+}
+
 // ========================================================================= //
 
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Object {
     Gap,
     Wall,
@@ -318,3 +564,107 @@ pub enum Object {
 }
 
 // ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use toml;
+
+    use gui::Point;
+    use save::Direction;
+    use save::ice::{Symbol, Transform};
+    use save::util::{Tomlable, to_table};
+    use super::{BlockSlide, Object, ObjectGrid};
+
+    fn test_grid() -> ObjectGrid {
+        let mut grid = ObjectGrid::new(5, 4);
+        grid.add_object(0, 0, Object::Gap);
+        grid.add_object(1, 0, Object::Wall);
+        grid.add_object(2, 0, Object::PushPop(Direction::South));
+        grid.add_object(3, 0, Object::Rotator);
+        grid.add_object(4, 0, Object::Reflector(true));
+        let transform = Transform::identity().rotated_cw().flipped_horz();
+        grid.add_object(0, 3, Object::Goal(Symbol::CyanQ(transform)));
+        grid.add_ice_block(2, 2, Symbol::RedTriangle(Direction::West));
+        grid
+    }
+
+    fn parse(string: &str) -> Result<ObjectGrid, String> {
+        let table = to_table(string.parse::<toml::Value>().unwrap());
+        ObjectGrid::from_layout_toml(table)
+    }
+
+    #[test]
+    fn layout_toml_round_trip() {
+        let grid = test_grid();
+        let table = to_table(grid.to_layout_toml());
+        let loaded = ObjectGrid::from_layout_toml(table).unwrap();
+        assert_eq!(loaded.size(), (5, 4));
+        assert_eq!(loaded.objects(), grid.objects());
+        assert_eq!(loaded.ice_blocks(), grid.ice_blocks());
+        assert!(!loaded.is_modified());
+    }
+
+    #[test]
+    fn layout_toml_is_superset_of_saved_toml() {
+        // A saved grid state read back with from_toml (as a saved puzzle's
+        // grid is) should ignore the extra layout keys.
+        let grid = test_grid();
+        let table = to_table(grid.to_layout_toml());
+        let loaded = ObjectGrid::from_toml(table, &grid);
+        assert_eq!(loaded.objects(), grid.objects());
+        assert_eq!(loaded.ice_blocks(), grid.ice_blocks());
+    }
+
+    #[test]
+    fn invalid_layouts() {
+        assert!(parse("num_cols = 0\nnum_rows = 3").is_err());
+        assert!(parse("num_cols = 11\nnum_rows = 3").is_err());
+        assert!(parse("num_cols = 3\nnum_rows = 0").is_err());
+        assert!(parse("num_cols = 3\nnum_rows = 9").is_err());
+        assert!(parse("num_cols = 3\nnum_rows = 3").is_ok());
+        let block = "[[blocks]]\ncol = 1\nrow = 1\n\
+                     symbol = { shape = \"GS\" }\n";
+        let valid = format!("num_cols = 3\nnum_rows = 3\n{}", block);
+        assert!(parse(&valid).is_ok());
+        let doubled = format!("{}{}", valid, block);
+        assert!(parse(&doubled).is_err());
+        let out_of_bounds = format!("{}[[objects]]\ncol = 3\nrow = 0\n\
+                                     kind = \"wall\"\n",
+                                    valid);
+        assert!(parse(&out_of_bounds).is_err());
+        let bad_kind = format!("{}[[objects]]\ncol = 0\nrow = 0\n\
+                                kind = \"lava\"\n",
+                               valid);
+        assert!(parse(&bad_kind).is_err());
+    }
+
+    #[test]
+    fn resize_drops_contents_outside() {
+        let grid = test_grid().resized(4, 3);
+        assert_eq!(grid.size(), (4, 3));
+        assert_eq!(grid.objects().len(), 4);
+        assert!(grid.objects().get(&Point::new(4, 0)).is_none());
+        assert_eq!(grid.ice_blocks().len(), 1);
+    }
+
+    #[test]
+    fn block_slide_toml_round_trip() {
+        let original = test_grid();
+        let mut grid = original.clone();
+        let slide = grid.slide_ice_block(Point::new(2, 2), Direction::North)
+            .unwrap();
+        let slid = grid.ice_blocks().clone();
+        let loaded = BlockSlide::from_toml(slide.to_toml());
+        assert_eq!(loaded.direction(), slide.direction());
+        assert_eq!(loaded.to_coords(), slide.to_coords());
+        assert_eq!(loaded.pushed(), slide.pushed());
+        assert_eq!(loaded.transform(), slide.transform());
+        grid.undo_slide(&loaded);
+        assert_eq!(grid.ice_blocks(), original.ice_blocks());
+        assert_eq!(grid.objects(), original.objects());
+        grid.redo_slide(&loaded);
+        assert_eq!(grid.ice_blocks(), &slid);
+    }
+}
+
+// ========================================================================= //
@@ -17,11 +17,14 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
+mod editor;
 mod grid;
 mod symbol;
 mod transform;
 
-pub use self::grid::{BlockSlide, Object, ObjectGrid};
+pub use self::editor::{EditorTool, IceEditor, load_layout, save_layout};
+pub use self::grid::{BlockSlide, MAX_LAYOUT_COLS, MAX_LAYOUT_ROWS, Object,
+                     ObjectGrid};
 pub use self::symbol::Symbol;
 pub use self::transform::Transform;
 
@@ -25,7 +25,7 @@ use save::util::{Tomlable, to_table};
 
 // ========================================================================= //
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Symbol {
     RedTriangle(Direction),
     GreenSquare,
@@ -25,7 +25,7 @@ use save::util::Tomlable;
 
 // ========================================================================= //
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Transform {
     rotated_cw: i32,
     mirrored: bool,
@@ -282,18 +282,18 @@ impl Location {
             Location::Finale => "finale",
         }
     }
+
+    pub fn from_key(key: &str) -> Option<Location> {
+        Location::all().iter().cloned().find(|loc| loc.key() == key)
+    }
 }
 
 impl Tomlable for Location {
     fn from_toml(value: toml::Value) -> Location {
-        if let Some(string) = value.as_str() {
-            for &location in Location::all() {
-                if string == location.key() {
-                    return location;
-                }
-            }
-        }
-        Default::default()
+        value
+            .as_str()
+            .and_then(Location::from_key)
+            .unwrap_or_default()
     }
 
     fn to_toml(&self) -> toml::Value {
@@ -367,6 +367,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn key_round_trip() {
+        for &original in Location::all() {
+            assert_eq!(Location::from_key(original.key()), Some(original));
+        }
+        assert_eq!(Location::from_key("not_a_location"), None);
+    }
+
     #[test]
     fn come_from_prereq() {
         for from in Location::all() {
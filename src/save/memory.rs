@@ -22,6 +22,7 @@ use std::collections::{HashMap, HashSet};
 use toml;
 
 use save::Direction;
+use save::rng::with_game_rng;
 use save::util::Tomlable;
 
 // ========================================================================= //
@@ -165,9 +166,9 @@ impl Grid {
                 indices.push(index);
             }
         }
-        let sample = rand::seq::sample_slice(&mut rand::thread_rng(),
-                                             &indices,
-                                             indices.len().min(num));
+        let sample = with_game_rng(|rng| {
+            rand::seq::sample_slice(rng, &indices, indices.len().min(num))
+        });
         for index in sample {
             self.values[index] = -self.values[index];
         }
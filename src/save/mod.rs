@@ -25,6 +25,7 @@ mod data;
 pub mod device;
 mod direction;
 mod game;
+mod history;
 pub mod ice;
 mod location;
 pub mod memory;
@@ -33,29 +34,37 @@ pub mod plane;
 mod prefs;
 mod puzzles;
 pub mod pyramid;
+mod rng;
+pub mod solver;
+mod stats;
 pub mod tree;
+mod transcript;
 pub mod util;
+mod version;
 
 pub use self::access::Access;
 pub use self::color::{MixedColor, PrimaryColor};
 pub use self::crossword::{CrosswordState, ValidChars};
-pub use self::data::SaveData;
+pub use self::data::{MAX_SLOT_NAME_LEN, SaveData};
 pub use self::direction::Direction;
 pub use self::game::Game;
+pub use self::history::{UndoHistory, UndoHistoryNode};
 pub use self::location::Location;
 pub use self::path::get_default_save_file_path;
 pub use self::prefs::Prefs;
 pub use self::puzzles::{AtticState, AutoState, BlackState, BlameState,
-                        BlindState, CubeState, DayState, DisconState,
-                        DotsState, DoubleState, FailureState, FictionState,
-                        FinaleState, GearsState, GroundState, HeadedState,
-                        HexState, IcyEmState, JogState, LaneState,
+                        BlindState, CharacterMove, CubeState, DayState,
+                        DisconState, DotsState, DoubleState, FailureState,
+                        FictionState, FinaleState, GearsState, GroundState,
+                        HeadedState, HexState, IcyEmState, JogState, LaneState,
                         LevelUpState, LineState, LogLevelState, MeetState,
-                        MissedState, NoReturnState, OrderState,
-                        PasswordState, PovState, PrologState, PuzzleState,
-                        RightState, SauceState, ServesState, SimpleState,
-                        StarState, SyrupState, SyzygyStage, SyzygyState,
-                        TheYState, TreadState, WhatchaState, WordDir,
-                        WreckedState};
+                        MissedState, NoReturnState, OrderState, PasswordState,
+                        PovState, PrologState, PuzzleState, RightState,
+                        SauceState, ServesState, SimpleState, StarState,
+                        SyrupState, SyzygyStage, SyzygyState, TheYState,
+                        TreadState, WhatchaState, WordDir, WreckedState};
+pub use self::rng::{game_rng_is_seeded, seed_game_rng};
+pub use self::stats::{PuzzleStats, pin_today, today};
+pub use self::transcript::{TalkStyle, Transcript, TranscriptLine};
 
 // ========================================================================= //
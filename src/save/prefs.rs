@@ -17,16 +17,50 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
+use std::{cmp, f64};
 use toml;
 
+use gui::{ControllerAction, ControllerButton, ControllerMap};
+
 // ========================================================================= //
 
+const MAX_VOLUME: i32 = 100;
+const MAX_TEXT_SPEED: f64 = 4.0;
+const TEXT_SPEEDS: &[f64] = &[0.5, 1.0, 2.0, MAX_TEXT_SPEED,
+                              INSTANT_TEXT_SPEED];
+
+/// The text speed at which speech bubbles show all their text at once.
+const INSTANT_TEXT_SPEED: f64 = f64::INFINITY;
+
+const DEFAULT_MUSIC_VOLUME: i32 = 50;
+const DEFAULT_TEXT_SPEED: f64 = INSTANT_TEXT_SPEED;
+const DEFAULT_LOCALE: &str = "en";
+
+// ========================================================================= //
+
+#[derive(Clone)]
 pub struct Prefs {
     fullscreen: bool,
+    volume: i32,
+    music_volume: i32,
+    mute: bool,
+    text_speed: f64,
+    locale: String,
+    controller_map: ControllerMap,
 }
 
 impl Prefs {
-    pub fn with_defaults() -> Prefs { Prefs { fullscreen: true } }
+    pub fn with_defaults() -> Prefs {
+        Prefs {
+            fullscreen: true,
+            volume: MAX_VOLUME,
+            music_volume: DEFAULT_MUSIC_VOLUME,
+            mute: false,
+            text_speed: DEFAULT_TEXT_SPEED,
+            locale: DEFAULT_LOCALE.to_string(),
+            controller_map: ControllerMap::with_defaults(),
+        }
+    }
 
     pub fn from_toml(table: &toml::value::Table) -> Prefs {
         let mut prefs = Prefs::with_defaults();
@@ -36,6 +70,54 @@ impl Prefs {
         {
             prefs.fullscreen = fullscreen;
         }
+        if let Some(volume) =
+            table.get(VOLUME_KEY).and_then(toml::Value::as_integer)
+        {
+            let volume = cmp::max(0, cmp::min(MAX_VOLUME as i64, volume));
+            prefs.set_volume(volume as i32);
+        }
+        if let Some(volume) =
+            table.get(MUSIC_VOLUME_KEY).and_then(toml::Value::as_integer)
+        {
+            let volume = cmp::max(0, cmp::min(MAX_VOLUME as i64, volume));
+            prefs.set_music_volume(volume as i32);
+        }
+        if let Some(mute) = table.get(MUTE_KEY).and_then(toml::Value::as_bool)
+        {
+            prefs.mute = mute;
+        }
+        match table.get(TEXT_SPEED_KEY) {
+            Some(&toml::Value::Float(speed)) => prefs.set_text_speed(speed),
+            Some(&toml::Value::String(ref speed)) if speed == INSTANT => {
+                prefs.set_text_speed(INSTANT_TEXT_SPEED);
+            }
+            _ => {}
+        }
+        if let Some(locale) =
+            table.get(LOCALE_KEY).and_then(toml::Value::as_str)
+        {
+            prefs.set_locale(locale);
+        }
+        if let Some(bindings) =
+            table.get(CONTROLLER_KEY).and_then(toml::Value::as_table)
+        {
+            for (name, value) in bindings.iter() {
+                let button = match ControllerButton::from_name(name) {
+                    Some(button) => button,
+                    None => continue,
+                };
+                match value.as_str() {
+                    Some(NO_ACTION) => prefs.controller_map.bind(button, None),
+                    Some(name) => {
+                        if let Some(action) = ControllerAction::from_name(name)
+                        {
+                            prefs.controller_map.bind(button, Some(action));
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
         prefs
     }
 
@@ -43,17 +125,258 @@ impl Prefs {
         let mut table = toml::value::Table::new();
         table.insert(FULLSCREEN_KEY.to_string(),
                      toml::Value::Boolean(self.fullscreen));
+        table.insert(VOLUME_KEY.to_string(),
+                     toml::Value::Integer(self.volume as i64));
+        table.insert(MUSIC_VOLUME_KEY.to_string(),
+                     toml::Value::Integer(self.music_volume as i64));
+        table.insert(MUTE_KEY.to_string(), toml::Value::Boolean(self.mute));
+        let text_speed = if self.text_speed == INSTANT_TEXT_SPEED {
+            toml::Value::String(INSTANT.to_string())
+        } else {
+            toml::Value::Float(self.text_speed)
+        };
+        table.insert(TEXT_SPEED_KEY.to_string(), text_speed);
+        table.insert(LOCALE_KEY.to_string(),
+                     toml::Value::String(self.locale.clone()));
+        let mut bindings = toml::value::Table::new();
+        for &button in ControllerButton::all() {
+            let action = self.controller_map
+                .action(button)
+                .map(ControllerAction::name)
+                .unwrap_or(NO_ACTION);
+            bindings.insert(button.name().to_string(),
+                            toml::Value::String(action.to_string()));
+        }
+        table.insert(CONTROLLER_KEY.to_string(), toml::Value::Table(bindings));
         toml::Value::Table(table)
     }
 
     pub fn fullscreen(&self) -> bool { self.fullscreen }
 
-    #[allow(dead_code)]
     pub fn set_fullscreen(&mut self, fullscreen: bool) {
         self.fullscreen = fullscreen;
     }
+
+    /// Returns the master volume, as a percentage from 0 to `MAX_VOLUME`.
+    pub fn volume(&self) -> i32 { self.volume }
+
+    pub fn set_volume(&mut self, volume: i32) {
+        self.volume = cmp::max(0, cmp::min(MAX_VOLUME, volume));
+    }
+
+    /// Returns the background music volume, as a percentage from 0 to
+    /// `MAX_VOLUME`.  This is applied on top of the master volume.
+    pub fn music_volume(&self) -> i32 { self.music_volume }
+
+    pub fn set_music_volume(&mut self, volume: i32) {
+        self.music_volume = cmp::max(0, cmp::min(MAX_VOLUME, volume));
+    }
+
+    pub fn mute(&self) -> bool { self.mute }
+
+    pub fn set_mute(&mut self, mute: bool) { self.mute = mute; }
+
+    /// Returns the gain that should be applied to all sound output, taking
+    /// both the master volume and the mute setting into account.
+    pub fn sound_gain(&self) -> f32 {
+        if self.mute {
+            0.0
+        } else {
+            self.volume as f32 / MAX_VOLUME as f32
+        }
+    }
+
+    /// Returns the gain that should be applied to background music, relative
+    /// to the overall sound gain.
+    pub fn music_gain(&self) -> f32 {
+        self.music_volume as f32 / MAX_VOLUME as f32
+    }
+
+    /// Returns the multiplier for how quickly speech bubble text is revealed
+    /// during cutscenes.  This is infinite if text should appear all at
+    /// once.
+    pub fn text_speed(&self) -> f64 { self.text_speed }
+
+    pub fn set_text_speed(&mut self, speed: f64) {
+        self.text_speed = if speed.is_nan() {
+            DEFAULT_TEXT_SPEED
+        } else if speed == INSTANT_TEXT_SPEED {
+            INSTANT_TEXT_SPEED
+        } else {
+            speed.max(TEXT_SPEEDS[0]).min(MAX_TEXT_SPEED)
+        };
+    }
+
+    /// Switches to the next text speed in `TEXT_SPEEDS`, wrapping around
+    /// from the fastest back to the slowest.
+    pub fn cycle_text_speed(&mut self) {
+        let next = TEXT_SPEEDS
+            .iter()
+            .cloned()
+            .find(|&speed| speed > self.text_speed)
+            .unwrap_or(TEXT_SPEEDS[0]);
+        self.text_speed = next;
+    }
+
+    /// Returns the locale whose message catalog should be used for text.
+    pub fn locale(&self) -> &str { &self.locale }
+
+    /// Sets the locale, unless `locale` isn't a valid locale name (which may
+    /// only contain ASCII letters, digits, hyphens, and underscores).
+    pub fn set_locale(&mut self, locale: &str) {
+        if !locale.is_empty() &&
+            locale.chars().all(|chr| {
+                                   chr.is_ascii_alphanumeric() || chr == '-' ||
+                                       chr == '_'
+                               })
+        {
+            self.locale = locale.to_string();
+        }
+    }
+
+    /// Returns which actions the game controller buttons perform.
+    pub fn controller_map(&self) -> &ControllerMap { &self.controller_map }
 }
 
+const CONTROLLER_KEY: &str = "controller";
 const FULLSCREEN_KEY: &str = "fullscreen";
+const LOCALE_KEY: &str = "locale";
+const MUSIC_VOLUME_KEY: &str = "music_volume";
+const MUTE_KEY: &str = "mute";
+const TEXT_SPEED_KEY: &str = "text_speed";
+const VOLUME_KEY: &str = "volume";
+
+/// The value of the text speed key for instant text.
+const INSTANT: &str = "instant";
+
+/// The action name used in the controller table for an unbound button.
+const NO_ACTION: &str = "none";
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use toml;
+
+    use gui::{ControllerAction, ControllerButton, ControllerMap};
+    use super::{DEFAULT_MUSIC_VOLUME, INSTANT_TEXT_SPEED, MAX_TEXT_SPEED,
+                MAX_VOLUME, Prefs, TEXT_SPEEDS};
+
+    fn parse(string: &str) -> Prefs {
+        match string.parse::<toml::Value>().unwrap() {
+            toml::Value::Table(table) => Prefs::from_toml(&table),
+            _ => panic!("not a table"),
+        }
+    }
+
+    #[test]
+    fn missing_keys_use_defaults() {
+        let prefs = parse("");
+        assert!(prefs.fullscreen());
+        assert_eq!(prefs.volume(), MAX_VOLUME);
+        assert_eq!(prefs.music_volume(), DEFAULT_MUSIC_VOLUME);
+        assert!(!prefs.mute());
+        assert_eq!(prefs.text_speed(), INSTANT_TEXT_SPEED);
+        assert_eq!(prefs.sound_gain(), 1.0);
+        assert_eq!(prefs.locale(), "en");
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut prefs = Prefs::with_defaults();
+        prefs.set_fullscreen(false);
+        prefs.set_volume(40);
+        prefs.set_music_volume(70);
+        prefs.set_mute(true);
+        prefs.set_text_speed(2.0);
+        prefs.set_locale("fr");
+        let prefs = match prefs.to_toml() {
+            toml::Value::Table(table) => Prefs::from_toml(&table),
+            _ => panic!("not a table"),
+        };
+        assert!(!prefs.fullscreen());
+        assert_eq!(prefs.volume(), 40);
+        assert_eq!(prefs.music_volume(), 70);
+        assert!(prefs.mute());
+        assert_eq!(prefs.text_speed(), 2.0);
+        assert_eq!(prefs.sound_gain(), 0.0);
+        assert_eq!(prefs.locale(), "fr");
+    }
+
+    #[test]
+    fn invalid_locales_are_ignored() {
+        assert_eq!(parse("locale = \"pt-BR\"").locale(), "pt-BR");
+        assert_eq!(parse("locale = \"\"").locale(), "en");
+        assert_eq!(parse("locale = \"../../etc\"").locale(), "en");
+        assert_eq!(parse("locale = 7").locale(), "en");
+    }
+
+    #[test]
+    fn out_of_range_values_are_clamped() {
+        let prefs =
+            parse("volume = 250\nmusic_volume = 101\ntext_speed = 100.0");
+        assert_eq!(prefs.volume(), MAX_VOLUME);
+        assert_eq!(prefs.music_volume(), MAX_VOLUME);
+        assert_eq!(prefs.text_speed(), MAX_TEXT_SPEED);
+        let prefs = parse("volume = -3\nmusic_volume = -1\ntext_speed = 0.0");
+        assert_eq!(prefs.volume(), 0);
+        assert_eq!(prefs.music_volume(), 0);
+        assert_eq!(prefs.text_speed(), TEXT_SPEEDS[0]);
+    }
+
+    #[test]
+    fn cycle_text_speed_wraps_around() {
+        let mut prefs = Prefs::with_defaults();
+        let mut seen = Vec::new();
+        for _ in 0..TEXT_SPEEDS.len() {
+            prefs.cycle_text_speed();
+            seen.push(prefs.text_speed());
+        }
+        assert_eq!(seen, vec![0.5, 1.0, 2.0, 4.0, INSTANT_TEXT_SPEED]);
+    }
+
+    #[test]
+    fn instant_text_round_trip() {
+        let mut prefs = parse("text_speed = \"instant\"");
+        assert_eq!(prefs.text_speed(), INSTANT_TEXT_SPEED);
+        prefs.set_text_speed(0.5);
+        let table = match prefs.to_toml() {
+            toml::Value::Table(table) => table,
+            _ => panic!("not a table"),
+        };
+        assert_eq!(Prefs::from_toml(&table).text_speed(), 0.5);
+        prefs.set_text_speed(INSTANT_TEXT_SPEED);
+        let table = match prefs.to_toml() {
+            toml::Value::Table(table) => table,
+            _ => panic!("not a table"),
+        };
+        assert_eq!(table.get("text_speed"),
+                   Some(&toml::Value::String("instant".to_string())));
+        assert_eq!(Prefs::from_toml(&table).text_speed(),
+                   INSTANT_TEXT_SPEED);
+    }
+
+    #[test]
+    fn controller_bindings() {
+        assert_eq!(parse("").controller_map(),
+                   &ControllerMap::with_defaults());
+        let prefs = parse("[controller]\na = \"undo\"\nb = \"none\"\n\
+                           leftstick = \"select\"\nguide = \"cancel\"\n\
+                           x = \"fly\"");
+        let map = prefs.controller_map();
+        assert_eq!(map.action(ControllerButton::A),
+                   Some(ControllerAction::Undo));
+        assert_eq!(map.action(ControllerButton::B), None);
+        assert_eq!(map.action(ControllerButton::LeftStick),
+                   Some(ControllerAction::Select));
+        assert_eq!(map.action(ControllerButton::X),
+                   Some(ControllerAction::Drag));
+        let round_trip = match prefs.to_toml() {
+            toml::Value::Table(table) => Prefs::from_toml(&table),
+            _ => panic!("not a table"),
+        };
+        assert_eq!(round_trip.controller_map(), map);
+    }
+}
 
 // ========================================================================= //
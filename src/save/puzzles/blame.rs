@@ -23,6 +23,7 @@ use toml;
 use save::{Access, Location};
 use save::util::{ACCESS_KEY, Tomlable, to_table};
 use super::PuzzleState;
+use super::shift::{CharacterMove, PlatformShift, ShiftLeg};
 
 // ========================================================================= //
 
@@ -34,9 +35,11 @@ const MIN_MEZURE_ROW: i32 = -1;
 const MAX_MEZURE_ROW: i32 = NUM_ROWS;
 const INITIAL_POSITIONS: &[i32] = &[0, 0, 0, 0, 0, 0, 0];
 const INITIAL_MEZURE_ROW: i32 = MAX_MEZURE_ROW;
+const SHIFT_AMOUNTS: &[i32] = &[3, 5, 3, 7, 5, 3];
 
 // ========================================================================= //
 
+#[derive(Clone)]
 pub struct BlameState {
     access: Access,
     positions: Vec<i32>,
@@ -59,6 +62,13 @@ impl BlameState {
         if row >= 5 { 9 } else { 8 }
     }
 
+    /// Returns the amount that the arrows for the given pair of rows (`row`
+    /// and `row + 1`) shift their platforms by.
+    pub fn shift_amount(row: i32) -> i32 {
+        assert!(row >= 0 && row < NUM_ROWS - 1);
+        SHIFT_AMOUNTS[row as usize]
+    }
+
     pub fn get_position(&self, row: i32) -> i32 {
         assert!(row >= 0 && row < NUM_ROWS);
         self.positions[row as usize]
@@ -96,6 +106,219 @@ impl BlameState {
         }
         row
     }
+
+    /// Shifts the platforms in rows `row_1` and `row_1 + 1` together by
+    /// `delta` (each bouncing off the ends of its track), and moves Mezure
+    /// as a result.
+    pub fn shift_platforms(&mut self, row_1: i32, delta: i32)
+                           -> PlatformShift {
+        let last_row = NUM_ROWS - 1;
+        let max_pos_for_last_row = BlameState::max_position_for_row(last_row);
+        let max_pos_for_row_5 = BlameState::max_position_for_row(5);
+        debug_assert!(row_1 >= 0 && row_1 < NUM_ROWS - 1);
+        let row_2 = row_1 + 1;
+        let max_pos_1 = BlameState::max_position_for_row(row_1);
+        let max_pos_2 = BlameState::max_position_for_row(row_2);
+        let original_mezure_row = self.mezure_row;
+        let mut mezure_row = self.mezure_row;
+        let mut mezure_pos = 0;
+        if mezure_row >= 0 && mezure_row < NUM_ROWS {
+            mezure_pos = self.get_position(mezure_row);
+        }
+
+        // Move platforms:
+        let mut legs = Vec::new();
+        let mut delta_1 = delta;
+        let mut delta_2 = delta;
+        let mut pos_1 = self.get_position(row_1);
+        let mut pos_2 = self.get_position(row_2);
+        while delta_1 != 0 || delta_2 != 0 {
+            if delta_1 < 0 && pos_1 == 0 || delta_1 > 0 && pos_1 == max_pos_1 {
+                delta_1 = -delta_1;
+            }
+            if delta_2 < 0 && pos_2 == 0 || delta_2 > 0 && pos_2 == max_pos_2 {
+                delta_2 = -delta_2;
+            }
+            debug_assert_eq!(delta_1.abs(), delta_2.abs());
+            let mut step = delta_1.abs();
+            if pos_1 + delta_1 < 0 {
+                step = min(step, pos_1);
+            }
+            if pos_2 + delta_2 < 0 {
+                step = min(step, pos_2);
+            }
+            if pos_1 + delta_1 > max_pos_1 {
+                step = min(step, max_pos_1 - pos_1);
+            }
+            if pos_2 + delta_2 > max_pos_2 {
+                step = min(step, max_pos_2 - pos_2);
+            }
+            debug_assert!(step > 0);
+            let old_pos_1 = pos_1;
+            let old_pos_2 = pos_2;
+            if delta_1 < 0 {
+                pos_1 -= step;
+                delta_1 += step;
+            } else {
+                pos_1 += step;
+                delta_1 -= step;
+            }
+            if delta_2 < 0 {
+                pos_2 -= step;
+                delta_2 += step;
+            } else {
+                pos_2 += step;
+                delta_2 -= step;
+            }
+            self.set_position(row_1, pos_1);
+            self.set_position(row_2, pos_2);
+
+            // Move Mezure and/or knock them downwards.
+            let mut character = CharacterMove::Still;
+            if mezure_row == row_1 || mezure_row == row_2 {
+                let impact = if mezure_row == row_1 {
+                    if row_1 == 5 && pos_1 == max_pos_for_row_5 {
+                        Some(max_pos_for_row_5 - 1)
+                    } else if row_1 > 0 {
+                        let pos_0 = self.get_position(row_1 - 1);
+                        if pos_1 > old_pos_1 && pos_0 > old_pos_1 &&
+                            pos_0 <= pos_1
+                        {
+                            Some(pos_0 - 1)
+                        } else if pos_1 < old_pos_1 && pos_0 < old_pos_1 &&
+                                   pos_0 >= pos_1
+                        {
+                            Some(pos_0 + 1)
+                        } else {
+                            None
+                        }
+                    } else if pos_1 == 0 {
+                        Some(1)
+                    } else {
+                        None
+                    }
+                } else {
+                    debug_assert_eq!(mezure_row, row_2);
+                    if old_pos_1 < old_pos_2 && pos_1 >= pos_2 {
+                        Some(old_pos_1 + (old_pos_2 - old_pos_1) / 2 + 1)
+                    } else if old_pos_1 > old_pos_2 && pos_1 <= pos_2 {
+                        Some(old_pos_2 + (old_pos_1 - old_pos_2 + 1) / 2 - 1)
+                    } else if row_2 == 5 && pos_2 == max_pos_for_row_5 {
+                        Some(max_pos_for_row_5 - 1)
+                    } else {
+                        None
+                    }
+                };
+                let (row, pos) = if mezure_row == row_1 {
+                    (row_1, pos_1)
+                } else {
+                    (row_2, pos_2)
+                };
+                if let Some(new_mezure_pos) = impact {
+                    let from_pos = mezure_pos;
+                    mezure_pos = new_mezure_pos;
+                    mezure_row = self.fall_from(mezure_row, mezure_pos);
+                    character = CharacterMove::Pushed {
+                        platform: row,
+                        from_pos: from_pos,
+                        pos: mezure_pos,
+                        row: mezure_row,
+                    };
+                } else {
+                    mezure_pos = pos;
+                    character = CharacterMove::Ride {
+                        platform: row,
+                        pos: mezure_pos,
+                    };
+                }
+            } else if mezure_row < NUM_ROWS && mezure_row == row_2 + 1 {
+                let impact = if pos_2 > old_pos_2 && mezure_pos > old_pos_2 &&
+                    mezure_pos <= pos_2
+                {
+                    Some((mezure_pos - 1,
+                          min(BlameState::max_position_for_row(mezure_row),
+                              mezure_pos + 1)))
+                } else if pos_2 < old_pos_2 && mezure_pos >= pos_2 &&
+                           mezure_pos < old_pos_2
+                {
+                    Some((mezure_pos + 1, max(0, mezure_pos - 1)))
+                } else {
+                    None
+                };
+                if let Some((hit_pos, new_mezure_pos)) = impact {
+                    mezure_pos = new_mezure_pos;
+                    mezure_row = self.fall_from(mezure_row, mezure_pos);
+                    character = CharacterMove::Knocked {
+                        platform: row_2,
+                        hit_pos: hit_pos,
+                        pos: mezure_pos,
+                        row: mezure_row,
+                    };
+                }
+            }
+            legs.push(ShiftLeg {
+                platforms: vec![(row_1, old_pos_1, pos_1),
+                                (row_2, old_pos_2, pos_2)],
+                character: character,
+            });
+        }
+
+        // Note whether Mezure fell all the way to the floor:
+        let fell_to_floor = if mezure_row == NUM_ROWS &&
+            original_mezure_row != NUM_ROWS
+        {
+            Some(mezure_pos)
+        } else {
+            None
+        };
+
+        // Make Mezure climb upwards:
+        let mut climbs = Vec::new();
+        if mezure_row == NUM_ROWS &&
+            self.get_position(last_row) == max_pos_for_last_row &&
+            self.get_position(last_row - 1) != max_pos_for_last_row
+        {
+            mezure_row -= 1;
+            climbs.push(mezure_row);
+        }
+        if mezure_row < NUM_ROWS {
+            while mezure_row > 0 {
+                let pos_0 = self.get_position(mezure_row);
+                let pos_1 = self.get_position(mezure_row - 1);
+                if (pos_0 - pos_1).abs() != 1 {
+                    break;
+                }
+                if mezure_row >= 2 {
+                    let pos_2 = self.get_position(mezure_row - 2);
+                    if pos_2 == pos_1 || pos_2 == pos_0 {
+                        break;
+                    }
+                }
+                if mezure_row == last_row &&
+                    (pos_0 == max_pos_for_last_row ||
+                         pos_1 == max_pos_for_last_row) ||
+                    mezure_row == 1 && (pos_0 == 0 || pos_1 == 0)
+                {
+                    break;
+                }
+                mezure_row -= 1;
+                climbs.push(mezure_row);
+            }
+        }
+        if mezure_row == 0 &&
+            self.get_position(0) == BlameState::max_position_for_row(0)
+        {
+            mezure_row -= 1;
+            climbs.push(mezure_row);
+        }
+        self.set_mezure_row(mezure_row);
+
+        PlatformShift {
+            legs: legs,
+            fell_to_floor: fell_to_floor,
+            climbs: climbs,
+        }
+    }
 }
 
 impl PuzzleState for BlameState {
@@ -177,9 +400,78 @@ mod tests {
     use toml;
 
     use save::{Access, PuzzleState};
+    use save::solver::{Solvable, apply_moves, shortest_solution};
     use save::util::{ACCESS_KEY, Tomlable};
     use super::{BlameState, INITIAL_MEZURE_ROW, INITIAL_POSITIONS,
-                MEZURE_ROW_KEY, MIN_MEZURE_ROW, POSITIONS_KEY};
+                MEZURE_ROW_KEY, MIN_MEZURE_ROW, NUM_ROWS, POSITIONS_KEY};
+
+    impl Solvable for BlameState {
+        type Move = (i32, i32);
+        type Key = (Vec<i32>, i32);
+
+        fn search_key(&self) -> (Vec<i32>, i32) {
+            (self.positions.clone(), self.mezure_row)
+        }
+
+        fn legal_moves(&self) -> Vec<(i32, i32)> {
+            let mut moves = Vec::new();
+            for row in 0..(NUM_ROWS - 1) {
+                let amount = BlameState::shift_amount(row);
+                moves.push((row, amount));
+                moves.push((row, -amount));
+            }
+            moves
+        }
+
+        fn apply_move(&mut self, &(row, delta): &(i32, i32)) {
+            self.shift_platforms(row, delta);
+        }
+
+        fn is_goal(&self) -> bool { self.is_solved() }
+    }
+
+    /// A shortest solution from the initial state, as found by
+    /// `shortest_solution`.  Searching from scratch takes too long for an
+    /// unoptimized test build, so most tests check this sequence and search
+    /// only its final few moves; `solution_is_shortest` repeats the full
+    /// search.
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const SOLUTION: &[(i32, i32)] = &[
+        (0, 3), (0, 3), (0, 3), (0, -3), (0, -3), (0, -3),
+        (3, 7), (4, 5), (5, 3), (5, 3), (5, 3), (5, -3),
+        (4, -5), (4, 5), (2, -3), (1, 5), (0, 3), (0, 3),
+    ];
+
+    #[test]
+    fn initial_state_is_solvable() {
+        let state = BlameState::from_toml(toml::Value::Boolean(false));
+        assert!(!state.is_solved());
+        assert!(apply_moves(&state, SOLUTION).is_solved());
+    }
+
+    /// Searches from the initial state to confirm that `SOLUTION` is as
+    /// short as possible.  Run with `cargo test --release shortest --
+    /// --ignored`.
+    #[test]
+    #[ignore]
+    fn solution_is_shortest() {
+        let state = BlameState::from_toml(toml::Value::Boolean(false));
+        let solution = shortest_solution(&state, 50_000_000).unwrap();
+        assert_eq!(solution.len(), SOLUTION.len());
+        assert_eq!(SOLUTION.len(), 18);
+        assert!(apply_moves(&state, &solution).is_solved());
+    }
+
+    #[test]
+    fn solver_finishes_known_solution() {
+        let state = BlameState::from_toml(toml::Value::Boolean(false));
+        let split = SOLUTION.len() - 5;
+        let state = apply_moves(&state, &SOLUTION[..split]);
+        assert!(!state.is_solved());
+        let solution = shortest_solution(&state, 1_000_000).unwrap();
+        assert_eq!(solution.len(), 5);
+        assert!(apply_moves(&state, &solution).is_solved());
+    }
 
     #[test]
     fn toml_round_trip() {
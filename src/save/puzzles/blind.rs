@@ -22,18 +22,46 @@ use toml;
 use gui::Point;
 use save::{Access, Direction, Location};
 use save::ice::{BlockSlide, Object, ObjectGrid, Symbol, Transform};
-use save::util::{ACCESS_KEY, Tomlable, pop_table, to_table};
+use save::solver::{HINT_MAX_STATES, next_move_along};
+use save::util::{ACCESS_KEY, HINTS_KEY, Tomlable, pop_table, to_table};
 use super::PuzzleState;
 
 // ========================================================================= //
 
 const GRID_KEY: &str = "grid";
 
+/// A known solution from the initial grid (not necessarily a shortest one).
+/// There are too many states to search for a solution from scratch, so hints
+/// steer the player back onto this route instead.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SOLUTION: &[(i32, i32, Direction)] = &[
+    (2, 0, Direction::East), (7, 4, Direction::West),
+    (4, 4, Direction::North), (4, 0, Direction::East),
+    (5, 0, Direction::South), (6, 0, Direction::West),
+    (5, 2, Direction::North), (0, 0, Direction::East),
+    (4, 0, Direction::South), (4, 6, Direction::North),
+    (4, 0, Direction::South), (7, 2, Direction::West),
+    (0, 2, Direction::South), (0, 6, Direction::East),
+    (3, 6, Direction::North), (3, 4, Direction::East),
+    (4, 6, Direction::North), (4, 5, Direction::East),
+    (5, 5, Direction::North), (4, 4, Direction::North),
+    (5, 4, Direction::West), (0, 4, Direction::North),
+    (4, 0, Direction::West), (5, 0, Direction::West),
+    (2, 0, Direction::South), (2, 4, Direction::West),
+    (0, 4, Direction::North), (0, 1, Direction::East),
+    (1, 0, Direction::South), (1, 2, Direction::East),
+    (2, 2, Direction::South), (2, 4, Direction::West),
+    (0, 0, Direction::South), (0, 4, Direction::East),
+    (5, 1, Direction::South), (5, 2, Direction::East),
+    (7, 2, Direction::North), (7, 4, Direction::South),
+];
+
 // ========================================================================= //
 
 pub struct BlindState {
     access: Access,
     grid: ObjectGrid,
+    hints: i32,
 }
 
 impl BlindState {
@@ -55,6 +83,21 @@ impl BlindState {
         slide
     }
 
+    /// Returns the next slide to make towards the solution, or `None` if the
+    /// grid has strayed too far to find a route back.
+    pub fn hint(&self) -> Option<(Point, Direction)> {
+        let route: Vec<(Point, Direction)> = SOLUTION
+            .iter()
+            .map(|&(col, row, dir)| (Point::new(col, row), dir))
+            .collect();
+        next_move_along(&BlindState::initial_grid(),
+                        &route,
+                        &self.grid,
+                        HINT_MAX_STATES)
+    }
+
+    pub fn record_hint(&mut self) { self.hints += 1; }
+
     fn base_grid() -> ObjectGrid {
         let red = Symbol::RedTriangle(Direction::East);
         let yellow = Symbol::YellowRhombus(true, true);
@@ -102,6 +145,10 @@ impl PuzzleState for BlindState {
     fn can_reset(&self) -> bool { self.grid.is_modified() }
 
     fn reset(&mut self) { self.grid = BlindState::initial_grid(); }
+
+    fn can_hint(&self) -> bool { true }
+
+    fn hints_used(&self) -> i32 { self.hints }
 }
 
 impl Tomlable for BlindState {
@@ -111,6 +158,9 @@ impl Tomlable for BlindState {
         if self.grid.is_modified() && !self.is_solved() {
             table.insert(GRID_KEY.to_string(), self.grid.to_toml());
         }
+        if self.hints > 0 {
+            table.insert(HINTS_KEY.to_string(), self.hints.to_toml());
+        }
         toml::Value::Table(table)
     }
 
@@ -123,9 +173,11 @@ impl Tomlable for BlindState {
             let grid = pop_table(&mut table, GRID_KEY);
             ObjectGrid::from_toml(grid, &BlindState::initial_grid())
         };
+        let hints = i32::pop_from_table(&mut table, HINTS_KEY).max(0);
         BlindState {
             access: access,
             grid: grid,
+            hints: hints,
         }
     }
 }
@@ -137,9 +189,57 @@ mod tests {
     use toml;
 
     use gui::Point;
-    use save::{Access, Direction};
+    use save::{Access, Direction, PuzzleState};
+    use save::solver::shortest_solution;
     use save::util::{ACCESS_KEY, Tomlable};
-    use super::BlindState;
+    use super::{BlindState, SOLUTION};
+
+
+    fn apply_solution(state: &mut BlindState,
+                      moves: &[(i32, i32, Direction)]) {
+        for &(col, row, dir) in moves.iter() {
+            let coords = Point::new(col, row);
+            assert!(state.slide_ice_block(coords, dir).is_some());
+        }
+    }
+
+    #[test]
+    fn initial_grid_is_solvable() {
+        let mut state = BlindState::from_toml(toml::Value::Boolean(false));
+        assert_eq!(SOLUTION.len(), 38);
+        apply_solution(&mut state, SOLUTION);
+        assert!(state.is_solved());
+    }
+
+    #[test]
+    fn solver_finishes_known_solution() {
+        let mut state = BlindState::from_toml(toml::Value::Boolean(false));
+        let split = SOLUTION.len() - 5;
+        apply_solution(&mut state, &SOLUTION[..split]);
+        assert!(!state.is_solved());
+        let solution = shortest_solution(state.grid(), 1_000_000).unwrap();
+        assert_eq!(solution.len(), 5);
+        for &(coords, dir) in solution.iter() {
+            assert!(state.slide_ice_block(coords, dir).is_some());
+        }
+        assert!(state.is_solved());
+    }
+
+    #[test]
+    fn hints_lead_back_to_known_solution() {
+        let mut state = BlindState::from_toml(toml::Value::Boolean(false));
+        let (col, row, dir) = SOLUTION[0];
+        assert_eq!(state.hint(), Some((Point::new(col, row), dir)));
+        assert!(state.slide_ice_block(Point::new(2, 0), Direction::South)
+                     .is_some());
+        let mut steps = 0;
+        while let Some((coords, dir)) = state.hint() {
+            assert!(state.slide_ice_block(coords, dir).is_some());
+            steps += 1;
+            assert!(steps <= SOLUTION.len() + 5);
+        }
+        assert!(state.is_solved());
+    }
 
     #[test]
     fn toml_round_trip() {
@@ -0,0 +1,140 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+// ========================================================================= //
+
+/// The answers and clues for a word puzzle.  The built-in English list can be
+/// replaced with a translated one, which must have the same number of entries
+/// so that saved progress (which records clues by index) stays meaningful.
+pub struct WordClues {
+    english: &'static [(&'static str, &'static str)],
+    translated: Vec<(String, String)>,
+}
+
+impl WordClues {
+    pub fn new(english: &'static [(&'static str, &'static str)])
+               -> WordClues {
+        WordClues {
+            english: english,
+            translated: Vec::new(),
+        }
+    }
+
+    pub fn answer(&self, index: usize) -> &str {
+        if self.translated.is_empty() {
+            self.english[index].0
+        } else {
+            &self.translated[index].0
+        }
+    }
+
+    pub fn clue(&self, index: usize) -> &str {
+        if self.translated.is_empty() {
+            self.english[index].1
+        } else {
+            &self.translated[index].1
+        }
+    }
+
+    /// Switches to the given translated answers and clues, or back to English
+    /// if `translated` is `None`.  Each answer must consist of words made of
+    /// the letters A-Z separated by single spaces.  If the translated list
+    /// is invalid, this switches back to English and returns false.
+    pub fn localize(&mut self, translated: Option<&[(String, String)]>)
+                    -> bool {
+        self.translated.clear();
+        if let Some(translated) = translated {
+            if translated.len() != self.english.len() ||
+                !translated.iter().all(|&(ref answer, ref clue)| {
+                                           is_valid_answer(answer) &&
+                                               !clue.is_empty()
+                                       })
+            {
+                return false;
+            }
+            self.translated = translated.to_vec();
+        }
+        true
+    }
+}
+
+fn is_valid_answer(answer: &str) -> bool {
+    answer.split(' ').all(|word| {
+                              !word.is_empty() &&
+                                  word.chars().all(|chr| {
+                                                       'A' <= chr &&
+                                                           chr <= 'Z'
+                                                   })
+                          })
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use super::{WordClues, is_valid_answer};
+
+    const ENGLISH: &[(&str, &str)] = &[("ONE SUN", "a single star"),
+                                        ("HIGH EYE", "a raised eye")];
+
+    fn pairs(list: &[(&str, &str)]) -> Vec<(String, String)> {
+        list.iter()
+            .map(|&(answer, clue)| (answer.to_string(), clue.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn valid_answers() {
+        assert!(is_valid_answer("ONE SUN"));
+        assert!(is_valid_answer("SOLEIL"));
+        assert!(!is_valid_answer(""));
+        assert!(!is_valid_answer("ONE  SUN"));
+        assert!(!is_valid_answer(" SUN"));
+        assert!(!is_valid_answer("UN SOLEIL "));
+        assert!(!is_valid_answer("ÉTÉ"));
+        assert!(!is_valid_answer("sun"));
+    }
+
+    #[test]
+    fn localize_clues() {
+        let mut clues = WordClues::new(ENGLISH);
+        assert_eq!(clues.answer(1), "HIGH EYE");
+        let french = pairs(&[("UN SOLEIL", "une seule étoile"),
+                             ("OEIL HAUT", "un oeil levé")]);
+        assert!(clues.localize(Some(&french)));
+        assert_eq!(clues.answer(0), "UN SOLEIL");
+        assert_eq!(clues.clue(1), "un oeil levé");
+        assert!(clues.localize(None));
+        assert_eq!(clues.clue(1), "a raised eye");
+    }
+
+    #[test]
+    fn invalid_translations_fall_back_to_english() {
+        let mut clues = WordClues::new(ENGLISH);
+        let too_short = pairs(&[("UN SOLEIL", "une seule étoile")]);
+        assert!(!clues.localize(Some(&too_short)));
+        assert_eq!(clues.answer(0), "ONE SUN");
+        let bad_answer = pairs(&[("UN SOLEIL", "une seule étoile"),
+                                 ("ŒIL HAUT", "un oeil levé")]);
+        assert!(!clues.localize(Some(&bad_answer)));
+        assert_eq!(clues.answer(1), "HIGH EYE");
+    }
+}
+
+// ========================================================================= //
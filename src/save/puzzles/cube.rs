@@ -20,7 +20,8 @@
 use toml;
 
 use save::{Access, Direction, Location};
-use save::util::{ACCESS_KEY, Tomlable, pop_array, to_table};
+use save::solver::{HINT_MAX_STATES, Solvable, next_move_along};
+use save::util::{ACCESS_KEY, HINTS_KEY, Tomlable, pop_array, to_table};
 use super::PuzzleState;
 
 // ========================================================================= //
@@ -34,12 +35,36 @@ const INITIAL_GRID: &[i32] =
     &[23, 16, 18, 12, 15, 9, 17, 22, 21, 3, 8, 6, 19, 1, 4, 11];
 const SOLVED_GRID: &[i32] = &[4, 4, 3, 3, 4, 2, 2, 3, 1, 2, 2, 0, 1, 1, 0, 0];
 
+/// A known solution from the initial grid.  The cube puzzle has too many
+/// states to search for a solution from scratch, so hints steer the player
+/// back onto this route instead.
+const SOLUTION: &[(Direction, i32)] = &[
+    (Direction::South, 1),
+    (Direction::East, 1),
+    (Direction::North, 2),
+    (Direction::West, 2),
+    (Direction::North, 2),
+    (Direction::North, 3),
+    (Direction::West, 3),
+    (Direction::South, 0),
+    (Direction::East, 0),
+    (Direction::North, 1),
+    (Direction::West, 2),
+    (Direction::South, 3),
+    (Direction::West, 3),
+    (Direction::East, 0),
+    (Direction::North, 0),
+    (Direction::East, 3),
+];
+
 // ========================================================================= //
 
+#[derive(Clone)]
 pub struct CubeState {
     access: Access,
     grid: Vec<i32>,
     is_initial: bool,
+    hints: i32,
 }
 
 impl CubeState {
@@ -78,6 +103,16 @@ impl CubeState {
             self.access = Access::Solved;
         }
     }
+
+    /// Returns the next single rotation to make towards the solution, or
+    /// `None` if the grid has strayed too far to find a route back.
+    pub fn hint(&self) -> Option<(Direction, i32)> {
+        let mut initial = self.clone();
+        initial.reset();
+        next_move_along(&initial, SOLUTION, self, HINT_MAX_STATES)
+    }
+
+    pub fn record_hint(&mut self) { self.hints += 1; }
 }
 
 impl PuzzleState for CubeState {
@@ -93,6 +128,38 @@ impl PuzzleState for CubeState {
         self.grid = INITIAL_GRID.to_vec();
         self.is_initial = true;
     }
+
+    fn can_hint(&self) -> bool { true }
+
+    fn hints_used(&self) -> i32 { self.hints }
+}
+
+impl Solvable for CubeState {
+    type Move = (Direction, i32);
+    type Key = Vec<i32>;
+
+    fn search_key(&self) -> Vec<i32> { self.grid.clone() }
+
+    fn legal_moves(&self) -> Vec<(Direction, i32)> {
+        let mut moves = Vec::new();
+        for dir in Direction::all() {
+            let num_ranks = if dir.is_vertical() {
+                NUM_COLS
+            } else {
+                NUM_ROWS
+            };
+            for rank in 0..num_ranks {
+                moves.push((dir, rank));
+            }
+        }
+        moves
+    }
+
+    fn apply_move(&mut self, &(dir, rank): &(Direction, i32)) {
+        self.rotate_cubes(dir, rank, 1);
+    }
+
+    fn is_goal(&self) -> bool { self.is_solved() }
 }
 
 impl Tomlable for CubeState {
@@ -106,6 +173,9 @@ impl Tomlable for CubeState {
                 .collect();
             table.insert(GRID_KEY.to_string(), toml::Value::Array(grid));
         }
+        if self.hints > 0 {
+            table.insert(HINTS_KEY.to_string(), self.hints.to_toml());
+        }
         toml::Value::Table(table)
     }
 
@@ -127,10 +197,12 @@ impl Tomlable for CubeState {
             grid
         };
         let is_initial = &grid as &[i32] == INITIAL_GRID;
+        let hints = i32::pop_from_table(&mut table, HINTS_KEY).max(0);
         CubeState {
             access: access,
             grid: grid,
             is_initial: is_initial,
+            hints: hints,
         }
     }
 }
@@ -232,10 +304,30 @@ fn rotate_vert(orientation: i32, by: i32) -> i32 {
 mod tests {
     use toml;
 
-    use save::Access;
-    use save::util::{ACCESS_KEY, Tomlable};
-    use super::{CubeState, INITIAL_GRID, NUM_COLS, NUM_ROWS, SOLVED_GRID,
-                rotate_vert};
+    use save::{Access, Direction, PuzzleState};
+    use save::solver::{Solvable, apply_moves, shortest_solution};
+    use save::util::{ACCESS_KEY, HINTS_KEY, Tomlable};
+    use super::{CubeState, INITIAL_GRID, NUM_COLS, NUM_ROWS, SOLUTION,
+                SOLVED_GRID, rotate_vert};
+
+
+    #[test]
+    fn initial_grid_is_solvable() {
+        let state = CubeState::from_toml(toml::Value::Boolean(false));
+        assert!(!state.is_solved());
+        assert!(apply_moves(&state, SOLUTION).is_solved());
+    }
+
+    #[test]
+    fn solver_finishes_known_solution() {
+        let state = CubeState::from_toml(toml::Value::Boolean(false));
+        let split = SOLUTION.len() - 4;
+        let state = apply_moves(&state, &SOLUTION[..split]);
+        assert!(!state.is_solved());
+        let solution = shortest_solution(&state, 100_000).unwrap();
+        assert!(solution.len() <= 4);
+        assert!(apply_moves(&state, &solution).is_solved());
+    }
 
     #[test]
     fn grid_sizes() {
@@ -285,6 +377,45 @@ mod tests {
         assert_eq!(state.grid, SOLVED_GRID.to_vec());
         assert!(!state.is_initial);
     }
+
+    #[test]
+    fn hint_follows_known_solution() {
+        let state = CubeState::from_toml(toml::Value::Boolean(false));
+        assert_eq!(state.hint(), Some(SOLUTION[0]));
+        let state = apply_moves(&state, &SOLUTION[..5]);
+        assert_eq!(state.hint(), Some(SOLUTION[5]));
+    }
+
+    #[test]
+    fn hints_recover_from_wrong_move() {
+        let state = CubeState::from_toml(toml::Value::Boolean(false));
+        let mut state = apply_moves(&state, &SOLUTION[..3]);
+        state.rotate_cubes(Direction::East, 3, 1);
+        let mut steps = 0;
+        while let Some(mv) = state.hint() {
+            state.apply_move(&mv);
+            steps += 1;
+            assert!(steps <= SOLUTION.len());
+        }
+        assert!(state.is_solved());
+    }
+
+    #[test]
+    fn hints_survive_solving() {
+        let mut state = CubeState::from_toml(toml::Value::Boolean(false));
+        state.access = Access::Unsolved;
+        state.record_hint();
+        state.record_hint();
+        state.solve();
+        let state = CubeState::from_toml(state.to_toml());
+        assert_eq!(state.access, Access::Solved);
+        assert_eq!(state.hints_used(), 2);
+
+        let mut table = toml::value::Table::new();
+        table.insert(HINTS_KEY.to_string(), toml::Value::Integer(-3));
+        let state = CubeState::from_toml(toml::Value::Table(table));
+        assert_eq!(state.hints_used(), 0);
+    }
 }
 
 // ========================================================================= //
@@ -20,13 +20,14 @@
 use toml;
 
 use save::{Access, Location};
-use save::pyramid::{Board, Coords, Team};
+use save::pyramid::{Board, Coords, Difficulty, Team};
 use save::util::{ACCESS_KEY, Tomlable, to_table};
 use super::PuzzleState;
 
 // ========================================================================= //
 
 const BOARD_KEY: &str = "board";
+const DIFFICULTY_KEY: &str = "difficulty";
 const MID_SCENE_DONE_KEY: &str = "mid_done";
 
 // ========================================================================= //
@@ -36,6 +37,7 @@ pub struct FailureState {
     mid_scene_done: bool,
     board: Board,
     committed_board: Board,
+    difficulty: Difficulty,
 }
 
 impl FailureState {
@@ -63,6 +65,12 @@ impl FailureState {
         self.mid_scene_done = done;
     }
 
+    pub fn difficulty(&self) -> Difficulty { self.difficulty }
+
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+    }
+
     pub fn board(&self) -> &Board { &self.board }
 
     pub fn board_mut(&mut self) -> &mut Board { &mut self.board }
@@ -99,6 +107,7 @@ impl Tomlable for FailureState {
     fn to_toml(&self) -> toml::Value {
         let mut table = toml::value::Table::new();
         table.insert(ACCESS_KEY.to_string(), self.access.to_toml());
+        table.insert(DIFFICULTY_KEY.to_string(), self.difficulty.to_toml());
         if self.mid_scene_done {
             table.insert(MID_SCENE_DONE_KEY.to_string(),
                          toml::Value::Boolean(self.mid_scene_done));
@@ -112,12 +121,15 @@ impl Tomlable for FailureState {
         let mut table = to_table(value);
         let access = Access::pop_from_table(&mut table, ACCESS_KEY);
         let board = Board::pop_from_table(&mut table, BOARD_KEY);
+        let difficulty =
+            Difficulty::pop_from_table(&mut table, DIFFICULTY_KEY);
         let mut state = FailureState {
             access: access,
             mid_scene_done: bool::pop_from_table(&mut table,
                                                  MID_SCENE_DONE_KEY),
             board: board.clone(),
             committed_board: board,
+            difficulty: difficulty,
         };
         if access.is_solved() {
             state.solve();
@@ -133,7 +145,7 @@ mod tests {
     use toml;
 
     use save::Access;
-    use save::pyramid::{Coords, Team};
+    use save::pyramid::{Coords, Difficulty, Team};
     use save::util::{ACCESS_KEY, Tomlable};
     use super::FailureState;
 
@@ -142,6 +154,7 @@ mod tests {
         let mut state = FailureState::from_toml(toml::Value::Boolean(false));
         state.access = Access::Replaying;
         state.mid_scene_done = true;
+        state.difficulty = Difficulty::Easy;
         state.board.set_piece_at(Coords::new(0, 1), Team::You);
         state.board.set_piece_at(Coords::new(0, 5), Team::SRB);
         state.commit_board();
@@ -149,6 +162,7 @@ mod tests {
         let state = FailureState::from_toml(state.to_toml());
         assert_eq!(state.access, Access::Replaying);
         assert!(state.mid_scene_done);
+        assert_eq!(state.difficulty(), Difficulty::Easy);
         assert_eq!(state.board().piece_at(Coords::new(0, 1)), Some(Team::You));
         assert_eq!(state.board().piece_at(Coords::new(0, 5)), Some(Team::SRB));
     }
@@ -158,6 +172,7 @@ mod tests {
         let state = FailureState::from_toml(toml::Value::Boolean(false));
         assert_eq!(state.access, Access::Unvisited);
         assert!(!state.mid_scene_is_done());
+        assert_eq!(state.difficulty(), Difficulty::Hard);
         assert!(state.board().is_empty());
     }
 
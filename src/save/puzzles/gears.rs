@@ -23,6 +23,7 @@ use toml;
 use save::{Access, Location};
 use save::util::{ACCESS_KEY, Tomlable, to_table};
 use super::PuzzleState;
+use super::shift::{CharacterMove, PlatformShift, ShiftLeg};
 
 // ========================================================================= //
 
@@ -34,9 +35,12 @@ const MIN_UGRENT_ROW: i32 = -1;
 const MAX_UGRENT_ROW: i32 = NUM_ROWS;
 const INITIAL_POSITIONS: &[i32] = &[1, 0, 0, 0, 10, 10, 10, 9];
 const INITIAL_UGRENT_ROW: i32 = MAX_UGRENT_ROW;
+const SHIFT_AMOUNTS: &[i32] = &[3, 3, 6, 3, 2, 7, 5, 3];
+const UGRENT_SHIFT_AMOUNT: i32 = 4;
 
 // ========================================================================= //
 
+#[derive(Clone)]
 pub struct GearsState {
     access: Access,
     positions: Vec<i32>,
@@ -64,6 +68,17 @@ impl GearsState {
         if row == 3 { 4 } else { 10 }
     }
 
+    /// Returns the amount that the arrows for the given row shift its
+    /// platform by, when Ugrent is not standing on it.
+    pub fn shift_amount(row: i32) -> i32 {
+        assert!(row >= 0 && row < NUM_ROWS);
+        SHIFT_AMOUNTS[row as usize]
+    }
+
+    /// Returns the amount that the arrows for Ugrent's row shift its
+    /// platform by.
+    pub fn ugrent_shift_amount() -> i32 { UGRENT_SHIFT_AMOUNT }
+
     pub fn get_position(&self, row: i32) -> i32 {
         assert!(row >= 0 && row < NUM_ROWS);
         self.positions[row as usize]
@@ -109,6 +124,195 @@ impl GearsState {
         }
         row
     }
+
+    /// Shifts the platform in the given row by `delta` (bouncing off the
+    /// ends of its track), and moves Ugrent as a result.
+    pub fn shift_platform(&mut self, row: i32, mut delta: i32)
+                          -> PlatformShift {
+        let min_pos = GearsState::min_position_for_row(row);
+        let max_pos = GearsState::max_position_for_row(row);
+        let original_ugrent_row = self.ugrent_row;
+        let mut ugrent_row = self.ugrent_row;
+        let mut ugrent_pos = 0;
+        if ugrent_row >= 0 && ugrent_row < NUM_ROWS {
+            ugrent_pos = self.get_position(ugrent_row);
+        }
+
+        // Move platform:
+        let mut legs = Vec::new();
+        let mut pos = self.get_position(row);
+        while delta != 0 {
+            let old_pos = pos;
+            pos += delta;
+            if pos < min_pos {
+                debug_assert!(delta < 0);
+                pos = min_pos;
+                delta = -(delta + (old_pos - min_pos));
+                debug_assert!(delta > 0);
+            } else if pos > max_pos {
+                debug_assert!(delta > 0);
+                pos = max_pos;
+                delta = -(delta - (max_pos - old_pos));
+                debug_assert!(delta < 0);
+            } else {
+                delta = 0;
+            }
+            let mut character = CharacterMove::Still;
+            if ugrent_row >= 0 && ugrent_row < NUM_ROWS &&
+                (row == ugrent_row - 1 ||
+                     ((row == 2 || row == 3) && row == ugrent_row - 2))
+            {
+                let impact = if pos > old_pos && ugrent_pos > old_pos &&
+                    ugrent_pos <= pos
+                {
+                    Some((ugrent_pos - 1, min(10, ugrent_pos + 1)))
+                } else if pos < old_pos && ugrent_pos >= pos &&
+                           ugrent_pos < old_pos
+                {
+                    Some((ugrent_pos + 1, max(0, ugrent_pos - 1)))
+                } else {
+                    None
+                };
+                if let Some((hit_pos, new_ugrent_pos)) = impact {
+                    ugrent_pos = new_ugrent_pos;
+                    ugrent_row = self.fall_from(ugrent_row, ugrent_pos);
+                    character = CharacterMove::Knocked {
+                        platform: row,
+                        hit_pos: hit_pos,
+                        pos: ugrent_pos,
+                        row: ugrent_row,
+                    };
+                }
+            } else if row == ugrent_row {
+                let barrier_pos_1 = if row > 0 {
+                    self.get_position(row - 1)
+                } else {
+                    5
+                };
+                let barrier_pos_2 = if row == 4 || row == 5 {
+                    self.get_position(row - 2)
+                } else {
+                    barrier_pos_1
+                };
+                let impact = if pos > old_pos {
+                    if barrier_pos_1 > old_pos && barrier_pos_1 <= pos {
+                        if barrier_pos_2 > old_pos &&
+                            barrier_pos_2 < barrier_pos_1
+                        {
+                            Some(barrier_pos_2 - 1)
+                        } else {
+                            Some(barrier_pos_1 - 1)
+                        }
+                    } else if barrier_pos_2 > old_pos && barrier_pos_2 <= pos {
+                        Some(barrier_pos_2 - 1)
+                    } else {
+                        None
+                    }
+                } else {
+                    if barrier_pos_1 < old_pos && barrier_pos_1 >= pos {
+                        if barrier_pos_2 < old_pos &&
+                            barrier_pos_2 > barrier_pos_1
+                        {
+                            Some(barrier_pos_2 + 1)
+                        } else {
+                            Some(barrier_pos_1 + 1)
+                        }
+                    } else if barrier_pos_2 < old_pos && barrier_pos_2 >= pos {
+                        Some(barrier_pos_2 + 1)
+                    } else {
+                        None
+                    }
+                };
+                if let Some(new_ugrent_pos) = impact {
+                    let from_pos = ugrent_pos;
+                    ugrent_pos = new_ugrent_pos;
+                    ugrent_row = self.fall_from(ugrent_row, ugrent_pos);
+                    character = CharacterMove::Pushed {
+                        platform: row,
+                        from_pos: from_pos,
+                        pos: ugrent_pos,
+                        row: ugrent_row,
+                    };
+                } else {
+                    ugrent_pos = pos;
+                    character = CharacterMove::Ride {
+                        platform: row,
+                        pos: ugrent_pos,
+                    };
+                }
+            }
+            legs.push(ShiftLeg {
+                platforms: vec![(row, old_pos, pos)],
+                character: character,
+            });
+        }
+        self.set_position(row, pos);
+
+        // Note whether Ugrent fell all the way to the floor:
+        let fell_to_floor = if ugrent_row == NUM_ROWS &&
+            original_ugrent_row != NUM_ROWS
+        {
+            Some(ugrent_pos)
+        } else {
+            None
+        };
+
+        // Make Ugrent climb upwards:
+        let mut climbs = Vec::new();
+        let last_row = NUM_ROWS - 1;
+        let min_pos_for_last_row = GearsState::min_position_for_row(last_row);
+        if ugrent_row == NUM_ROWS &&
+            self.get_position(last_row) == min_pos_for_last_row &&
+            self.get_position(last_row - 1) != min_pos_for_last_row
+        {
+            ugrent_row -= 1;
+            climbs.push(ugrent_row);
+        }
+        if ugrent_row < NUM_ROWS {
+            while ugrent_row > 0 {
+                let ugrent_pos = self.get_position(ugrent_row);
+                let pos_1 = self.get_position(ugrent_row - 1);
+                let pos_2 = if ugrent_row > 1 {
+                    self.get_position(ugrent_row - 2)
+                } else {
+                    5
+                };
+                let mut next_row = ugrent_row;
+                if (ugrent_pos - pos_1).abs() == 1 && pos_2 != pos_1 &&
+                    pos_2 != ugrent_pos &&
+                    ((ugrent_row != 5 && ugrent_row != 6) ||
+                         self.get_position(ugrent_row - 3) != pos_1)
+                {
+                    next_row = ugrent_row - 1;
+                } else if (ugrent_row == 4 || ugrent_row == 5) &&
+                           (ugrent_pos - pos_2).abs() == 1
+                {
+                    let pos_3 = self.get_position(ugrent_row - 3);
+                    if pos_3 != pos_2 && pos_3 != ugrent_pos {
+                        next_row = ugrent_row - 2;
+                    }
+                }
+                if next_row == ugrent_row {
+                    break;
+                }
+                ugrent_row = next_row;
+                climbs.push(ugrent_row);
+            }
+        }
+        if ugrent_row == 0 &&
+            self.get_position(0) == GearsState::max_position_for_row(0)
+        {
+            ugrent_row -= 1;
+            climbs.push(ugrent_row);
+        }
+        self.set_ugrent_row(ugrent_row);
+
+        PlatformShift {
+            legs: legs,
+            fell_to_floor: fell_to_floor,
+            climbs: climbs,
+        }
+    }
 }
 
 impl PuzzleState for GearsState {
@@ -192,9 +396,82 @@ mod tests {
     use toml;
 
     use save::{Access, PuzzleState};
+    use save::solver::{Solvable, apply_moves, shortest_solution};
     use save::util::{ACCESS_KEY, Tomlable};
     use super::{GearsState, INITIAL_POSITIONS, INITIAL_UGRENT_ROW,
-                MIN_UGRENT_ROW, POSITIONS_KEY, UGRENT_ROW_KEY};
+                MIN_UGRENT_ROW, NUM_ROWS, POSITIONS_KEY, UGRENT_ROW_KEY};
+
+    impl Solvable for GearsState {
+        type Move = (i32, i32);
+        type Key = (Vec<i32>, i32);
+
+        fn search_key(&self) -> (Vec<i32>, i32) {
+            (self.positions.clone(), self.ugrent_row)
+        }
+
+        fn legal_moves(&self) -> Vec<(i32, i32)> {
+            let mut moves = Vec::new();
+            for row in 0..NUM_ROWS {
+                let amount = if row == self.ugrent_row {
+                    GearsState::ugrent_shift_amount()
+                } else {
+                    GearsState::shift_amount(row)
+                };
+                moves.push((row, amount));
+                moves.push((row, -amount));
+            }
+            moves
+        }
+
+        fn apply_move(&mut self, &(row, delta): &(i32, i32)) {
+            self.shift_platform(row, delta);
+        }
+
+        fn is_goal(&self) -> bool { self.is_solved() }
+    }
+
+    /// A shortest solution from the initial state, as found by
+    /// `shortest_solution`.  Searching from scratch takes too long for an
+    /// unoptimized test build, so most tests check this sequence and search
+    /// only its final few moves; `solution_is_shortest` repeats the full
+    /// search.
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const SOLUTION: &[(i32, i32)] = &[
+        (0, 3), (0, 3), (0, 3), (1, 3), (1, 3), (1, 3),
+        (3, 3), (5, 7), (6, 5), (7, -3), (7, -3), (7, -3),
+        (7, 4), (5, -7), (3, 4), (2, 4), (2, 4),
+    ];
+
+    #[test]
+    fn initial_state_is_solvable() {
+        let state = GearsState::from_toml(toml::Value::Boolean(false));
+        assert!(!state.is_solved());
+        assert!(apply_moves(&state, SOLUTION).is_solved());
+    }
+
+    /// Searches from the initial state to confirm that `SOLUTION` is as
+    /// short as possible.  Run with `cargo test --release shortest --
+    /// --ignored`.
+    #[test]
+    #[ignore]
+    fn solution_is_shortest() {
+        let state = GearsState::from_toml(toml::Value::Boolean(false));
+        let solution = shortest_solution(&state, 50_000_000).unwrap();
+        assert_eq!(solution.len(), SOLUTION.len());
+        assert_eq!(SOLUTION.len(), 17);
+        assert!(apply_moves(&state, &solution).is_solved());
+    }
+
+    #[test]
+    fn solver_finishes_known_solution() {
+        let state = GearsState::from_toml(toml::Value::Boolean(false));
+        let split = SOLUTION.len() - 5;
+        let state = apply_moves(&state, &SOLUTION[..split]);
+        assert!(!state.is_solved());
+        let solution = shortest_solution(&state, 1_000_000).unwrap();
+        assert_eq!(solution.len(), 5);
+        assert!(apply_moves(&state, &solution).is_solved());
+    }
 
     #[test]
     fn toml_round_trip() {
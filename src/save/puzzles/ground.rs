@@ -23,6 +23,7 @@ use toml;
 use save::{Access, Location};
 use save::util::{ACCESS_KEY, Tomlable, to_table};
 use super::PuzzleState;
+use super::shift::{CharacterMove, PlatformShift, ShiftLeg};
 
 // ========================================================================= //
 
@@ -35,9 +36,11 @@ const MIN_ELINSA_ROW: i32 = -1;
 const MAX_ELINSA_ROW: i32 = NUM_ROWS;
 const INITIAL_POSITIONS: &[i32] = &[9, 9, 9, 9, 9, 9, 8];
 const INITIAL_ELINSA_ROW: i32 = MAX_ELINSA_ROW;
+const SHIFT_AMOUNTS: &[i32] = &[8, 5, 6, 7, 5, 3, 4];
 
 // ========================================================================= //
 
+#[derive(Clone)]
 pub struct GroundState {
     access: Access,
     positions: Vec<i32>,
@@ -55,7 +58,10 @@ impl GroundState {
 
     pub fn num_rows() -> i32 { NUM_ROWS }
 
-    pub fn max_position() -> i32 { MAX_POSITION }
+    pub fn shift_amount(row: i32) -> i32 {
+        assert!(row >= 0 && row < NUM_ROWS);
+        SHIFT_AMOUNTS[row as usize]
+    }
 
     pub fn get_position(&self, row: i32) -> i32 {
         assert!(row >= 0 && row < NUM_ROWS);
@@ -94,6 +100,151 @@ impl GroundState {
         }
         row
     }
+
+    /// Shifts the platform in the given row by `delta` (bouncing off the
+    /// ends of its track), and moves Elinsa as a result.
+    pub fn shift_platform(&mut self, row: i32, mut delta: i32)
+                          -> PlatformShift {
+        let original_elinsa_row = self.elinsa_row;
+        let mut elinsa_row = self.elinsa_row;
+        let mut elinsa_pos = 0;
+        if elinsa_row >= 0 && elinsa_row < NUM_ROWS {
+            elinsa_pos = self.get_position(elinsa_row);
+        }
+
+        // Move platform:
+        let mut legs = Vec::new();
+        let mut pos = self.get_position(row);
+        while delta != 0 {
+            let old_pos = pos;
+            pos += delta;
+            if pos < 0 {
+                debug_assert!(delta < 0);
+                pos = 0;
+                delta = -(delta + old_pos);
+                debug_assert!(delta > 0);
+            } else if pos > MAX_POSITION {
+                debug_assert!(delta > 0);
+                pos = MAX_POSITION;
+                delta = -(delta - (MAX_POSITION - old_pos));
+                debug_assert!(delta < 0);
+            } else {
+                delta = 0;
+            }
+            let mut character = CharacterMove::Still;
+            if elinsa_row >= 0 && elinsa_row < NUM_ROWS &&
+                row == elinsa_row - 1
+            {
+                let impact = if pos > old_pos && elinsa_pos > old_pos &&
+                    elinsa_pos <= pos
+                {
+                    Some((elinsa_pos - 1, cmp::min(MAX_POSITION,
+                                                   elinsa_pos + 1)))
+                } else if pos < old_pos && elinsa_pos >= pos &&
+                           elinsa_pos < old_pos
+                {
+                    Some((elinsa_pos + 1, cmp::max(0, elinsa_pos - 1)))
+                } else {
+                    None
+                };
+                if let Some((hit_pos, new_elinsa_pos)) = impact {
+                    elinsa_pos = new_elinsa_pos;
+                    elinsa_row = self.fall_from(elinsa_row, elinsa_pos);
+                    character = CharacterMove::Knocked {
+                        platform: row,
+                        hit_pos: hit_pos,
+                        pos: elinsa_pos,
+                        row: elinsa_row,
+                    };
+                }
+            } else if row == elinsa_row {
+                let impact = if row > 0 {
+                    let barrier_pos = self.get_position(row - 1);
+                    if pos > old_pos && barrier_pos > old_pos &&
+                        barrier_pos <= pos
+                    {
+                        Some(barrier_pos - 1)
+                    } else if pos < old_pos && barrier_pos < old_pos &&
+                               barrier_pos >= pos
+                    {
+                        Some(barrier_pos + 1)
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                };
+                if let Some(new_elinsa_pos) = impact {
+                    let from_pos = elinsa_pos;
+                    elinsa_pos = new_elinsa_pos;
+                    elinsa_row = self.fall_from(elinsa_row, elinsa_pos);
+                    character = CharacterMove::Pushed {
+                        platform: row,
+                        from_pos: from_pos,
+                        pos: elinsa_pos,
+                        row: elinsa_row,
+                    };
+                } else {
+                    elinsa_pos = pos;
+                    character = CharacterMove::Ride {
+                        platform: row,
+                        pos: elinsa_pos,
+                    };
+                }
+            }
+            legs.push(ShiftLeg {
+                platforms: vec![(row, old_pos, pos)],
+                character: character,
+            });
+        }
+        self.set_position(row, pos);
+
+        // Note whether Elinsa fell all the way to the floor:
+        let fell_to_floor = if elinsa_row == NUM_ROWS &&
+            original_elinsa_row != NUM_ROWS
+        {
+            Some(elinsa_pos)
+        } else {
+            None
+        };
+
+        // Make Elinsa climb upwards:
+        let mut climbs = Vec::new();
+        if elinsa_row == NUM_ROWS && self.get_position(NUM_ROWS - 1) == 0 &&
+            self.get_position(NUM_ROWS - 2) != 0
+        {
+            elinsa_row -= 1;
+            climbs.push(elinsa_row);
+        }
+        if elinsa_row < NUM_ROWS {
+            while elinsa_row > 0 {
+                let pos_0 = self.get_position(elinsa_row);
+                let pos_1 = self.get_position(elinsa_row - 1);
+                if (pos_0 - pos_1).abs() != 1 {
+                    break;
+                }
+                if elinsa_row >= 2 {
+                    let pos_2 = self.get_position(elinsa_row - 2);
+                    if pos_2 == pos_1 || pos_2 == pos_0 {
+                        break;
+                    }
+                }
+                elinsa_row -= 1;
+                climbs.push(elinsa_row);
+            }
+        }
+        if elinsa_row == 0 && self.get_position(0) == MAX_POSITION {
+            elinsa_row -= 1;
+            climbs.push(elinsa_row);
+        }
+        self.set_elinsa_row(elinsa_row);
+
+        PlatformShift {
+            legs: legs,
+            fell_to_floor: fell_to_floor,
+            climbs: climbs,
+        }
+    }
 }
 
 impl PuzzleState for GroundState {
@@ -174,9 +325,43 @@ mod tests {
     use toml;
 
     use save::{Access, PuzzleState};
+    use save::solver::{Solvable, apply_moves, shortest_solution};
     use save::util::{ACCESS_KEY, Tomlable};
     use super::{ELINSA_ROW_KEY, GroundState, INITIAL_ELINSA_ROW,
-                INITIAL_POSITIONS, MIN_ELINSA_ROW, POSITIONS_KEY};
+                INITIAL_POSITIONS, MIN_ELINSA_ROW, NUM_ROWS, POSITIONS_KEY};
+
+    impl Solvable for GroundState {
+        type Move = (i32, i32);
+        type Key = (Vec<i32>, i32);
+
+        fn search_key(&self) -> (Vec<i32>, i32) {
+            (self.positions.clone(), self.elinsa_row)
+        }
+
+        fn legal_moves(&self) -> Vec<(i32, i32)> {
+            let mut moves = Vec::new();
+            for row in 0..NUM_ROWS {
+                let amount = GroundState::shift_amount(row);
+                moves.push((row, amount));
+                moves.push((row, -amount));
+            }
+            moves
+        }
+
+        fn apply_move(&mut self, &(row, delta): &(i32, i32)) {
+            self.shift_platform(row, delta);
+        }
+
+        fn is_goal(&self) -> bool { self.is_solved() }
+    }
+
+    #[test]
+    fn initial_state_is_solvable() {
+        let state = GroundState::from_toml(toml::Value::Boolean(false));
+        let solution = shortest_solution(&state, 1_000_000).unwrap();
+        assert!(!solution.is_empty());
+        assert!(apply_moves(&state, &solution).is_solved());
+    }
 
     #[test]
     fn toml_round_trip() {
@@ -20,7 +20,7 @@
 use toml;
 
 use save::{Access, CrosswordState, Location, ValidChars};
-use save::util::{ACCESS_KEY, Tomlable, pop_array, to_table};
+use save::util::{ACCESS_KEY, HINTS_KEY, Tomlable, pop_array, to_table};
 use super::PuzzleState;
 
 // ========================================================================= //
@@ -46,6 +46,7 @@ const VALID_CHARS: ValidChars = ValidChars::Letters;
 pub struct HeadedState {
     access: Access,
     words: CrosswordState,
+    hints: i32,
 }
 
 impl HeadedState {
@@ -63,6 +64,16 @@ impl HeadedState {
     pub fn crossword(&self) -> &CrosswordState { &self.words }
 
     pub fn crossword_mut(&mut self) -> &mut CrosswordState { &mut self.words }
+
+    /// Fills in the first incorrect word of the crossword, and returns its
+    /// row, or returns `None` if every word is already correct.
+    pub fn reveal_word(&mut self) -> Option<i32> {
+        let row = self.words.reveal_word(SOLVED_WORDS);
+        self.check_if_solved();
+        row
+    }
+
+    pub fn record_hint(&mut self) { self.hints += 1; }
 }
 
 impl PuzzleState for HeadedState {
@@ -75,6 +86,10 @@ impl PuzzleState for HeadedState {
     fn can_reset(&self) -> bool { self.words.can_reset() }
 
     fn reset(&mut self) { self.words.reset(); }
+
+    fn can_hint(&self) -> bool { true }
+
+    fn hints_used(&self) -> i32 { self.hints }
 }
 
 impl Tomlable for HeadedState {
@@ -84,6 +99,9 @@ impl Tomlable for HeadedState {
         if !self.is_solved() && self.can_reset() {
             table.insert(WORDS_KEY.to_string(), self.words.to_toml());
         }
+        if self.hints > 0 {
+            table.insert(HINTS_KEY.to_string(), self.hints.to_toml());
+        }
         toml::Value::Table(table)
     }
 
@@ -97,9 +115,11 @@ impl Tomlable for HeadedState {
                                       VALID_CHARS,
                                       SOLVED_WORDS)
         };
+        let hints = i32::pop_from_table(&mut table, HINTS_KEY).max(0);
         HeadedState {
             access: access,
             words: words,
+            hints: hints,
         }
     }
 }
@@ -20,7 +20,7 @@
 use toml;
 
 use save::{Access, CrosswordState, Location, ValidChars};
-use save::util::{ACCESS_KEY, Tomlable, pop_array, to_table};
+use save::util::{ACCESS_KEY, HINTS_KEY, Tomlable, pop_array, to_table};
 use super::PuzzleState;
 
 // ========================================================================= //
@@ -47,6 +47,7 @@ const VALID_CHARS: ValidChars = ValidChars::LettersAndSymbols;
 pub struct LevelUpState {
     access: Access,
     words: CrosswordState,
+    hints: i32,
 }
 
 impl LevelUpState {
@@ -64,6 +65,16 @@ impl LevelUpState {
     pub fn crossword(&self) -> &CrosswordState { &self.words }
 
     pub fn crossword_mut(&mut self) -> &mut CrosswordState { &mut self.words }
+
+    /// Fills in the first incorrect word of the crossword, and returns its
+    /// row, or returns `None` if every word is already correct.
+    pub fn reveal_word(&mut self) -> Option<i32> {
+        let row = self.words.reveal_word(SOLVED_WORDS);
+        self.check_if_solved();
+        row
+    }
+
+    pub fn record_hint(&mut self) { self.hints += 1; }
 }
 
 impl PuzzleState for LevelUpState {
@@ -76,6 +87,10 @@ impl PuzzleState for LevelUpState {
     fn can_reset(&self) -> bool { self.words.can_reset() }
 
     fn reset(&mut self) { self.words.reset(); }
+
+    fn can_hint(&self) -> bool { true }
+
+    fn hints_used(&self) -> i32 { self.hints }
 }
 
 impl Tomlable for LevelUpState {
@@ -85,6 +100,9 @@ impl Tomlable for LevelUpState {
         if !self.is_solved() && self.can_reset() {
             table.insert(WORDS_KEY.to_string(), self.words.to_toml());
         }
+        if self.hints > 0 {
+            table.insert(HINTS_KEY.to_string(), self.hints.to_toml());
+        }
         toml::Value::Table(table)
     }
 
@@ -98,9 +116,11 @@ impl Tomlable for LevelUpState {
                                       VALID_CHARS,
                                       SOLVED_WORDS)
         };
+        let hints = i32::pop_from_table(&mut table, HINTS_KEY).max(0);
         LevelUpState {
             access: access,
             words: words,
+            hints: hints,
         }
     }
 }
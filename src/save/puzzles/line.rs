@@ -21,6 +21,7 @@ use rand::{self, Rng, SeedableRng};
 use toml;
 
 use save::{Access, Location};
+use save::rng::game_random;
 use save::util::{ACCESS_KEY, Tomlable, to_table};
 use super::PuzzleState;
 
@@ -103,7 +104,7 @@ impl LineState {
 
     pub fn reseed(&mut self) {
         for value in self.seed.iter_mut() {
-            *value = rand::random();
+            *value = game_random();
         }
         self.update_grids();
     }
@@ -179,7 +180,7 @@ impl Tomlable for LineState {
                 }
             }
             while index < 8 {
-                seed[index] = rand::random();
+                seed[index] = game_random();
                 index += 1;
             }
         }
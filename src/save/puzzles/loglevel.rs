@@ -20,7 +20,7 @@
 use toml;
 
 use save::{Access, CrosswordState, Location, ValidChars};
-use save::util::{ACCESS_KEY, Tomlable, pop_array, to_table};
+use save::util::{ACCESS_KEY, HINTS_KEY, Tomlable, pop_array, to_table};
 use super::PuzzleState;
 
 // ========================================================================= //
@@ -47,6 +47,7 @@ const VALID_CHARS: ValidChars = ValidChars::LettersAndNumbers;
 pub struct LogLevelState {
     access: Access,
     words: CrosswordState,
+    hints: i32,
 }
 
 impl LogLevelState {
@@ -64,6 +65,16 @@ impl LogLevelState {
     pub fn crossword(&self) -> &CrosswordState { &self.words }
 
     pub fn crossword_mut(&mut self) -> &mut CrosswordState { &mut self.words }
+
+    /// Fills in the first incorrect word of the crossword, and returns its
+    /// row, or returns `None` if every word is already correct.
+    pub fn reveal_word(&mut self) -> Option<i32> {
+        let row = self.words.reveal_word(SOLVED_WORDS);
+        self.check_if_solved();
+        row
+    }
+
+    pub fn record_hint(&mut self) { self.hints += 1; }
 }
 
 impl PuzzleState for LogLevelState {
@@ -76,6 +87,10 @@ impl PuzzleState for LogLevelState {
     fn can_reset(&self) -> bool { self.words.can_reset() }
 
     fn reset(&mut self) { self.words.reset(); }
+
+    fn can_hint(&self) -> bool { true }
+
+    fn hints_used(&self) -> i32 { self.hints }
 }
 
 impl Tomlable for LogLevelState {
@@ -85,6 +100,9 @@ impl Tomlable for LogLevelState {
         if !self.is_solved() && self.can_reset() {
             table.insert(WORDS_KEY.to_string(), self.words.to_toml());
         }
+        if self.hints > 0 {
+            table.insert(HINTS_KEY.to_string(), self.hints.to_toml());
+        }
         toml::Value::Table(table)
     }
 
@@ -98,9 +116,11 @@ impl Tomlable for LogLevelState {
                                       VALID_CHARS,
                                       SOLVED_WORDS)
         };
+        let hints = i32::pop_from_table(&mut table, HINTS_KEY).max(0);
         LogLevelState {
             access: access,
             words: words,
+            hints: hints,
         }
     }
 }
@@ -111,7 +131,7 @@ impl Tomlable for LogLevelState {
 mod tests {
     use toml;
 
-    use save::Access;
+    use save::{Access, PuzzleState};
     use save::util::{ACCESS_KEY, Tomlable};
     use super::{LogLevelState, SOLVED_WORDS};
 
@@ -131,6 +151,21 @@ mod tests {
         assert_eq!(state.words.words()[1], vec!['H', 'E', 'L', 'L', 'O']);
     }
 
+    #[test]
+    fn revealing_words_solves_puzzle() {
+        let mut state = LogLevelState::from_toml(toml::Value::Boolean(false));
+        state.access = Access::Unsolved;
+        state.crossword_mut().set_char(1, 0, 'X');
+        assert_eq!(state.reveal_word(), Some(0));
+        assert_eq!(state.reveal_word(), Some(1));
+        for row in 2..SOLVED_WORDS.len() {
+            assert!(!state.is_solved());
+            assert_eq!(state.reveal_word(), Some(row as i32));
+        }
+        assert!(state.is_solved());
+        assert_eq!(state.reveal_word(), None);
+    }
+
     #[test]
     fn from_empty_toml() {
         let state = LogLevelState::from_toml(toml::Value::Boolean(false));
@@ -22,7 +22,8 @@ use toml;
 use gui::Point;
 use save::{Access, Direction, Location};
 use save::ice::{BlockSlide, Object, ObjectGrid, Symbol};
-use save::util::{ACCESS_KEY, Tomlable, pop_table, to_table};
+use save::solver::{HINT_MAX_STATES, shortest_solution};
+use save::util::{ACCESS_KEY, HINTS_KEY, Tomlable, pop_table, to_table};
 use super::PuzzleState;
 
 // ========================================================================= //
@@ -34,6 +35,7 @@ const GRID_KEY: &str = "grid";
 pub struct MeetState {
     access: Access,
     grid: ObjectGrid,
+    hints: i32,
 }
 
 impl MeetState {
@@ -55,6 +57,15 @@ impl MeetState {
         slide
     }
 
+    /// Returns the first slide of a shortest solution from the current grid,
+    /// or `None` if no solution can be found.
+    pub fn hint(&self) -> Option<(Point, Direction)> {
+        shortest_solution(&self.grid, HINT_MAX_STATES)
+            .and_then(|slides| slides.into_iter().next())
+    }
+
+    pub fn record_hint(&mut self) { self.hints += 1; }
+
     fn base_grid() -> ObjectGrid {
         let red = Symbol::RedTriangle(Direction::North);
         let mut grid = ObjectGrid::new(7, 7);
@@ -99,6 +110,10 @@ impl PuzzleState for MeetState {
     fn can_reset(&self) -> bool { self.grid.is_modified() }
 
     fn reset(&mut self) { self.grid = MeetState::initial_grid(); }
+
+    fn can_hint(&self) -> bool { true }
+
+    fn hints_used(&self) -> i32 { self.hints }
 }
 
 impl Tomlable for MeetState {
@@ -108,6 +123,9 @@ impl Tomlable for MeetState {
         if self.grid.is_modified() && !self.is_solved() {
             table.insert(GRID_KEY.to_string(), self.grid.to_toml());
         }
+        if self.hints > 0 {
+            table.insert(HINTS_KEY.to_string(), self.hints.to_toml());
+        }
         toml::Value::Table(table)
     }
 
@@ -120,9 +138,11 @@ impl Tomlable for MeetState {
             let grid = pop_table(&mut table, GRID_KEY);
             ObjectGrid::from_toml(grid, &MeetState::initial_grid())
         };
+        let hints = i32::pop_from_table(&mut table, HINTS_KEY).max(0);
         MeetState {
             access: access,
             grid: grid,
+            hints: hints,
         }
     }
 }
@@ -134,10 +154,31 @@ mod tests {
     use toml;
 
     use gui::Point;
-    use save::{Access, Direction};
+    use save::{Access, Direction, PuzzleState};
+    use save::solver::shortest_solution;
     use save::util::{ACCESS_KEY, Tomlable};
     use super::MeetState;
 
+    #[test]
+    fn initial_grid_is_solvable() {
+        let mut state = MeetState::from_toml(toml::Value::Boolean(false));
+        let solution = shortest_solution(state.grid(), 100_000).unwrap();
+        assert_eq!(solution.len(), 30);
+        for &(coords, dir) in solution.iter() {
+            assert!(state.slide_ice_block(coords, dir).is_some());
+        }
+        assert!(state.is_solved());
+    }
+
+    #[test]
+    fn hint_starts_shortest_solution() {
+        let mut state = MeetState::from_toml(toml::Value::Boolean(false));
+        let (coords, dir) = state.hint().unwrap();
+        assert!(state.slide_ice_block(coords, dir).is_some());
+        let solution = shortest_solution(state.grid(), 100_000).unwrap();
+        assert_eq!(solution.len(), 29);
+    }
+
     #[test]
     fn toml_round_trip() {
         let mut state = MeetState::from_toml(toml::Value::Boolean(false));
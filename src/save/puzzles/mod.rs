@@ -22,6 +22,7 @@ mod auto;
 mod black;
 mod blame;
 mod blind;
+mod clues;
 mod cube;
 mod day;
 mod discon;
@@ -51,6 +52,7 @@ mod puzzle;
 mod right;
 mod sauce;
 mod serves;
+mod shift;
 mod simple;
 mod star;
 mod syrup;
@@ -94,6 +96,7 @@ pub use self::puzzle::PuzzleState;
 pub use self::right::RightState;
 pub use self::sauce::SauceState;
 pub use self::serves::ServesState;
+pub use self::shift::CharacterMove;
 pub use self::simple::SimpleState;
 pub use self::star::{StarState, WordDir};
 pub use self::syrup::SyrupState;
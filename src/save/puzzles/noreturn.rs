@@ -44,6 +44,7 @@ const SOLVED_ORDER: [usize; 7] = [4, 0, 5, 2, 1, 6, 3];
 
 // ========================================================================= //
 
+#[derive(Clone)]
 pub struct NoReturnState {
     access: Access,
     order: [usize; 7],
@@ -174,9 +175,52 @@ mod tests {
     use toml;
 
     use save::{Access, PuzzleState};
+    use save::solver::{Solvable, apply_moves, shortest_solution};
     use save::util::{ACCESS_KEY, Tomlable};
     use super::{INITIAL_ORDER, NoReturnState, ORDER_KEY, SOLVED_ORDER};
 
+    impl Solvable for NoReturnState {
+        type Move = (usize, usize);
+        type Key = [usize; 7];
+
+        fn search_key(&self) -> [usize; 7] { self.order }
+
+        fn legal_moves(&self) -> Vec<(usize, usize)> {
+            let mut moves = Vec::new();
+            for from in 0..self.order.len() {
+                for to in 0..self.order.len() {
+                    if from != to {
+                        moves.push((from, to));
+                    }
+                }
+            }
+            moves
+        }
+
+        fn apply_move(&mut self, &(from, to): &(usize, usize)) {
+            self.move_tile(from, to);
+        }
+
+        fn is_goal(&self) -> bool { self.clone().check_if_solved() }
+    }
+
+    #[test]
+    fn initial_order_is_solvable() {
+        let state = NoReturnState::from_toml(toml::Value::Boolean(false));
+        let solution = shortest_solution(&state, 10_000).unwrap();
+        assert!(!solution.is_empty());
+        let mut state = apply_moves(&state, &solution);
+        assert!(state.check_if_solved());
+        assert!(state.is_solved());
+    }
+
+    #[test]
+    fn solved_order_is_solved() {
+        let mut state = NoReturnState::from_toml(toml::Value::Boolean(false));
+        state.order = SOLVED_ORDER;
+        assert!(state.check_if_solved());
+    }
+
     #[test]
     fn toml_round_trip() {
         let mut state = NoReturnState::from_toml(toml::Value::Boolean(false));
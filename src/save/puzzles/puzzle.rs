@@ -47,6 +47,12 @@ pub trait PuzzleState: Tomlable {
 
     fn allow_reset_for_undo_redo(&self) -> bool { true }
 
+    /// Returns true if this puzzle is able to offer the player a hint.
+    fn can_hint(&self) -> bool { false }
+
+    /// Returns the number of hints that the player has used on this puzzle.
+    fn hints_used(&self) -> i32 { 0 }
+
     fn can_reset(&self) -> bool;
 
     fn reset(&mut self);
@@ -22,18 +22,44 @@ use toml;
 use gui::Point;
 use save::{Access, Direction, Location};
 use save::ice::{BlockSlide, Object, ObjectGrid, Symbol};
-use save::util::{ACCESS_KEY, Tomlable, pop_table, to_table};
+use save::solver::{HINT_MAX_STATES, next_move_along};
+use save::util::{ACCESS_KEY, HINTS_KEY, Tomlable, pop_table, to_table};
 use super::PuzzleState;
 
 // ========================================================================= //
 
 const GRID_KEY: &str = "grid";
 
+/// A known solution from the initial grid (not necessarily a shortest one).
+/// There are too many states to search for a solution from scratch, so hints
+/// steer the player back onto this route instead.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SOLUTION: &[(i32, i32, Direction)] = &[
+    (1, 1, Direction::East), (3, 4, Direction::East),
+    (9, 0, Direction::West), (0, 0, Direction::South),
+    (0, 3, Direction::East), (2, 3, Direction::North),
+    (2, 2, Direction::East), (2, 1, Direction::South),
+    (9, 2, Direction::West), (2, 5, Direction::East),
+    (5, 4, Direction::South), (9, 5, Direction::West),
+    (6, 5, Direction::North), (0, 2, Direction::East),
+    (6, 4, Direction::South), (5, 5, Direction::North),
+    (5, 4, Direction::West), (1, 4, Direction::North),
+    (1, 0, Direction::East), (5, 2, Direction::North),
+    (9, 0, Direction::West), (5, 0, Direction::West),
+    (6, 0, Direction::South), (6, 2, Direction::West),
+    (0, 0, Direction::South), (0, 2, Direction::South),
+    (0, 3, Direction::East), (0, 1, Direction::East),
+    (2, 1, Direction::South), (2, 3, Direction::South),
+    (2, 5, Direction::East), (6, 5, Direction::North),
+    (6, 4, Direction::West), (5, 5, Direction::North),
+];
+
 // ========================================================================= //
 
 pub struct RightState {
     access: Access,
     grid: ObjectGrid,
+    hints: i32,
 }
 
 impl RightState {
@@ -55,6 +81,21 @@ impl RightState {
         slide
     }
 
+    /// Returns the next slide to make towards the solution, or `None` if the
+    /// grid has strayed too far to find a route back.
+    pub fn hint(&self) -> Option<(Point, Direction)> {
+        let route: Vec<(Point, Direction)> = SOLUTION
+            .iter()
+            .map(|&(col, row, dir)| (Point::new(col, row), dir))
+            .collect();
+        next_move_along(&RightState::initial_grid(),
+                        &route,
+                        &self.grid,
+                        HINT_MAX_STATES)
+    }
+
+    pub fn record_hint(&mut self) { self.hints += 1; }
+
     fn base_grid() -> ObjectGrid {
         let red = Symbol::RedTriangle(Direction::West);
         let yellow = Symbol::YellowRhombus(true, false);
@@ -101,6 +142,10 @@ impl PuzzleState for RightState {
     fn can_reset(&self) -> bool { self.grid.is_modified() }
 
     fn reset(&mut self) { self.grid = RightState::initial_grid(); }
+
+    fn can_hint(&self) -> bool { true }
+
+    fn hints_used(&self) -> i32 { self.hints }
 }
 
 impl Tomlable for RightState {
@@ -110,6 +155,9 @@ impl Tomlable for RightState {
         if self.grid.is_modified() && !self.is_solved() {
             table.insert(GRID_KEY.to_string(), self.grid.to_toml());
         }
+        if self.hints > 0 {
+            table.insert(HINTS_KEY.to_string(), self.hints.to_toml());
+        }
         toml::Value::Table(table)
     }
 
@@ -122,9 +170,11 @@ impl Tomlable for RightState {
             let grid = pop_table(&mut table, GRID_KEY);
             ObjectGrid::from_toml(grid, &RightState::initial_grid())
         };
+        let hints = i32::pop_from_table(&mut table, HINTS_KEY).max(0);
         RightState {
             access: access,
             grid: grid,
+            hints: hints,
         }
     }
 }
@@ -136,9 +186,41 @@ mod tests {
     use toml;
 
     use gui::Point;
-    use save::{Access, Direction};
+    use save::{Access, Direction, PuzzleState};
+    use save::solver::shortest_solution;
     use save::util::{ACCESS_KEY, Tomlable};
-    use super::RightState;
+    use super::{RightState, SOLUTION};
+
+
+    fn apply_solution(state: &mut RightState,
+                      moves: &[(i32, i32, Direction)]) {
+        for &(col, row, dir) in moves.iter() {
+            let coords = Point::new(col, row);
+            assert!(state.slide_ice_block(coords, dir).is_some());
+        }
+    }
+
+    #[test]
+    fn initial_grid_is_solvable() {
+        let mut state = RightState::from_toml(toml::Value::Boolean(false));
+        assert_eq!(SOLUTION.len(), 34);
+        apply_solution(&mut state, SOLUTION);
+        assert!(state.is_solved());
+    }
+
+    #[test]
+    fn solver_finishes_known_solution() {
+        let mut state = RightState::from_toml(toml::Value::Boolean(false));
+        let split = SOLUTION.len() - 5;
+        apply_solution(&mut state, &SOLUTION[..split]);
+        assert!(!state.is_solved());
+        let solution = shortest_solution(state.grid(), 1_000_000).unwrap();
+        assert_eq!(solution.len(), 5);
+        for &(coords, dir) in solution.iter() {
+            assert!(state.slide_ice_block(coords, dir).is_some());
+        }
+        assert!(state.is_solved());
+    }
 
     #[test]
     fn toml_round_trip() {
@@ -24,6 +24,7 @@ use toml;
 use save::{Access, Location};
 use save::util::{ACCESS_KEY, Tomlable, to_table};
 use super::PuzzleState;
+use super::clues::WordClues;
 
 // ========================================================================= //
 
@@ -70,6 +71,7 @@ pub struct SauceState {
     access: Access,
     done: HashSet<i32>,
     current: i32,
+    clues: WordClues,
 }
 
 impl SauceState {
@@ -83,10 +85,17 @@ impl SauceState {
 
     pub fn num_clues_done(&self) -> u32 { self.done.len() as u32 }
 
-    pub fn current_clue(&self) -> &'static str {
+    pub fn current_clue(&self) -> &str {
         debug_assert!(self.current >= 0 &&
                           self.current < WORD_CLUES.len() as i32);
-        WORD_CLUES[self.current as usize].1
+        self.clues.clue(self.current as usize)
+    }
+
+    /// Switches to translated answers and clues (see `WordClues::localize`).
+    /// Returns false if the translated list is invalid.
+    pub fn localize_clues(&mut self, clues: Option<&[(String, String)]>)
+                          -> bool {
+        self.clues.localize(clues)
     }
 
     pub fn go_next(&mut self) {
@@ -115,7 +124,7 @@ impl SauceState {
     pub fn try_text(&mut self, text: &str) -> (String, bool, bool) {
         let mut prefix = String::new();
         let mut chars = text.chars().peekable();
-        for chr in WORD_CLUES[self.current as usize].0.chars() {
+        for chr in self.clues.answer(self.current as usize).chars() {
             if chr == ' ' {
                 prefix.push(' ');
                 if chars.peek() == Some(&' ') {
@@ -197,6 +206,7 @@ impl Tomlable for SauceState {
             access: access,
             done: done,
             current: current,
+            clues: WordClues::new(WORD_CLUES),
         };
         if !state.is_solved() && state.done.contains(&state.current) {
             state.go_next();
@@ -0,0 +1,70 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+// ========================================================================= //
+
+/// The outcome of shifting a platform in one of the "shift" puzzles, with
+/// enough detail for the view to animate it.
+pub struct PlatformShift {
+    /// The legs of the shift, in order.  A platform that reaches the end of
+    /// its track bounces back, so one shift may take more than one leg.
+    pub legs: Vec<ShiftLeg>,
+    /// If the character fell all the way to the floor during the shift,
+    /// their position on the floor.  (They then walk back to the starting
+    /// platform.)
+    pub fell_to_floor: Option<i32>,
+    /// The rows that the character climbed up to after the shift, in order.
+    /// A row of -1 means they climbed out of the puzzle.
+    pub climbs: Vec<i32>,
+}
+
+pub struct ShiftLeg {
+    /// The row, old position, and new position of each platform that moved
+    /// during this leg.
+    pub platforms: Vec<(i32, i32, i32)>,
+    pub character: CharacterMove,
+}
+
+pub enum CharacterMove {
+    /// The character was unaffected by this leg.
+    Still,
+    /// The character rode along on the platform in row `platform`, ending
+    /// at position `pos`.
+    Ride { platform: i32, pos: i32 },
+    /// The platform in row `platform` (somewhere above the character) struck
+    /// the character when it reached position `hit_pos`, knocking them to
+    /// position `pos` in row `row`.
+    Knocked {
+        platform: i32,
+        hit_pos: i32,
+        pos: i32,
+        row: i32,
+    },
+    /// While riding on the platform in row `platform`, the character was
+    /// pushed from position `from_pos` to `pos` by a barrier, and then fell
+    /// to row `row`.
+    Pushed {
+        platform: i32,
+        from_pos: i32,
+        pos: i32,
+        row: i32,
+    },
+}
+
+// ========================================================================= //
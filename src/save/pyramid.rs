@@ -17,11 +17,16 @@
 // | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
 // +--------------------------------------------------------------------------+
 
-use rand;
-use std::cmp;
-use std::collections::{HashMap, HashSet};
+use rand::{self, ChaChaRng, Rng, SeedableRng};
+use std::{cmp, u32};
+use std::collections::{BTreeMap, HashSet};
+use std::f64;
+use std::mem;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 use toml;
 
+use save::rng::game_random;
 use save::util::Tomlable;
 
 // ========================================================================= //
@@ -32,7 +37,308 @@ const STARTING_PIECES: i32 = 18;
 
 const FORMATION_LINE_LENGTH: usize = 4;
 
-const MINIMAX_DEPTH: i32 = 9;
+/// How many positions the SRB searches between checks for cancellation and
+/// for running out of time.
+const NODES_PER_CHECK: u32 = 1024;
+
+/// How much an open position where a team could complete a formation is
+/// worth, in terms of pieces in that team's supply, for `Heuristic::Threats`.
+const THREAT_VALUE: f64 = 0.5;
+
+// ========================================================================= //
+
+/// How strongly the SRB plays.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    /// The only level before difficulty levels were added, so it is the
+    /// default for games saved before then.
+    Hard,
+    Expert,
+}
+
+impl Difficulty {
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Expert => "Expert",
+        }
+    }
+
+    /// Returns the next difficulty level, wrapping around from hardest to
+    /// easiest.
+    pub fn next(self) -> Difficulty {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Expert,
+            Difficulty::Expert => Difficulty::Easy,
+        }
+    }
+
+    /// Returns how long, in milliseconds, the SRB spends thinking about each
+    /// move at this level.
+    pub fn time_budget_millis(self) -> u32 {
+        match self {
+            Difficulty::Easy => 250,
+            Difficulty::Normal => 500,
+            Difficulty::Hard => 1000,
+            Difficulty::Expert => 1500,
+        }
+    }
+
+    /// Returns the deepest that the SRB will search at this level, not
+    /// counting its own next move.
+    fn max_depth(self) -> i32 {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 5,
+            Difficulty::Hard | Difficulty::Expert => 9,
+        }
+    }
+
+    fn heuristic(self) -> Heuristic {
+        match self {
+            Difficulty::Easy | Difficulty::Normal | Difficulty::Hard => {
+                Heuristic::Supply
+            }
+            Difficulty::Expert => Heuristic::Threats,
+        }
+    }
+
+    #[cfg(test)]
+    fn all() -> Vec<Difficulty> {
+        vec![Difficulty::Easy,
+             Difficulty::Normal,
+             Difficulty::Hard,
+             Difficulty::Expert]
+    }
+}
+
+impl Default for Difficulty {
+    fn default() -> Difficulty { Difficulty::Hard }
+}
+
+impl Tomlable for Difficulty {
+    fn to_toml(&self) -> toml::Value {
+        let string = match *self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Expert => "expert",
+        };
+        toml::Value::String(string.to_string())
+    }
+
+    fn from_toml(value: toml::Value) -> Difficulty {
+        if let Some(string) = value.as_str() {
+            match string {
+                "easy" => return Difficulty::Easy,
+                "normal" => return Difficulty::Normal,
+                "hard" => return Difficulty::Hard,
+                "expert" => return Difficulty::Expert,
+                _ => {}
+            }
+        }
+        Default::default()
+    }
+}
+
+/// Returns a new random number generator for breaking ties between
+/// equally-good SRB moves.  It is seeded from the game logic RNG, so that
+/// recorded games are replayed with the same choices.
+pub fn new_srb_rng() -> ChaChaRng { ChaChaRng::from_seed(&[game_random()]) }
+
+// ========================================================================= //
+
+/// How the SRB scores a board that it hasn't searched to the end of the game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Heuristic {
+    /// Compare only the sizes of the two teams' supplies.
+    Supply,
+    /// Also credit each team for the open positions where it could complete
+    /// a formation on its next move.
+    Threats,
+}
+
+/// Bookkeeping for a single (possibly time-limited) search.
+struct Search<'a> {
+    heuristic: Heuristic,
+    deadline: Option<Instant>,
+    cancel: Option<&'a AtomicBool>,
+    nodes: u32,
+    stopped: bool,
+    table: TranspositionTable,
+    /// For each ply, up to two moves that recently caused a cutoff there.
+    killers: Vec<[Option<MoveKey>; 2]>,
+    /// How often each move has caused a cutoff, weighted by depth.
+    history: Vec<u32>,
+    /// A reusable buffer of child positions for each ply, so that the search
+    /// doesn't need to allocate once it has warmed up.
+    children: Vec<Vec<Child>>,
+}
+
+impl<'a> Search<'a> {
+    fn new(heuristic: Heuristic) -> Search<'a> {
+        Search {
+            heuristic: heuristic,
+            deadline: None,
+            cancel: None,
+            nodes: 0,
+            stopped: false,
+            table: TranspositionTable::new(),
+            killers: Vec::new(),
+            history: vec![0; NUM_MOVE_KEYS],
+            children: Vec::new(),
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel.map_or(false, |cancel| cancel.load(Ordering::Relaxed))
+    }
+
+    fn is_past_deadline(&self) -> bool {
+        self.deadline.map_or(false, |deadline| Instant::now() >= deadline)
+    }
+
+    /// Counts one more searched position, and returns true if the search has
+    /// run out of time or been cancelled (and should be abandoned).
+    fn should_stop(&mut self) -> bool {
+        if !self.stopped {
+            self.nodes = self.nodes.wrapping_add(1);
+            self.stopped = self.nodes % NODES_PER_CHECK == 0 &&
+                (self.is_cancelled() || self.is_past_deadline());
+        }
+        self.stopped
+    }
+
+    /// Takes the child buffer for the given ply out of the search (so that
+    /// the search can still be borrowed mutably while it is in use).
+    fn take_children(&mut self, ply: usize) -> Vec<Child> {
+        while self.children.len() <= ply {
+            self.children.push(Vec::new());
+        }
+        mem::replace(&mut self.children[ply], Vec::new())
+    }
+
+    fn return_children(&mut self, ply: usize, children: Vec<Child>) {
+        self.children[ply] = children;
+    }
+
+    /// Sorts the children so that the moves most likely to cause a cutoff
+    /// come first: the best move found the last time this position was
+    /// searched, then killer moves, then by history.
+    fn order_children(&mut self, ply: usize, best: Option<MoveKey>,
+                      children: &mut [Child]) {
+        while self.killers.len() <= ply {
+            self.killers.push([None, None]);
+        }
+        let killers = self.killers[ply];
+        for child in children.iter_mut() {
+            child.order = if Some(child.key) == best {
+                u32::MAX
+            } else if Some(child.key) == killers[0] {
+                u32::MAX - 1
+            } else if Some(child.key) == killers[1] {
+                u32::MAX - 2
+            } else {
+                cmp::min(self.history[child.key.index()], u32::MAX - 3)
+            };
+        }
+        children.sort_unstable_by(|a, b| b.order.cmp(&a.order));
+    }
+
+    /// Records that the given move caused a cutoff at the given ply.
+    fn record_cutoff(&mut self, ply: usize, key: MoveKey, depth: i32) {
+        let killers = &mut self.killers[ply];
+        if killers[0] != Some(key) {
+            killers[1] = killers[0];
+            killers[0] = Some(key);
+        }
+        let history = &mut self.history[key.index()];
+        *history = history.saturating_add((depth * depth) as u32);
+    }
+}
+
+// ========================================================================= //
+
+/// Identifies a move by where the piece came from (if it was a jump) and
+/// where it went, for the purposes of move ordering.  Two moves with the same
+/// key may differ in which pieces were removed afterwards.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct MoveKey {
+    from: Option<Coords>,
+    to: Coords,
+}
+
+/// The number of distinct MoveKey indices.
+const NUM_MOVE_KEYS: usize = (NUM_CELLS + 1) * NUM_CELLS;
+
+impl MoveKey {
+    fn index(self) -> usize {
+        let from = self.from.map_or(NUM_CELLS, |from| from.index());
+        from * NUM_CELLS + self.to.index()
+    }
+}
+
+/// A position reachable in one move during the search.
+#[derive(Clone, Copy)]
+struct Child {
+    key: MoveKey,
+    board: Board,
+    order: u32,
+}
+
+// ========================================================================= //
+
+/// The number of entries in the transposition table (about 2 MB worth).
+const TABLE_SIZE: usize = 1 << 16;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Bound {
+    /// The stored value is the exact score of the position.
+    Exact,
+    /// The search failed high; the real score is at least the stored value.
+    Lower,
+    /// The search failed low; the real score is at most the stored value.
+    Upper,
+}
+
+#[derive(Clone, Copy)]
+struct TableEntry {
+    key: u64,
+    depth: i32,
+    value: f64,
+    bound: Bound,
+    best: Option<MoveKey>,
+}
+
+/// Remembers the results of searching positions that have already been seen,
+/// indexed by the board's Zobrist hash combined with the team to move.
+/// Colliding entries simply replace one another.
+struct TranspositionTable {
+    entries: Vec<Option<TableEntry>>,
+}
+
+impl TranspositionTable {
+    fn new() -> TranspositionTable {
+        TranspositionTable { entries: vec![None; TABLE_SIZE] }
+    }
+
+    fn get(&self, key: u64) -> Option<TableEntry> {
+        match self.entries[(key % TABLE_SIZE as u64) as usize] {
+            Some(entry) if entry.key == key => Some(entry),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, entry: TableEntry) {
+        self.entries[(entry.key % TABLE_SIZE as u64) as usize] = Some(entry);
+    }
+}
 
 // ========================================================================= //
 
@@ -121,6 +427,17 @@ impl Coords {
     }
 }
 
+impl Tomlable for Coords {
+    fn to_toml(&self) -> toml::Value {
+        toml::Value::Integer(self.index() as i64)
+    }
+
+    fn from_toml(value: toml::Value) -> Coords {
+        Coords::from_index(usize::from_toml(value))
+            .unwrap_or_else(|| Coords::new(0, 0))
+    }
+}
+
 pub struct CoordsIter {
     row: i32,
     col: i32,
@@ -165,19 +482,60 @@ pub enum Move {
 
 const NUM_CELLS: usize = 2 * STARTING_PIECES as usize;
 
-#[derive(Clone)]
+/// There can never be more than this many pieces that a team could remove
+/// (one for each column).
+const MAX_POSSIBLE_REMOVALS: usize = 8;
+
+/// A fixed-capacity list of the pieces that a team could remove.
+struct Removals {
+    coords: [Coords; MAX_POSSIBLE_REMOVALS],
+    len: usize,
+}
+
+impl Removals {
+    fn as_slice(&self) -> &[Coords] { &self.coords[..self.len] }
+}
+
+/// Returns the Zobrist key for the given cell holding the given value.  A
+/// board's hash is the XOR of the keys for all of its cells.  Rather than
+/// storing a table of random keys, we compute each key by scrambling the
+/// cell index and value with the SplitMix64 finalizer.
+fn zobrist_key(index: usize, value: i8) -> u64 {
+    if value == 0 {
+        return 0;
+    }
+    splitmix64((index * 3) as u64 + value as u64)
+}
+
+/// Combined with a board's hash when it is the SRB's turn to move, so that
+/// the same board with different teams to move gets different table entries.
+const SRB_TO_MOVE_KEY: u64 = 0x5f3a_91c4_d2e8_6b17;
+
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// A board is small enough to copy freely (which the search does a lot of),
+/// and carries a Zobrist hash of its cells, which is updated as pieces are
+/// placed and removed.
+#[derive(Clone, Copy)]
 pub struct Board {
-    cells: Vec<i8>,
+    cells: [i8; NUM_CELLS],
     you: i32,
     srb: i32,
+    hash: u64,
 }
 
 impl Board {
     pub fn new() -> Board {
         Board {
-            cells: vec![0; NUM_CELLS],
+            cells: [0; NUM_CELLS],
             you: STARTING_PIECES,
             srb: STARTING_PIECES,
+            hash: 0,
         }
     }
 
@@ -188,11 +546,14 @@ impl Board {
         if you > STARTING_PIECES || srb > STARTING_PIECES {
             return Board::new();
         }
-        Board {
-            cells: cells,
-            you: STARTING_PIECES - you,
-            srb: STARTING_PIECES - srb,
+        let mut board = Board::new();
+        for (index, &value) in cells.iter().enumerate() {
+            board.cells[index] = value;
+            board.hash ^= zobrist_key(index, value);
         }
+        board.you = STARTING_PIECES - you;
+        board.srb = STARTING_PIECES - srb;
+        board
     }
 
     pub fn is_empty(&self) -> bool {
@@ -213,6 +574,7 @@ impl Board {
         let index = coords.index();
         debug_assert_eq!(self.cells[index], 0);
         self.cells[index] = team.value();
+        self.hash ^= zobrist_key(index, team.value());
         match team {
             Team::You => {
                 self.you -= 1;
@@ -224,7 +586,7 @@ impl Board {
     }
 
     pub fn with_piece_at(&self, coords: Coords, team: Team) -> Board {
-        let mut board = self.clone();
+        let mut board = *self;
         board.set_piece_at(coords, team);
         board
     }
@@ -238,10 +600,11 @@ impl Board {
             self.srb += 1;
         }
         self.cells[index] = 0;
+        self.hash ^= zobrist_key(index, value);
     }
 
     pub fn with_removed(&self, coords: Coords) -> Board {
-        let mut board = self.clone();
+        let mut board = *self;
         board.remove_piece(coords);
         board
     }
@@ -296,17 +659,16 @@ impl Board {
     }
 
     pub fn possible_removals(&self, team: Team) -> HashSet<Coords> {
-        self.possible_removals_vec(team).into_iter().collect()
+        self.removals(team).as_slice().iter().cloned().collect()
     }
 
     // This is a relatively hot function when calling best_srb_move, so it's
     // been optimized a bit.
-    fn possible_removals_vec(&self, team: Team) -> Vec<Coords> {
-        // There can never be more than 8 possible removals (one for each
-        // column), so we can pre-allocate the vector.  (There's a debug_assert
-        // below to check that this limit is correct.)
-        let results_capacity = 8;
-        let mut results = Vec::with_capacity(results_capacity);
+    fn removals(&self, team: Team) -> Removals {
+        let mut results = Removals {
+            coords: [Coords::new(0, 0); MAX_POSSIBLE_REMOVALS],
+            len: 0,
+        };
         let value = team.value();
         // The most straightforward implementation would be this:
         //     for coords in Coords::all() {
@@ -327,16 +689,22 @@ impl Board {
                     row -= 1;
                     continue;
                 } else if cell == value && self.can_remove_from(coords) {
-                    results.push(coords);
+                    results.coords[results.len] = coords;
+                    results.len += 1;
                 }
                 break;
             }
         }
-        debug_assert!(results.len() <= results_capacity);
         results
     }
 
     pub fn formation_at(&self, coords: Coords) -> Option<Vec<Coords>> {
+        self.formation_line(coords).map(|line| line.to_vec())
+    }
+
+    /// Like `formation_at`, but doesn't allocate.
+    fn formation_line(&self, coords: Coords)
+                      -> Option<[Coords; FORMATION_LINE_LENGTH]> {
         let value = self.get(coords);
         if value == 0 {
             return None;
@@ -349,16 +717,18 @@ impl Board {
             let upper_col =
                 cmp::min(8 - row, coords.col() + FORMATION_LINE_LENGTH as i32);
             if upper_col - lower_col >= FORMATION_LINE_LENGTH as i32 {
-                let mut formation = Vec::with_capacity(FORMATION_LINE_LENGTH);
+                let mut formation = [coords; FORMATION_LINE_LENGTH];
+                let mut len = 0;
                 for col in lower_col..upper_col {
                     let coords = Coords::new(row, col);
                     if self.get(coords) == value {
-                        formation.push(coords);
-                        if formation.len() >= FORMATION_LINE_LENGTH {
+                        formation[len] = coords;
+                        len += 1;
+                        if len >= FORMATION_LINE_LENGTH {
                             return Some(formation);
                         }
                     } else {
-                        formation.clear();
+                        len = 0;
                     }
                 }
             }
@@ -371,16 +741,18 @@ impl Board {
             let upper_row =
                 cmp::min(8 - col, coords.row() + FORMATION_LINE_LENGTH as i32);
             if upper_row - lower_row >= FORMATION_LINE_LENGTH as i32 {
-                let mut formation = Vec::with_capacity(FORMATION_LINE_LENGTH);
+                let mut formation = [coords; FORMATION_LINE_LENGTH];
+                let mut len = 0;
                 for row in lower_row..upper_row {
                     let coords = Coords::new(row, col);
                     if self.get(coords) == value {
-                        formation.push(coords);
-                        if formation.len() >= FORMATION_LINE_LENGTH {
+                        formation[len] = coords;
+                        len += 1;
+                        if len >= FORMATION_LINE_LENGTH {
                             return Some(formation);
                         }
                     } else {
-                        formation.clear();
+                        len = 0;
                     }
                 }
             }
@@ -393,17 +765,19 @@ impl Board {
                                      coords.row() +
                                          FORMATION_LINE_LENGTH as i32);
             if upper_row - lower_row >= FORMATION_LINE_LENGTH as i32 {
-                let mut formation = Vec::with_capacity(FORMATION_LINE_LENGTH);
+                let mut formation = [coords; FORMATION_LINE_LENGTH];
+                let mut len = 0;
                 for row in lower_row..upper_row {
                     let coords =
                         Coords::new(row, coords.col() + coords.row() - row);
                     if self.get(coords) == value {
-                        formation.push(coords);
-                        if formation.len() >= FORMATION_LINE_LENGTH {
+                        formation[len] = coords;
+                        len += 1;
+                        if len >= FORMATION_LINE_LENGTH {
                             return Some(formation);
                         }
                     } else {
-                        formation.clear();
+                        len = 0;
                     }
                 }
             }
@@ -418,13 +792,138 @@ impl Board {
         }
     }
 
-    pub fn best_srb_move(&self) -> Move {
-        let depth = MINIMAX_DEPTH;
+    fn evaluate(&self, team: Team, heuristic: Heuristic) -> f64 {
+        match heuristic {
+            Heuristic::Supply => self.favoribility(team),
+            Heuristic::Threats => {
+                let (mine, theirs) = match team {
+                    Team::You => (self.you, self.srb),
+                    Team::SRB => (self.srb, self.you),
+                };
+                let mine = mine as f64 +
+                    THREAT_VALUE * self.num_threats(team) as f64;
+                let theirs = theirs as f64 +
+                    THREAT_VALUE * self.num_threats(team.opponent()) as f64;
+                mine / theirs
+            }
+        }
+    }
+
+    /// Returns the number of open positions where the given team could
+    /// complete a formation by placing a piece.
+    fn num_threats(&self, team: Team) -> i32 {
+        let mut board = *self;
+        let mut threats = 0;
+        for coords in Coords::all() {
+            if board.can_place_at(coords) {
+                let index = coords.index();
+                board.cells[index] = team.value();
+                if board.formation_line(coords).is_some() {
+                    threats += 1;
+                }
+                board.cells[index] = 0;
+            }
+        }
+        threats
+    }
+
+    /// Returns the key for this position in the transposition table.
+    fn table_key(&self, team: Team) -> u64 {
+        match team {
+            Team::You => self.hash,
+            Team::SRB => self.hash ^ SRB_TO_MOVE_KEY,
+        }
+    }
+
+    /// Chooses a move for the SRB.  The search deepens one move at a time
+    /// until it reaches the difficulty's maximum depth or the `deadline`
+    /// passes, and then uses the results of the deepest search that finished
+    /// (the shallowest search always finishes, however long it takes).  Ties
+    /// between equally-good moves are broken using `rng`.  If `cancel` gets
+    /// set (e.g. from another thread), the search gives up as soon as
+    /// possible and returns `None`.
+    pub fn best_srb_move<R: Rng>(&self, difficulty: Difficulty,
+                                 deadline: Option<Instant>, rng: &mut R,
+                                 cancel: &AtomicBool)
+                                 -> Option<Move> {
+        let (depth, score, moves) =
+            match self.deepen_srb_search(difficulty, deadline, cancel) {
+                Some(result) => result,
+                None => return None,
+            };
+        if cfg!(debug_assertions) {
+            if moves.len() > 1 {
+                println!("Choosing randomly between {} equally-good moves \
+                          (score = {}, depth = {})",
+                         moves.len(),
+                         score,
+                         depth);
+            } else {
+                println!("Found single best move (score = {}, depth = {})",
+                         score,
+                         depth);
+            }
+        }
+        let mov = rand::seq::sample_iter(rng, moves, 1)
+            .expect("no moves were possible")
+            .pop()
+            .unwrap();
+        Some(mov)
+    }
+
+    /// Runs the iterative-deepening search for `best_srb_move`, returning the
+    /// depth of the deepest search that finished, along with its best score
+    /// and all moves that get that score (or `None` if it was cancelled).
+    fn deepen_srb_search(&self, difficulty: Difficulty,
+                         deadline: Option<Instant>, cancel: &AtomicBool)
+                         -> Option<(i32, f64, Vec<Move>)> {
+        let mut search = Search::new(difficulty.heuristic());
+        search.cancel = Some(cancel);
+        let mut best_score = 0.0;
+        let mut best_moves = Vec::new();
+        let mut finished_depth = 0;
+        for depth in 0..(difficulty.max_depth() + 1) {
+            let (score, moves) = self.best_srb_moves(depth, &mut search);
+            if search.is_cancelled() {
+                return None;
+            }
+            if search.stopped {
+                break;
+            }
+            best_score = score;
+            best_moves = moves;
+            finished_depth = depth;
+            // Once the SRB can see that it will definitely win (or lose),
+            // searching deeper won't change its mind.
+            if best_score == f64::INFINITY || best_score == 0.0 {
+                break;
+            }
+            search.deadline = deadline;
+            if search.is_past_deadline() {
+                break;
+            }
+        }
+        Some((finished_depth, best_score, best_moves))
+    }
+
+    /// Returns the best score the SRB can get by moving next, searching to
+    /// the given depth after that move, along with all moves that get that
+    /// score.
+    fn best_srb_moves(&self, depth: i32, search: &mut Search)
+                      -> (f64, Vec<Move>) {
         let mut best_score = 0.0;
         let mut best_moves = Vec::new();
         for (mov, board) in self.all_moves(Team::SRB) {
             let score = 1.0 /
-                board.minimax(depth, 0.0, 1.0 / best_score, Team::You);
+                board.minimax(depth,
+                              0.0,
+                              1.0 / best_score,
+                              Team::You,
+                              0,
+                              search);
+            if search.stopped {
+                break;
+            }
             if score > best_score {
                 best_score = score;
                 best_moves = vec![mov];
@@ -432,50 +931,158 @@ impl Board {
                 best_moves.push(mov);
             }
         }
-        if cfg!(debug_assertions) {
-            if best_moves.len() > 1 {
-                println!("Choosing randomly between {} equally-good moves \
-                          (score = {})",
-                         best_moves.len(),
-                         best_score);
-            } else {
-                println!("Found single best move (score = {})", best_score);
-            }
-        }
-        rand::seq::sample_iter(&mut rand::thread_rng(), best_moves, 1)
-            .expect("no moves were possible")
-            .pop()
-            .unwrap()
+        (best_score, best_moves)
     }
 
     /// Returns the best board score that the given team can guarantee getting
-    /// if it gets to make the next move.
-    fn minimax(&self, depth: i32, mut alpha: f64, beta: f64, team: Team)
+    /// if it gets to make the next move, where `ply` is how many moves deep
+    /// this position is in the search.  If the search runs out of time, the
+    /// result is meaningless.
+    fn minimax(&self, depth: i32, mut alpha: f64, mut beta: f64, team: Team,
+               ply: usize, search: &mut Search)
                -> f64 {
         debug_assert!(depth >= 0);
-        if depth == 0 || self.you == 0 || self.srb == 0 {
+        if self.you == 0 || self.srb == 0 {
             return self.favoribility(team);
         }
+        if depth == 0 {
+            return self.evaluate(team, search.heuristic);
+        }
+        let key = self.table_key(team);
+        let mut table_best = None;
+        if let Some(entry) = search.table.get(key) {
+            table_best = entry.best;
+            if entry.depth >= depth {
+                match entry.bound {
+                    Bound::Exact => return entry.value,
+                    Bound::Lower => alpha = alpha.max(entry.value),
+                    Bound::Upper => beta = beta.min(entry.value),
+                }
+                if alpha >= beta {
+                    return entry.value;
+                }
+            }
+        }
+        let (window_alpha, window_beta) = (alpha, beta);
+        let mut children = search.take_children(ply);
+        self.generate_children(team, &mut children);
+        search.order_children(ply, table_best, &mut children);
         let mut best = 0.0;
-        for (_, board) in self.all_moves(team) {
+        let mut best_key = None;
+        for child in children.iter() {
+            if search.should_stop() {
+                break;
+            }
             let score = 1.0 /
-                board.minimax(depth - 1,
-                              1.0 / beta,
-                              1.0 / alpha,
-                              team.opponent());
+                child.board.minimax(depth - 1,
+                                    1.0 / beta,
+                                    1.0 / alpha,
+                                    team.opponent(),
+                                    ply + 1,
+                                    search);
             if score > best {
                 best = score;
+                best_key = Some(child.key);
             }
             if score > alpha {
                 alpha = score;
             }
             if alpha >= beta {
+                search.record_cutoff(ply, child.key, depth);
                 break;
             }
         }
+        search.return_children(ply, children);
+        if !search.stopped {
+            let bound = if best <= window_alpha {
+                Bound::Upper
+            } else if best >= window_beta {
+                Bound::Lower
+            } else {
+                Bound::Exact
+            };
+            search.table.insert(TableEntry {
+                                    key: key,
+                                    depth: depth,
+                                    value: best,
+                                    bound: bound,
+                                    best: best_key,
+                                });
+        }
         best
     }
 
+    /// Fills `children` with every position that the given team can reach in
+    /// one move (replacing its previous contents), without allocating
+    /// (unless `children` needs to grow).
+    fn generate_children(&self, team: Team, children: &mut Vec<Child>) {
+        children.clear();
+        for coords in Coords::all() {
+            if self.can_place_at(coords) {
+                let board = self.with_piece_at(coords, team);
+                let key = MoveKey {
+                    from: None,
+                    to: coords,
+                };
+                board.push_children_after(key, team, children);
+            } else if self.get(coords) == team.value() &&
+                       self.can_remove_from(coords)
+            {
+                let board2 = self.with_removed(coords);
+                for coords2 in Coords::all_above_row(coords.row) {
+                    if board2.can_place_at(coords2) {
+                        let board3 = board2.with_piece_at(coords2, team);
+                        let key = MoveKey {
+                            from: Some(coords),
+                            to: coords2,
+                        };
+                        board3.push_children_after(key, team, children);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Pushes the child positions that result from the given team's piece
+    /// having just been moved to `key.to`: either just this board, or (if
+    /// that made a formation) each distinct board after removing pieces.
+    fn push_children_after(&self, key: MoveKey, team: Team,
+                           children: &mut Vec<Child>) {
+        if self.formation_line(key.to).is_none() {
+            children.push(Child {
+                              key: key,
+                              board: *self,
+                              order: 0,
+                          });
+            return;
+        }
+        let start = children.len();
+        let push_unique = |board: Board, children: &mut Vec<Child>| {
+            if !children[start..]
+                .iter()
+                .any(|child| child.board.hash == board.hash &&
+                         child.board.cells[..] == board.cells[..])
+            {
+                children.push(Child {
+                                  key: key,
+                                  board: board,
+                                  order: 0,
+                              });
+            }
+        };
+        for &coords1 in self.removals(team).as_slice() {
+            let board2 = self.with_removed(coords1);
+            let removals2 = board2.removals(team);
+            if removals2.len == 0 {
+                push_unique(board2, children);
+            } else {
+                for &coords2 in removals2.as_slice() {
+                    push_unique(board2.with_removed(coords2), children);
+                }
+            }
+        }
+    }
+
     fn all_moves(&self, team: Team) -> Vec<(Move, Board)> {
         let mut moves = Vec::new();
         for coords in Coords::all() {
@@ -531,18 +1138,22 @@ impl Board {
         moves
     }
 
+    /// Returns each distinct board that can result from the team removing
+    /// pieces, along with the pieces to remove.  The boards are in a fixed
+    /// order (unlike a `HashMap`'s), so that the SRB's search is
+    /// deterministic.
     fn all_removals(&self, team: Team) -> Vec<(Vec<Coords>, Board)> {
-        let mut results = HashMap::new();
-        for coords1 in self.possible_removals_vec(team) {
+        let mut results = BTreeMap::new();
+        for &coords1 in self.removals(team).as_slice() {
             let board2 = self.with_removed(coords1);
-            let removals2 = board2.possible_removals_vec(team);
-            if removals2.is_empty() {
-                let key = board2.cells.clone();
+            let removals2 = board2.removals(team);
+            if removals2.len == 0 {
+                let key = board2.cells.to_vec();
                 results.insert(key, (vec![coords1], board2));
             } else {
-                for coords2 in removals2 {
+                for &coords2 in removals2.as_slice() {
                     let board3 = board2.with_removed(coords2);
-                    let key = board3.cells.clone();
+                    let key = board3.cells.to_vec();
                     results.insert(key, (vec![coords2, coords1], board3));
                 }
             }
@@ -573,11 +1184,15 @@ impl Tomlable for Board {
 
 #[cfg(test)]
 mod tests {
+    use rand::{ChaChaRng, Rng, SeedableRng};
     use std::collections::HashSet;
-    use std::f64;
+    use std::{f64, u32};
+    use std::sync::atomic::AtomicBool;
+    use std::time::{Duration, Instant};
 
     use save::util::Tomlable;
-    use super::{Board, Coords, Move, NUM_CELLS, Team};
+    use super::{Board, Coords, Difficulty, Heuristic, Move, NUM_CELLS,
+                Search, Team};
 
     #[test]
     fn team_values() {
@@ -594,6 +1209,13 @@ mod tests {
         assert_eq!(Coords::all().count(), NUM_CELLS);
     }
 
+    #[test]
+    fn coords_toml_round_trip() {
+        for coords in Coords::all() {
+            assert_eq!(Coords::from_toml(coords.to_toml()), coords);
+        }
+    }
+
     #[test]
     fn coords_index() {
         let mut indices = HashSet::new();
@@ -729,24 +1351,367 @@ mod tests {
         assert_eq!(board.you_supply(), 2);
         assert_eq!(board.srb_supply(), 2);
         // Test that best_srb_move() finds the winning move.
-        assert_eq!(board.best_srb_move(), Move::Jump {
-            from: Coords::new(4, 3),
-            to: Coords::new(6, 0),
-            formation: vec![],
-            remove: vec![],
-        });
+        for &difficulty in &[Difficulty::Normal,
+                             Difficulty::Hard,
+                             Difficulty::Expert]
+        {
+            let mut rng = ChaChaRng::from_seed(&[1]);
+            let cancel = AtomicBool::new(false);
+            assert_eq!(board.best_srb_move(difficulty,
+                                           None,
+                                           &mut rng,
+                                           &cancel),
+                       Some(Move::Jump {
+                                from: Coords::new(4, 3),
+                                to: Coords::new(6, 0),
+                                formation: vec![],
+                                remove: vec![],
+                            }));
+        }
         // With high enough minimax depth, we can see that you definitely lose
         // if you go first (score 0), and the SRB wins if it goes first (score
         // infinity).
-        assert_eq!(board.minimax(9, 0.0, f64::INFINITY, Team::You), 0.0);
-        assert_eq!(board.minimax(9, 0.0, f64::INFINITY, Team::SRB),
-                   f64::INFINITY);
+        let minimax = |depth, team| {
+            // Use a fresh search each time, so that results from deeper
+            // searches in the transposition table don't leak into shallower
+            // ones.
+            let search = &mut Search::new(Heuristic::Supply);
+            let score = board.minimax(depth, 0.0, f64::INFINITY, team, 0,
+                                      search);
+            assert!(!search.stopped);
+            score
+        };
+        assert_eq!(minimax(9, Team::You), 0.0);
+        assert_eq!(minimax(9, Team::SRB), f64::INFINITY);
         // With a minimax depth of one, we can only see the results of the
         // first move: if you make a move, you end up with a supply of 1
         // vs. the SRB's 2 (score 1/2), but if the SRB makes a move, it can
         // maintain the 2/2 supply ratio by making a jump (score 1).
-        assert_eq!(board.minimax(1, 0.0, f64::INFINITY, Team::You), 0.5);
-        assert_eq!(board.minimax(1, 0.0, f64::INFINITY, Team::SRB), 1.0);
+        assert_eq!(minimax(1, Team::You), 0.5);
+        assert_eq!(minimax(1, Team::SRB), 1.0);
+    }
+
+    #[test]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    fn board_threats() {
+        let board = Board::from_cells(vec![0,
+                                         0, 0,
+                                       0, 0, 0,
+                                     0, 0, 0, 0,
+                                   0, 0, 0, 0, 0,
+                                 0, 0, 0, 0, 0, 0,
+                               1, 1, 0, 0, 0, 0, 0,
+                             2, 2, 2, 0, 1, 1, 1, 0]);
+        assert_eq!(board.num_threats(Team::SRB), 1);
+        assert_eq!(board.num_threats(Team::You), 2);
+        assert_eq!(board.evaluate(Team::You, Heuristic::Supply),
+                   board.favoribility(Team::You));
+        assert_eq!(board.favoribility(Team::You), 13.0 / 15.0);
+        assert_eq!(board.evaluate(Team::You, Heuristic::Threats),
+                   14.0 / 15.5);
+        assert_eq!(board.evaluate(Team::SRB, Heuristic::Threats),
+                   15.5 / 14.0);
+    }
+
+    #[test]
+    fn difficulty_toml_round_trip() {
+        for original in Difficulty::all() {
+            let result = Difficulty::from_toml(original.to_toml());
+            assert_eq!(result, original);
+        }
+        assert_eq!(Difficulty::from_toml(false.to_toml()), Difficulty::Hard);
+    }
+
+    #[test]
+    fn srb_move_ties_depend_only_on_seed() {
+        // On an empty board, every placement looks the same at shallow depth,
+        // so the choice is made by the RNG.
+        let board = Board::new();
+        let mut chosen = HashSet::new();
+        for seed in 0..20 {
+            let mut rng1 = ChaChaRng::from_seed(&[seed]);
+            let mut rng2 = ChaChaRng::from_seed(&[seed]);
+            let easy = Difficulty::Easy;
+            let cancel = AtomicBool::new(false);
+            let move1 = board.best_srb_move(easy, None, &mut rng1, &cancel)
+                .unwrap();
+            let move2 = board.best_srb_move(easy, None, &mut rng2, &cancel)
+                .unwrap();
+            match move1 {
+                Move::Place { at, .. } => {
+                    assert_eq!(at.row(), 0);
+                    chosen.insert(at);
+                }
+                Move::Jump { .. } => panic!("unexpected jump: {:?}", move1),
+            }
+            assert_eq!(move1, move2);
+        }
+        assert!(chosen.len() > 1);
+    }
+
+    #[test]
+    fn srb_move_without_deadline_depends_only_on_seed() {
+        // With no deadline, the search goes as deep as the difficulty allows,
+        // so it gives the same move every time, however long it takes.
+        let cancel = AtomicBool::new(false);
+        for &difficulty in &[Difficulty::Normal, Difficulty::Expert] {
+            for seed in 0..3 {
+                let board = random_board(seed, 6 + seed as usize);
+                let mut rng1 = ChaChaRng::from_seed(&[seed]);
+                let mut rng2 = ChaChaRng::from_seed(&[seed]);
+                let move1 =
+                    board.best_srb_move(difficulty, None, &mut rng1, &cancel);
+                let move2 =
+                    board.best_srb_move(difficulty, None, &mut rng2, &cancel);
+                assert!(move1.is_some());
+                assert_eq!(move1, move2);
+            }
+        }
+    }
+
+    #[test]
+    fn srb_move_past_deadline() {
+        // Even with no time at all, the SRB still finishes the shallowest
+        // search and picks a legal move.
+        let mut board = Board::new();
+        board.set_piece_at(Coords::new(0, 3), Team::You);
+        let deadline = Some(Instant::now());
+        let cancel = AtomicBool::new(false);
+        let hard = Difficulty::Hard;
+        let (depth, _, moves) =
+            board.deepen_srb_search(hard, deadline, &cancel).unwrap();
+        assert_eq!(depth, 0);
+        let mut rng = ChaChaRng::from_seed(&[1]);
+        let mov = board.best_srb_move(hard, deadline, &mut rng, &cancel)
+            .unwrap();
+        assert!(moves.contains(&mov));
+        match mov {
+            Move::Place { at, .. } => assert!(board.can_place_at(at)),
+            mov => panic!("unexpected move: {:?}", mov),
+        }
+    }
+
+    #[test]
+    fn cancelled_srb_move() {
+        let board = Board::new();
+        let mut rng = ChaChaRng::from_seed(&[1]);
+        let cancel = AtomicBool::new(true);
+        let hard = Difficulty::Hard;
+        assert_eq!(board.best_srb_move(hard, None, &mut rng, &cancel), None);
+    }
+
+    /// Returns the board reached by playing the given number of random moves
+    /// (alternating teams, you first) from an empty board.
+    fn random_board(seed: u32, num_moves: usize) -> Board {
+        let mut rng = ChaChaRng::from_seed(&[seed]);
+        let mut board = Board::new();
+        let mut team = Team::You;
+        for _ in 0..num_moves {
+            if board.you == 0 || board.srb == 0 {
+                break;
+            }
+            let mut moves = board.all_moves(team);
+            // Sort the moves, since all_moves doesn't promise any particular
+            // order, and we want the same board for the same seed.
+            moves.sort_by(|a, b| a.1.cells.cmp(&b.1.cells));
+            let index = rng.gen_range(0, moves.len());
+            board = moves.swap_remove(index).1;
+            team = team.opponent();
+        }
+        board
+    }
+
+    /// A straightforward alpha-beta search with no transposition table or
+    /// move ordering, to check the real search against.
+    fn plain_minimax(board: &Board, depth: i32, mut alpha: f64, beta: f64,
+                     team: Team, heuristic: Heuristic)
+                     -> f64 {
+        if board.you == 0 || board.srb == 0 {
+            return board.favoribility(team);
+        }
+        if depth == 0 {
+            return board.evaluate(team, heuristic);
+        }
+        let mut best = 0.0;
+        for (_, child) in board.all_moves(team) {
+            let score = 1.0 /
+                plain_minimax(&child,
+                              depth - 1,
+                              1.0 / beta,
+                              1.0 / alpha,
+                              team.opponent(),
+                              heuristic);
+            best = if score > best { score } else { best };
+            alpha = if score > alpha { score } else { alpha };
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn children_match_all_moves() {
+        for seed in 0..20 {
+            let board = random_board(seed, (seed % 10) as usize);
+            for &team in &[Team::You, Team::SRB] {
+                let mut children = Vec::new();
+                board.generate_children(team, &mut children);
+                let mut expected: Vec<Vec<i8>> = board.all_moves(team)
+                    .into_iter()
+                    .map(|(_, child)| child.cells.to_vec())
+                    .collect();
+                let mut actual: Vec<Vec<i8>> = children.iter()
+                    .map(|child| child.board.cells.to_vec())
+                    .collect();
+                expected.sort();
+                actual.sort();
+                assert_eq!(actual, expected);
+                for child in children.iter() {
+                    let rehashed = Board::from_cells(child.board
+                                                         .cells
+                                                         .to_vec());
+                    assert_eq!(child.board.hash, rehashed.hash);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn minimax_matches_plain_search() {
+        for seed in 0..12 {
+            let board = random_board(seed, 4 + (seed % 8) as usize);
+            for &heuristic in &[Heuristic::Supply, Heuristic::Threats] {
+                for &team in &[Team::You, Team::SRB] {
+                    let search = &mut Search::new(heuristic);
+                    // Search to increasing depths with the same table, as
+                    // best_srb_move does.
+                    for depth in 1..4 {
+                        let expected = plain_minimax(&board,
+                                                     depth,
+                                                     0.0,
+                                                     f64::INFINITY,
+                                                     team,
+                                                     heuristic);
+                        let actual = board.minimax(depth,
+                                                   0.0,
+                                                   f64::INFINITY,
+                                                   team,
+                                                   0,
+                                                   search);
+                        assert_eq!(actual, expected);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the best score and moves for the SRB as the original search
+    /// found them (before iterative deepening, the transposition table and
+    /// move ordering), by a plain alpha-beta search to the given depth after
+    /// each of the SRB's moves.
+    fn baseline_srb_moves(board: &Board, depth: i32) -> (f64, Vec<Move>) {
+        let mut best_score = 0.0;
+        let mut best_moves = Vec::new();
+        for (mov, child) in board.all_moves(Team::SRB) {
+            let score = 1.0 /
+                plain_minimax(&child,
+                              depth,
+                              0.0,
+                              1.0 / best_score,
+                              Team::You,
+                              Heuristic::Supply);
+            if score > best_score {
+                best_score = score;
+                best_moves = vec![mov];
+            } else if score == best_score {
+                best_moves.push(mov);
+            }
+        }
+        (best_score, best_moves)
+    }
+
+    /// Compares the SRB's move at Hard difficulty, within its time budget,
+    /// against the original depth-9 search over the same positions, reporting
+    /// how deep the budgeted search got and how often the two agree.  Run
+    /// with `cargo test --release bench -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_depth_9_search() {
+        let hard = Difficulty::Hard;
+        let budget =
+            Duration::from_millis(hard.time_budget_millis() as u64);
+        let cancel = AtomicBool::new(false);
+        let num_boards = 12;
+        let mut baseline_total = Duration::from_secs(0);
+        let mut search_total = Duration::from_secs(0);
+        let mut num_full_depth = 0;
+        let mut num_same_score = 0;
+        let mut num_same_move = 0;
+        for seed in 0..num_boards {
+            let board = random_board(seed, 4 + 2 * seed as usize);
+            let start = Instant::now();
+            let (baseline_score, baseline_moves) =
+                baseline_srb_moves(&board, 9);
+            let baseline_time = start.elapsed();
+            let start = Instant::now();
+            let deadline = Some(start + budget);
+            let (depth, score, _) =
+                board.deepen_srb_search(hard, deadline, &cancel).unwrap();
+            let mut rng = ChaChaRng::from_seed(&[seed]);
+            let mov = board.best_srb_move(hard, deadline, &mut rng, &cancel)
+                .unwrap();
+            let search_time = start.elapsed();
+            let same_move = baseline_moves.contains(&mov);
+            println!("seed {}: depth {}, score {} (baseline {}), {} \
+                      baseline move, {:?} vs. {:?}",
+                     seed,
+                     depth,
+                     score,
+                     baseline_score,
+                     if same_move { "a" } else { "NOT a" },
+                     search_time,
+                     baseline_time);
+            if depth == hard.max_depth() {
+                num_full_depth += 1;
+            }
+            if score == baseline_score {
+                num_same_score += 1;
+            }
+            if same_move {
+                num_same_move += 1;
+            }
+            baseline_total += baseline_time;
+            search_total += search_time;
+        }
+        println!("reached depth 9 on {}/{} boards, same score on {}/{}, \
+                  same move on {}/{}",
+                 num_full_depth,
+                 num_boards,
+                 num_same_score,
+                 num_boards,
+                 num_same_move,
+                 num_boards);
+        println!("total: {:?} vs. {:?}", search_total, baseline_total);
+        assert!(search_total < baseline_total);
+    }
+
+    /// Measures how long the SRB takes to choose a move at Hard difficulty
+    /// with no deadline.  Run with `cargo test --release bench --
+    /// --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_hard_srb_move() {
+        let mut rng = ChaChaRng::from_seed(&[1]);
+        let cancel = AtomicBool::new(false);
+        for seed in 0..6 {
+            let board = random_board(seed, 6 + 2 * seed as usize);
+            let start = Instant::now();
+            let hard = Difficulty::Hard;
+            let mov = board.best_srb_move(hard, None, &mut rng, &cancel);
+            println!("seed {}: {:?} in {:?}", seed, mov, start.elapsed());
+            assert!(mov.is_some());
+        }
     }
 }
 
@@ -0,0 +1,81 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use rand::{self, ChaChaRng, Rng, SeedableRng};
+use std::cell::{Cell, RefCell};
+
+// ========================================================================= //
+
+thread_local! {
+    static GAME_RNG: RefCell<ChaChaRng> = RefCell::new(random_rng());
+    static GAME_RNG_SEEDED: Cell<bool> = Cell::new(false);
+}
+
+fn random_rng() -> ChaChaRng {
+    let seed: u32 = rand::random();
+    ChaChaRng::from_seed(&[seed])
+}
+
+/// Reseeds the random number generator used for random choices in game
+/// logic on the current thread, so that the same sequence of player inputs
+/// will produce the same results.  This is used when recording or replaying
+/// input events.
+pub fn seed_game_rng(seed: u32) {
+    GAME_RNG.with(|rng| *rng.borrow_mut() = ChaChaRng::from_seed(&[seed]));
+    GAME_RNG_SEEDED.with(|seeded| seeded.set(true));
+}
+
+/// Returns true if `seed_game_rng` has been called on the current thread, in
+/// which case game logic must make the same choices however fast the
+/// computer is (e.g. it mustn't cut a search short when time runs out).
+pub fn game_rng_is_seeded() -> bool { GAME_RNG_SEEDED.with(Cell::get) }
+
+/// Returns a random value from the game logic random number generator.
+pub fn game_random<T: rand::Rand>() -> T {
+    GAME_RNG.with(|rng| rng.borrow_mut().gen())
+}
+
+/// Calls `func` with the game logic random number generator.
+pub fn with_game_rng<R, F: FnOnce(&mut ChaChaRng) -> R>(func: F) -> R {
+    GAME_RNG.with(|rng| func(&mut rng.borrow_mut()))
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use super::{game_random, game_rng_is_seeded, seed_game_rng};
+
+    #[test]
+    fn same_seed_gives_same_values() {
+        seed_game_rng(12345);
+        let first: Vec<u32> = (0..10).map(|_| game_random()).collect();
+        seed_game_rng(12345);
+        let second: Vec<u32> = (0..10).map(|_| game_random()).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn seeding_is_remembered() {
+        seed_game_rng(12345);
+        assert!(game_rng_is_seeded());
+    }
+}
+
+// ========================================================================= //
@@ -0,0 +1,231 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// ========================================================================= //
+
+/// The most states that a search for a hint should visit before giving up;
+/// this keeps hints quick enough to compute in response to a click.
+pub const HINT_MAX_STATES: usize = 100_000;
+
+/// A puzzle state with a discrete set of moves, which can be searched for a
+/// solution by `shortest_solution`.
+pub trait Solvable: Clone {
+    type Move: Clone;
+    type Key: Eq + Hash;
+
+    /// Returns a value that identifies this state for the purposes of the
+    /// search; two states with equal keys must have the same legal moves and
+    /// lead to the same states.
+    fn search_key(&self) -> Self::Key;
+
+    fn legal_moves(&self) -> Vec<Self::Move>;
+
+    fn apply_move(&mut self, mv: &Self::Move);
+
+    fn is_goal(&self) -> bool;
+}
+
+/// Performs a breadth-first search from `start`, and returns a shortest
+/// sequence of moves that reaches a goal state.  Returns `None` if no goal
+/// state is reachable, or if the search visits more than `max_states`
+/// distinct states without finding one.
+pub fn shortest_solution<S: Solvable>(start: &S, max_states: usize)
+                                      -> Option<Vec<S::Move>> {
+    shortest_path(start, S::is_goal, max_states)
+}
+
+/// Returns the next move to make from `state` in order to reach a goal by
+/// way of `route`, a known solution starting from `origin`.  If `state` lies
+/// along the route, this is just the route's next move; otherwise, it is the
+/// first move of a shortest path back onto the route (or straight to a
+/// goal).  Returns `None` if `state` is already a goal, or if no such path is
+/// found within `max_states` states.
+pub fn next_move_along<S: Solvable>(origin: &S, route: &[S::Move], state: &S,
+                                    max_states: usize)
+                                    -> Option<S::Move> {
+    if state.is_goal() {
+        return None;
+    }
+    let mut steps = HashMap::new();
+    let mut waypoint = origin.clone();
+    for (index, mv) in route.iter().enumerate() {
+        steps.insert(waypoint.search_key(), index);
+        waypoint.apply_move(mv);
+    }
+    if let Some(&index) = steps.get(&state.search_key()) {
+        return Some(route[index].clone());
+    }
+    let is_target =
+        |next: &S| next.is_goal() || steps.contains_key(&next.search_key());
+    shortest_path(state, is_target, max_states)
+        .and_then(|path| path.into_iter().next())
+}
+
+fn shortest_path<S, F>(start: &S, is_target: F, max_states: usize)
+                       -> Option<Vec<S::Move>>
+where
+    S: Solvable,
+    F: Fn(&S) -> bool,
+{
+    if is_target(start) {
+        return Some(Vec::new());
+    }
+    let mut visited = HashSet::new();
+    visited.insert(start.search_key());
+    // Each entry is the index of the parent state and the move that was made
+    // from the parent to reach the state.
+    let mut parents: Vec<(usize, Option<S::Move>)> = vec![(0, None)];
+    let mut queue = VecDeque::new();
+    queue.push_back((0, start.clone()));
+    while let Some((index, state)) = queue.pop_front() {
+        for mv in state.legal_moves() {
+            let mut next = state.clone();
+            next.apply_move(&mv);
+            if !visited.insert(next.search_key()) {
+                continue;
+            }
+            let next_index = parents.len();
+            parents.push((index, Some(mv)));
+            if is_target(&next) {
+                return Some(path_to(&parents, next_index));
+            }
+            if parents.len() > max_states {
+                return None;
+            }
+            queue.push_back((next_index, next));
+        }
+    }
+    None
+}
+
+fn path_to<M: Clone>(parents: &[(usize, Option<M>)], mut index: usize)
+                     -> Vec<M> {
+    let mut moves = Vec::new();
+    while let (parent, Some(ref mv)) = parents[index] {
+        moves.push(mv.clone());
+        index = parent;
+    }
+    moves.reverse();
+    moves
+}
+
+/// Applies a sequence of moves to a copy of `start`, and returns the
+/// resulting state.
+#[cfg(test)]
+pub fn apply_moves<S: Solvable>(start: &S, moves: &[S::Move]) -> S {
+    let mut state = start.clone();
+    for mv in moves.iter() {
+        state.apply_move(mv);
+    }
+    state
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use super::{Solvable, apply_moves, next_move_along, shortest_solution};
+
+    /// A number that can be incremented or doubled, with the goal of
+    /// reaching a target value.
+    #[derive(Clone)]
+    struct Counter {
+        value: u32,
+        target: u32,
+    }
+
+    impl Solvable for Counter {
+        type Move = char;
+        type Key = u32;
+
+        fn search_key(&self) -> u32 { self.value }
+
+        fn legal_moves(&self) -> Vec<char> {
+            if self.value > self.target {
+                Vec::new()
+            } else {
+                vec!['+', '*']
+            }
+        }
+
+        fn apply_move(&mut self, &mv: &char) {
+            if mv == '+' {
+                self.value += 1;
+            } else {
+                self.value *= 2;
+            }
+        }
+
+        fn is_goal(&self) -> bool { self.value == self.target }
+    }
+
+    #[test]
+    fn already_solved() {
+        let counter = Counter { value: 5, target: 5 };
+        assert_eq!(shortest_solution(&counter, 100), Some(vec![]));
+    }
+
+    #[test]
+    fn finds_shortest_path() {
+        let counter = Counter { value: 1, target: 12 };
+        let solution = shortest_solution(&counter, 100).unwrap();
+        assert_eq!(solution, vec!['+', '+', '*', '*']);
+        assert!(apply_moves(&counter, &solution).is_goal());
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let counter = Counter { value: 5, target: 3 };
+        assert_eq!(shortest_solution(&counter, 100), None);
+    }
+
+    #[test]
+    fn gives_up_after_max_states() {
+        let counter = Counter { value: 1, target: 1000 };
+        assert_eq!(shortest_solution(&counter, 10), None);
+        assert!(shortest_solution(&counter, 1000).is_some());
+    }
+
+    #[test]
+    fn next_move_follows_route() {
+        let origin = Counter { value: 1, target: 12 };
+        let route = ['+', '+', '*', '*'];
+        let state = Counter { value: 3, target: 12 };
+        assert_eq!(next_move_along(&origin, &route, &state, 100), Some('*'));
+        let state = Counter { value: 12, target: 12 };
+        assert_eq!(next_move_along(&origin, &route, &state, 100), None);
+    }
+
+    #[test]
+    fn next_move_returns_to_route() {
+        let origin = Counter { value: 1, target: 12 };
+        let route = ['+', '+', '*', '*'];
+        // From 4, adding one twice leads back to 6, which is on the route.
+        let state = Counter { value: 4, target: 12 };
+        assert_eq!(next_move_along(&origin, &route, &state, 100), Some('+'));
+        // From 13, there is no way back onto the route or to the goal.
+        let state = Counter { value: 13, target: 12 };
+        assert_eq!(next_move_along(&origin, &route, &state, 100), None);
+    }
+}
+
+// ========================================================================= //
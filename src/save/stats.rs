@@ -0,0 +1,270 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::cell::Cell;
+use std::time::{SystemTime, UNIX_EPOCH};
+use toml;
+
+use save::util::{Tomlable, to_table};
+
+// ========================================================================= //
+
+const MOVES_KEY: &str = "moves";
+const UNDOS_KEY: &str = "undos";
+const RESETS_KEY: &str = "resets";
+const SECONDS_KEY: &str = "seconds";
+const FIRST_SOLVED_KEY: &str = "first_solved";
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// ========================================================================= //
+
+/// Statistics about how the player has fared on a single puzzle.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PuzzleStats {
+    pub moves: i32,
+    pub undos: i32,
+    pub resets: i32,
+    pub seconds: i32,
+    /// The day on which the puzzle was first solved, counted in days since
+    /// 1970-01-01 (UTC).
+    pub first_solved: Option<i32>,
+}
+
+impl PuzzleStats {
+    pub fn is_empty(&self) -> bool { *self == PuzzleStats::default() }
+
+    /// Records today as the day of first solve, unless one is already set.
+    pub fn mark_solved_today(&mut self) {
+        if self.first_solved.is_none() {
+            self.first_solved = Some(today());
+        }
+    }
+
+    /// Returns lines of text describing these statistics, for display to the
+    /// player.
+    pub fn summary(&self) -> Vec<String> {
+        let solved = match self.first_solved {
+            Some(day) => format!("First solved: {}", format_date(day)),
+            None => "Not yet solved".to_string(),
+        };
+        vec![
+            format!("Moves made: {}", self.moves),
+            format!("Undos: {}", self.undos),
+            format!("Resets: {}", self.resets),
+            format!("Time spent: {}", format_duration(self.seconds)),
+            solved,
+        ]
+    }
+}
+
+impl Tomlable for PuzzleStats {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        table.insert(MOVES_KEY.to_string(), self.moves.to_toml());
+        table.insert(UNDOS_KEY.to_string(), self.undos.to_toml());
+        table.insert(RESETS_KEY.to_string(), self.resets.to_toml());
+        table.insert(SECONDS_KEY.to_string(), self.seconds.to_toml());
+        if let Some(day) = self.first_solved {
+            table.insert(FIRST_SOLVED_KEY.to_string(),
+                         toml::Value::String(format_date(day)));
+        }
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> PuzzleStats {
+        let mut table = to_table(value);
+        let first_solved =
+            parse_date(&String::pop_from_table(&mut table, FIRST_SOLVED_KEY));
+        PuzzleStats {
+            moves: i32::pop_from_table(&mut table, MOVES_KEY).max(0),
+            undos: i32::pop_from_table(&mut table, UNDOS_KEY).max(0),
+            resets: i32::pop_from_table(&mut table, RESETS_KEY).max(0),
+            seconds: i32::pop_from_table(&mut table, SECONDS_KEY).max(0),
+            first_solved: first_solved,
+        }
+    }
+}
+
+// ========================================================================= //
+
+thread_local! {
+    static PINNED_DAY: Cell<Option<i32>> = Cell::new(None);
+}
+
+/// Fixes the day that `today` returns on the current thread, so that solve
+/// dates don't depend on when the game is played.  This is used when
+/// recording or replaying input events.
+pub fn pin_today(day: i32) { PINNED_DAY.with(|pinned| pinned.set(Some(day))); }
+
+/// Returns the current day, counted in days since 1970-01-01 (UTC), or the
+/// day given to `pin_today` if there was one.
+pub fn today() -> i32 {
+    if let Some(day) = PINNED_DAY.with(Cell::get) {
+        return day;
+    }
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    (since_epoch / SECONDS_PER_DAY) as i32
+}
+
+fn format_duration(seconds: i32) -> String {
+    let minutes = seconds / 60;
+    if minutes < 60 {
+        format!("{}m {:02}s", minutes, seconds % 60)
+    } else {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    }
+}
+
+/// Formats a day number (as returned by `today`) as a YYYY-MM-DD date.
+fn format_date(day: i32) -> String {
+    let (year, month, day) = civil_from_days(day);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Parses a YYYY-MM-DD date into a day number, or returns `None` if the
+/// string isn't a valid date.
+fn parse_date(string: &str) -> Option<i32> {
+    let parts: Vec<&str> = string.split('-').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let year = parts[0].parse::<i32>().ok()?;
+    let month = parts[1].parse::<i32>().ok()?;
+    let day = parts[2].parse::<i32>().ok()?;
+    let number = days_from_civil(year, month, day);
+    if civil_from_days(number) == (year, month, day) {
+        Some(number)
+    } else {
+        None
+    }
+}
+
+/// Converts a number of days since 1970-01-01 into a (year, month, day)
+/// date in the proleptic Gregorian calendar.
+fn civil_from_days(days: i32) -> (i32, i32, i32) {
+    let days = days + 719468;
+    let era = if days >= 0 { days } else { days - 146096 } / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 +
+                           day_of_era / 36524 -
+                           day_of_era / 146096) / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Converts a (year, month, day) date in the proleptic Gregorian calendar
+/// into a number of days since 1970-01-01.
+fn days_from_civil(year: i32, month: i32, day: i32) -> i32 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 +
+        day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use toml;
+
+    use save::util::Tomlable;
+    use super::{PuzzleStats, civil_from_days, days_from_civil,
+                format_duration, parse_date, pin_today};
+
+    #[test]
+    fn toml_round_trip() {
+        let stats = PuzzleStats {
+            moves: 123,
+            undos: 4,
+            resets: 2,
+            seconds: 3725,
+            first_solved: Some(17000),
+        };
+        assert_eq!(PuzzleStats::from_toml(stats.to_toml()), stats);
+    }
+
+    #[test]
+    fn from_empty_toml() {
+        let stats = PuzzleStats::from_toml(toml::Value::Boolean(false));
+        assert!(stats.is_empty());
+    }
+
+    #[test]
+    fn from_invalid_toml() {
+        let mut table = toml::value::Table::new();
+        table.insert("moves".to_string(), toml::Value::Integer(-7));
+        table.insert("first_solved".to_string(),
+                     toml::Value::String("2017-02-30".to_string()));
+        let stats = PuzzleStats::from_toml(toml::Value::Table(table));
+        assert_eq!(stats.moves, 0);
+        assert_eq!(stats.first_solved, None);
+    }
+
+    #[test]
+    fn pinned_day_is_used_for_solve_date() {
+        pin_today(17226);
+        let mut stats = PuzzleStats::default();
+        stats.mark_solved_today();
+        assert_eq!(stats.first_solved, Some(17226));
+        pin_today(17300);
+        stats.mark_solved_today();
+        assert_eq!(stats.first_solved, Some(17226));
+    }
+
+    #[test]
+    fn date_conversions() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+        assert_eq!(days_from_civil(2017, 3, 1), 17226);
+        for day in -1000..30000 {
+            let (year, month, date) = civil_from_days(day);
+            assert_eq!(days_from_civil(year, month, date), day);
+        }
+        assert_eq!(parse_date("2000-02-29"), Some(11016));
+        assert_eq!(parse_date("2001-02-29"), None);
+        assert_eq!(parse_date("yesterday"), None);
+    }
+
+    #[test]
+    fn duration_formatting() {
+        assert_eq!(format_duration(0), "0m 00s");
+        assert_eq!(format_duration(125), "2m 05s");
+        assert_eq!(format_duration(3725), "1h 02m");
+    }
+}
+
+// ========================================================================= //
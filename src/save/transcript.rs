@@ -0,0 +1,214 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use toml;
+
+use save::util::{Tomlable, to_table};
+
+// ========================================================================= //
+
+const SPEAKER_KEY: &str = "speaker";
+const STYLE_KEY: &str = "style";
+const TEXT_KEY: &str = "text";
+const SCENE_KEY: &str = "scene";
+const LINE_KEY: &str = "line";
+
+// ========================================================================= //
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TalkStyle {
+    Comm,
+    Evil,
+    Good,
+    Normal,
+    System,
+    Thought,
+}
+
+impl TalkStyle {
+    fn name(self) -> &'static str {
+        match self {
+            TalkStyle::Comm => "comm",
+            TalkStyle::Evil => "evil",
+            TalkStyle::Good => "good",
+            TalkStyle::Normal => "normal",
+            TalkStyle::System => "system",
+            TalkStyle::Thought => "thought",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<TalkStyle> {
+        match name {
+            "comm" => Some(TalkStyle::Comm),
+            "evil" => Some(TalkStyle::Evil),
+            "good" => Some(TalkStyle::Good),
+            "normal" => Some(TalkStyle::Normal),
+            "system" => Some(TalkStyle::System),
+            "thought" => Some(TalkStyle::Thought),
+            _ => None,
+        }
+    }
+}
+
+// ========================================================================= //
+
+/// One line of dialogue spoken during a cutscene.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TranscriptLine {
+    pub speaker: String,
+    pub style: TalkStyle,
+    pub text: String,
+    /// The ID of the scene that the line is from (e.g. `cube_tangle/intro`),
+    /// or an empty string if the scene has no ID.
+    pub scene: String,
+    /// The position of the line within its scene, counting from zero.
+    pub line: usize,
+}
+
+impl Tomlable for TranscriptLine {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        table.insert(SPEAKER_KEY.to_string(), self.speaker.to_toml());
+        table.insert(STYLE_KEY.to_string(),
+                     toml::Value::String(self.style.name().to_string()));
+        table.insert(TEXT_KEY.to_string(), self.text.to_toml());
+        if !self.scene.is_empty() {
+            table.insert(SCENE_KEY.to_string(), self.scene.to_toml());
+            table.insert(LINE_KEY.to_string(), self.line.to_toml());
+        }
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> TranscriptLine {
+        let mut table = to_table(value);
+        let style = String::pop_from_table(&mut table, STYLE_KEY);
+        TranscriptLine {
+            speaker: String::pop_from_table(&mut table, SPEAKER_KEY),
+            style: TalkStyle::from_name(&style).unwrap_or(TalkStyle::Normal),
+            text: String::pop_from_table(&mut table, TEXT_KEY),
+            scene: String::pop_from_table(&mut table, SCENE_KEY),
+            line: usize::pop_from_table(&mut table, LINE_KEY),
+        }
+    }
+}
+
+// ========================================================================= //
+
+/// The lines of dialogue that the player has seen at one location, in the
+/// order in which they were first seen.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Transcript {
+    lines: Vec<TranscriptLine>,
+}
+
+impl Transcript {
+    pub fn is_empty(&self) -> bool { self.lines.is_empty() }
+
+    pub fn lines(&self) -> &[TranscriptLine] { &self.lines }
+
+    /// Adds a line to the end of the transcript, unless the same line of the
+    /// same scene is already in it (for example, when replaying a scene).
+    /// Lines that aren't from an identified scene are always added.
+    pub fn record(&mut self, line: TranscriptLine) {
+        let is_repeat = |other: &TranscriptLine| {
+            other.scene == line.scene && other.line == line.line
+        };
+        if line.scene.is_empty() || !self.lines.iter().any(is_repeat) {
+            self.lines.push(line);
+        }
+    }
+}
+
+impl Tomlable for Transcript {
+    fn to_toml(&self) -> toml::Value { self.lines.to_toml() }
+
+    fn from_toml(value: toml::Value) -> Transcript {
+        let mut transcript = Transcript::default();
+        for line in Vec::<TranscriptLine>::from_toml(value) {
+            if !line.text.is_empty() {
+                transcript.record(line);
+            }
+        }
+        transcript
+    }
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use save::util::Tomlable;
+    use super::{TalkStyle, Transcript, TranscriptLine};
+
+    fn line(speaker: &str, style: TalkStyle, text: &str) -> TranscriptLine {
+        TranscriptLine {
+            speaker: speaker.to_string(),
+            style: style,
+            text: text.to_string(),
+            scene: String::new(),
+            line: 0,
+        }
+    }
+
+    fn scene_line(scene: &str, index: usize, text: &str) -> TranscriptLine {
+        TranscriptLine {
+            scene: scene.to_string(),
+            line: index,
+            ..line("Elinsa", TalkStyle::Normal, text)
+        }
+    }
+
+    #[test]
+    fn record_skips_replayed_scene_lines() {
+        let mut transcript = Transcript::default();
+        assert!(transcript.is_empty());
+        transcript.record(scene_line("cube_tangle/outro", 0, "Ha!"));
+        transcript.record(scene_line("cube_tangle/outro", 1, "Huh?"));
+        transcript.record(scene_line("cube_tangle/outro", 0, "Ha!"));
+        transcript.record(scene_line("cube_tangle/outro", 1, "Huh?"));
+        assert_eq!(transcript.lines(),
+                   &[scene_line("cube_tangle/outro", 0, "Ha!"),
+                     scene_line("cube_tangle/outro", 1, "Huh?")]);
+    }
+
+    #[test]
+    fn record_keeps_repeated_text() {
+        let mut transcript = Transcript::default();
+        transcript.record(scene_line("cube_tangle/outro", 1, "Huh?"));
+        transcript.record(scene_line("cube_tangle/outro", 4, "Huh?"));
+        transcript.record(scene_line("cube_tangle/extra2", 1, "Huh?"));
+        transcript.record(line("Elinsa", TalkStyle::Normal, "Huh?"));
+        transcript.record(line("Elinsa", TalkStyle::Normal, "Huh?"));
+        assert_eq!(transcript.lines().len(), 5);
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut transcript = Transcript::default();
+        for &style in &[TalkStyle::Comm, TalkStyle::Evil, TalkStyle::Good,
+                        TalkStyle::Normal, TalkStyle::System,
+                        TalkStyle::Thought] {
+            transcript.record(line("System", style, "Line one\nLine two"));
+        }
+        transcript.record(scene_line("cube_tangle/intro", 3, "Um."));
+        assert_eq!(Transcript::from_toml(transcript.to_toml()), transcript);
+    }
+}
+
+// ========================================================================= //
@@ -27,6 +27,7 @@ use gui::Point;
 // ========================================================================= //
 
 pub const ACCESS_KEY: &str = "access";
+pub const HINTS_KEY: &str = "hints";
 
 pub fn pop_array(table: &mut toml::value::Table, key: &str)
                  -> toml::value::Array {
@@ -169,6 +170,97 @@ impl Tomlable for u32 {
     }
 }
 
+impl Tomlable for usize {
+    fn to_toml(&self) -> toml::Value { toml::Value::Integer(*self as i64) }
+
+    fn from_toml(value: toml::Value) -> usize {
+        match value {
+            toml::Value::Integer(integer) => {
+                if integer < 0 {
+                    0
+                } else if (integer as u64) > (usize::MAX as u64) {
+                    usize::MAX
+                } else {
+                    integer as usize
+                }
+            }
+            _ => 0,
+        }
+    }
+}
+
+impl Tomlable for char {
+    fn to_toml(&self) -> toml::Value { toml::Value::String(self.to_string()) }
+
+    fn from_toml(value: toml::Value) -> char {
+        String::from_toml(value).chars().next().unwrap_or(' ')
+    }
+}
+
+impl Tomlable for () {
+    fn to_toml(&self) -> toml::Value {
+        toml::Value::Table(toml::value::Table::new())
+    }
+
+    fn from_toml(_value: toml::Value) -> () { () }
+}
+
+// Tuples are stored as tables keyed by position, rather than as arrays, since
+// TOML arrays can't mix types.
+
+impl<A: Tomlable, B: Tomlable> Tomlable for (A, B) {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        table.insert("0".to_string(), self.0.to_toml());
+        table.insert("1".to_string(), self.1.to_toml());
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> (A, B) {
+        let mut table = to_table(value);
+        let a = A::pop_from_table(&mut table, "0");
+        let b = B::pop_from_table(&mut table, "1");
+        (a, b)
+    }
+}
+
+impl<A: Tomlable, B: Tomlable, C: Tomlable> Tomlable for (A, B, C) {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        table.insert("0".to_string(), self.0.to_toml());
+        table.insert("1".to_string(), self.1.to_toml());
+        table.insert("2".to_string(), self.2.to_toml());
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> (A, B, C) {
+        let mut table = to_table(value);
+        (A::pop_from_table(&mut table, "0"),
+         B::pop_from_table(&mut table, "1"),
+         C::pop_from_table(&mut table, "2"))
+    }
+}
+
+impl<A: Tomlable, B: Tomlable, C: Tomlable, D: Tomlable> Tomlable
+    for (A, B, C, D) {
+    fn to_toml(&self) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        table.insert("0".to_string(), self.0.to_toml());
+        table.insert("1".to_string(), self.1.to_toml());
+        table.insert("2".to_string(), self.2.to_toml());
+        table.insert("3".to_string(), self.3.to_toml());
+        toml::Value::Table(table)
+    }
+
+    fn from_toml(value: toml::Value) -> (A, B, C, D) {
+        let mut table = to_table(value);
+        (A::pop_from_table(&mut table, "0"),
+         B::pop_from_table(&mut table, "1"),
+         C::pop_from_table(&mut table, "2"),
+         D::pop_from_table(&mut table, "3"))
+    }
+}
+
 impl<T: Hash + Ord + Tomlable> Tomlable for HashSet<T> {
     fn to_toml(&self) -> toml::Value {
         let mut vector: Vec<&T> = self.iter().collect();
@@ -308,6 +400,30 @@ mod tests {
                    4294967295);
         assert_eq!(u32::from_toml(toml::Value::Integer(-2147483648)), 0);
     }
+
+    #[test]
+    fn usize_and_char_to_toml() {
+        assert_eq!(usize::from_toml(toml::Value::Integer(-1)), 0);
+        assert_eq!(usize::from_toml(toml::Value::Integer(42)), 42);
+        assert_eq!(char::from_toml('Q'.to_toml()), 'Q');
+        assert_eq!(char::from_toml(toml::Value::Integer(1)), ' ');
+    }
+
+    #[test]
+    fn tuples_to_toml() {
+        let pair = (3usize, -4);
+        assert_eq!(<(usize, i32)>::from_toml(pair.to_toml()), pair);
+        let triple = (1, 'x', true);
+        assert_eq!(<(i32, char, bool)>::from_toml(triple.to_toml()), triple);
+        let quad = (1, 2, 'a', 'b');
+        let value = vec![quad, quad].to_toml();
+        // Mixed-type tuples must still serialize as valid TOML.
+        let mut table = toml::value::Table::new();
+        table.insert("quads".to_string(), value.clone());
+        assert!(!toml::Value::Table(table).to_string().is_empty());
+        assert_eq!(Vec::<(i32, i32, char, char)>::from_toml(value),
+                   vec![quad, quad]);
+    }
 }
 
 // ========================================================================= //
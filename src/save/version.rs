@@ -0,0 +1,179 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::io;
+use toml;
+
+// ========================================================================= //
+
+const VERSION_KEY: &str = "version";
+
+/// The save file format version written by this version of the game.  Bump
+/// this (and add an entry to `MIGRATIONS`) whenever the format of the save
+/// data changes in a way that older save files need to be converted for.
+pub const CURRENT_VERSION: i64 = 2;
+
+/// Each entry converts a save table from version `n` to version `n + 1`,
+/// where `n` is the entry's index.  Version 0 is the original, unversioned
+/// save file format.
+const MIGRATIONS: &[fn(&mut toml::value::Table)] =
+    &[migrate_v0_to_v1, migrate_v1_to_v2];
+
+// ========================================================================= //
+
+/// Returns the format version of the given (top-level) save table.  Save
+/// files written before versioning was introduced have no version key, and
+/// are treated as version 0.
+pub fn save_version(table: &toml::value::Table) -> io::Result<i64> {
+    match table.get(VERSION_KEY) {
+        None => Ok(0),
+        Some(&toml::Value::Integer(version)) if version >= 0 => Ok(version),
+        Some(_) => {
+            Err(io::Error::new(io::ErrorKind::InvalidData,
+                               "invalid save file version"))
+        }
+    }
+}
+
+/// Upgrades the given (top-level) save table in place to `CURRENT_VERSION`,
+/// running each needed migration in turn.  Returns an error rather than
+/// touching the table if the save file is from a newer version of the game,
+/// since loading it anyway would quietly discard the player's progress.
+pub fn migrate_to_current(table: &mut toml::value::Table) -> io::Result<()> {
+    let version = save_version(table)?;
+    if version > CURRENT_VERSION {
+        let msg = format!("save file version {} is newer than the latest \
+                           supported version ({})",
+                          version,
+                          CURRENT_VERSION);
+        return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+    }
+    for migration in MIGRATIONS[(version as usize)..].iter() {
+        migration(table);
+    }
+    set_current_version(table);
+    Ok(())
+}
+
+pub fn set_current_version(table: &mut toml::value::Table) {
+    table.insert(VERSION_KEY.to_string(),
+                 toml::Value::Integer(CURRENT_VERSION));
+}
+
+// ========================================================================= //
+
+/// Version 1 has the same layout as version 0; it only adds the version key
+/// itself.
+fn migrate_v0_to_v1(_table: &mut toml::value::Table) {}
+
+/// Version 2 adds save slots.  The single game from version 1 (if any)
+/// becomes the game in the first slot.
+fn migrate_v1_to_v2(table: &mut toml::value::Table) {
+    let mut slot = toml::value::Table::new();
+    if let Some(game) = table.remove("game") {
+        slot.insert("game".to_string(), game);
+    }
+    table.insert("slots".to_string(),
+                 toml::Value::Array(vec![toml::Value::Table(slot)]));
+    table.insert("current_slot".to_string(), toml::Value::Integer(0));
+}
+
+// ========================================================================= //
+
+#[cfg(test)]
+mod tests {
+    use toml;
+
+    use super::{CURRENT_VERSION, MIGRATIONS, VERSION_KEY, migrate_to_current,
+                migrate_v1_to_v2, save_version};
+
+    #[test]
+    fn one_migration_per_version() {
+        assert_eq!(MIGRATIONS.len() as i64, CURRENT_VERSION);
+    }
+
+    #[test]
+    fn unversioned_is_version_zero() {
+        let table = toml::value::Table::new();
+        assert_eq!(save_version(&table).unwrap(), 0);
+    }
+
+    #[test]
+    fn migrate_unversioned() {
+        let mut table = toml::value::Table::new();
+        migrate_to_current(&mut table).unwrap();
+        assert_eq!(save_version(&table).unwrap(), CURRENT_VERSION);
+    }
+
+    #[test]
+    fn migrate_game_into_first_slot() {
+        let mut game = toml::value::Table::new();
+        game.insert("location".to_string(),
+                    toml::Value::String("cube_tangle".to_string()));
+        let mut table = toml::value::Table::new();
+        table.insert("game".to_string(), toml::Value::Table(game.clone()));
+        migrate_v1_to_v2(&mut table);
+        assert!(!table.contains_key("game"));
+        assert_eq!(table.get("current_slot"), Some(&toml::Value::Integer(0)));
+        let slots = table.get("slots").unwrap().as_array().unwrap();
+        assert_eq!(slots.len(), 1);
+        assert_eq!(slots[0].get("game"), Some(&toml::Value::Table(game)));
+    }
+
+    #[test]
+    fn migrate_version_1_save() {
+        let string = include_str!("testdata/save_v1.toml");
+        let mut table = match string.parse::<toml::Value>().unwrap() {
+            toml::Value::Table(table) => table,
+            _ => panic!("save file is not a table"),
+        };
+        let game = table.get("game").unwrap().clone();
+        let prefs = table.get("prefs").unwrap().clone();
+        assert_eq!(save_version(&table).unwrap(), 1);
+        migrate_to_current(&mut table).unwrap();
+        assert_eq!(save_version(&table).unwrap(), CURRENT_VERSION);
+        assert!(!table.contains_key("game"));
+        assert_eq!(table.get("prefs"), Some(&prefs));
+        assert_eq!(table.get("current_slot"), Some(&toml::Value::Integer(0)));
+        let slots = table.get("slots").unwrap().as_array().unwrap();
+        assert_eq!(slots.len(), 1);
+        assert_eq!(slots[0].get("game"), Some(&game));
+    }
+
+    #[test]
+    fn reject_newer_version() {
+        let mut table = toml::value::Table::new();
+        table.insert(VERSION_KEY.to_string(),
+                     toml::Value::Integer(CURRENT_VERSION + 1));
+        assert!(migrate_to_current(&mut table).is_err());
+        assert_eq!(save_version(&table).unwrap(), CURRENT_VERSION + 1);
+    }
+
+    #[test]
+    fn reject_invalid_version() {
+        let mut table = toml::value::Table::new();
+        table.insert(VERSION_KEY.to_string(),
+                     toml::Value::String("1".to_string()));
+        assert!(migrate_to_current(&mut table).is_err());
+        table.insert(VERSION_KEY.to_string(), toml::Value::Integer(-1));
+        assert!(migrate_to_current(&mut table).is_err());
+    }
+}
+
+// ========================================================================= //
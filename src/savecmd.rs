@@ -0,0 +1,167 @@
+// +--------------------------------------------------------------------------+
+// | Copyright 2016 Matthew D. Steele <mdsteele@alum.mit.edu>                 |
+// |                                                                          |
+// | This file is part of System Syzygy.                                      |
+// |                                                                          |
+// | System Syzygy is free software: you can redistribute it and/or modify it |
+// | under the terms of the GNU General Public License as published by the    |
+// | Free Software Foundation, either version 3 of the License, or (at your   |
+// | option) any later version.                                               |
+// |                                                                          |
+// | System Syzygy is distributed in the hope that it will be useful, but     |
+// | WITHOUT ANY WARRANTY; without even the implied warranty of               |
+// | MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU        |
+// | General Public License for details.                                      |
+// |                                                                          |
+// | You should have received a copy of the GNU General Public License along  |
+// | with System Syzygy.  If not, see <http://www.gnu.org/licenses/>.         |
+// +--------------------------------------------------------------------------+
+
+use std::path::{Path, PathBuf};
+
+use gui::{Sound, render_sound, save_wav};
+use save::{Location, SaveData};
+use save::util::Tomlable;
+
+// ========================================================================= //
+
+/// The sample rate that `render-sound` uses, matching the rate the game asks
+/// the audio device for.
+const SOUND_SAMPLE_RATE: i32 = 44100;
+
+/// How long `render-sound` lets a sound play before cutting it off.
+const MAX_SOUND_SECONDS: f32 = 60.0;
+
+pub const USAGE: &str = "\
+Save file commands (these don't open a window, and act on the game in the
+currently selected save slot):
+    list                 list every location and its access
+    dump LOCATION        print the state of one puzzle as TOML
+    solve LOCATION...    mark puzzles solved (\"all\" for every puzzle)
+
+Other commands (these don't open a window or touch the save file):
+    render-sound NAME FILE
+                         render a sound effect (e.g. \"beep\" or
+                         \"platform_shift_3\") to a WAV file";
+
+// ========================================================================= //
+
+/// Runs a save file command (or one of the other commands listed in `USAGE`)
+/// without initializing SDL, so that it works on a machine with no display
+/// or audio device.  Returns the process exit code.
+pub fn run_save_command(save_file: PathBuf, args: &[String]) -> i32 {
+    if let ("render-sound", &[ref name, ref path]) =
+        (&args[0] as &str, &args[1..])
+    {
+        return exit_code(write_sound(name, Path::new(path)));
+    }
+    let mut save_data = match SaveData::load_or_create(save_file) {
+        Ok(save_data) => save_data,
+        Err(error) => {
+            eprintln!("Error: failed to load save file: {}", error);
+            return 1;
+        }
+    };
+    let result = match (&args[0] as &str, &args[1..]) {
+        ("list", &[]) => list_locations(&save_data),
+        ("dump", &[ref key]) => dump_location(&save_data, key),
+        ("solve", keys) if !keys.is_empty() => {
+            solve_locations(&mut save_data, keys)
+        }
+        _ => Err(format!("invalid command: {}", args.join(" "))),
+    };
+    exit_code(result)
+}
+
+fn exit_code(result: Result<(), String>) -> i32 {
+    match result {
+        Ok(()) => 0,
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            eprintln!("Run with --help to see available commands.");
+            1
+        }
+    }
+}
+
+fn list_locations(save_data: &SaveData) -> Result<(), String> {
+    let game = match save_data.game() {
+        Some(game) => game,
+        None => {
+            println!("No game in progress.");
+            return Ok(());
+        }
+    };
+    println!("Save slot: {}", save_data.slot_name(save_data.current_slot()));
+    println!("Current location: {}", game.location.key());
+    for &location in Location::all() {
+        if location == Location::Map {
+            continue;
+        }
+        let access = game.puzzle_state(location).access().to_toml();
+        println!("{:<22} {:<13} {}",
+                 location.key(),
+                 access.as_str().unwrap_or(""),
+                 location.name());
+    }
+    Ok(())
+}
+
+fn dump_location(save_data: &SaveData, key: &str) -> Result<(), String> {
+    let location = parse_location(key)?;
+    match save_data.game() {
+        Some(game) => print!("{}", game.puzzle_state(location).to_toml()),
+        None => println!("No game in progress."),
+    }
+    Ok(())
+}
+
+fn solve_locations(save_data: &mut SaveData, keys: &[String])
+                   -> Result<(), String> {
+    let mut locations = Vec::new();
+    for key in keys.iter() {
+        if key == "all" {
+            locations.extend(Location::all()
+                                 .iter()
+                                 .filter(|&&loc| loc != Location::Map));
+        } else {
+            locations.push(parse_location(key)?);
+        }
+    }
+    {
+        let game = save_data.game_mut();
+        for &location in locations.iter() {
+            game.solve_puzzle(location);
+            println!("Solved {}.", location.key());
+        }
+    }
+    save_data
+        .save_to_disk()
+        .map_err(|error| format!("failed to save game: {}", error))
+}
+
+fn write_sound(name: &str, path: &Path) -> Result<(), String> {
+    let sound = match Sound::named(name) {
+        Some(sound) => sound,
+        None => return Err(format!("no such sound: {}", name)),
+    };
+    let samples = render_sound(sound, SOUND_SAMPLE_RATE, MAX_SOUND_SECONDS);
+    save_wav(path, SOUND_SAMPLE_RATE, &samples).map_err(|error| {
+        format!("failed to write {}: {}", path.display(), error)
+    })?;
+    // The samples are interleaved stereo, so there are two per frame:
+    let seconds = samples.len() as f32 / (2 * SOUND_SAMPLE_RATE) as f32;
+    println!("Wrote {} ({:.2} seconds).", path.display(), seconds);
+    Ok(())
+}
+
+fn parse_location(key: &str) -> Result<Location, String> {
+    match Location::from_key(key) {
+        Some(Location::Map) | None => {
+            Err(format!("no such puzzle location: {}", key))
+        }
+        Some(location) => Ok(location),
+    }
+}
+
+// ========================================================================= //